@@ -0,0 +1,196 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#ifndef IOTA_SDK_H
+#define IOTA_SDK_H
+
+/* Generated with cbindgen:0.26.0 */
+
+// This file is generated by cbindgen from the sources of the iota-sdk-c crate, don't edit it.
+
+#include <stdbool.h>
+
+// A client handle.
+typedef struct IotaClient IotaClient;
+
+// A secret manager handle.
+typedef struct IotaSecretManager IotaSecretManager;
+
+// A wallet handle.
+typedef struct IotaWallet IotaWallet;
+
+// A callback that is called with an event as JSON string and the user data it was registered with.
+typedef void (*IotaCallback)(const char *event, void *user_data);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Initializes the logger with a JSON logger output config. Returns `false` on failure.
+//
+// # Safety
+//
+// `config` must be a NUL-terminated UTF-8 string.
+bool iota_init_logger(const char *config);
+
+// Calls a utils method given as JSON and returns the JSON response, to be freed with `iota_destroy_string`.
+// Returns null on failure.
+//
+// # Safety
+//
+// `method` must be a NUL-terminated UTF-8 string.
+char *iota_call_utils_method(const char *method);
+
+// Frees a string returned by the library. Does nothing if `string` is null.
+//
+// # Safety
+//
+// `string` must be null or a string returned by the library that wasn't freed yet.
+void iota_destroy_string(char *string);
+
+// Returns the message of the last error on the calling thread and clears it, to be freed with
+// `iota_destroy_string`. Returns null if there is none.
+char *iota_get_last_error(void);
+
+// Creates a client from JSON client options, or with the default options if `options` is null. Returns null on
+// failure, otherwise the client has to be freed with `iota_destroy_client`.
+//
+// # Safety
+//
+// `options` must be null or a NUL-terminated UTF-8 string.
+IotaClient *iota_create_client(const char *options);
+
+// Frees a client. Does nothing if `client` is null.
+//
+// # Safety
+//
+// `client` must be null or a client returned by the library that wasn't freed yet and isn't used anymore.
+void iota_destroy_client(IotaClient *client);
+
+// Calls a client method given as JSON and returns the JSON response, to be freed with
+// `iota_destroy_string`. Returns null on failure.
+//
+// # Safety
+//
+// `client` must be a live client and `method` a NUL-terminated UTF-8 string.
+char *iota_call_client_method(const IotaClient *client, const char *method);
+
+// Cancels a client method that was called with `operation_id`, it stops at its next cancellation point. Returns
+// `false` if no such method is running or on failure.
+//
+// # Safety
+//
+// `client` must be a live client and `operation_id` a NUL-terminated UTF-8 string.
+bool iota_cancel_client_operation(const IotaClient *client,
+                                  const char *operation_id);
+
+// Subscribes to the MQTT topics given as JSON array of strings. The callback is called with the JSON event and
+// `user_data`. Returns `false` on failure.
+//
+// # Safety
+//
+// `client` must be a live client, `topics` a NUL-terminated UTF-8 string and `user_data` must be usable from any
+// thread until the client and all clients sharing its connection are freed.
+bool iota_listen_mqtt(const IotaClient *client,
+                      const char *topics,
+                      IotaCallback callback,
+                      void *user_data);
+
+// Creates a secret manager from JSON secret manager options. Returns null on failure, otherwise the secret manager
+// has to be freed with `iota_destroy_secret_manager`.
+//
+// # Safety
+//
+// `options` must be a NUL-terminated UTF-8 string.
+IotaSecretManager *iota_create_secret_manager(const char *options);
+
+// Frees a secret manager. Does nothing if `secret_manager` is null.
+//
+// # Safety
+//
+// `secret_manager` must be null or a secret manager returned by the library that wasn't freed yet and isn't used
+// anymore.
+void iota_destroy_secret_manager(IotaSecretManager *secret_manager);
+
+// Calls a secret manager method given as JSON and returns the JSON response, to be freed with
+// `iota_destroy_string`. Returns null on failure.
+//
+// # Safety
+//
+// `secret_manager` must be a live secret manager and `method` a NUL-terminated UTF-8 string.
+char *iota_call_secret_manager_method(const IotaSecretManager *secret_manager, const char *method);
+
+// Creates a wallet from JSON wallet options. Returns null on failure, otherwise the wallet has to be freed with
+// `iota_destroy_wallet`.
+//
+// # Safety
+//
+// `options` must be a NUL-terminated UTF-8 string.
+IotaWallet *iota_create_wallet(const char *options);
+
+// Frees a wallet. Does nothing if `wallet` is null.
+//
+// # Safety
+//
+// `wallet` must be null or a wallet returned by the library that wasn't freed yet and isn't used anymore.
+void iota_destroy_wallet(IotaWallet *wallet);
+
+// Calls a wallet method given as JSON and returns the JSON response, to be freed with
+// `iota_destroy_string`. Returns null on failure.
+//
+// # Safety
+//
+// `wallet` must be a live wallet and `method` a NUL-terminated UTF-8 string.
+char *iota_call_wallet_method(const IotaWallet *wallet, const char *method);
+
+// Cancels a wallet method that was called with `operation_id`, it stops at its next cancellation point. Returns
+// `false` if no such method is running or on failure.
+//
+// # Safety
+//
+// `wallet` must be a live wallet and `operation_id` a NUL-terminated UTF-8 string.
+bool iota_cancel_wallet_operation(const IotaWallet *wallet,
+                                  const char *operation_id);
+
+// Registers a callback for the wallet events given as JSON array of event types, or for all events if it's empty.
+// The callback is called with the JSON event and `user_data`. Returns `false` on failure.
+//
+// # Safety
+//
+// `wallet` must be a live wallet, `events` a NUL-terminated UTF-8 string and `user_data` must be usable from any
+// thread until the listeners are cleared with `iota_clear_wallet_listeners` or the wallet is freed.
+bool iota_listen_wallet(const IotaWallet *wallet,
+                        const char *events,
+                        IotaCallback callback,
+                        void *user_data);
+
+// Removes the callbacks for the wallet events given as JSON array of event types, or all callbacks if it's empty.
+// Returns `false` on failure.
+//
+// # Safety
+//
+// `wallet` must be a live wallet and `events` a NUL-terminated UTF-8 string.
+bool iota_clear_wallet_listeners(const IotaWallet *wallet,
+                                 const char *events);
+
+// Returns the client of a wallet, to be freed with `iota_destroy_client`. Returns
+// null on failure.
+//
+// # Safety
+//
+// `wallet` must be a live wallet.
+IotaClient *iota_get_client_from_wallet(const IotaWallet *wallet);
+
+// Returns the secret manager of a wallet, to be freed with
+// `iota_destroy_secret_manager`. Returns null on failure.
+//
+// # Safety
+//
+// `wallet` must be a live wallet.
+IotaSecretManager *iota_get_secret_manager_from_wallet(const IotaWallet *wallet);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* IOTA_SDK_H */
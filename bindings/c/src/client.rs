@@ -0,0 +1,115 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ffi::{c_char, c_void};
+
+use iota_sdk_bindings_core::{
+    iota_sdk::client::{mqtt::Topic, Client, ClientBuilder},
+    listen_mqtt, CallMethod, ClientMethod, Operations,
+};
+
+use crate::{
+    block_on, borrow_handle, borrow_str, call_method, destroy_handle, ffi_call, into_handle, Callback, IotaCallback,
+};
+
+/// A client handle.
+pub struct IotaClient {
+    client: Client,
+    operations: Operations,
+}
+
+impl IotaClient {
+    pub(crate) fn new(client: Client) -> Self {
+        Self {
+            client,
+            operations: Operations::default(),
+        }
+    }
+}
+
+/// Creates a client from JSON client options, or with the default options if `options` is null. Returns null on
+/// failure, otherwise the client has to be freed with `iota_destroy_client`.
+///
+/// # Safety
+///
+/// `options` must be null or a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_create_client(options: *const c_char) -> *mut IotaClient {
+    ffi_call(std::ptr::null_mut(), || {
+        let builder = match options.is_null() {
+            true => ClientBuilder::new(),
+            false => ClientBuilder::new().from_json(borrow_str(options, "options")?)?,
+        };
+        let client = block_on(builder.finish())?;
+
+        Ok(into_handle(IotaClient::new(client)))
+    })
+}
+
+/// Frees a client. Does nothing if `client` is null.
+///
+/// # Safety
+///
+/// `client` must be null or a client returned by the library that wasn't freed yet and isn't used anymore.
+#[no_mangle]
+pub unsafe extern "C" fn iota_destroy_client(client: *mut IotaClient) {
+    destroy_handle(client)
+}
+
+/// Calls a client method given as JSON and returns the JSON response, to be freed with
+/// `iota_destroy_string`. Returns null on failure.
+///
+/// # Safety
+///
+/// `client` must be a live client and `method` a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_call_client_method(client: *const IotaClient, method: *const c_char) -> *mut c_char {
+    ffi_call(std::ptr::null_mut(), || {
+        let IotaClient { client, operations } = borrow_handle(client, "client")?;
+        call_method::<ClientMethod>(borrow_str(method, "method")?, |method| {
+            block_on(client.call_versioned_method(operations, method))
+        })
+    })
+}
+
+/// Cancels a client method that was called with `operation_id`, it stops at its next cancellation point. Returns
+/// `false` if no such method is running or on failure.
+///
+/// # Safety
+///
+/// `client` must be a live client and `operation_id` a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_cancel_client_operation(client: *const IotaClient, operation_id: *const c_char) -> bool {
+    ffi_call(false, || {
+        let operations = &borrow_handle(client, "client")?.operations;
+        Ok(operations.cancel(borrow_str(operation_id, "operation_id")?))
+    })
+}
+
+/// Subscribes to the MQTT topics given as JSON array of strings. The callback is called with the JSON event and
+/// `user_data`. Returns `false` on failure.
+///
+/// # Safety
+///
+/// `client` must be a live client, `topics` a NUL-terminated UTF-8 string and `user_data` must be usable from any
+/// thread until the client and all clients sharing its connection are freed.
+#[no_mangle]
+pub unsafe extern "C" fn iota_listen_mqtt(
+    client: *const IotaClient,
+    topics: *const c_char,
+    callback: IotaCallback,
+    user_data: *mut c_void,
+) -> bool {
+    ffi_call(false, || {
+        let client = &borrow_handle(client, "client")?.client;
+        let topics = serde_json::from_str::<Vec<String>>(borrow_str(topics, "topics")?)?
+            .into_iter()
+            .map(Topic::new)
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let callback = Callback::new(callback, user_data)?;
+
+        block_on(listen_mqtt(client, topics, move |event| callback.call(event)));
+
+        Ok(true)
+    })
+}
@@ -0,0 +1,61 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{cell::RefCell, ffi::CString};
+
+use iota_sdk_bindings_core::iota_sdk::{client::mqtt::Error as MqttError, wallet::Error as WalletError};
+
+/// The result type of the C bindings.
+pub(crate) type Result<T> = std::result::Result<T, Error>;
+
+/// Error type of the C bindings, returned by [`iota_get_last_error`](crate::iota_get_last_error).
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum Error {
+    /// Bindings core errors.
+    #[error("{0}")]
+    Bindings(#[from] iota_sdk_bindings_core::Error),
+    /// Client errors.
+    #[error("{0}")]
+    Client(#[from] iota_sdk_bindings_core::iota_sdk::client::Error),
+    /// Wallet errors.
+    #[error("{0}")]
+    Wallet(#[from] WalletError),
+    /// MQTT errors.
+    #[error("{0}")]
+    Mqtt(#[from] MqttError),
+    /// SerdeJson errors.
+    #[error("{0}")]
+    SerdeJson(#[from] serde_json::Error),
+    /// The logger couldn't be initialized.
+    #[error("failed to initialize the logger: {0}")]
+    Logger(String),
+    /// A wallet event type that doesn't exist.
+    #[error("{0}")]
+    EventType(String),
+    /// A required pointer argument was null.
+    #[error("`{0}` is null")]
+    NullPointer(&'static str),
+    /// A string argument wasn't valid UTF-8.
+    #[error("`{0}` is not valid UTF-8")]
+    InvalidUtf8(&'static str),
+    /// A function panicked.
+    #[error("panic: {0}")]
+    Panic(String),
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Stores the error as the last error of the calling thread.
+pub(crate) fn set_last_error(error: Error) {
+    log::debug!("C bindings error: {error:?}");
+    // Interior NUL bytes are replaced, so the message can always be returned
+    let message = CString::new(error.to_string().replace('\0', " ")).expect("no NUL bytes");
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = Some(message));
+}
+
+/// Takes the last error of the calling thread.
+pub(crate) fn take_last_error() -> Option<CString> {
+    LAST_ERROR.with(|last_error| last_error.borrow_mut().take())
+}
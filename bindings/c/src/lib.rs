@@ -0,0 +1,198 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # C binding implementation for the iota-sdk library.
+//!
+//! Methods, options, responses and events are exchanged as NUL-terminated UTF-8 JSON strings, in the same shape as
+//! for the other bindings.
+//!
+//! Ownership rules:
+//! - String arguments are borrowed for the duration of the call.
+//! - Returned strings are owned by the caller and have to be freed with `iota_destroy_string`.
+//! - Returned handles are owned by the caller and have to be freed with the matching `iota_destroy_*` function, after
+//!   all calls using them returned.
+//! - Strings passed to callbacks are only valid during the callback. Callbacks can be called from any thread and must
+//!   not call back into the library.
+//!
+//! Functions that fail return null or `false` and store an error message, which can be taken with
+//! `iota_get_last_error`. Errors of methods are returned as error responses instead.
+
+mod client;
+mod error;
+mod secret_manager;
+mod wallet;
+
+use std::{
+    ffi::{c_char, c_void, CStr, CString},
+    future::Future,
+    panic::{catch_unwind, AssertUnwindSafe},
+    sync::OnceLock,
+};
+
+use iota_sdk_bindings_core::{
+    call_utils_method, init_logger, ProtocolMethod, Response, UtilsMethod, VersionedMethod, VersionedResponse,
+};
+use tokio::runtime::Runtime;
+
+use self::error::{set_last_error, take_last_error, Error, Result};
+pub use self::{client::*, secret_manager::*, wallet::*};
+
+/// A callback that is called with an event as JSON string and the user data it was registered with.
+pub type IotaCallback = Option<unsafe extern "C" fn(event: *const c_char, user_data: *mut c_void)>;
+
+/// A registered callback with its user data.
+#[derive(Clone, Copy)]
+struct Callback {
+    callback: unsafe extern "C" fn(event: *const c_char, user_data: *mut c_void),
+    user_data: *mut c_void,
+}
+
+// The caller guarantees that the user data can be used from any thread, as long as the callback is registered.
+unsafe impl Send for Callback {}
+unsafe impl Sync for Callback {}
+
+impl Callback {
+    fn new(callback: IotaCallback, user_data: *mut c_void) -> Result<Self> {
+        Ok(Self {
+            callback: callback.ok_or(Error::NullPointer("callback"))?,
+            user_data,
+        })
+    }
+
+    fn call(&self, event: String) {
+        let event = CString::new(event).expect("JSON without NUL bytes");
+        unsafe { (self.callback)(event.as_ptr(), self.user_data) }
+    }
+}
+
+/// Returns the runtime all handles share, so methods can be called concurrently from multiple threads.
+pub(crate) fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| Runtime::new().expect("failed to create the runtime"))
+}
+
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    runtime().block_on(future)
+}
+
+/// Runs the body of an exported function, returns `failed` and stores the error if it fails or panics.
+pub(crate) fn ffi_call<T>(failed: T, f: impl FnOnce() -> Result<T>) -> T {
+    let error = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(value)) => return value,
+        Ok(Err(error)) => error,
+        Err(payload) => Error::Panic(
+            payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default(),
+        ),
+    };
+    set_last_error(error);
+    failed
+}
+
+/// Borrows a string argument.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a NUL-terminated string that lives for `'a`.
+pub(crate) unsafe fn borrow_str<'a>(ptr: *const c_char, name: &'static str) -> Result<&'a str> {
+    if ptr.is_null() {
+        return Err(Error::NullPointer(name));
+    }
+    CStr::from_ptr(ptr).to_str().map_err(|_| Error::InvalidUtf8(name))
+}
+
+/// Borrows a handle argument.
+///
+/// # Safety
+///
+/// `ptr` must be null or point to a live handle.
+pub(crate) unsafe fn borrow_handle<'a, T>(ptr: *const T, name: &'static str) -> Result<&'a T> {
+    ptr.as_ref().ok_or(Error::NullPointer(name))
+}
+
+/// Moves a handle to the caller.
+pub(crate) fn into_handle<T>(handle: T) -> *mut T {
+    Box::into_raw(Box::new(handle))
+}
+
+/// Frees a handle in the context of the runtime, which is needed to drop some of its tasks.
+///
+/// # Safety
+///
+/// `ptr` must be null or a handle returned by the library that wasn't freed yet.
+pub(crate) unsafe fn destroy_handle<T>(ptr: *mut T) {
+    if !ptr.is_null() {
+        let _guard = runtime().enter();
+        drop(Box::from_raw(ptr));
+    }
+}
+
+/// Moves a string to the caller.
+pub(crate) fn into_c_string(string: String) -> *mut c_char {
+    CString::new(string).expect("JSON without NUL bytes").into_raw()
+}
+
+/// Deserializes a method sent with any supported protocol version, calls it and serializes the response. Methods
+/// that can't be deserialized are answered with an error response.
+pub(crate) fn call_method<M: ProtocolMethod>(
+    method: &str,
+    call: impl FnOnce(VersionedMethod<M>) -> VersionedResponse,
+) -> Result<*mut c_char> {
+    let response = match VersionedMethod::<M>::from_json(method) {
+        Ok(method) => call(method),
+        Err(error) => VersionedResponse::from(Response::Error(error)),
+    };
+    Ok(into_c_string(serde_json::to_string(&response)?))
+}
+
+/// Initializes the logger with a JSON logger output config. Returns `false` on failure.
+///
+/// # Safety
+///
+/// `config` must be a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_init_logger(config: *const c_char) -> bool {
+    ffi_call(false, || {
+        let config = borrow_str(config, "config")?;
+        init_logger(config.to_string()).map_err(|error| Error::Logger(format!("{error:?}")))?;
+        Ok(true)
+    })
+}
+
+/// Calls a utils method given as JSON and returns the JSON response, to be freed with `iota_destroy_string`.
+/// Returns null on failure.
+///
+/// # Safety
+///
+/// `method` must be a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_call_utils_method(method: *const c_char) -> *mut c_char {
+    ffi_call(std::ptr::null_mut(), || {
+        call_method::<UtilsMethod>(borrow_str(method, "method")?, |method| VersionedResponse {
+            response: call_utils_method(method.method),
+            deprecations: method.deprecations,
+        })
+    })
+}
+
+/// Frees a string returned by the library. Does nothing if `string` is null.
+///
+/// # Safety
+///
+/// `string` must be null or a string returned by the library that wasn't freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn iota_destroy_string(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Returns the message of the last error on the calling thread and clears it, to be freed with
+/// `iota_destroy_string`. Returns null if there is none.
+#[no_mangle]
+pub extern "C" fn iota_get_last_error() -> *mut c_char {
+    take_last_error().map_or(std::ptr::null_mut(), CString::into_raw)
+}
@@ -0,0 +1,73 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{ffi::c_char, sync::Arc};
+
+use iota_sdk_bindings_core::{
+    call_secret_manager_method,
+    iota_sdk::client::secret::{SecretManager, SecretManagerDto},
+    SecretManagerMethod, VersionedResponse,
+};
+use tokio::sync::RwLock;
+
+use crate::{block_on, borrow_handle, borrow_str, call_method, destroy_handle, ffi_call, into_handle};
+
+/// A secret manager handle.
+pub struct IotaSecretManager {
+    secret_manager: Arc<RwLock<SecretManager>>,
+}
+
+impl IotaSecretManager {
+    pub(crate) fn new(secret_manager: Arc<RwLock<SecretManager>>) -> Self {
+        Self { secret_manager }
+    }
+}
+
+/// Creates a secret manager from JSON secret manager options. Returns null on failure, otherwise the secret manager
+/// has to be freed with `iota_destroy_secret_manager`.
+///
+/// # Safety
+///
+/// `options` must be a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_create_secret_manager(options: *const c_char) -> *mut IotaSecretManager {
+    ffi_call(std::ptr::null_mut(), || {
+        let options = serde_json::from_str::<SecretManagerDto>(borrow_str(options, "options")?)?;
+        let secret_manager = SecretManager::try_from(options)?;
+
+        Ok(into_handle(IotaSecretManager::new(Arc::new(RwLock::new(
+            secret_manager,
+        )))))
+    })
+}
+
+/// Frees a secret manager. Does nothing if `secret_manager` is null.
+///
+/// # Safety
+///
+/// `secret_manager` must be null or a secret manager returned by the library that wasn't freed yet and isn't used
+/// anymore.
+#[no_mangle]
+pub unsafe extern "C" fn iota_destroy_secret_manager(secret_manager: *mut IotaSecretManager) {
+    destroy_handle(secret_manager)
+}
+
+/// Calls a secret manager method given as JSON and returns the JSON response, to be freed with
+/// `iota_destroy_string`. Returns null on failure.
+///
+/// # Safety
+///
+/// `secret_manager` must be a live secret manager and `method` a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_call_secret_manager_method(
+    secret_manager: *const IotaSecretManager,
+    method: *const c_char,
+) -> *mut c_char {
+    ffi_call(std::ptr::null_mut(), || {
+        let secret_manager = &borrow_handle(secret_manager, "secret_manager")?.secret_manager;
+        call_method::<SecretManagerMethod>(borrow_str(method, "method")?, |method| VersionedResponse {
+            response: block_on(call_secret_manager_method(secret_manager, method.method)),
+            deprecations: method.deprecations,
+        })
+    })
+}
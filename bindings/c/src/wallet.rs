@@ -0,0 +1,163 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::ffi::{c_char, c_void};
+
+use iota_sdk_bindings_core::{
+    iota_sdk::wallet::{events::types::WalletEventType, Wallet},
+    CallMethod, Operations, WalletMethod, WalletOptions,
+};
+
+use crate::{
+    block_on, borrow_handle, borrow_str, call_method, destroy_handle,
+    error::{Error, Result},
+    ffi_call, into_handle, Callback, IotaCallback, IotaClient, IotaSecretManager,
+};
+
+/// A wallet handle.
+pub struct IotaWallet {
+    wallet: Wallet,
+    operations: Operations,
+}
+
+/// Parses a JSON array of wallet event types.
+fn parse_event_types(events: &str) -> Result<Vec<WalletEventType>> {
+    serde_json::from_str::<Vec<u8>>(events)?
+        .into_iter()
+        .map(|event| WalletEventType::try_from(event).map_err(Error::EventType))
+        .collect()
+}
+
+/// Creates a wallet from JSON wallet options. Returns null on failure, otherwise the wallet has to be freed with
+/// `iota_destroy_wallet`.
+///
+/// # Safety
+///
+/// `options` must be a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_create_wallet(options: *const c_char) -> *mut IotaWallet {
+    ffi_call(std::ptr::null_mut(), || {
+        let options = serde_json::from_str::<WalletOptions>(borrow_str(options, "options")?)?;
+        let wallet = block_on(options.build())?;
+
+        Ok(into_handle(IotaWallet {
+            wallet,
+            operations: Operations::default(),
+        }))
+    })
+}
+
+/// Frees a wallet. Does nothing if `wallet` is null.
+///
+/// # Safety
+///
+/// `wallet` must be null or a wallet returned by the library that wasn't freed yet and isn't used anymore.
+#[no_mangle]
+pub unsafe extern "C" fn iota_destroy_wallet(wallet: *mut IotaWallet) {
+    destroy_handle(wallet)
+}
+
+/// Calls a wallet method given as JSON and returns the JSON response, to be freed with
+/// `iota_destroy_string`. Returns null on failure.
+///
+/// # Safety
+///
+/// `wallet` must be a live wallet and `method` a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_call_wallet_method(wallet: *const IotaWallet, method: *const c_char) -> *mut c_char {
+    ffi_call(std::ptr::null_mut(), || {
+        let IotaWallet { wallet, operations } = borrow_handle(wallet, "wallet")?;
+        call_method::<WalletMethod>(borrow_str(method, "method")?, |method| {
+            block_on(wallet.call_versioned_method(operations, method))
+        })
+    })
+}
+
+/// Cancels a wallet method that was called with `operation_id`, it stops at its next cancellation point. Returns
+/// `false` if no such method is running or on failure.
+///
+/// # Safety
+///
+/// `wallet` must be a live wallet and `operation_id` a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_cancel_wallet_operation(wallet: *const IotaWallet, operation_id: *const c_char) -> bool {
+    ffi_call(false, || {
+        let operations = &borrow_handle(wallet, "wallet")?.operations;
+        Ok(operations.cancel(borrow_str(operation_id, "operation_id")?))
+    })
+}
+
+/// Registers a callback for the wallet events given as JSON array of event types, or for all events if it's empty.
+/// The callback is called with the JSON event and `user_data`. Returns `false` on failure.
+///
+/// # Safety
+///
+/// `wallet` must be a live wallet, `events` a NUL-terminated UTF-8 string and `user_data` must be usable from any
+/// thread until the listeners are cleared with `iota_clear_wallet_listeners` or the wallet is freed.
+#[no_mangle]
+pub unsafe extern "C" fn iota_listen_wallet(
+    wallet: *const IotaWallet,
+    events: *const c_char,
+    callback: IotaCallback,
+    user_data: *mut c_void,
+) -> bool {
+    ffi_call(false, || {
+        let wallet = &borrow_handle(wallet, "wallet")?.wallet;
+        let events = parse_event_types(borrow_str(events, "events")?)?;
+        let callback = Callback::new(callback, user_data)?;
+
+        block_on(wallet.listen(events, move |event| {
+            callback.call(serde_json::to_string(event).expect("json to string error"))
+        }));
+
+        Ok(true)
+    })
+}
+
+/// Removes the callbacks for the wallet events given as JSON array of event types, or all callbacks if it's empty.
+/// Returns `false` on failure.
+///
+/// # Safety
+///
+/// `wallet` must be a live wallet and `events` a NUL-terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn iota_clear_wallet_listeners(wallet: *const IotaWallet, events: *const c_char) -> bool {
+    ffi_call(false, || {
+        let wallet = &borrow_handle(wallet, "wallet")?.wallet;
+        let events = parse_event_types(borrow_str(events, "events")?)?;
+
+        block_on(wallet.clear_listeners(events));
+
+        Ok(true)
+    })
+}
+
+/// Returns the client of a wallet, to be freed with `iota_destroy_client`. Returns
+/// null on failure.
+///
+/// # Safety
+///
+/// `wallet` must be a live wallet.
+#[no_mangle]
+pub unsafe extern "C" fn iota_get_client_from_wallet(wallet: *const IotaWallet) -> *mut IotaClient {
+    ffi_call(std::ptr::null_mut(), || {
+        let wallet = &borrow_handle(wallet, "wallet")?.wallet;
+
+        Ok(into_handle(IotaClient::new(wallet.client().clone())))
+    })
+}
+
+/// Returns the secret manager of a wallet, to be freed with
+/// `iota_destroy_secret_manager`. Returns null on failure.
+///
+/// # Safety
+///
+/// `wallet` must be a live wallet.
+#[no_mangle]
+pub unsafe extern "C" fn iota_get_secret_manager_from_wallet(wallet: *const IotaWallet) -> *mut IotaSecretManager {
+    ffi_call(std::ptr::null_mut(), || {
+        let wallet = &borrow_handle(wallet, "wallet")?.wallet;
+
+        Ok(into_handle(IotaSecretManager::new(wallet.get_secret_manager().clone())))
+    })
+}
@@ -0,0 +1,27 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+// The committed header is generated with `cbindgen.toml`, run with `UPDATE_HEADER=1` to regenerate it.
+
+use std::path::Path;
+
+#[test]
+fn header_is_up_to_date() {
+    let crate_dir = env!("CARGO_MANIFEST_DIR");
+    let path = Path::new(crate_dir).join("include").join("iota_sdk.h");
+    let mut header = Vec::new();
+    cbindgen::generate(crate_dir).unwrap().write(&mut header);
+    let header = String::from_utf8(header).unwrap();
+
+    if std::env::var_os("UPDATE_HEADER").is_some() {
+        std::fs::write(&path, header).unwrap();
+        return;
+    }
+
+    let committed = std::fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        committed == header,
+        "{} is outdated, regenerate it with `UPDATE_HEADER=1`",
+        path.display()
+    );
+}
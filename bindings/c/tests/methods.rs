@@ -0,0 +1,184 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    ffi::{c_char, c_void, CStr, CString},
+    sync::{Arc, Mutex},
+};
+
+use iota_sdk_c::*;
+use serde_json::{json, Value};
+
+const MNEMONIC: &str = "endorse answer radar about source reunion marriage tag sausage weekend frost daring base attack because joke dream slender leisure group reason prepare broken river";
+
+/// Takes ownership of a string returned by the library.
+fn take_string(string: *mut c_char) -> Option<String> {
+    if string.is_null() {
+        return None;
+    }
+    let owned = unsafe { CStr::from_ptr(string) }.to_str().unwrap().to_owned();
+    unsafe { iota_destroy_string(string) };
+    Some(owned)
+}
+
+fn last_error() -> Option<String> {
+    take_string(iota_get_last_error())
+}
+
+fn parse_response(string: *mut c_char) -> Value {
+    serde_json::from_str(&take_string(string).expect("no response")).unwrap()
+}
+
+fn c_string(value: impl ToString) -> CString {
+    CString::new(value.to_string()).unwrap()
+}
+
+#[test]
+fn call_utils_method() {
+    let method = c_string(json!({ "name": "generateMnemonic", "protocolVersion": 1 }));
+    let response = parse_response(unsafe { iota_call_utils_method(method.as_ptr()) });
+
+    assert_eq!(response["type"], "generatedMnemonic");
+    assert_eq!(response["payload"].as_str().unwrap().split(' ').count(), 24);
+}
+
+#[test]
+fn invalid_method_is_an_error_response() {
+    let method = c_string(json!({ "name": "unknownMethod" }));
+    let response = parse_response(unsafe { iota_call_utils_method(method.as_ptr()) });
+    assert_eq!(response["type"], "error");
+
+    let method = c_string(json!({ "name": "generateMnemonic", "protocolVersion": 100 }));
+    let response = parse_response(unsafe { iota_call_utils_method(method.as_ptr()) });
+    assert_eq!(response["type"], "error");
+}
+
+#[test]
+fn invalid_arguments_set_the_last_error() {
+    assert_eq!(last_error(), None);
+
+    assert!(unsafe { iota_call_utils_method(std::ptr::null()) }.is_null());
+    assert_eq!(last_error().as_deref(), Some("`method` is null"));
+    // The last error is taken
+    assert_eq!(last_error(), None);
+
+    let invalid_utf8 = CString::new(vec![0xff, 0xfe]).unwrap();
+    assert!(unsafe { iota_call_utils_method(invalid_utf8.as_ptr()) }.is_null());
+    assert_eq!(last_error().as_deref(), Some("`method` is not valid UTF-8"));
+
+    let options = c_string(json!({ "unknown": "options" }));
+    assert!(unsafe { iota_create_secret_manager(options.as_ptr()) }.is_null());
+    assert!(last_error().is_some());
+
+    // Freeing null is a no-op
+    unsafe {
+        iota_destroy_string(std::ptr::null_mut());
+        iota_destroy_wallet(std::ptr::null_mut());
+        iota_destroy_client(std::ptr::null_mut());
+        iota_destroy_secret_manager(std::ptr::null_mut());
+    }
+}
+
+#[test]
+fn call_secret_manager_method() {
+    let options = c_string(json!({ "mnemonic": MNEMONIC }));
+    let secret_manager = unsafe { iota_create_secret_manager(options.as_ptr()) };
+    assert!(!secret_manager.is_null(), "{:?}", last_error());
+
+    let method = c_string(json!({
+        "name": "generateEd25519Addresses",
+        "data": { "options": { "range": { "start": 0, "end": 1 } } },
+        "protocolVersion": 1,
+    }));
+    let response = parse_response(unsafe { iota_call_secret_manager_method(secret_manager, method.as_ptr()) });
+    unsafe { iota_destroy_secret_manager(secret_manager) };
+
+    assert_eq!(response["type"], "generatedEd25519Addresses");
+    assert_eq!(
+        response["payload"][0],
+        "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy"
+    );
+}
+
+#[test]
+fn create_client() {
+    let client = unsafe { iota_create_client(std::ptr::null()) };
+    assert!(!client.is_null(), "{:?}", last_error());
+    unsafe { iota_destroy_client(client) };
+
+    let options = c_string(json!({ "nodes": ["not a url"] }));
+    assert!(unsafe { iota_create_client(options.as_ptr()) }.is_null());
+    assert!(last_error().is_some());
+}
+
+unsafe extern "C" fn collect_event(event: *const c_char, user_data: *mut c_void) {
+    let events = &*(user_data as *const Mutex<Vec<String>>);
+    events
+        .lock()
+        .unwrap()
+        .push(CStr::from_ptr(event).to_str().unwrap().to_owned());
+}
+
+#[test]
+fn wallet() {
+    let storage_path = "test-storage/c_wallet";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let options = c_string(json!({
+        "storagePath": storage_path,
+        "clientOptions": { "nodes": ["http://localhost:14265"] },
+        "coinType": 4219,
+        "secretManager": { "mnemonic": MNEMONIC },
+    }));
+    let wallet = unsafe { iota_create_wallet(options.as_ptr()) };
+    assert!(!wallet.is_null(), "{:?}", last_error());
+
+    let events = Arc::new(Mutex::new(Vec::<String>::new()));
+    let all_events = c_string("[]");
+    assert!(unsafe {
+        iota_listen_wallet(
+            wallet,
+            all_events.as_ptr(),
+            Some(collect_event),
+            Arc::as_ptr(&events) as *mut c_void,
+        )
+    });
+    let unknown_event = c_string("[255]");
+    assert!(!unsafe {
+        iota_listen_wallet(
+            wallet,
+            unknown_event.as_ptr(),
+            Some(collect_event),
+            std::ptr::null_mut(),
+        )
+    });
+    assert!(last_error().is_some());
+    assert!(!unsafe { iota_listen_wallet(wallet, all_events.as_ptr(), None, std::ptr::null_mut()) });
+    assert_eq!(last_error().as_deref(), Some("`callback` is null"));
+
+    let method = c_string(json!({
+        "name": "createAccount",
+        "data": { "alias": "Alice", "bech32Hrp": "rms" },
+        "protocolVersion": 1,
+    }));
+    let response = parse_response(unsafe { iota_call_wallet_method(wallet, method.as_ptr()) });
+    assert_eq!(response["type"], "account", "{response}");
+    assert_eq!(response["payload"]["alias"], "Alice");
+
+    let operation_id = c_string("sync");
+    assert!(!unsafe { iota_cancel_wallet_operation(wallet, operation_id.as_ptr()) });
+    assert!(last_error().is_none());
+
+    let secret_manager = unsafe { iota_get_secret_manager_from_wallet(wallet) };
+    let client = unsafe { iota_get_client_from_wallet(wallet) };
+    assert!(!secret_manager.is_null() && !client.is_null());
+
+    assert!(unsafe { iota_clear_wallet_listeners(wallet, all_events.as_ptr()) });
+    unsafe {
+        iota_destroy_secret_manager(secret_manager);
+        iota_destroy_client(client);
+        iota_destroy_wallet(wallet);
+    }
+
+    std::fs::remove_dir_all(storage_path).ok();
+}
@@ -30,6 +30,12 @@ pub enum Error {
     /// Unpack errors.
     #[error("{0}")]
     Unpack(#[from] packable::error::UnpackError<iota_sdk::types::block::Error, UnexpectedEOF>),
+    /// A method was sent with the id of an operation that is still running.
+    #[error("operation id `{0}` is already in use")]
+    OperationIdInUse(String),
+    /// A method was sent with an unsupported protocol version.
+    #[error("unsupported protocol version {0}, supported are {min} to {current}", min = crate::MIN_PROTOCOL_VERSION, current = crate::PROTOCOL_VERSION)]
+    UnsupportedProtocolVersion(u32),
 }
 
 #[cfg(feature = "stronghold")]
@@ -66,3 +72,26 @@ impl Serialize for Error {
         seq.end()
     }
 }
+
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for Error {
+    fn schema_name() -> String {
+        "Error".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut object = schemars::schema::ObjectValidation::default();
+        for field in ["type", "error"] {
+            object
+                .properties
+                .insert(field.to_string(), gen.subschema_for::<String>());
+            object.required.insert(field.to_string());
+        }
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::Object.into()),
+            object: Some(Box::new(object)),
+            ..Default::default()
+        }
+        .into()
+    }
+}
@@ -6,8 +6,12 @@
 mod error;
 mod method;
 mod method_handler;
+mod operation;
 mod panic;
+mod protocol;
 mod response;
+#[cfg(feature = "json_schema")]
+mod schema;
 
 use std::fmt::{Formatter, Result as FmtResult};
 
@@ -24,10 +28,20 @@ use serde::Deserialize;
 pub use self::method_handler::listen_mqtt;
 #[cfg(not(target_family = "wasm"))]
 pub use self::method_handler::CallMethod;
+#[cfg(feature = "json_schema")]
+pub use self::schema::json_schema;
 pub use self::{
     error::{Error, Result},
     method::{AccountMethod, ClientMethod, SecretManagerMethod, UtilsMethod, WalletMethod},
-    method_handler::{call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method},
+    method_handler::{
+        call_client_method, call_secret_manager_method, call_utils_method, call_versioned_wallet_method,
+        call_wallet_method,
+    },
+    operation::Operations,
+    protocol::{
+        Capabilities, Deprecation, ProtocolMethod, VersionedMethod, VersionedResponse, MIN_PROTOCOL_VERSION,
+        PROTOCOL_VERSION,
+    },
     response::Response,
 };
 
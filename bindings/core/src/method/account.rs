@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::path::PathBuf;
+
 #[cfg(feature = "participation")]
 use iota_sdk::{
     client::node_manager::node::Node,
@@ -20,7 +22,8 @@ use iota_sdk::{
     wallet::{
         account::{
             ConsolidationParams, CreateAliasParams, CreateNativeTokenParams, FilterOptions, MintNftParams,
-            OutputParams, OutputsToClaim, SyncOptions, TransactionOptionsDto,
+            OutputParams, OutputsToClaim, SpendingPolicy, SyncOptions, TransactionHistoryFormat,
+            TransactionHistoryOptions, TransactionOptionsDto,
         },
         SendNativeTokensParams, SendNftParams, SendParams,
     },
@@ -30,6 +33,7 @@ use serde::{Deserialize, Serialize};
 
 /// Each public account method.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(tag = "name", content = "data", rename_all = "camelCase")]
 #[non_exhaustive]
 pub enum AccountMethod {
@@ -40,12 +44,16 @@ pub enum AccountMethod {
     /// Expected response:
     /// [`AddressesWithUnspentOutputs`](crate::Response::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs,
+    /// Sign and submit the transaction of a pending approval, the approver can't be its creator.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    ApprovePendingApproval { id: String, approver: String },
     /// Get outputs with additional unlock conditions
     /// Expected response: [`OutputIds`](crate::Response::OutputIds)
     #[serde(rename_all = "camelCase")]
     ClaimableOutputs { outputs_to_claim: OutputsToClaim },
     /// Claim outputs.
-    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction), or
+    /// [`PendingApproval`](crate::Response::PendingApproval) if the spending policy requires approvals
     #[serde(rename_all = "camelCase")]
     ClaimOutputs { output_ids_to_claim: Vec<OutputId> },
     /// Removes a previously registered participation event from local storage.
@@ -54,6 +62,14 @@ pub enum AccountMethod {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[serde(rename_all = "camelCase")]
     DeregisterParticipationEvent { event_id: ParticipationEventId },
+    /// Write the transaction history of the account to a file.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    ExportTransactionHistory {
+        destination: PathBuf,
+        #[serde(default)]
+        format: TransactionHistoryFormat,
+        options: Option<TransactionHistoryOptions>,
+    },
     /// Generate new Ed25519 addresses.
     /// Expected response: [`GeneratedEd25519Addresses`](crate::Response::GeneratedEd25519Addresses)
     GenerateEd25519Addresses {
@@ -113,6 +129,12 @@ pub enum AccountMethod {
     /// Expected response: [`Transaction`](crate::Response::Transaction)
     #[serde(rename_all = "camelCase")]
     GetTransaction { transaction_id: TransactionId },
+    /// Get the spending policy of the account.
+    /// Expected response: [`SpendingPolicy`](crate::Response::SpendingPolicy)
+    GetSpendingPolicy,
+    /// Get a pending approval of the account.
+    /// Expected response: [`PendingApproval`](crate::Response::PendingApproval)
+    GetPendingApproval { id: String },
     /// Get the account's total voting power (voting or NOT voting).
     /// Expected response: [`VotingPower`](crate::Response::VotingPower)
     #[cfg(feature = "participation")]
@@ -126,6 +148,9 @@ pub enum AccountMethod {
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
     Outputs { filter_options: Option<FilterOptions> },
+    /// Returns all pending approvals of the account
+    /// Expected response: [`PendingApprovals`](crate::Response::PendingApprovals)
+    PendingApprovals,
     /// Returns all pending transactions of the account
     /// Expected response: [`Transactions`](crate::Response::Transactions)
     PendingTransactions,
@@ -178,6 +203,7 @@ pub enum AccountMethod {
         /// Native token id
         token_id: TokenId,
         /// To be melted amount
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         melt_amount: U256,
         options: Option<TransactionOptionsDto>,
     },
@@ -188,6 +214,7 @@ pub enum AccountMethod {
         /// Native token id
         token_id: TokenId,
         /// To be minted amount
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         mint_amount: U256,
         options: Option<TransactionOptionsDto>,
     },
@@ -252,6 +279,20 @@ pub enum AccountMethod {
     RegisterParticipationEvents {
         options: ParticipationEventRegistrationOptions,
     },
+    /// Remove a pending approval and unlock its inputs.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    RejectPendingApproval { id: String },
+    /// Persist a prepared transaction as pending approval instead of signing it.
+    /// Expected response: [`PendingApproval`](crate::Response::PendingApproval)
+    #[serde(rename_all = "camelCase")]
+    RequestApproval {
+        prepared_transaction_data: Box<PreparedTransactionDataDto>,
+        options: Option<TransactionOptionsDto>,
+        /// Who requests the approval
+        creator: String,
+        /// Information for the approver
+        metadata: Option<String>,
+    },
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
     /// included (referenced by a milestone). Returns the included block id.
     /// Expected response: [`BlockId`](crate::Response::BlockId)
@@ -265,21 +306,25 @@ pub enum AccountMethod {
         max_attempts: Option<u64>,
     },
     /// Send base coins.
-    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction), or
+    /// [`PendingApproval`](crate::Response::PendingApproval) if the spending policy requires approvals
     Send {
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         #[serde(with = "iota_sdk::utils::serde::string")]
         amount: u64,
         address: Bech32Address,
         options: Option<TransactionOptionsDto>,
     },
     /// Send base coins to multiple addresses, or with additional parameters.
-    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction), or
+    /// [`PendingApproval`](crate::Response::PendingApproval) if the spending policy requires approvals
     SendWithParams {
         params: Vec<SendParams>,
         options: Option<TransactionOptionsDto>,
     },
     /// Send outputs in a transaction.
-    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction), or
+    /// [`PendingApproval`](crate::Response::PendingApproval) if the spending policy requires approvals
     SendOutputs {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
@@ -291,17 +336,24 @@ pub enum AccountMethod {
     /// If storage is enabled, will persist during restarts.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetDefaultSyncOptions { options: SyncOptions },
+    /// Set the spending policy that prepared and signed transactions of the account have to satisfy.
+    /// If storage is enabled, will persist during restarts.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetSpendingPolicy { policy: SpendingPolicy },
     /// Validate the transaction, sign it, submit it to a node and store it in the account.
-    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction), or
+    /// [`PendingApproval`](crate::Response::PendingApproval) if the spending policy requires approvals
     #[serde(rename_all = "camelCase")]
     SignAndSubmitTransaction {
         prepared_transaction_data: PreparedTransactionDataDto,
     },
-    /// Sign a prepared transaction.
-    /// Expected response: [`SignedTransactionData`](crate::Response::SignedTransactionData)
+    /// Sign a prepared transaction, the spending policy is checked with the options it was prepared with.
+    /// Expected response: [`SignedTransactionData`](crate::Response::SignedTransactionData), or
+    /// [`PendingApproval`](crate::Response::PendingApproval) if the spending policy requires approvals
     #[serde(rename_all = "camelCase")]
     SignTransactionEssence {
-        prepared_transaction_data: PreparedTransactionDataDto,
+        prepared_transaction_data: Box<PreparedTransactionDataDto>,
+        options: Option<TransactionOptionsDto>,
     },
     /// Validate the transaction, submit it to a node and store it in the account.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
@@ -319,6 +371,9 @@ pub enum AccountMethod {
     /// Returns all transaction of the account
     /// Expected response: [`Transactions`](crate::Response::Transactions)
     Transactions,
+    /// Returns the sent and received transactions of the account ordered by timestamp.
+    /// Expected response: [`TransactionHistory`](crate::Response::TransactionHistory)
+    TransactionHistory { options: Option<TransactionHistoryOptions> },
     /// Returns all unspent outputs of the account
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
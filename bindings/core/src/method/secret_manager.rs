@@ -14,6 +14,7 @@ use crate::OmittedDebug;
 
 /// Each public secret manager method.
 #[derive(Clone, Derivative, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[derivative(Debug)]
 #[serde(tag = "name", content = "data", rename_all = "camelCase")]
 #[non_exhaustive]
@@ -21,6 +21,7 @@ use crate::OmittedDebug;
 
 /// Each public utils method.
 #[derive(Clone, Derivative, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[derivative(Debug)]
 #[serde(tag = "name", content = "data", rename_all = "camelCase")]
 #[non_exhaustive]
@@ -159,4 +160,7 @@ pub enum UtilsMethod {
     /// Returns the hex representation of the serialized output bytes.
     #[serde(rename_all = "camelCase")]
     OutputHexBytes { output: OutputDto },
+    /// Returns the supported protocol versions and methods.
+    /// Expected response: [`Capabilities`](crate::Response::Capabilities)
+    GetCapabilities,
 }
@@ -1,7 +1,6 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(feature = "stronghold")]
 use std::path::PathBuf;
 
 use derivative::Derivative;
@@ -9,24 +8,27 @@ use derivative::Derivative;
 use iota_sdk::wallet::events::types::{WalletEvent, WalletEventType};
 use iota_sdk::{
     client::{node_manager::node::NodeAuth, secret::GenerateAddressOptions},
-    types::block::address::Hrp,
+    types::block::{
+        address::{Bech32Address, Hrp},
+        output::{NftId, OutputId},
+    },
     wallet::{
         account::{
             types::{AccountAddress, AccountIdentifier},
             SyncOptions,
         },
+        core::AddressBookEntry,
         ClientOptions,
     },
     Url,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::method::account::AccountMethod;
-#[cfg(feature = "stronghold")]
-use crate::OmittedDebug;
+use crate::{method::account::AccountMethod, OmittedDebug};
 
 /// The methods that can be sent to the actor.
 #[derive(Clone, Derivative, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[derivative(Debug)]
 #[serde(tag = "name", content = "data", rename_all = "camelCase")]
 #[non_exhaustive]
@@ -141,6 +143,52 @@ pub enum WalletMethod {
         /// accounts will be restored.
         ignore_if_bech32_mismatch: Option<Hrp>,
     },
+    /// Exports the wallet data in the portable JSON format, encrypted if a password is provided. The secret manager
+    /// is not exported.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    ExportData {
+        /// The export destination.
+        destination: PathBuf,
+        /// The password to encrypt the export with.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: Option<String>,
+    },
+    /// Imports wallet data in the portable JSON format into a wallet without accounts, which has to use the same seed.
+    /// Replaces the coin type and client options.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    ImportData {
+        /// The path to the export.
+        source: PathBuf,
+        /// The password the export was encrypted with.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: Option<String>,
+    },
+    /// Returns the entries of the address book.
+    /// Expected response: [`AddressBook`](crate::Response::AddressBook)
+    GetAddressBook,
+    /// Returns the address book entry of an address.
+    /// Expected response: [`AddressBookEntry`](crate::Response::AddressBookEntry)
+    GetAddressBookEntry { address: Bech32Address },
+    /// Adds an entry to the address book or replaces the entry of its address.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetAddressBookEntry { entry: AddressBookEntry },
+    /// Removes the address book entry of an address.
+    /// Expected response: [`AddressBookEntry`](crate::Response::AddressBookEntry)
+    RemoveAddressBookEntry { address: Bech32Address },
+    /// Returns the labels of outputs.
+    /// Expected response: [`OutputLabels`](crate::Response::OutputLabels)
+    GetOutputLabels,
+    /// Sets the label of an output, or removes it if no label is provided.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetOutputLabel { output_id: OutputId, label: Option<String> },
+    /// Returns the labels of NFTs.
+    /// Expected response: [`NftLabels`](crate::Response::NftLabels)
+    GetNftLabels,
+    /// Sets the label of an NFT, or removes it if no label is provided.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetNftLabel { nft_id: NftId, label: Option<String> },
     /// Removes the latest account (account with the largest account index).
     /// Expected response: [`Ok`](crate::Response::Ok)
     RemoveLatestAccount,
@@ -197,6 +245,14 @@ pub enum WalletMethod {
         /// Interval in milliseconds
         interval_in_milliseconds: Option<u64>,
     },
+    /// Start syncing with outputs and transactions pushed over MQTT. Stopped with `StopBackgroundSync`.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[cfg(feature = "mqtt")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
+    StartPushSync {
+        /// Sync options
+        options: Option<SyncOptions>,
+    },
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::Response::Ok)
     StopBackgroundSync,
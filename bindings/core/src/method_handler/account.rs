@@ -29,6 +29,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let addresses = account.addresses_with_unspent_outputs().await?;
             Response::AddressesWithUnspentOutputs(addresses)
         }
+        AccountMethod::ApprovePendingApproval { id, approver } => {
+            let transaction = account.approve_pending_approval(&id, approver).await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         AccountMethod::ClaimableOutputs { outputs_to_claim } => {
             let output_ids = account.claimable_outputs(outputs_to_claim).await?;
             Response::OutputIds(output_ids)
@@ -42,6 +46,14 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             account.deregister_participation_event(&event_id).await?;
             Response::Ok
         }
+        AccountMethod::ExportTransactionHistory {
+            destination,
+            format,
+            options,
+        } => {
+            account.export_transaction_history(destination, format, options).await?;
+            Response::Ok
+        }
         AccountMethod::GenerateEd25519Addresses { amount, options } => {
             let address = account.generate_ed25519_addresses(amount, options).await?;
             Response::GeneratedAccountAddresses(address)
@@ -92,6 +104,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transaction = account.get_transaction(&transaction_id).await;
             Response::Transaction(transaction.as_ref().map(TransactionDto::from).map(Box::new))
         }
+        AccountMethod::GetSpendingPolicy => Response::SpendingPolicy(account.spending_policy().await),
+        AccountMethod::GetPendingApproval { id } => {
+            Response::PendingApproval(account.get_pending_approval(&id).await.map(Box::new))
+        }
         #[cfg(feature = "participation")]
         AccountMethod::GetVotingPower => {
             let voting_power = account.get_voting_power().await?;
@@ -105,6 +121,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let outputs = account.outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
         }
+        AccountMethod::PendingApprovals => Response::PendingApprovals(account.pending_approvals().await),
         AccountMethod::PendingTransactions => {
             let transactions = account.pending_transactions().await;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
@@ -247,6 +264,29 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let events = account.register_participation_events(&options).await?;
             Response::ParticipationEvents(events)
         }
+        AccountMethod::RejectPendingApproval { id } => {
+            account.reject_pending_approval(&id).await?;
+            Response::Ok
+        }
+        AccountMethod::RequestApproval {
+            prepared_transaction_data,
+            options,
+            creator,
+            metadata,
+        } => {
+            let pending_approval = account
+                .request_approval(
+                    PreparedTransactionData::try_from_dto_with_params(
+                        *prepared_transaction_data,
+                        account.client().get_protocol_parameters().await?,
+                    )?,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                    creator,
+                    metadata,
+                )
+                .await?;
+            Response::PendingApproval(Some(Box::new(pending_approval)))
+        }
         AccountMethod::RetryTransactionUntilIncluded {
             transaction_id,
             interval,
@@ -298,6 +338,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             account.set_default_sync_options(options).await?;
             Response::Ok
         }
+        AccountMethod::SetSpendingPolicy { policy } => {
+            account.set_spending_policy(policy).await?;
+            Response::Ok
+        }
         AccountMethod::SignAndSubmitTransaction {
             prepared_transaction_data,
         } => {
@@ -314,9 +358,13 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
         }
         AccountMethod::SignTransactionEssence {
             prepared_transaction_data,
+            options,
         } => {
             let signed_transaction_data = account
-                .sign_transaction_essence(&PreparedTransactionData::try_from_dto(prepared_transaction_data)?)
+                .sign_transaction_essence(
+                    &PreparedTransactionData::try_from_dto(*prepared_transaction_data)?,
+                    options.map(TransactionOptions::try_from_dto).transpose()?,
+                )
                 .await?;
             Response::SignedTransactionData(SignedTransactionDataDto::from(&signed_transaction_data))
         }
@@ -337,6 +385,9 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transactions = account.transactions().await;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
         }
+        AccountMethod::TransactionHistory { options } => {
+            Response::TransactionHistory(account.transaction_history(options).await?)
+        }
         AccountMethod::UnspentOutputs { filter_options } => {
             let outputs = account.unspent_outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
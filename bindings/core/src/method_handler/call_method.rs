@@ -16,7 +16,9 @@ use crate::{
         client::call_client_method_internal, secret_manager::call_secret_manager_method_internal,
         utils::call_utils_method_internal, wallet::call_wallet_method_internal,
     },
+    operation::Operations,
     panic::{convert_async_panics, convert_panics},
+    protocol::{VersionedMethod, VersionedResponse},
     response::Response,
     UtilsMethod,
 };
@@ -26,6 +28,31 @@ pub trait CallMethod {
 
     // This uses a manual async_trait-like impl because it's not worth it to import the lib for one trait
     fn call_method<'a>(&'a self, method: Self::Method) -> Pin<Box<dyn Future<Output = Response> + 'a>>;
+
+    /// Calls a method sent with any supported protocol version, the response lists the deprecated shapes it was sent
+    /// with. Methods sent with an operation id can be cancelled with `operations` while they run.
+    fn call_versioned_method<'a>(
+        &'a self,
+        operations: &'a Operations,
+        method: VersionedMethod<Self::Method>,
+    ) -> Pin<Box<dyn Future<Output = VersionedResponse> + 'a>>
+    where
+        Self::Method: 'a,
+    {
+        Box::pin(async move {
+            let response = match method.operation_id {
+                Some(operation_id) => match operations.register(operation_id) {
+                    Ok(registered) => registered.operation().run(self.call_method(method.method)).await,
+                    Err(err) => Response::Error(err),
+                },
+                None => self.call_method(method.method).await,
+            };
+            VersionedResponse {
+                response,
+                deprecations: method.deprecations,
+            }
+        })
+    }
 }
 
 impl CallMethod for Client {
@@ -42,6 +69,17 @@ impl CallMethod for Wallet {
     fn call_method<'a>(&'a self, method: Self::Method) -> Pin<Box<dyn Future<Output = Response> + 'a>> {
         Box::pin(call_wallet_method(self, method))
     }
+
+    fn call_versioned_method<'a>(
+        &'a self,
+        operations: &'a Operations,
+        method: VersionedMethod<Self::Method>,
+    ) -> Pin<Box<dyn Future<Output = VersionedResponse> + 'a>>
+    where
+        Self::Method: 'a,
+    {
+        Box::pin(call_versioned_wallet_method(self, operations, method))
+    }
 }
 
 /// Call a client method.
@@ -66,6 +104,42 @@ pub async fn call_wallet_method(wallet: &Wallet, method: WalletMethod) -> Respon
     response
 }
 
+/// Call a wallet method sent with any supported protocol version, like [`CallMethod::call_versioned_method()`]. The
+/// progress of methods sent with an operation id is emitted as wallet events.
+pub async fn call_versioned_wallet_method(
+    wallet: &Wallet,
+    operations: &Operations,
+    method: VersionedMethod<WalletMethod>,
+) -> VersionedResponse {
+    let response = match method.operation_id {
+        Some(operation_id) => match operations.register(operation_id) {
+            #[cfg(feature = "events")]
+            Ok(registered) => {
+                wallet
+                    .run_operation(
+                        registered.id(),
+                        registered.operation().clone(),
+                        call_wallet_method(wallet, method.method),
+                    )
+                    .await
+            }
+            #[cfg(not(feature = "events"))]
+            Ok(registered) => {
+                registered
+                    .operation()
+                    .run(call_wallet_method(wallet, method.method))
+                    .await
+            }
+            Err(err) => Response::Error(err),
+        },
+        None => call_wallet_method(wallet, method.method).await,
+    };
+    VersionedResponse {
+        response,
+        deprecations: method.deprecations,
+    }
+}
+
 /// Call a utils method.
 pub fn call_utils_method(method: UtilsMethod) -> Response {
     log::debug!("Utils method: {method:?}");
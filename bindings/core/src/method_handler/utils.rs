@@ -18,7 +18,7 @@ use iota_sdk::{
 };
 use packable::PackableExt;
 
-use crate::{method::UtilsMethod, response::Response, Result};
+use crate::{method::UtilsMethod, protocol::Capabilities, response::Response, Result};
 
 /// Call a utils method.
 pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response> {
@@ -114,6 +114,7 @@ pub(crate) fn call_utils_method_internal(method: UtilsMethod) -> Result<Response
             let output = Output::try_from_dto(output)?;
             Response::HexBytes(prefix_hex::encode(output.pack_to_vec()))
         }
+        UtilsMethod::GetCapabilities => Response::Capabilities(Capabilities::get()),
     };
     Ok(response)
 }
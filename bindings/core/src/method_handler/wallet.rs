@@ -9,7 +9,7 @@ use iota_sdk::{
 };
 
 use super::account::call_account_method_internal;
-use crate::{method::WalletMethod, response::Response, Result};
+use crate::{method::WalletMethod, response::Response, Error, Result};
 
 /// Call a wallet method.
 pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletMethod) -> Result<Response> {
@@ -66,7 +66,13 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
         WalletMethod::GetChrysalisData => Response::ChrysalisData(wallet.get_chrysalis_data().await?),
         WalletMethod::CallAccountMethod { account_id, method } => {
             let account = wallet.get_account(account_id).await?;
-            call_account_method_internal(&account, method).await?
+            match call_account_method_internal(&account, method).await {
+                // The transaction was persisted as pending approval instead of being signed
+                Err(Error::Wallet(iota_sdk::wallet::Error::ApprovalRequired(pending_approval))) => {
+                    Response::PendingApproval(Some(pending_approval))
+                }
+                response => response?,
+            }
         }
         #[cfg(feature = "stronghold")]
         WalletMethod::Backup { destination, password } => {
@@ -130,6 +136,35 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
                 .await?;
             Response::Ok
         }
+        WalletMethod::ExportData { destination, password } => {
+            wallet.export_to_file(destination, password.map(Into::into)).await?;
+            Response::Ok
+        }
+        WalletMethod::ImportData { source, password } => {
+            wallet.import_from_file(source, password.map(Into::into)).await?;
+            Response::Ok
+        }
+        WalletMethod::GetAddressBook => Response::AddressBook(wallet.address_book().await),
+        WalletMethod::GetAddressBookEntry { address } => {
+            Response::AddressBookEntry(wallet.get_address_book_entry(address).await?)
+        }
+        WalletMethod::SetAddressBookEntry { entry } => {
+            wallet.set_address_book_entry(entry).await?;
+            Response::Ok
+        }
+        WalletMethod::RemoveAddressBookEntry { address } => {
+            Response::AddressBookEntry(wallet.remove_address_book_entry(address).await?)
+        }
+        WalletMethod::GetOutputLabels => Response::OutputLabels(wallet.output_labels().await),
+        WalletMethod::SetOutputLabel { output_id, label } => {
+            wallet.set_output_label(output_id, label).await?;
+            Response::Ok
+        }
+        WalletMethod::GetNftLabels => Response::NftLabels(wallet.nft_labels().await),
+        WalletMethod::SetNftLabel { nft_id, label } => {
+            wallet.set_nft_label(nft_id, label).await?;
+            Response::Ok
+        }
         WalletMethod::SetClientOptions { client_options } => {
             wallet.set_client_options(*client_options).await?;
             Response::Ok
@@ -182,6 +217,11 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.start_background_syncing(options, duration).await?;
             Response::Ok
         }
+        #[cfg(feature = "mqtt")]
+        WalletMethod::StartPushSync { options } => {
+            wallet.start_push_syncing(options).await?;
+            Response::Ok
+        }
         WalletMethod::StopBackgroundSync => {
             wallet.stop_background_syncing().await?;
             Response::Ok
@@ -0,0 +1,92 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeMap, sync::Mutex};
+
+use iota_sdk::client::operation::Operation;
+
+use crate::{Error, Result};
+
+/// The operations running on a method handler, by id. Methods sent with an `operationId` can be cancelled with the
+/// operations of the method handler they were called on.
+#[derive(Debug, Default)]
+pub struct Operations(Mutex<BTreeMap<String, Operation>>);
+
+impl Operations {
+    /// Registers a new operation, fails if an operation with the same id is still running.
+    pub(crate) fn register(&self, id: String) -> Result<RegisteredOperation<'_>> {
+        let mut operations = self.0.lock().expect("operations lock poisoned");
+        if operations.contains_key(&id) {
+            return Err(Error::OperationIdInUse(id));
+        }
+        let operation = Operation::new();
+        operations.insert(id.clone(), operation.clone());
+        Ok(RegisteredOperation {
+            operations: self,
+            id,
+            operation,
+        })
+    }
+
+    /// Cancels a running operation, it stops at its next cancellation point with an `operationCancelled` error.
+    /// Returns `false` if there is no operation with this id.
+    pub fn cancel(&self, id: &str) -> bool {
+        self.0
+            .lock()
+            .expect("operations lock poisoned")
+            .get(id)
+            .map(Operation::cancel)
+            .is_some()
+    }
+}
+
+/// An operation that can be cancelled by its id until it's dropped.
+pub(crate) struct RegisteredOperation<'a> {
+    operations: &'a Operations,
+    id: String,
+    operation: Operation,
+}
+
+impl RegisteredOperation<'_> {
+    pub(crate) fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn operation(&self) -> &Operation {
+        &self.operation
+    }
+}
+
+impl Drop for RegisteredOperation<'_> {
+    fn drop(&mut self) {
+        self.operations
+            .0
+            .lock()
+            .expect("operations lock poisoned")
+            .remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_and_cancel() {
+        let operations = Operations::default();
+        let registered = operations.register("register_and_cancel".to_string()).unwrap();
+        assert!(matches!(
+            operations.register("register_and_cancel".to_string()),
+            Err(Error::OperationIdInUse(_))
+        ));
+
+        // Operations are scoped to the method handler they were registered on
+        assert!(!Operations::default().cancel("register_and_cancel"));
+        assert!(!registered.operation().is_cancelled());
+        assert!(operations.cancel("register_and_cancel"));
+        assert!(registered.operation().is_cancelled());
+
+        drop(registered);
+        assert!(!operations.cancel("register_and_cancel"));
+    }
+}
@@ -0,0 +1,429 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeMap, fmt};
+
+use serde::{
+    de::{value::MapDeserializer, DeserializeOwned},
+    Deserialize, Serialize,
+};
+use serde_json::{Map, Value};
+
+use crate::{
+    method::{AccountMethod, ClientMethod, SecretManagerMethod, UtilsMethod, WalletMethod},
+    response::Response,
+    Error, Result,
+};
+
+/// The version of the method protocol, increased when the shape of a method changes.
+pub const PROTOCOL_VERSION: u32 = 1;
+/// The oldest protocol version whose method shapes are still accepted.
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+/// The protocol version of methods sent without a `protocolVersion`, by bindings that predate it.
+const UNVERSIONED_PROTOCOL_VERSION: u32 = 1;
+
+/// A method shape that changed in a protocol version.
+struct MethodShim {
+    /// The protocol version in which the shape changed.
+    version: u32,
+    /// The name of the changed method.
+    method: &'static str,
+    /// What changed.
+    message: &'static str,
+    /// Converts the data of the previous shape into the current one, returns whether it had the previous shape.
+    upgrade: fn(&mut Map<String, Value>) -> bool,
+}
+
+// When the shape of a method changes, the protocol version is increased and a shim for the previous shape is added.
+const WALLET_METHOD_SHIMS: &[MethodShim] = &[];
+
+const ACCOUNT_METHOD_SHIMS: &[MethodShim] = &[];
+
+/// A previous method shape that is still accepted.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Deprecation {
+    /// The namespace of the method.
+    pub namespace: String,
+    /// The name of the method.
+    pub method: String,
+    /// The protocol version in which the shape of the method changed.
+    pub version: u32,
+    /// What changed.
+    pub message: String,
+}
+
+impl Deprecation {
+    fn new(namespace: &str, shim: &MethodShim) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            method: shim.method.to_string(),
+            version: shim.version,
+            message: shim.message.to_string(),
+        }
+    }
+}
+
+/// A method of the bindings whose shape is versioned by the protocol.
+pub trait ProtocolMethod: DeserializeOwned {
+    /// The namespace of the methods.
+    const NAMESPACE: &'static str;
+
+    /// Converts a method sent with an older protocol version into the current shape and reports the deprecated shapes
+    /// it used.
+    fn upgrade(_method: &mut Value, _version: u32, _deprecations: &mut Vec<Deprecation>) {}
+
+    /// Returns the method shapes that are still accepted, but deprecated.
+    fn deprecations() -> Vec<Deprecation> {
+        Vec::new()
+    }
+
+    /// Returns the names of the methods. Depends on the features the bindings were built with.
+    fn names() -> Vec<String> {
+        method_names::<Self>()
+    }
+}
+
+impl ProtocolMethod for WalletMethod {
+    const NAMESPACE: &'static str = "wallet";
+
+    fn upgrade(method: &mut Value, version: u32, deprecations: &mut Vec<Deprecation>) {
+        apply_shims(Self::NAMESPACE, WALLET_METHOD_SHIMS, method, version, deprecations);
+
+        if method.get("name").and_then(Value::as_str) == Some("callAccountMethod") {
+            if let Some(account_method) = method.pointer_mut("/data/method") {
+                AccountMethod::upgrade(account_method, version, deprecations);
+            }
+        }
+    }
+
+    fn deprecations() -> Vec<Deprecation> {
+        shim_deprecations(Self::NAMESPACE, WALLET_METHOD_SHIMS)
+    }
+}
+
+impl ProtocolMethod for AccountMethod {
+    const NAMESPACE: &'static str = "account";
+
+    fn upgrade(method: &mut Value, version: u32, deprecations: &mut Vec<Deprecation>) {
+        apply_shims(Self::NAMESPACE, ACCOUNT_METHOD_SHIMS, method, version, deprecations);
+    }
+
+    fn deprecations() -> Vec<Deprecation> {
+        shim_deprecations(Self::NAMESPACE, ACCOUNT_METHOD_SHIMS)
+    }
+}
+
+impl ProtocolMethod for ClientMethod {
+    const NAMESPACE: &'static str = "client";
+}
+
+impl ProtocolMethod for SecretManagerMethod {
+    const NAMESPACE: &'static str = "secretManager";
+}
+
+impl ProtocolMethod for UtilsMethod {
+    const NAMESPACE: &'static str = "utils";
+}
+
+fn apply_shims(
+    namespace: &str,
+    shims: &[MethodShim],
+    method: &mut Value,
+    version: u32,
+    deprecations: &mut Vec<Deprecation>,
+) {
+    let Some(name) = method.get("name").and_then(Value::as_str).map(ToOwned::to_owned) else {
+        return;
+    };
+
+    for shim in shims
+        .iter()
+        .filter(|shim| shim.version > version && shim.method == name)
+    {
+        if let Some(data) = method.get_mut("data").and_then(Value::as_object_mut) {
+            if (shim.upgrade)(data) {
+                log::warn!(
+                    "deprecated shape of {namespace} method {name} (protocol version {version}): {}",
+                    shim.message
+                );
+                deprecations.push(Deprecation::new(namespace, shim));
+            }
+        }
+    }
+}
+
+fn shim_deprecations(namespace: &str, shims: &[MethodShim]) -> Vec<Deprecation> {
+    shims.iter().map(|shim| Deprecation::new(namespace, shim)).collect()
+}
+
+/// A method sent with a protocol version.
+#[derive(Debug)]
+pub struct VersionedMethod<M> {
+    /// The protocol version the method was sent with.
+    pub protocol_version: u32,
+    /// The id of the operation the method was sent with, to cancel it with
+    /// [`Operations::cancel()`](crate::Operations::cancel).
+    pub operation_id: Option<String>,
+    /// The method in its current shape.
+    pub method: M,
+    /// The deprecated shapes the method was sent with.
+    pub deprecations: Vec<Deprecation>,
+}
+
+impl<M: ProtocolMethod> VersionedMethod<M> {
+    /// Deserializes a method sent with any supported protocol version, given by its `protocolVersion` field. Methods
+    /// without one are treated as sent with protocol version 1. An optional `operationId` field makes the method
+    /// cancellable.
+    pub fn from_value(mut value: Value) -> Result<Self> {
+        let protocol_version = match value
+            .as_object_mut()
+            .and_then(|method| method.remove("protocolVersion"))
+        {
+            Some(protocol_version) => serde_json::from_value(protocol_version)?,
+            None => UNVERSIONED_PROTOCOL_VERSION,
+        };
+        let operation_id = match value.as_object_mut().and_then(|method| method.remove("operationId")) {
+            Some(operation_id) => Some(serde_json::from_value(operation_id)?),
+            None => None,
+        };
+        if !(MIN_PROTOCOL_VERSION..=PROTOCOL_VERSION).contains(&protocol_version) {
+            return Err(Error::UnsupportedProtocolVersion(protocol_version));
+        }
+
+        let mut deprecations = Vec::new();
+        M::upgrade(&mut value, protocol_version, &mut deprecations);
+
+        Ok(Self {
+            protocol_version,
+            operation_id,
+            method: serde_json::from_value(value)?,
+            deprecations,
+        })
+    }
+
+    /// Deserializes a method sent as JSON with any supported protocol version.
+    pub fn from_json(method: &str) -> Result<Self> {
+        Self::from_value(serde_json::from_str(method)?)
+    }
+}
+
+/// The response to a [`VersionedMethod`], which lists the deprecated shapes the method was sent with next to the
+/// `type` and `payload` of the response.
+#[derive(Debug, Serialize)]
+pub struct VersionedResponse {
+    /// The response.
+    #[serde(flatten)]
+    pub response: Response,
+    /// The deprecated shapes the method was sent with.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deprecations: Vec<Deprecation>,
+}
+
+impl From<Response> for VersionedResponse {
+    fn from(response: Response) -> Self {
+        Self {
+            response,
+            deprecations: Vec::new(),
+        }
+    }
+}
+
+/// The protocol versions and methods supported by the bindings.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Capabilities {
+    /// The current protocol version.
+    pub protocol_version: u32,
+    /// The oldest protocol version whose method shapes are still accepted.
+    pub min_protocol_version: u32,
+    /// The names of the supported methods, by namespace. Depends on the features the bindings were built with.
+    pub methods: BTreeMap<String, Vec<String>>,
+    /// The previous method shapes that are still accepted.
+    pub deprecations: Vec<Deprecation>,
+}
+
+impl Capabilities {
+    /// Returns the capabilities of the bindings.
+    pub fn get() -> Self {
+        let mut methods = BTreeMap::new();
+        let mut deprecations = Vec::new();
+        add_methods::<WalletMethod>(&mut methods, &mut deprecations);
+        add_methods::<AccountMethod>(&mut methods, &mut deprecations);
+        add_methods::<ClientMethod>(&mut methods, &mut deprecations);
+        add_methods::<SecretManagerMethod>(&mut methods, &mut deprecations);
+        add_methods::<UtilsMethod>(&mut methods, &mut deprecations);
+
+        Self {
+            protocol_version: PROTOCOL_VERSION,
+            min_protocol_version: MIN_PROTOCOL_VERSION,
+            methods,
+            deprecations,
+        }
+    }
+}
+
+fn add_methods<M: ProtocolMethod>(methods: &mut BTreeMap<String, Vec<String>>, deprecations: &mut Vec<Deprecation>) {
+    methods.insert(M::NAMESPACE.to_string(), M::names());
+    deprecations.extend(M::deprecations());
+}
+
+/// Returns the method names a method enum accepts, taken from the error serde reports for an unknown one.
+fn method_names<M: DeserializeOwned>() -> Vec<String> {
+    let method = MapDeserializer::<_, MethodNames>::new(std::iter::once(("name", "")));
+    M::deserialize(method)
+        .err()
+        .map(|MethodNames(names)| names.iter().map(ToString::to_string).collect())
+        .unwrap_or_default()
+}
+
+#[derive(Debug)]
+struct MethodNames(&'static [&'static str]);
+
+impl serde::de::Error for MethodNames {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        Self(&[])
+    }
+
+    fn unknown_variant(_variant: &str, expected: &'static [&'static str]) -> Self {
+        Self(expected)
+    }
+}
+
+impl fmt::Display for MethodNames {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "expected one of {:?}", self.0)
+    }
+}
+
+impl std::error::Error for MethodNames {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// A changed method shape, only for the tests as no method changed yet.
+    const TEST_SHIMS: &[MethodShim] = &[MethodShim {
+        version: PROTOCOL_VERSION + 1,
+        method: "claimOutputs",
+        message: "`outputIds` was renamed to `outputIdsToClaim`",
+        upgrade: |data| match data.remove("outputIds") {
+            Some(value) => {
+                data.entry("outputIdsToClaim").or_insert(value);
+                true
+            }
+            None => false,
+        },
+    }];
+
+    #[test]
+    fn upgrade_previous_shape() {
+        let previous_shape = json!({ "name": "claimOutputs", "data": { "outputIds": [] } });
+
+        let mut method = previous_shape.clone();
+        let mut deprecations = Vec::new();
+        apply_shims("account", TEST_SHIMS, &mut method, PROTOCOL_VERSION, &mut deprecations);
+        assert_eq!(deprecations, shim_deprecations("account", TEST_SHIMS));
+        assert!(matches!(
+            serde_json::from_value(method).unwrap(),
+            AccountMethod::ClaimOutputs { .. }
+        ));
+
+        // Methods sent with the version of the change already have the current shape
+        let mut method = previous_shape;
+        let mut deprecations = Vec::new();
+        apply_shims(
+            "account",
+            TEST_SHIMS,
+            &mut method,
+            PROTOCOL_VERSION + 1,
+            &mut deprecations,
+        );
+        assert!(deprecations.is_empty());
+        assert!(serde_json::from_value::<AccountMethod>(method).is_err());
+    }
+
+    #[test]
+    fn versioned_method() {
+        let method = VersionedMethod::<WalletMethod>::from_value(json!({
+            "name": "callAccountMethod",
+            "data": {
+                "accountId": 0,
+                "method": { "name": "claimOutputs", "data": { "outputIdsToClaim": [] } }
+            }
+        }))
+        .unwrap();
+        assert_eq!(method.protocol_version, 1);
+        assert!(method.deprecations.is_empty());
+        assert!(matches!(
+            method.method,
+            WalletMethod::CallAccountMethod {
+                method: AccountMethod::ClaimOutputs { .. },
+                ..
+            }
+        ));
+
+        let method = VersionedMethod::<AccountMethod>::from_value(json!({
+            "protocolVersion": PROTOCOL_VERSION,
+            "operationId": "claim",
+            "name": "claimOutputs",
+            "data": { "outputIdsToClaim": [] }
+        }))
+        .unwrap();
+        assert_eq!(method.operation_id.as_deref(), Some("claim"));
+
+        assert!(matches!(
+            VersionedMethod::<AccountMethod>::from_value(json!({
+                "protocolVersion": PROTOCOL_VERSION,
+                "name": "claimOutputs",
+                "data": { "outputIds": [] }
+            })),
+            Err(Error::SerdeJson(_))
+        ));
+        assert!(matches!(
+            VersionedMethod::<UtilsMethod>::from_value(json!({
+                "protocolVersion": PROTOCOL_VERSION + 1,
+                "name": "getCapabilities"
+            })),
+            Err(Error::UnsupportedProtocolVersion(_))
+        ));
+    }
+
+    #[test]
+    fn versioned_response() {
+        let response = VersionedResponse {
+            response: Response::Ok,
+            deprecations: shim_deprecations("account", TEST_SHIMS),
+        };
+        assert_eq!(
+            serde_json::to_value(response).unwrap(),
+            json!({
+                "type": "ok",
+                "deprecations": [{
+                    "namespace": "account",
+                    "method": "claimOutputs",
+                    "version": PROTOCOL_VERSION + 1,
+                    "message": "`outputIds` was renamed to `outputIdsToClaim`"
+                }]
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(VersionedResponse::from(Response::Bool(true))).unwrap(),
+            json!({ "type": "bool", "payload": true })
+        );
+    }
+
+    #[test]
+    fn capabilities() {
+        let capabilities = Capabilities::get();
+        assert_eq!(capabilities.methods.len(), 5);
+        assert!(capabilities.methods["wallet"].contains(&"callAccountMethod".to_string()));
+        assert!(capabilities.methods["account"].contains(&"claimOutputs".to_string()));
+        assert!(capabilities.methods["utils"].contains(&"getCapabilities".to_string()));
+        assert!(capabilities.deprecations.is_empty());
+    }
+}
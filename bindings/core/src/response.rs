@@ -37,9 +37,13 @@ use iota_sdk::{
             BlockDto, BlockId,
         },
     },
-    wallet::account::{
-        types::{AccountAddress, AddressWithUnspentOutputs, Balance, OutputDataDto, TransactionDto},
-        AccountDetailsDto, PreparedCreateNativeTokenTransactionDto,
+    wallet::{
+        account::{
+            types::{AccountAddress, AddressWithUnspentOutputs, Balance, OutputDataDto, TransactionDto},
+            AccountDetailsDto, PendingApproval, PreparedCreateNativeTokenTransactionDto, SpendingPolicy,
+            TransactionHistoryEntry,
+        },
+        core::AddressBookEntry,
     },
 };
 use serde::Serialize;
@@ -49,10 +53,11 @@ use {
     iota_sdk::wallet::account::{AccountParticipationOverview, ParticipationEventWithNodes},
 };
 
-use crate::{error::Error, OmittedDebug};
+use crate::{error::Error, protocol::Capabilities, OmittedDebug};
 
 /// The response message.
 #[derive(Serialize, Derivative)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[derivative(Debug)]
 #[serde(tag = "type", content = "payload", rename_all = "camelCase")]
 #[non_exhaustive]
@@ -230,6 +235,8 @@ pub enum Response {
     HexAddress(String),
     /// Response for [`OutputHexBytes`](crate::method::UtilsMethod::OutputHexBytes)
     HexBytes(String),
+    /// Response for [`GetCapabilities`](crate::method::UtilsMethod::GetCapabilities)
+    Capabilities(Capabilities),
     /// Response for [`CallPluginRoute`](crate::method::ClientMethod::CallPluginRoute)
     CustomJson(serde_json::Value),
 
@@ -278,10 +285,18 @@ pub enum Response {
     /// - [`ClearStrongholdPassword`](crate::method::WalletMethod::ClearStrongholdPassword),
     /// - [`DeregisterParticipationEvent`](crate::method::AccountMethod::DeregisterParticipationEvent),
     /// - [`EmitTestEvent`](crate::method::WalletMethod::EmitTestEvent),
+    /// - [`ExportData`](crate::method::WalletMethod::ExportData),
+    /// - [`ExportTransactionHistory`](crate::method::AccountMethod::ExportTransactionHistory),
+    /// - [`ImportData`](crate::method::WalletMethod::ImportData),
+    /// - [`RejectPendingApproval`](crate::method::AccountMethod::RejectPendingApproval),
     /// - [`RestoreBackup`](crate::method::WalletMethod::RestoreBackup),
+    /// - [`SetAddressBookEntry`](crate::method::WalletMethod::SetAddressBookEntry),
     /// - [`SetAlias`](crate::method::AccountMethod::SetAlias),
     /// - [`SetClientOptions`](crate::method::WalletMethod::SetClientOptions),
     /// - [`SetDefaultSyncOptions`](crate::method::AccountMethod::SetDefaultSyncOptions),
+    /// - [`SetNftLabel`](crate::method::WalletMethod::SetNftLabel),
+    /// - [`SetOutputLabel`](crate::method::WalletMethod::SetOutputLabel),
+    /// - [`SetSpendingPolicy`](crate::method::AccountMethod::SetSpendingPolicy),
     /// - [`SetStrongholdPassword`](crate::method::WalletMethod::SetStrongholdPassword),
     /// - [`SetStrongholdPasswordClearInterval`](crate::method::WalletMethod::SetStrongholdPasswordClearInterval),
     /// - [`StartBackgroundSync`](crate::method::WalletMethod::StartBackgroundSync),
@@ -305,6 +320,13 @@ pub enum Response {
     /// - [`GetAccounts`](crate::method::WalletMethod::GetAccounts)
     Accounts(Vec<AccountDetailsDto>),
     /// Response for:
+    /// - [`GetAddressBook`](crate::method::WalletMethod::GetAddressBook)
+    AddressBook(Vec<AddressBookEntry>),
+    /// Response for:
+    /// - [`GetAddressBookEntry`](crate::method::WalletMethod::GetAddressBookEntry),
+    /// - [`RemoveAddressBookEntry`](crate::method::WalletMethod::RemoveAddressBookEntry)
+    AddressBookEntry(Option<AddressBookEntry>),
+    /// Response for:
     /// - [`Addresses`](crate::method::AccountMethod::Addresses)
     Addresses(Vec<AccountAddress>),
     /// Response for:
@@ -321,6 +343,12 @@ pub enum Response {
     /// - [`ClaimableOutputs`](crate::method::AccountMethod::ClaimableOutputs)
     OutputIds(Vec<OutputId>),
     /// Response for:
+    /// - [`GetOutputLabels`](crate::method::WalletMethod::GetOutputLabels)
+    OutputLabels(HashMap<OutputId, String>),
+    /// Response for:
+    /// - [`GetNftLabels`](crate::method::WalletMethod::GetNftLabels)
+    NftLabels(HashMap<NftId, String>),
+    /// Response for:
     /// - [`GetOutput`](crate::method::AccountMethod::GetOutput)
     OutputData(Option<Box<OutputDataDto>>),
     /// Response for:
@@ -356,6 +384,20 @@ pub enum Response {
     /// - [`Transactions`](crate::method::AccountMethod::Transactions),
     Transactions(Vec<TransactionDto>),
     /// Response for:
+    /// - [`TransactionHistory`](crate::method::AccountMethod::TransactionHistory)
+    TransactionHistory(Vec<TransactionHistoryEntry>),
+    /// Response for:
+    /// - [`GetSpendingPolicy`](crate::method::AccountMethod::GetSpendingPolicy)
+    SpendingPolicy(SpendingPolicy),
+    /// Response for:
+    /// - [`GetPendingApproval`](crate::method::AccountMethod::GetPendingApproval)
+    /// - [`RequestApproval`](crate::method::AccountMethod::RequestApproval)
+    /// - methods that sign a transaction if the spending policy requires approvals
+    PendingApproval(Option<Box<PendingApproval>>),
+    /// Response for:
+    /// - [`PendingApprovals`](crate::method::AccountMethod::PendingApprovals)
+    PendingApprovals(Vec<PendingApproval>),
+    /// Response for:
     /// - [`SignTransactionEssence`](crate::method::AccountMethod::SignTransactionEssence)
     SignedTransactionData(SignedTransactionDataDto),
     /// GenerateAddress response.
@@ -367,6 +409,7 @@ pub enum Response {
     /// - [`Sync`](crate::method::AccountMethod::Sync)
     Balance(Balance),
     /// Response for:
+    /// - [`ApprovePendingApproval`](crate::method::AccountMethod::ApprovePendingApproval)
     /// - [`ClaimOutputs`](crate::method::AccountMethod::ClaimOutputs)
     /// - [`Send`](crate::method::AccountMethod::Send)
     /// - [`SendOutputs`](crate::method::AccountMethod::SendOutputs)
@@ -0,0 +1,33 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use schemars::{
+    gen::SchemaSettings,
+    schema::{Metadata, RootSchema, SchemaObject},
+};
+
+use crate::{AccountMethod, ClientMethod, Response, SecretManagerMethod, UtilsMethod, WalletMethod};
+
+/// Returns the JSON schema of the methods and responses of the bindings. The methods, the response and all the DTOs
+/// they contain are listed in the `definitions` of the schema, so the types of the bindings can be generated from it.
+pub fn json_schema() -> RootSchema {
+    let mut gen = SchemaSettings::draft07().into_generator();
+    gen.subschema_for::<WalletMethod>();
+    gen.subschema_for::<AccountMethod>();
+    gen.subschema_for::<ClientMethod>();
+    gen.subschema_for::<SecretManagerMethod>();
+    gen.subschema_for::<UtilsMethod>();
+    gen.subschema_for::<Response>();
+
+    RootSchema {
+        meta_schema: gen.settings().meta_schema.clone(),
+        schema: SchemaObject {
+            metadata: Some(Box::new(Metadata {
+                title: Some("IOTA SDK bindings".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        },
+        definitions: gen.take_definitions(),
+    }
+}
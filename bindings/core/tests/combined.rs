@@ -231,3 +231,61 @@ async fn client_from_wallet() -> Result<()> {
     std::fs::remove_dir_all(storage_path).ok();
     Ok(())
 }
+
+#[cfg(feature = "events")]
+#[tokio::test]
+async fn emit_sync_finished_event() -> Result<()> {
+    use std::sync::{Arc, Mutex};
+
+    use iota_sdk::wallet::events::types::{SyncFinishedEvent, WalletEvent, WalletEventType};
+
+    let storage_path = "test-storage/emit_sync_finished_event";
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let client_options = r#"{
+            "nodes":[
+               {
+                  "url":"http://localhost:14265",
+                  "auth":null,
+                  "disabled":false
+               }
+            ]
+         }"#;
+
+    let wallet = WalletOptions::default()
+        .with_storage_path(storage_path.to_string())
+        .with_client_options(ClientBuilder::new().from_json(client_options).unwrap())
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_secret_manager(serde_json::from_str::<SecretManagerDto>(secret_manager).unwrap())
+        .build()
+        .await?;
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = received.clone();
+    wallet
+        .listen([WalletEventType::SyncFinished], move |event| {
+            received_clone.lock().unwrap().push(event.event.clone());
+        })
+        .await;
+
+    // Events are sent as JSON by the bindings
+    let method = serde_json::from_str::<WalletMethod>(
+        r#"{"name":"emitTestEvent","data":{"event":{"type":15,"durationMs":10,"syncedAddresses":1,"syncedOutputs":2}}}"#,
+    )
+    .unwrap();
+    let response = wallet.call_method(method).await;
+    assert!(matches!(response, Response::Ok), "unexpected response {response:?}");
+
+    assert_eq!(
+        *received.lock().unwrap(),
+        vec![WalletEvent::SyncFinished(SyncFinishedEvent {
+            duration_ms: 10,
+            synced_addresses: 1,
+            synced_outputs: 2,
+        })]
+    );
+
+    std::fs::remove_dir_all(storage_path).ok();
+    Ok(())
+}
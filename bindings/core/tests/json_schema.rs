@@ -0,0 +1,56 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+// The committed schema is generated with these features, run with `UPDATE_SCHEMA=1` to regenerate it:
+// cargo test -p iota-sdk-bindings-core --features
+// events,json_schema,mqtt,participation,private_key_secret_manager,storage,stronghold --test json_schema
+#![cfg(all(
+    feature = "events",
+    feature = "json_schema",
+    feature = "mqtt",
+    feature = "participation",
+    feature = "private_key_secret_manager",
+    feature = "storage",
+    feature = "stronghold",
+    not(feature = "ledger_nano")
+))]
+
+use std::path::Path;
+
+use iota_sdk_bindings_core::json_schema;
+
+#[test]
+fn json_schema_is_up_to_date() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("schema.json");
+    let schema = serde_json::to_string_pretty(&json_schema()).unwrap() + "\n";
+
+    if std::env::var_os("UPDATE_SCHEMA").is_some() {
+        std::fs::write(&path, schema).unwrap();
+        return;
+    }
+
+    let committed = std::fs::read_to_string(&path).unwrap_or_default();
+    assert!(
+        committed == schema,
+        "{} is outdated, regenerate it with `UPDATE_SCHEMA=1`",
+        path.display()
+    );
+}
+
+#[test]
+fn json_schema_definitions() {
+    let schema = json_schema();
+
+    for name in [
+        "WalletMethod",
+        "AccountMethod",
+        "ClientMethod",
+        "SecretManagerMethod",
+        "UtilsMethod",
+        "Response",
+        "OutputDto",
+        "WalletEvent",
+    ] {
+        assert!(schema.definitions.contains_key(name), "missing definition {name}");
+    }
+}
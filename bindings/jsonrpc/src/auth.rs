@@ -0,0 +1,164 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::fmt::{Debug, Formatter, Result as FmtResult};
+
+use axum::http::{header::AUTHORIZATION, HeaderMap};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Header that can be used instead of `Authorization: Bearer <key>` to send the API key.
+pub(crate) const API_KEY_HEADER: &str = "X-Api-Key";
+/// The wallet method that calls an account method given in its params.
+const CALL_ACCOUNT_METHOD: &str = "wallet.callAccountMethod";
+
+/// A key that clients authenticate with, and the methods it may call.
+#[derive(Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKey {
+    /// The secret key.
+    pub key: String,
+    /// The methods that may be called with the key, all methods if not set. Entries are method names like
+    /// `wallet.getAccounts`, `namespace.*` to allow a whole namespace or `*`.
+    pub allowed_methods: Option<Vec<String>>,
+}
+
+impl ApiKey {
+    /// Creates a key that may call all methods.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            allowed_methods: None,
+        }
+    }
+
+    /// Restricts the methods that may be called with the key.
+    pub fn with_allowed_methods(mut self, allowed_methods: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.allowed_methods = Some(allowed_methods.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+impl Debug for ApiKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ApiKey")
+            .field("key", &"<omitted>")
+            .field("allowed_methods", &self.allowed_methods)
+            .finish()
+    }
+}
+
+/// The methods an authenticated connection may call.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Permissions {
+    allowed_methods: Option<Vec<String>>,
+}
+
+impl Permissions {
+    /// Returns whether the method may be called with the params. `wallet.callAccountMethod` additionally requires
+    /// the account method it calls to be allowed as `account.<name>`.
+    pub(crate) fn allows(&self, method: &str, params: &Value) -> bool {
+        let Some(allowed_methods) = &self.allowed_methods else {
+            return true;
+        };
+        let is_allowed = |method: &str| {
+            allowed_methods.iter().any(|allowed| match allowed.strip_suffix('*') {
+                Some(prefix) => method.starts_with(prefix),
+                None => allowed == method,
+            })
+        };
+        if !is_allowed(method) {
+            return false;
+        }
+        if method != CALL_ACCOUNT_METHOD {
+            return true;
+        }
+        params
+            .get("method")
+            .and_then(|account_method| account_method.get("name"))
+            .and_then(Value::as_str)
+            .is_some_and(|name| is_allowed(&format!("account.{name}")))
+    }
+}
+
+/// Returns the permissions of the API key sent with the request headers, `None` if the request isn't authenticated.
+/// Without configured keys, all requests are permitted.
+pub(crate) fn authenticate(api_keys: &[ApiKey], headers: &HeaderMap) -> Option<Permissions> {
+    if api_keys.is_empty() {
+        return Some(Permissions::default());
+    }
+
+    let key = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .or_else(|| headers.get(API_KEY_HEADER).and_then(|value| value.to_str().ok()))?;
+
+    api_keys
+        .iter()
+        .find(|api_key| constant_time_eq(api_key.key.as_bytes(), key.as_bytes()))
+        .map(|api_key| Permissions {
+            allowed_methods: api_key.allowed_methods.clone(),
+        })
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (a, b)| acc | (a ^ b)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn headers(name: &str, value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            axum::http::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+            value.parse().unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn api_key_authentication() {
+        let api_keys = [
+            ApiKey::new("admin"),
+            ApiKey::new("reader").with_allowed_methods(["wallet.getAccounts", "utils.*"]),
+        ];
+
+        assert!(authenticate(&[], &HeaderMap::new()).is_some());
+        assert!(authenticate(&api_keys, &HeaderMap::new()).is_none());
+        assert!(authenticate(&api_keys, &headers("authorization", "Bearer unknown")).is_none());
+        assert!(authenticate(&api_keys, &headers("authorization", "admin")).is_none());
+
+        let admin = authenticate(&api_keys, &headers("authorization", "Bearer admin")).unwrap();
+        assert!(admin.allows("wallet.createAccount", &Value::Null));
+
+        let reader = authenticate(&api_keys, &headers(API_KEY_HEADER, "reader")).unwrap();
+        assert!(reader.allows("wallet.getAccounts", &Value::Null));
+        assert!(reader.allows("utils.generateMnemonic", &Value::Null));
+        assert!(!reader.allows("wallet.getAccount", &Value::Null));
+        assert!(!reader.allows("wallet.createAccount", &Value::Null));
+        assert!(!reader.allows("account.sync", &Value::Null));
+    }
+
+    #[test]
+    fn call_account_method_permissions() {
+        let call_account_method = |name: &str| {
+            serde_json::json!({
+                "accountId": 0,
+                "method": { "name": name }
+            })
+        };
+        let wallet_only = Permissions {
+            allowed_methods: Some(vec!["wallet.*".to_string(), "account.getBalance".to_string()]),
+        };
+
+        assert!(wallet_only.allows("wallet.callAccountMethod", &call_account_method("getBalance")));
+        assert!(!wallet_only.allows("wallet.callAccountMethod", &call_account_method("sendOutputs")));
+        assert!(!wallet_only.allows("wallet.callAccountMethod", &Value::Null));
+
+        let all = Permissions::default();
+        assert!(all.allows("wallet.callAccountMethod", &call_account_method("sendOutputs")));
+    }
+}
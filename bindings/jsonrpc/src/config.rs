@@ -0,0 +1,43 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    path::Path,
+};
+
+use iota_sdk_bindings_core::WalletOptions;
+use serde::Deserialize;
+
+use crate::{auth::ApiKey, error::Result};
+
+/// The default address the server listens on.
+pub const DEFAULT_BIND_ADDRESS: SocketAddr = SocketAddr::new(std::net::IpAddr::V4(Ipv4Addr::LOCALHOST), 14266);
+
+/// The configuration of the JSON-RPC server.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerConfig {
+    /// The address the server listens on.
+    #[serde(default = "default_bind_address")]
+    pub bind_address: SocketAddr,
+    /// The keys that are accepted to authenticate requests. If empty, requests are not authenticated.
+    #[serde(default)]
+    pub api_keys: Vec<ApiKey>,
+    /// The options to build the wallet that serves the requests.
+    pub wallet: WalletOptions,
+    /// The logger output configuration, as accepted by [`init_logger()`](iota_sdk_bindings_core::init_logger).
+    pub logger: Option<serde_json::Value>,
+}
+
+fn default_bind_address() -> SocketAddr {
+    DEFAULT_BIND_ADDRESS
+}
+
+impl ServerConfig {
+    /// Reads the configuration from a JSON file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let config = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&config)?)
+    }
+}
@@ -0,0 +1,25 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/// Result type of the JSON-RPC server.
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Error type of the JSON-RPC server.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// Io errors.
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    /// Logger errors.
+    #[error("failed to initialize the logger: {0}")]
+    Logger(String),
+    /// SerdeJson errors.
+    #[error("{0}")]
+    SerdeJson(#[from] serde_json::Error),
+    /// Server errors.
+    #[error("server error: {0}")]
+    Server(String),
+    /// Wallet errors.
+    #[error("{0}")]
+    Wallet(#[from] iota_sdk_bindings_core::iota_sdk::wallet::Error),
+}
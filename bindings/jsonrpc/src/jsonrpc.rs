@@ -0,0 +1,135 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Types of the JSON-RPC 2.0 protocol, see <https://www.jsonrpc.org/specification>
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+pub(crate) const JSONRPC_VERSION: &str = "2.0";
+
+/// Invalid JSON was received.
+pub(crate) const PARSE_ERROR: i64 = -32700;
+/// The JSON sent is not a valid request object.
+pub(crate) const INVALID_REQUEST: i64 = -32600;
+/// The method does not exist.
+pub(crate) const METHOD_NOT_FOUND: i64 = -32601;
+/// Invalid method parameters.
+pub(crate) const INVALID_PARAMS: i64 = -32602;
+/// The method panicked.
+pub(crate) const INTERNAL_ERROR: i64 = -32603;
+/// The method returned an error, which is sent as `data`.
+pub(crate) const METHOD_ERROR: i64 = -32000;
+/// The request isn't authenticated with a valid API key.
+pub(crate) const UNAUTHORIZED: i64 = -32001;
+/// The API key doesn't allow the method.
+pub(crate) const METHOD_NOT_ALLOWED: i64 = -32002;
+/// The method requires a WebSocket connection.
+pub(crate) const WEBSOCKET_REQUIRED: i64 = -32003;
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct Request {
+    pub(crate) jsonrpc: String,
+    /// `None` for notifications, which don't get a response.
+    #[serde(default, deserialize_with = "deserialize_id")]
+    pub(crate) id: Option<Value>,
+    pub(crate) method: String,
+    #[serde(default)]
+    pub(crate) params: Value,
+}
+
+// Distinguishes a `null` id from a missing one.
+fn deserialize_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Value>, D::Error> {
+    Value::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum Outcome {
+    Result(Value),
+    Error(ErrorObject),
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Response {
+    jsonrpc: &'static str,
+    #[serde(flatten)]
+    outcome: Outcome,
+    id: Value,
+}
+
+impl Response {
+    pub(crate) fn new(id: Value, result: Result<Value, ErrorObject>) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            outcome: match result {
+                Ok(result) => Outcome::Result(result),
+                Err(error) => Outcome::Error(error),
+            },
+            id,
+        }
+    }
+}
+
+/// A server-sent message for a subscription.
+#[derive(Debug, Serialize)]
+pub(crate) struct Notification {
+    jsonrpc: &'static str,
+    method: &'static str,
+    params: NotificationParams,
+}
+
+#[derive(Debug, Serialize)]
+struct NotificationParams {
+    subscription: u64,
+    result: Value,
+}
+
+impl Notification {
+    pub(crate) fn new(method: &'static str, subscription: u64, result: Value) -> Self {
+        Self {
+            jsonrpc: JSONRPC_VERSION,
+            method,
+            params: NotificationParams { subscription, result },
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct ErrorObject {
+    code: i64,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<Value>,
+}
+
+impl ErrorObject {
+    pub(crate) fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    pub(crate) fn with_data(mut self, data: Value) -> Self {
+        self.data = Some(data);
+        self
+    }
+
+    pub(crate) fn parse_error(error: impl ToString) -> Self {
+        Self::new(PARSE_ERROR, format!("parse error: {}", error.to_string()))
+    }
+
+    pub(crate) fn invalid_request(error: impl ToString) -> Self {
+        Self::new(INVALID_REQUEST, format!("invalid request: {}", error.to_string()))
+    }
+
+    pub(crate) fn method_not_found(method: &str) -> Self {
+        Self::new(METHOD_NOT_FOUND, format!("method not found: {method}"))
+    }
+
+    pub(crate) fn invalid_params(error: impl ToString) -> Self {
+        Self::new(INVALID_PARAMS, format!("invalid params: {}", error.to_string()))
+    }
+}
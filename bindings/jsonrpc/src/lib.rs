@@ -0,0 +1,18 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! JSON-RPC 2.0 server exposing the methods of the iota-sdk bindings over HTTP and WebSocket
+
+mod auth;
+mod config;
+mod error;
+mod jsonrpc;
+mod method;
+mod server;
+
+pub use self::{
+    auth::ApiKey,
+    config::ServerConfig,
+    error::{Error, Result},
+    server::Server,
+};
@@ -0,0 +1,40 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{net::TcpListener, path::PathBuf};
+
+use clap::Parser;
+use iota_sdk_bindings_core::init_logger;
+use iota_sdk_jsonrpc::{Error, Result, Server, ServerConfig};
+
+/// JSON-RPC 2.0 server for the IOTA SDK
+#[derive(Debug, Parser)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Path of the JSON configuration file.
+    #[arg(short, long, env = "IOTA_SDK_JSONRPC_CONFIG", default_value = "jsonrpc-config.json")]
+    config: PathBuf,
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+    let config = ServerConfig::from_file(&args.config)?;
+
+    if let Some(logger) = &config.logger {
+        init_logger(logger.to_string()).map_err(|e| Error::Logger(e.to_string()))?;
+    }
+    if config.api_keys.is_empty() {
+        log::warn!("no API keys are configured, requests are not authenticated");
+    }
+
+    let listener = TcpListener::bind(config.bind_address)?;
+    let server = Server::new(config.wallet.build().await?).with_api_keys(config.api_keys);
+    log::info!("listening on {}", config.bind_address);
+
+    server
+        .serve(listener, async {
+            tokio::signal::ctrl_c().await.ok();
+        })
+        .await
+}
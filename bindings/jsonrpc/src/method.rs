@@ -0,0 +1,142 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk_bindings_core::{
+    call_client_method, call_secret_manager_method, call_utils_method, call_wallet_method,
+    iota_sdk::wallet::{account::types::AccountIdentifier, Wallet},
+    AccountMethod, ProtocolMethod, Response, VersionedMethod,
+};
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::jsonrpc::{ErrorObject, INTERNAL_ERROR, METHOD_ERROR};
+
+/// The parameters of the `account.*` methods.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountParams {
+    account_id: AccountIdentifier,
+    #[serde(default)]
+    data: Value,
+}
+
+/// Calls a bindings method. Methods are named `<namespace>.<method>`, with the namespaces `wallet`, `account`,
+/// `client`, `secretManager` and `utils`, and the data of the bindings method as params. The params of `account`
+/// methods are the `accountId` and the method `data`.
+pub(crate) async fn call_method(wallet: &Wallet, method: &str, params: Value) -> Result<Value, ErrorObject> {
+    let Some((namespace, name)) = method.split_once('.') else {
+        return Err(ErrorObject::method_not_found(method));
+    };
+
+    let response = match namespace {
+        "wallet" => call_wallet_method(wallet, parse_method(method, name, params)?).await,
+        "account" => {
+            let params = serde_json::from_value::<AccountParams>(params).map_err(ErrorObject::invalid_params)?;
+            let method = parse_method::<AccountMethod>(method, name, params.data)?;
+            call_wallet_method(
+                wallet,
+                iota_sdk_bindings_core::WalletMethod::CallAccountMethod {
+                    account_id: params.account_id,
+                    method,
+                },
+            )
+            .await
+        }
+        "client" => call_client_method(wallet.client(), parse_method(method, name, params)?).await,
+        "secretManager" => {
+            call_secret_manager_method(wallet.get_secret_manager(), parse_method(method, name, params)?).await
+        }
+        "utils" => call_utils_method(parse_method(method, name, params)?),
+        _ => return Err(ErrorObject::method_not_found(method)),
+    };
+
+    into_result(response)
+}
+
+/// Deserializes a bindings method from its name and data, as a method sent with the current protocol version.
+fn parse_method<M: ProtocolMethod>(method: &str, name: &str, data: Value) -> Result<M, ErrorObject> {
+    if !M::names().iter().any(|known| known == name) {
+        return Err(ErrorObject::method_not_found(method));
+    }
+
+    let mut value = serde_json::Map::new();
+    value.insert("name".to_string(), Value::String(name.to_string()));
+    // Methods without data don't accept a `null` one
+    if !data.is_null() {
+        value.insert("data".to_string(), data);
+    }
+
+    VersionedMethod::<M>::from_json(&Value::Object(value).to_string())
+        .map(|versioned| versioned.method)
+        .map_err(ErrorObject::invalid_params)
+}
+
+/// Converts an error returned by a method, which is sent as `data`.
+pub(crate) fn method_error(error: iota_sdk_bindings_core::Error) -> ErrorObject {
+    let data = serde_json::to_value(&error).unwrap_or_default();
+    ErrorObject::new(METHOD_ERROR, error.to_string()).with_data(data)
+}
+
+/// Returns the payload of a response, or the error it contains.
+fn into_result(response: Response) -> Result<Value, ErrorObject> {
+    match response {
+        Response::Error(error) => Err(method_error(error)),
+        Response::Panic(message) => Err(ErrorObject::new(INTERNAL_ERROR, message)),
+        response => {
+            let mut value =
+                serde_json::to_value(response).map_err(|err| ErrorObject::new(INTERNAL_ERROR, err.to_string()))?;
+            Ok(value.get_mut("payload").map(Value::take).unwrap_or_default())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_sdk_bindings_core::{ClientMethod, UtilsMethod, WalletMethod};
+
+    use super::*;
+    use crate::jsonrpc::{INVALID_PARAMS, METHOD_NOT_FOUND};
+
+    fn code(error: ErrorObject) -> i64 {
+        serde_json::to_value(error).unwrap()["code"].as_i64().unwrap()
+    }
+
+    #[test]
+    fn parse_methods() {
+        assert!(matches!(
+            parse_method::<WalletMethod>("wallet.getAccounts", "getAccounts", Value::Null),
+            Ok(WalletMethod::GetAccounts)
+        ));
+        assert!(matches!(
+            parse_method::<UtilsMethod>(
+                "utils.isAddressValid",
+                "isAddressValid",
+                serde_json::json!({ "address": "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy" })
+            ),
+            Ok(UtilsMethod::IsAddressValid { .. })
+        ));
+        assert_eq!(
+            code(parse_method::<WalletMethod>("wallet.unknown", "unknown", Value::Null).unwrap_err()),
+            METHOD_NOT_FOUND
+        );
+        assert_eq!(
+            code(
+                parse_method::<UtilsMethod>("utils.isAddressValid", "isAddressValid", serde_json::json!({}))
+                    .unwrap_err()
+            ),
+            INVALID_PARAMS
+        );
+        // Unknown variants in the params aren't an unknown method
+        assert_eq!(
+            code(
+                parse_method::<ClientMethod>(
+                    "client.basicOutputIds",
+                    "basicOutputIds",
+                    serde_json::json!({ "queryParameters": [{ "unknownParameter": true }] })
+                )
+                .unwrap_err()
+            ),
+            INVALID_PARAMS
+        );
+    }
+}
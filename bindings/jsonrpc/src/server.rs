@@ -0,0 +1,350 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    net::TcpListener,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+};
+
+use axum::{
+    body::Bytes,
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response as HttpResponse},
+    routing::post,
+    Json, Router,
+};
+use futures::{SinkExt, Stream, StreamExt};
+use iota_sdk_bindings_core::iota_sdk::{
+    client::mqtt::{StreamOptions, Topic},
+    wallet::{events::EventFilter, Wallet},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::{
+    sync::mpsc::{self, UnboundedSender},
+    task::JoinHandle,
+};
+
+use crate::{
+    auth::{authenticate, ApiKey, Permissions},
+    error::{Error, Result},
+    jsonrpc::{
+        ErrorObject, Notification, Request, Response, JSONRPC_VERSION, METHOD_NOT_ALLOWED, UNAUTHORIZED,
+        WEBSOCKET_REQUIRED,
+    },
+    method::{call_method, method_error},
+};
+
+const EVENTS_SUBSCRIBE: &str = "events.subscribe";
+const EVENTS_UNSUBSCRIBE: &str = "events.unsubscribe";
+const EVENTS_NOTIFICATION: &str = "events.notification";
+const MQTT_SUBSCRIBE: &str = "mqtt.subscribe";
+const MQTT_UNSUBSCRIBE: &str = "mqtt.unsubscribe";
+const MQTT_NOTIFICATION: &str = "mqtt.notification";
+
+/// Serves the bindings methods of a wallet over JSON-RPC 2.0. Requests are accepted as HTTP `POST` and over a
+/// WebSocket connection on `/`, the `events.*` and `mqtt.*` subscriptions only over WebSocket.
+#[derive(Debug, Clone)]
+pub struct Server {
+    wallet: Wallet,
+    api_keys: Vec<ApiKey>,
+}
+
+#[derive(Debug)]
+struct ServerState {
+    wallet: Wallet,
+    api_keys: Vec<ApiKey>,
+}
+
+impl Server {
+    /// Creates a server for the wallet that doesn't authenticate requests.
+    pub fn new(wallet: Wallet) -> Self {
+        Self {
+            wallet,
+            api_keys: Vec::new(),
+        }
+    }
+
+    /// Only accepts requests authenticated with one of the keys.
+    pub fn with_api_keys(mut self, api_keys: impl IntoIterator<Item = ApiKey>) -> Self {
+        self.api_keys = api_keys.into_iter().collect();
+        self
+    }
+
+    /// Returns the routes of the server.
+    pub fn router(&self) -> Router {
+        let state = Arc::new(ServerState {
+            wallet: self.wallet.clone(),
+            api_keys: self.api_keys.clone(),
+        });
+        Router::new()
+            .route("/", post(handle_http).get(handle_websocket))
+            .with_state(state)
+    }
+
+    /// Serves requests until `shutdown` completes.
+    pub async fn serve(
+        &self,
+        listener: TcpListener,
+        shutdown: impl Future<Output = ()> + Send + 'static,
+    ) -> Result<()> {
+        listener.set_nonblocking(true)?;
+        axum::Server::from_tcp(listener)
+            .map_err(|e| Error::Server(e.to_string()))?
+            .serve(self.router().into_make_service())
+            .with_graceful_shutdown(shutdown)
+            .await
+            .map_err(|e| Error::Server(e.to_string()))
+    }
+}
+
+fn unauthorized() -> HttpResponse {
+    let response = Response::new(
+        Value::Null,
+        Err(ErrorObject::new(UNAUTHORIZED, "missing or invalid API key")),
+    );
+    (StatusCode::UNAUTHORIZED, Json(response)).into_response()
+}
+
+async fn handle_http(State(state): State<Arc<ServerState>>, headers: HeaderMap, body: Bytes) -> HttpResponse {
+    let Some(permissions) = authenticate(&state.api_keys, &headers) else {
+        return unauthorized();
+    };
+
+    match state.handle_message(&permissions, &body, None).await {
+        Some(response) => Json(response).into_response(),
+        // Only notifications were sent
+        None => StatusCode::NO_CONTENT.into_response(),
+    }
+}
+
+async fn handle_websocket(
+    State(state): State<Arc<ServerState>>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+) -> HttpResponse {
+    let Some(permissions) = authenticate(&state.api_keys, &headers) else {
+        return unauthorized();
+    };
+
+    upgrade.on_upgrade(move |socket| run_session(state, permissions, socket))
+}
+
+/// Handles the requests of a WebSocket connection concurrently and forwards their responses and the notifications of
+/// the subscriptions.
+async fn run_session(state: Arc<ServerState>, permissions: Permissions, socket: WebSocket) {
+    let (mut socket_sender, mut socket_receiver) = socket.split();
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let session = Arc::new(Session {
+        sender,
+        subscriptions: Default::default(),
+        next_subscription_id: AtomicU64::new(1),
+    });
+
+    loop {
+        tokio::select! {
+            message = socket_receiver.next() => {
+                let message = match message {
+                    Some(Ok(Message::Text(text))) => text.into_bytes(),
+                    Some(Ok(Message::Binary(bytes))) => bytes,
+                    // Pings are answered by axum
+                    Some(Ok(Message::Ping(_) | Message::Pong(_))) => continue,
+                    Some(Ok(Message::Close(_)) | Err(_)) | None => break,
+                };
+                let (state, permissions, session) = (state.clone(), permissions.clone(), session.clone());
+                tokio::spawn(async move {
+                    if let Some(response) = state.handle_message(&permissions, &message, Some(&session)).await {
+                        session.send(&response);
+                    }
+                });
+            }
+            Some(message) = receiver.recv() => {
+                if socket_sender.send(Message::Text(message)).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    session.unsubscribe_all();
+}
+
+impl ServerState {
+    /// Handles a single request or a batch, returns `None` if no response is needed.
+    async fn handle_message(
+        &self,
+        permissions: &Permissions,
+        message: &[u8],
+        session: Option<&Arc<Session>>,
+    ) -> Option<Value> {
+        let message = match serde_json::from_slice::<Value>(message) {
+            Ok(message) => message,
+            Err(err) => return Some(response(Value::Null, Err(ErrorObject::parse_error(err)))),
+        };
+
+        match message {
+            Value::Array(batch) if batch.is_empty() => {
+                Some(response(Value::Null, Err(ErrorObject::invalid_request("empty batch"))))
+            }
+            Value::Array(batch) => {
+                let mut responses = Vec::new();
+                for request in batch {
+                    responses.extend(self.handle_request(permissions, request, session).await);
+                }
+                (!responses.is_empty()).then_some(Value::Array(responses))
+            }
+            request => self.handle_request(permissions, request, session).await,
+        }
+    }
+
+    async fn handle_request(
+        &self,
+        permissions: &Permissions,
+        request: Value,
+        session: Option<&Arc<Session>>,
+    ) -> Option<Value> {
+        let request = match serde_json::from_value::<Request>(request) {
+            Ok(request) if request.jsonrpc == JSONRPC_VERSION => request,
+            Ok(request) => {
+                return Some(response(
+                    request.id.unwrap_or_default(),
+                    Err(ErrorObject::invalid_request("unsupported jsonrpc version")),
+                ));
+            }
+            Err(err) => return Some(response(Value::Null, Err(ErrorObject::invalid_request(err)))),
+        };
+        log::debug!("[jsonrpc] {}", request.method);
+
+        let result = if !permissions.allows(&request.method, &request.params) {
+            Err(ErrorObject::new(
+                METHOD_NOT_ALLOWED,
+                format!("method not allowed: {}", request.method),
+            ))
+        } else {
+            match request.method.as_str() {
+                EVENTS_SUBSCRIBE | EVENTS_UNSUBSCRIBE | MQTT_SUBSCRIBE | MQTT_UNSUBSCRIBE => match session {
+                    Some(session) => session.call_method(&self.wallet, &request.method, request.params).await,
+                    None => Err(ErrorObject::new(
+                        WEBSOCKET_REQUIRED,
+                        format!("{} requires a WebSocket connection", request.method),
+                    )),
+                },
+                method => call_method(&self.wallet, method, request.params).await,
+            }
+        };
+
+        // Notifications don't get a response
+        request.id.map(|id| response(id, result))
+    }
+}
+
+fn response(id: Value, result: std::result::Result<Value, ErrorObject>) -> Value {
+    serde_json::to_value(Response::new(id, result)).expect("failed to serialize response")
+}
+
+/// The subscriptions of a WebSocket connection.
+#[derive(Debug)]
+struct Session {
+    sender: UnboundedSender<String>,
+    subscriptions: Mutex<HashMap<u64, JoinHandle<()>>>,
+    next_subscription_id: AtomicU64,
+}
+
+#[derive(Deserialize)]
+struct MqttSubscribeParams {
+    topics: Vec<Topic>,
+}
+
+#[derive(Deserialize)]
+struct UnsubscribeParams {
+    subscription: u64,
+}
+
+impl Session {
+    fn send(&self, message: &Value) {
+        // The receiver is only dropped once the connection is closed
+        self.sender.send(message.to_string()).ok();
+    }
+
+    async fn call_method(
+        &self,
+        wallet: &Wallet,
+        method: &str,
+        params: Value,
+    ) -> std::result::Result<Value, ErrorObject> {
+        match method {
+            EVENTS_SUBSCRIBE => {
+                let filter = if params.is_null() {
+                    EventFilter::new()
+                } else {
+                    serde_json::from_value(params).map_err(ErrorObject::invalid_params)?
+                };
+                let events = wallet.events(filter).await;
+                Ok(self.subscribe(EVENTS_NOTIFICATION, events).into())
+            }
+            MQTT_SUBSCRIBE => {
+                let params =
+                    serde_json::from_value::<MqttSubscribeParams>(params).map_err(ErrorObject::invalid_params)?;
+                let events = wallet
+                    .client()
+                    .subscribe_stream(params.topics, StreamOptions::new())
+                    .await
+                    .map_err(|err| method_error(err.into()))?;
+                Ok(self.subscribe(MQTT_NOTIFICATION, events).into())
+            }
+            _ => {
+                let params =
+                    serde_json::from_value::<UnsubscribeParams>(params).map_err(ErrorObject::invalid_params)?;
+                Ok(self.unsubscribe(params.subscription).into())
+            }
+        }
+    }
+
+    /// Forwards the items of the stream as notifications and returns the subscription id.
+    fn subscribe<S>(&self, method: &'static str, mut stream: S) -> u64
+    where
+        S: Stream + Unpin + Send + 'static,
+        S::Item: Serialize,
+    {
+        let subscription = self.next_subscription_id.fetch_add(1, Ordering::Relaxed);
+        let sender = self.sender.clone();
+        let task = tokio::spawn(async move {
+            while let Some(item) = stream.next().await {
+                let result = serde_json::to_value(item).expect("failed to serialize notification");
+                let notification = serde_json::to_string(&Notification::new(method, subscription, result))
+                    .expect("failed to serialize notification");
+                if sender.send(notification).is_err() {
+                    break;
+                }
+            }
+        });
+        self.subscriptions.lock().unwrap().insert(subscription, task);
+        subscription
+    }
+
+    /// Cancels a subscription, returns `false` if there was no such subscription.
+    fn unsubscribe(&self, subscription: u64) -> bool {
+        match self.subscriptions.lock().unwrap().remove(&subscription) {
+            Some(task) => {
+                task.abort();
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn unsubscribe_all(&self) {
+        for (_, task) in self.subscriptions.lock().unwrap().drain() {
+            task.abort();
+        }
+    }
+}
@@ -0,0 +1,193 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{net::SocketAddr, time::Duration};
+
+use futures::{SinkExt, StreamExt};
+use iota_sdk_bindings_core::{
+    iota_sdk::client::{constants::SHIMMER_COIN_TYPE, secret::SecretManagerDto, ClientBuilder},
+    WalletOptions,
+};
+use iota_sdk_jsonrpc::{ApiKey, Server};
+use serde_json::{json, Value};
+use tokio_tungstenite::tungstenite::{client::IntoClientRequest, Message};
+
+async fn start_server(storage_path: &str) -> SocketAddr {
+    std::fs::remove_dir_all(storage_path).ok();
+
+    let secret_manager = r#"{"Mnemonic":"about solution utility exist rail budget vacuum major survey clerk pave ankle wealth gym gossip still medal expect strong rely amazing inspire lazy lunar"}"#;
+    let wallet = WalletOptions::default()
+        .with_storage_path(storage_path.to_string())
+        .with_client_options(ClientBuilder::new().with_node("http://localhost:14265").unwrap())
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_secret_manager(serde_json::from_str::<SecretManagerDto>(secret_manager).unwrap())
+        .build()
+        .await
+        .unwrap();
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let server = Server::new(wallet).with_api_keys([
+        ApiKey::new("admin"),
+        ApiKey::new("reader").with_allowed_methods(["wallet.getAccounts", "utils.*"]),
+    ]);
+    tokio::spawn(async move { server.serve(listener, std::future::pending()).await.unwrap() });
+
+    address
+}
+
+async fn post(address: SocketAddr, api_key: &str, body: Value) -> (u16, Option<Value>) {
+    let response = reqwest::Client::new()
+        .post(format!("http://{address}/"))
+        .bearer_auth(api_key)
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap();
+    (status, (!body.is_empty()).then(|| serde_json::from_str(&body).unwrap()))
+}
+
+#[tokio::test]
+async fn http_requests() {
+    let storage_path = "test-storage/jsonrpc_http_requests";
+    let address = start_server(storage_path).await;
+
+    let (status, response) = post(
+        address,
+        "unknown",
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "wallet.getAccounts" }),
+    )
+    .await;
+    assert_eq!(status, 401);
+    assert_eq!(response.unwrap()["error"]["code"], -32001);
+
+    let (_, response) = post(
+        address,
+        "admin",
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "wallet.createAccount", "params": { "alias": "Alice" } }),
+    )
+    .await;
+    let response = response.unwrap();
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["alias"], "Alice");
+
+    // Batch with a notification, an account method and errors
+    let (_, response) = post(
+        address,
+        "admin",
+        json!([
+            { "jsonrpc": "2.0", "method": "wallet.getAccounts" },
+            { "jsonrpc": "2.0", "id": "addresses", "method": "account.addresses", "params": { "accountId": "Alice" } },
+            { "jsonrpc": "2.0", "id": 3, "method": "wallet.unknown" },
+            { "jsonrpc": "2.0", "id": 4, "method": "events.subscribe" },
+            { "jsonrpc": "2.0", "id": 5 },
+        ]),
+    )
+    .await;
+    let response = response.unwrap();
+    let responses = response.as_array().unwrap();
+    assert_eq!(responses.len(), 4);
+    assert_eq!(responses[0]["id"], "addresses");
+    assert_eq!(responses[0]["result"].as_array().unwrap().len(), 1);
+    assert_eq!(responses[1]["error"]["code"], -32601);
+    assert_eq!(responses[2]["error"]["code"], -32003);
+    assert_eq!(responses[3]["error"]["code"], -32600);
+
+    // Only notifications
+    let (status, response) = post(
+        address,
+        "admin",
+        json!({ "jsonrpc": "2.0", "method": "wallet.getAccounts" }),
+    )
+    .await;
+    assert_eq!(status, 204);
+    assert!(response.is_none());
+
+    // Method errors are returned with the bindings error as data
+    let (_, response) = post(
+        address,
+        "admin",
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "wallet.getAccount", "params": { "accountId": "Bob" } }),
+    )
+    .await;
+    assert_eq!(response.unwrap()["error"]["data"]["type"], "wallet");
+
+    let (_, response) = post(
+        address,
+        "reader",
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "wallet.getAccounts" }),
+    )
+    .await;
+    assert_eq!(response.unwrap()["result"].as_array().unwrap().len(), 1);
+    let (_, response) = post(
+        address,
+        "reader",
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "wallet.createAccount" }),
+    )
+    .await;
+    assert_eq!(response.unwrap()["error"]["code"], -32002);
+
+    std::fs::remove_dir_all(storage_path).ok();
+}
+
+#[tokio::test]
+async fn websocket_subscriptions() {
+    let storage_path = "test-storage/jsonrpc_websocket_subscriptions";
+    let address = start_server(storage_path).await;
+
+    let mut request = format!("ws://{address}/").into_client_request().unwrap();
+    request.headers_mut().insert("X-Api-Key", "admin".parse().unwrap());
+    let (mut socket, _) = tokio_tungstenite::connect_async(request).await.unwrap();
+
+    socket
+        .send(Message::Text(
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "events.subscribe" }).to_string(),
+        ))
+        .await
+        .unwrap();
+    let response = receive_json(&mut socket).await;
+    let subscription = response["result"].as_u64().unwrap();
+
+    socket
+        .send(Message::Text(
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "wallet.emitTestEvent", "params": { "event": { "type": 0 } } })
+                .to_string(),
+        ))
+        .await
+        .unwrap();
+    // The notification and the response can arrive in any order
+    let mut messages = [receive_json(&mut socket).await, receive_json(&mut socket).await];
+    messages.sort_by_key(|message| message.get("id").is_some());
+    assert_eq!(messages[0]["method"], "events.notification");
+    assert_eq!(messages[0]["params"]["subscription"], subscription);
+    assert_eq!(
+        messages[0]["params"]["result"],
+        json!({ "accountIndex": 0, "event": { "type": 0 } })
+    );
+    assert_eq!(messages[1]["id"], 2);
+
+    socket
+        .send(Message::Text(
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "events.unsubscribe", "params": { "subscription": subscription } })
+                .to_string(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(receive_json(&mut socket).await["result"], true);
+
+    std::fs::remove_dir_all(storage_path).ok();
+}
+
+async fn receive_json<S>(socket: &mut S) -> Value
+where
+    S: futures::Stream<Item = Result<Message, tokio_tungstenite::tungstenite::Error>> + Unpin,
+{
+    let message = tokio::time::timeout(Duration::from_secs(10), socket.next())
+        .await
+        .unwrap()
+        .unwrap()
+        .unwrap();
+    serde_json::from_str(message.to_text().unwrap()).unwrap()
+}
@@ -21,6 +21,7 @@ const {
     destroyClient,
     listenMqtt,
     callWalletMethod,
+    cancelWalletOperation,
     createWallet,
     listenWallet,
     destroyWallet,
@@ -111,6 +112,7 @@ export {
     callSecretManagerMethodAsync,
     callUtilsMethod,
     callWalletMethodAsync,
+    cancelWalletOperation,
     destroyWallet,
     listenWalletAsync,
     getClientFromWallet,
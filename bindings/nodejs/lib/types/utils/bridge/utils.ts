@@ -205,3 +205,7 @@ export interface __OutputHexBytes__ {
         output: Output;
     };
 }
+
+export interface __GetCapabilitiesMethod__ {
+    name: 'getCapabilities';
+}
@@ -0,0 +1,26 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/** A previous method shape that is still accepted by the bindings. */
+export interface Deprecation {
+    /** The namespace of the method, like `wallet` or `account`. */
+    namespace: string;
+    /** The name of the method. */
+    method: string;
+    /** The protocol version in which the shape changed. */
+    version: number;
+    /** What changed. */
+    message: string;
+}
+
+/** The protocol versions and methods supported by the bindings. */
+export interface Capabilities {
+    /** The current protocol version. */
+    protocolVersion: number;
+    /** The oldest protocol version whose method shapes are still accepted. */
+    minProtocolVersion: number;
+    /** The names of the supported methods, by namespace. */
+    methods: { [namespace: string]: string[] };
+    /** The previous method shapes that are still accepted. */
+    deprecations: Deprecation[];
+}
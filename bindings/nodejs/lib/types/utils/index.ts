@@ -2,5 +2,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
 export * from './bridge';
+export * from './capabilities';
 export * from './hex-encoding';
 export * from './numeric';
@@ -108,6 +108,10 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /** Only request output ids that were created since the last sync of an address. Default: false. */
+    incremental?: boolean;
+    /** The number of unused public and internal addresses that should follow the last used address, missing addresses are generated and synced. Default: 0, which disables it. */
+    addressGapLimit?: number;
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
@@ -0,0 +1,16 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { Bech32Address } from '../block/address';
+
+/** A labeled address in the address book of the wallet */
+export interface AddressBookEntry {
+    /** The address. */
+    address: Bech32Address;
+    /** The label, unique in the address book. */
+    label: string;
+    /** Tags to group addresses. */
+    tags?: string[];
+    /** A note about the address. */
+    note?: string;
+}
@@ -30,6 +30,11 @@ import type {
     ParticipationEventType,
 } from '../participation';
 import type { ConsolidationParams } from '../consolidation-params';
+import type { SpendingPolicy } from '../spending-policy';
+import type {
+    TransactionHistoryFormat,
+    TransactionHistoryOptions,
+} from '../transaction-history';
 import {
     HexEncodedAmount,
     NumericString,
@@ -105,6 +110,15 @@ export type __DeregisterParticipationEventMethod__ = {
     };
 };
 
+export type __ExportTransactionHistoryMethod__ = {
+    name: 'exportTransactionHistory';
+    data: {
+        destination: string;
+        format?: TransactionHistoryFormat;
+        options?: TransactionHistoryOptions;
+    };
+};
+
 export type __GenerateEd25519AddressesMethod__ = {
     name: 'generateEd25519Addresses';
     data: {
@@ -152,6 +166,46 @@ export type __GetTransactionMethod__ = {
     };
 };
 
+export type __GetSpendingPolicyMethod__ = {
+    name: 'getSpendingPolicy';
+};
+
+export type __GetPendingApprovalMethod__ = {
+    name: 'getPendingApproval';
+    data: {
+        id: string;
+    };
+};
+
+export type __PendingApprovalsMethod__ = {
+    name: 'pendingApprovals';
+};
+
+export type __ApprovePendingApprovalMethod__ = {
+    name: 'approvePendingApproval';
+    data: {
+        id: string;
+        approver: string;
+    };
+};
+
+export type __RejectPendingApprovalMethod__ = {
+    name: 'rejectPendingApproval';
+    data: {
+        id: string;
+    };
+};
+
+export type __RequestApprovalMethod__ = {
+    name: 'requestApproval';
+    data: {
+        preparedTransactionData: PreparedTransactionData;
+        options?: TransactionOptions;
+        creator: string;
+        metadata?: string;
+    };
+};
+
 export type __AddressesMethod__ = {
     name: 'addresses';
 };
@@ -179,6 +233,13 @@ export type __TransactionsMethod__ = {
     name: 'transactions';
 };
 
+export type __TransactionHistoryMethod__ = {
+    name: 'transactionHistory';
+    data: {
+        options?: TransactionHistoryOptions;
+    };
+};
+
 export type __UnspentOutputsMethod__ = {
     name: 'unspentOutputs';
     data: {
@@ -306,10 +367,18 @@ export type __SetDefaultSyncOptionsMethod__ = {
     };
 };
 
+export type __SetSpendingPolicyMethod__ = {
+    name: 'setSpendingPolicy';
+    data: {
+        policy: SpendingPolicy;
+    };
+};
+
 export type __SignTransactionEssenceMethod__ = {
     name: 'signTransactionEssence';
     data: {
         preparedTransactionData: PreparedTransactionData;
+        options?: TransactionOptions;
     };
 };
 
@@ -9,18 +9,26 @@ import type {
     __PrepareConsolidateOutputsMethod__,
     __PrepareCreateAliasOutputMethod__,
     __DeregisterParticipationEventMethod__,
+    __ExportTransactionHistoryMethod__,
     __GenerateEd25519AddressesMethod__,
     __GetBalanceMethod__,
     __GetOutputMethod__,
     __GetFoundryOutputMethod__,
     __ClaimableOutputsMethod__,
     __GetTransactionMethod__,
+    __GetSpendingPolicyMethod__,
+    __GetPendingApprovalMethod__,
+    __PendingApprovalsMethod__,
+    __ApprovePendingApprovalMethod__,
+    __RejectPendingApprovalMethod__,
+    __RequestApprovalMethod__,
     __AddressesMethod__,
     __AddressesWithUnspentOutputsMethod__,
     __OutputsMethod__,
     __PendingTransactionsMethod__,
     __IncomingTransactionsMethod__,
     __TransactionsMethod__,
+    __TransactionHistoryMethod__,
     __UnspentOutputsMethod__,
     __PrepareCreateNativeTokenMethod__,
     __PrepareMeltNativeTokenMethod__,
@@ -38,6 +46,7 @@ import type {
     __SendOutputsMethod__,
     __SetAliasMethod__,
     __SetDefaultSyncOptionsMethod__,
+    __SetSpendingPolicyMethod__,
     __SignTransactionEssenceMethod__,
     __SignAndSubmitTransactionMethod__,
     __SubmitAndStoreTransactionMethod__,
@@ -60,18 +69,28 @@ import type {
     __ClearListenersMethod__,
     __CreateAccountMethod__,
     __EmitTestEventMethod__,
+    __ExportDataMethod__,
     __GenerateMnemonicMethod__,
     __GetAccountMethod__,
     __GetAccountIndexesMethod__,
     __GetAccountsMethod__,
+    __GetAddressBookMethod__,
+    __GetAddressBookEntryMethod__,
     __GetChrysalisDataMethod__,
     __GetLedgerNanoStatusMethod__,
+    __GetNftLabelsMethod__,
+    __GetOutputLabelsMethod__,
     __GenerateEd25519AddressMethod__,
+    __ImportDataMethod__,
     __IsStrongholdPasswordAvailableMethod__,
     __RecoverAccountsMethod__,
+    __RemoveAddressBookEntryMethod__,
     __RemoveLatestAccountMethod__,
     __RestoreBackupMethod__,
+    __SetAddressBookEntryMethod__,
     __SetClientOptionsMethod__,
+    __SetNftLabelMethod__,
+    __SetOutputLabelMethod__,
     __SetStrongholdPasswordClearIntervalMethod__,
     __SetStrongholdPasswordMethod__,
     __StartBackgroundSyncMethod__,
@@ -90,6 +109,7 @@ export type __AccountMethod__ =
     | __PrepareConsolidateOutputsMethod__
     | __PrepareCreateAliasOutputMethod__
     | __DeregisterParticipationEventMethod__
+    | __ExportTransactionHistoryMethod__
     | __GenerateEd25519AddressesMethod__
     | __GetBalanceMethod__
     | __GetOutputMethod__
@@ -101,12 +121,19 @@ export type __AccountMethod__ =
     | __GetParticipationEventsMethod__
     | __GetParticipationEventStatusMethod__
     | __GetTransactionMethod__
+    | __GetSpendingPolicyMethod__
+    | __GetPendingApprovalMethod__
+    | __PendingApprovalsMethod__
+    | __ApprovePendingApprovalMethod__
+    | __RejectPendingApprovalMethod__
+    | __RequestApprovalMethod__
     | __AddressesMethod__
     | __AddressesWithUnspentOutputsMethod__
     | __OutputsMethod__
     | __PendingTransactionsMethod__
     | __IncomingTransactionsMethod__
     | __TransactionsMethod__
+    | __TransactionHistoryMethod__
     | __UnspentOutputsMethod__
     | __PrepareCreateNativeTokenMethod__
     | __PrepareMeltNativeTokenMethod__
@@ -124,6 +151,7 @@ export type __AccountMethod__ =
     | __SendOutputsMethod__
     | __SetAliasMethod__
     | __SetDefaultSyncOptionsMethod__
+    | __SetSpendingPolicyMethod__
     | __SignTransactionEssenceMethod__
     | __SignAndSubmitTransactionMethod__
     | __SubmitAndStoreTransactionMethod__
@@ -150,18 +178,28 @@ export type __Method__ =
     | __ClearStrongholdPasswordMethod__
     | __CreateAccountMethod__
     | __EmitTestEventMethod__
+    | __ExportDataMethod__
     | __GenerateMnemonicMethod__
     | __GetAccountMethod__
     | __GetAccountIndexesMethod__
     | __GetAccountsMethod__
+    | __GetAddressBookMethod__
+    | __GetAddressBookEntryMethod__
     | __GetChrysalisDataMethod__
     | __GetLedgerNanoStatusMethod__
+    | __GetNftLabelsMethod__
+    | __GetOutputLabelsMethod__
     | __GenerateEd25519AddressMethod__
+    | __ImportDataMethod__
     | __IsStrongholdPasswordAvailableMethod__
     | __RecoverAccountsMethod__
+    | __RemoveAddressBookEntryMethod__
     | __RemoveLatestAccountMethod__
     | __RestoreBackupMethod__
+    | __SetAddressBookEntryMethod__
     | __SetClientOptionsMethod__
+    | __SetNftLabelMethod__
+    | __SetOutputLabelMethod__
     | __SetStrongholdPasswordClearIntervalMethod__
     | __SetStrongholdPasswordMethod__
     | __StartBackgroundSyncMethod__
@@ -1,5 +1,7 @@
 import type { AccountId, CreateAccountPayload, SyncOptions } from '../account';
 import type { GenerateAddressOptions } from '../address';
+import type { AddressBookEntry } from '../address-book';
+import type { Bech32Address, NftId, OutputId } from '../../block';
 import type { WalletEventType, WalletEvent } from '../event';
 import type { IAuth, IClientOptions } from '../../client';
 
@@ -38,10 +40,27 @@ export type __EmitTestEventMethod__ = {
     data: { event: WalletEvent };
 };
 
+export type __ExportDataMethod__ = {
+    name: 'exportData';
+    data: {
+        destination: string;
+        password?: string;
+    };
+};
+
 export type __GenerateMnemonicMethod__ = {
     name: 'generateMnemonic';
 };
 
+export type __GetAddressBookMethod__ = {
+    name: 'getAddressBook';
+};
+
+export type __GetAddressBookEntryMethod__ = {
+    name: 'getAddressBookEntry';
+    data: { address: Bech32Address };
+};
+
 export type __GetAccountIndexesMethod__ = {
     name: 'getAccountIndexes';
 };
@@ -59,6 +78,14 @@ export type __GetChrysalisDataMethod__ = {
     name: 'getChrysalisData';
 };
 
+export type __GetNftLabelsMethod__ = {
+    name: 'getNftLabels';
+};
+
+export type __GetOutputLabelsMethod__ = {
+    name: 'getOutputLabels';
+};
+
 export type __GetLedgerNanoStatusMethod__ = {
     name: 'getLedgerNanoStatus';
 };
@@ -73,6 +100,14 @@ export type __GenerateEd25519AddressMethod__ = {
     };
 };
 
+export type __ImportDataMethod__ = {
+    name: 'importData';
+    data: {
+        source: string;
+        password?: string;
+    };
+};
+
 export type __IsStrongholdPasswordAvailableMethod__ = {
     name: 'isStrongholdPasswordAvailable';
 };
@@ -137,3 +172,29 @@ export type __UpdateNodeAuthMethod__ = {
     name: 'updateNodeAuth';
     data: { url: string; auth?: IAuth };
 };
+
+export type __RemoveAddressBookEntryMethod__ = {
+    name: 'removeAddressBookEntry';
+    data: { address: Bech32Address };
+};
+
+export type __SetAddressBookEntryMethod__ = {
+    name: 'setAddressBookEntry';
+    data: { entry: AddressBookEntry };
+};
+
+export type __SetNftLabelMethod__ = {
+    name: 'setNftLabel';
+    data: {
+        nftId: NftId;
+        label?: string;
+    };
+};
+
+export type __SetOutputLabelMethod__ = {
+    name: 'setOutputLabel';
+    data: {
+        outputId: OutputId;
+        label?: string;
+    };
+};
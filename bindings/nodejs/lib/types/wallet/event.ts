@@ -2,10 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 import type { OutputData } from './output';
+import type { Balance } from './account';
 import { InclusionState } from './transaction';
 import { InputSigningData, Remainder } from '../client';
 import { TransactionEssence, TransactionPayload } from '../block';
+import { Bech32Address } from '../block/address';
+import { BlockId, NftId, TokenId } from '../block/id';
+import { OutputId } from '../block/output';
 import { OutputResponse } from '../models';
+import { HexEncodedAmount } from '../utils';
 
 /**
  * A Transaction ID represented as hex-encoded string.
@@ -51,6 +56,30 @@ enum WalletEventType {
     TransactionInclusion = 4,
     /** A progress update while submitting a transaction. */
     TransactionProgress = 5,
+    /** The balance of the account changed. */
+    BalanceChange = 6,
+    /** Native tokens were received. */
+    NativeTokensReceived = 7,
+    /** Native tokens were sent. */
+    NativeTokensSent = 8,
+    /** An NFT was received. */
+    NftReceived = 9,
+    /** An NFT was sent. */
+    NftSent = 10,
+    /** An output with an expiration unlock condition is about to expire. */
+    ExpirationApproaching = 11,
+    /** An output with an expiration unlock condition expired. */
+    OutputExpired = 12,
+    /** The health of a node changed. */
+    NodeHealthChange = 13,
+    /** Syncing an account started. */
+    SyncStarted = 14,
+    /** Syncing an account finished. */
+    SyncFinished = 15,
+    /** Writing to the storage failed. */
+    StorageError = 16,
+    /** A progress update of a long running operation. */
+    OperationProgress = 17,
 }
 
 /**
@@ -98,21 +127,25 @@ class NewOutputWalletEvent extends WalletEvent {
     output: OutputData;
     transaction?: TransactionPayload;
     transactionInputs?: OutputResponse[];
+    senderLabel?: string;
 
     /**
      * @param output The new output.
      * @param transaction The transaction that created the output. Might be pruned and not available.
      * @param transactionInputs The inputs for the transaction that created the output. Might be pruned and not available.
+     * @param senderLabel The address book label of the sender of the output, if it has a sender feature.
      */
     constructor(
         output: OutputData,
         transaction?: TransactionPayload,
         transactionInputs?: OutputResponse[],
+        senderLabel?: string,
     ) {
         super(WalletEventType.NewOutput);
         this.output = output;
         this.transaction = transaction;
         this.transactionInputs = transactionInputs;
+        this.senderLabel = senderLabel;
     }
 }
 
@@ -149,6 +182,199 @@ class TransactionInclusionWalletEvent extends WalletEvent {
     }
 }
 
+/**
+ * A 'balance change' wallet event.
+ */
+class BalanceChangeWalletEvent extends WalletEvent {
+    previous: Balance;
+    current: Balance;
+
+    /**
+     * @param previous The balance before the change.
+     * @param current The balance after the change.
+     */
+    constructor(previous: Balance, current: Balance) {
+        super(WalletEventType.BalanceChange);
+        this.previous = previous;
+        this.current = current;
+    }
+}
+
+/**
+ * A 'native tokens received' or 'native tokens sent' wallet event.
+ */
+class NativeTokensWalletEvent extends WalletEvent {
+    tokenId: TokenId;
+    amount: HexEncodedAmount;
+
+    /**
+     * @param type The type of the event, `NativeTokensReceived` or `NativeTokensSent`.
+     * @param tokenId The ID of the native token.
+     * @param amount The amount that was received or sent.
+     */
+    constructor(
+        type:
+            | WalletEventType.NativeTokensReceived
+            | WalletEventType.NativeTokensSent,
+        tokenId: TokenId,
+        amount: HexEncodedAmount,
+    ) {
+        super(type);
+        this.tokenId = tokenId;
+        this.amount = amount;
+    }
+}
+
+/**
+ * An 'NFT received' or 'NFT sent' wallet event.
+ */
+class NftWalletEvent extends WalletEvent {
+    nftId: NftId;
+    label?: string;
+
+    /**
+     * @param type The type of the event, `NftReceived` or `NftSent`.
+     * @param nftId The ID of the NFT.
+     * @param label The label of the NFT, if it has one.
+     */
+    constructor(
+        type: WalletEventType.NftReceived | WalletEventType.NftSent,
+        nftId: NftId,
+        label?: string,
+    ) {
+        super(type);
+        this.nftId = nftId;
+        this.label = label;
+    }
+}
+
+/**
+ * An 'expiration approaching' or 'output expired' wallet event.
+ */
+class ExpirationWalletEvent extends WalletEvent {
+    outputId: OutputId;
+    expiresAt: number;
+    returnAddress: Bech32Address;
+    returnAddressLabel?: string;
+
+    /**
+     * @param type The type of the event, `ExpirationApproaching` or `OutputExpired`.
+     * @param outputId The ID of the output.
+     * @param expiresAt The unix timestamp in seconds when the output expires.
+     * @param returnAddress The address that can unlock the output after it expired.
+     * @param returnAddressLabel The address book label of the return address, if it has one.
+     */
+    constructor(
+        type:
+            | WalletEventType.ExpirationApproaching
+            | WalletEventType.OutputExpired,
+        outputId: OutputId,
+        expiresAt: number,
+        returnAddress: Bech32Address,
+        returnAddressLabel?: string,
+    ) {
+        super(type);
+        this.outputId = outputId;
+        this.expiresAt = expiresAt;
+        this.returnAddress = returnAddress;
+        this.returnAddressLabel = returnAddressLabel;
+    }
+}
+
+/**
+ * A 'node health change' wallet event. It isn't related to an account and passes every account filter.
+ */
+class NodeHealthChangeWalletEvent extends WalletEvent {
+    url: string;
+    healthy: boolean;
+
+    /**
+     * @param url The URL of the node.
+     * @param healthy Whether the node is healthy now.
+     */
+    constructor(url: string, healthy: boolean) {
+        super(WalletEventType.NodeHealthChange);
+        this.url = url;
+        this.healthy = healthy;
+    }
+}
+
+/**
+ * A 'sync started' wallet event.
+ */
+class SyncStartedWalletEvent extends WalletEvent {
+    constructor() {
+        super(WalletEventType.SyncStarted);
+    }
+}
+
+/**
+ * A 'sync finished' wallet event.
+ */
+class SyncFinishedWalletEvent extends WalletEvent {
+    durationMs: number;
+    syncedAddresses: number;
+    syncedOutputs: number;
+
+    /**
+     * @param durationMs How long the sync took in milliseconds.
+     * @param syncedAddresses The number of synced addresses.
+     * @param syncedOutputs The number of synced outputs.
+     */
+    constructor(
+        durationMs: number,
+        syncedAddresses: number,
+        syncedOutputs: number,
+    ) {
+        super(WalletEventType.SyncFinished);
+        this.durationMs = durationMs;
+        this.syncedAddresses = syncedAddresses;
+        this.syncedOutputs = syncedOutputs;
+    }
+}
+
+/**
+ * A 'storage error' wallet event.
+ */
+class StorageErrorWalletEvent extends WalletEvent {
+    error: string;
+
+    /**
+     * @param error The error message.
+     */
+    constructor(error: string) {
+        super(WalletEventType.StorageError);
+        this.error = error;
+    }
+}
+
+/**
+ * All of the operation progress types.
+ */
+type OperationProgress =
+    | { type: 'syncingAccount'; accountIndex: number }
+    | { type: 'recoveringAccount'; accountIndex: number }
+    | { type: 'retryingBlock'; blockId: BlockId; attempt: number }
+    | { type: 'performingPow'; attempt: number };
+
+/**
+ * An 'operation progress' wallet event.
+ */
+class OperationProgressWalletEvent extends WalletEvent {
+    operationId: string;
+    progress: OperationProgress;
+
+    /**
+     * @param operationId The ID of the operation.
+     * @param progress The progress of the operation.
+     */
+    constructor(operationId: string, progress: OperationProgress) {
+        super(WalletEventType.OperationProgress);
+        this.operationId = operationId;
+        this.progress = progress;
+    }
+}
+
 /**
  * All of the transaction progress types.
  */
@@ -299,6 +525,16 @@ export {
     SpentOutputWalletEvent,
     TransactionInclusionWalletEvent,
     TransactionProgressWalletEvent,
+    BalanceChangeWalletEvent,
+    NativeTokensWalletEvent,
+    NftWalletEvent,
+    ExpirationWalletEvent,
+    NodeHealthChangeWalletEvent,
+    SyncStartedWalletEvent,
+    SyncFinishedWalletEvent,
+    StorageErrorWalletEvent,
+    OperationProgress,
+    OperationProgressWalletEvent,
     TransactionProgress,
     SelectingInputsProgress,
     GeneratingRemainderDepositAddressProgress,
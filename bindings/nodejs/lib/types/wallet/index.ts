@@ -4,16 +4,20 @@
 export * from './account';
 export * from './wallet';
 export * from './address';
+export * from './address-book';
 export * from './bridge';
 export * from './build-output-data';
 export * from './event';
 export * from './output';
 export * from './output-params';
 export * from './participation';
+export * from './pending-approval';
 export * from './prepared-create-token-transaction';
 export * from './prepared-transaction';
 export * from './signed-transaction-essence';
+export * from './spending-policy';
 export * from './transaction';
+export * from './transaction-history';
 export * from './transaction-options';
 export * from './prepared-create-token-transaction-data';
 export * from './consolidation-params';
@@ -0,0 +1,25 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import type { PreparedTransactionData } from '../client/prepared-transaction-data';
+import type { NumericString } from '../utils';
+import type { TransactionOptions } from './transaction-options';
+
+/**
+ * A prepared transaction that waits for an approver to sign and submit or reject it.
+ * Its inputs stay locked in the meantime.
+ */
+export interface PendingApproval {
+    /** The id, the hex encoded hash of the transaction essence. */
+    id: string;
+    /** Who requested the approval. */
+    creator: string;
+    /** Information for the approver, e.g. a reason or a ticket reference. */
+    metadata?: string;
+    /** Milliseconds since the Unix epoch when the approval was requested. */
+    timestamp: NumericString;
+    /** The prepared transaction. */
+    preparedTransactionData: PreparedTransactionData;
+    /** The options the transaction is submitted with. */
+    options?: TransactionOptions;
+}
@@ -1,7 +1,11 @@
 // Copyright 2021-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-import { Account, PreparedCreateNativeTokenTransactionData } from '../..';
+import {
+    Account,
+    PreparedCreateNativeTokenTransactionData,
+    TransactionOptions,
+} from '../..';
 
 import { PreparedTransaction } from './prepared-transaction';
 
@@ -14,12 +18,14 @@ export class PreparedCreateNativeTokenTransaction extends PreparedTransaction {
     /**
      * @param preparedData Prepared data to create a Native Token.
      * @param account A wallet account.
+     * @param options The options the transaction was prepared with.
      */
     constructor(
         preparedData: PreparedCreateNativeTokenTransactionData,
         account: Account,
+        options?: TransactionOptions,
     ) {
-        super(preparedData.transaction, account);
+        super(preparedData.transaction, account, options);
         this._tokenId = preparedData.tokenId;
     }
 
@@ -3,9 +3,11 @@
 
 import {
     Account,
+    PendingApproval,
     PreparedTransactionData,
     SignedTransactionEssence,
     Transaction,
+    TransactionOptions,
 } from '../..';
 
 /**
@@ -17,14 +19,21 @@ import {
 export class PreparedTransaction {
     readonly _preparedData: PreparedTransactionData;
     readonly _account: Account;
+    readonly _options?: TransactionOptions;
 
     /**
      * @param preparedData Prepared data to sign and submit a transaction.
      * @param account A wallet account.
+     * @param options The options the transaction was prepared with.
      */
-    constructor(preparedData: PreparedTransactionData, account: Account) {
+    constructor(
+        preparedData: PreparedTransactionData,
+        account: Account,
+        options?: TransactionOptions,
+    ) {
         this._preparedData = preparedData;
         this._account = account;
+        this._options = options;
     }
 
     /**
@@ -48,21 +57,23 @@ export class PreparedTransaction {
      * The `send()` method is returning a `Promise` that resolves to a `Transaction` object after it
      * has been signed and submitted.
      */
-    public async send(): Promise<Transaction> {
+    public async send(): Promise<Transaction | PendingApproval> {
         return this.signAndSubmitTransaction();
     }
 
     /**
      * This function signs a prepared transaction essence using the account's private key and returns
-     * the signed transaction essence.
+     * the signed transaction essence. The spending policy is checked with the options the transaction
+     * was prepared with.
      *
      * Returns:
      *
      * A `Promise` that resolves to a `SignedTransactionEssence` object.
      */
-    public async sign(): Promise<SignedTransactionEssence> {
+    public async sign(): Promise<SignedTransactionEssence | PendingApproval> {
         return this._account.signTransactionEssence(
             this.preparedTransactionData(),
+            this._options,
         );
     }
 
@@ -73,7 +84,9 @@ export class PreparedTransaction {
      *
      * A Promise that resolves to a Transaction object.
      */
-    public async signAndSubmitTransaction(): Promise<Transaction> {
+    public async signAndSubmitTransaction(): Promise<
+        Transaction | PendingApproval
+    > {
         return this._account.signAndSubmitTransaction(
             this.preparedTransactionData(),
         );
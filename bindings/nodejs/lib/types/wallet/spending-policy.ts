@@ -0,0 +1,34 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { Bech32Address } from '../block/address';
+import { NumericString } from '../utils';
+
+/**
+ * Rules that transactions of an account have to satisfy before they're prepared and signed.
+ * The spent amount of a transaction is the amount of the base coin in outputs that aren't owned by the account.
+ */
+export interface SpendingPolicy {
+    /** The maximum amount a single transaction can spend. */
+    maxAmountPerTransaction?: NumericString;
+    /** The maximum amount all transactions in a rolling window can spend. */
+    rollingLimit?: RollingSpendingLimit;
+    /** The only addresses outputs can be sent to, besides the addresses of the account. */
+    allowedDestinations?: Bech32Address[];
+    /** Whether transactions need a note. */
+    requireNote?: boolean;
+    /** Whether burning is forbidden. */
+    forbidBurning?: boolean;
+    /** The maximum number of native tokens an output can hold. */
+    maxNativeTokensPerOutput?: number;
+    /** Whether transactions are persisted as pending approvals instead of being signed. */
+    requireApproval?: boolean;
+}
+
+/** The maximum amount all transactions of an account can spend in a rolling window */
+export interface RollingSpendingLimit {
+    /** The maximum amount. */
+    amount: NumericString;
+    /** The length of the window in milliseconds, e.g. 86400000 for a day. */
+    windowInMilliseconds: number;
+}
@@ -0,0 +1,76 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { Bech32Address } from '../block/address';
+import { BlockId, TokenId } from '../block/id';
+import { HexEncodedAmount, NumericString } from '../utils';
+import type { InclusionState } from './transaction';
+
+/** The file format of an exported transaction history */
+export enum TransactionHistoryFormat {
+    /** Comma separated values with a row per transaction and asset, the base coin row has an empty token id. */
+    Csv = 'csv',
+    /** A JSON serialized transaction history entry per line. */
+    JsonLines = 'jsonLines',
+}
+
+/** Options to filter the transaction history */
+export interface TransactionHistoryOptions {
+    /** Only include transactions with a timestamp in milliseconds at or after this one. */
+    lowerBoundTimestamp?: NumericString;
+    /** Only include transactions with a timestamp in milliseconds at or before this one. */
+    upperBoundTimestamp?: NumericString;
+}
+
+/** The direction of a transaction from the point of view of the account */
+export enum TransactionDirection {
+    /** Sent by someone else to the account. */
+    Incoming = 'incoming',
+    /** Sent by the account to someone else. */
+    Outgoing = 'outgoing',
+    /** Sent by the account to its own addresses. */
+    SelfTransfer = 'selfTransfer',
+}
+
+/** The received and sent amount of a native token in a transaction history entry */
+export interface NativeTokenMovement {
+    /** The token id. */
+    tokenId: TokenId;
+    /** The received amount. */
+    received: HexEncodedAmount;
+    /** The sent amount. */
+    sent: HexEncodedAmount;
+}
+
+/**
+ * A transaction in the history of an account.
+ * Received and sent amounts are the net change of the account balance, only one of them is non zero.
+ */
+export interface TransactionHistoryEntry {
+    /** The transaction id. */
+    transactionId: string;
+    /** The id of the block the transaction was sent in. */
+    blockId?: BlockId;
+    /** The inclusion state of the transaction. */
+    inclusionState: InclusionState;
+    /** The timestamp in milliseconds, when the account created or received the transaction. */
+    timestamp: NumericString;
+    /** The direction of the transaction. */
+    direction: TransactionDirection;
+    /** The recipients of an outgoing transaction or the senders of an incoming one. */
+    counterparties: Bech32Address[];
+    /** The address book labels of the counterparties. */
+    counterpartyLabels?: { [address: Bech32Address]: string };
+    /** The received amount of the base coin. */
+    baseCoinReceived: NumericString;
+    /** The sent amount of the base coin. */
+    baseCoinSent: NumericString;
+    /** The received and sent native tokens. */
+    nativeTokens: NativeTokenMovement[];
+    /** The storage deposit sent to counterparties, which has to be returned to the account. */
+    storageDepositSent: NumericString;
+    /** The storage deposit received from counterparties, which the account has to return. */
+    storageDepositReceived: NumericString;
+    /** The note of the transaction. */
+    note?: string;
+}
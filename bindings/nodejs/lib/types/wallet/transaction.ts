@@ -36,6 +36,8 @@ export class Transaction {
     incoming!: boolean;
     /** Note that can be set when sending a transaction and is only stored locally */
     note?: string;
+    /** Who approved the transaction if the spending policy required approvals */
+    approver?: string;
     /**
      * Outputs that are used as input in the transaction.
      * May not be all, because some may have already been deleted from the node.
@@ -17,6 +17,7 @@ import {
     IRent,
     OutputId,
     Bech32Address,
+    Capabilities,
 } from '../types';
 import { AliasId, BlockId, FoundryId, NftId, TokenId } from '../types/block/id';
 
@@ -420,4 +421,15 @@ export class Utils {
         });
         return hexBytes;
     }
+
+    /**
+     * Returns the protocol versions and methods supported by the bindings.
+     *
+     * @returns The capabilities of the bindings.
+     */
+    static getCapabilities(): Capabilities {
+        return callUtilsMethod({
+            name: 'getCapabilities',
+        });
+    }
 }
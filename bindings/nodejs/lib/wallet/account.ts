@@ -20,7 +20,12 @@ import {
     OutputData,
     OutputParams,
     OutputsToClaim,
+    PendingApproval,
+    SpendingPolicy,
     Transaction,
+    TransactionHistoryEntry,
+    TransactionHistoryFormat,
+    TransactionHistoryOptions,
     TransactionOptions,
     ParticipationOverview,
     ParticipationEventId,
@@ -56,7 +61,7 @@ import {
     NumericString,
     Bech32Address,
 } from '../types';
-import { plainToInstance } from 'class-transformer';
+import { ClassConstructor, plainToInstance } from 'class-transformer';
 import { bigIntToHex, hexToBigInt } from '../types/utils/hex-encoding';
 
 /** The Account class. */
@@ -134,7 +139,7 @@ export class Account {
     async burn(
         burn: Burn,
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (await this.prepareBurn(burn, transactionOptions)).send();
     }
 
@@ -164,6 +169,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -200,6 +206,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -232,6 +239,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -241,7 +249,9 @@ export class Account {
      * @param outputIds The outputs to claim.
      * @returns The resulting transaction.
      */
-    async claimOutputs(outputIds: OutputId[]): Promise<Transaction> {
+    async claimOutputs(
+        outputIds: OutputId[],
+    ): Promise<Transaction | PendingApproval> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
@@ -251,8 +261,7 @@ export class Account {
                 },
             },
         );
-        const parsed = JSON.parse(response) as Response<Transaction>;
-        return plainToInstance(Transaction, parsed.payload);
+        return this.parseSignedResponse(Transaction, response);
     }
 
     /**
@@ -264,7 +273,7 @@ export class Account {
      */
     async consolidateOutputs(
         params: ConsolidationParams,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (await this.prepareConsolidateOutputs(params)).send();
     }
 
@@ -306,7 +315,7 @@ export class Account {
     async createAliasOutput(
         params?: AliasOutputParams,
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (
             await this.prepareCreateAliasOutput(params, transactionOptions)
         ).send();
@@ -339,6 +348,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -355,7 +365,7 @@ export class Account {
         tokenId: TokenId,
         meltAmount: bigint,
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (
             await this.prepareMeltNativeToken(
                 tokenId,
@@ -396,6 +406,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -449,6 +460,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -483,6 +495,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -558,6 +571,22 @@ export class Account {
         return payload;
     }
 
+    /**
+     * Parses the response of a method that signs a transaction, which is a
+     * pending approval if the spending policy of the account requires
+     * approvals.
+     */
+    private parseSignedResponse<T>(
+        cls: ClassConstructor<T>,
+        response: string,
+    ): T | PendingApproval {
+        const parsed = JSON.parse(response) as Response<T | PendingApproval>;
+        if (parsed.type === 'pendingApproval') {
+            return parsed.payload as PendingApproval;
+        }
+        return plainToInstance(cls, parsed.payload as T);
+    }
+
     /**
      * Get the data for an output.
      * @param outputId The output to get.
@@ -712,6 +741,21 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Get the spending policy of the account.
+     *
+     * @returns The spending policy.
+     */
+    async getSpendingPolicy(): Promise<SpendingPolicy> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getSpendingPolicy',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get the transaction with inputs of an incoming transaction stored in the account
      * List might not be complete, if the node pruned the data already
@@ -802,6 +846,111 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * List all the pending approvals of the account.
+     *
+     * @returns The pending approvals.
+     */
+    async pendingApprovals(): Promise<PendingApproval[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'pendingApprovals',
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get a pending approval of the account.
+     *
+     * @param id The ID of the pending approval.
+     * @returns The pending approval, if it exists.
+     */
+    async getPendingApproval(id: string): Promise<PendingApproval | undefined> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getPendingApproval',
+                data: {
+                    id,
+                },
+            },
+        );
+        return JSON.parse(response).payload ?? undefined;
+    }
+
+    /**
+     * Persist a prepared transaction as pending approval instead of signing it.
+     * Its inputs stay locked until the approval is approved or rejected.
+     *
+     * @param preparedTransactionData The prepared transaction data.
+     * @param creator Who requests the approval.
+     * @param metadata Information for the approver.
+     * @param transactionOptions The options the transaction is submitted with.
+     * @returns The pending approval.
+     */
+    async requestApproval(
+        preparedTransactionData: PreparedTransactionData,
+        creator: string,
+        metadata?: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<PendingApproval> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'requestApproval',
+                data: {
+                    preparedTransactionData,
+                    options: transactionOptions,
+                    creator,
+                    metadata,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Sign and submit the transaction of a pending approval.
+     *
+     * @param id The ID of the pending approval.
+     * @param approver The name of the approver, which can't be the creator of
+     * the pending approval.
+     * @returns The sent transaction.
+     */
+    async approvePendingApproval(
+        id: string,
+        approver: string,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'approvePendingApproval',
+                data: {
+                    id,
+                    approver,
+                },
+            },
+        );
+        const parsed = JSON.parse(response) as Response<Transaction>;
+        return plainToInstance(Transaction, parsed.payload);
+    }
+
+    /**
+     * Remove a pending approval and unlock its inputs.
+     *
+     * @param id The ID of the pending approval.
+     */
+    async rejectPendingApproval(id: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'rejectPendingApproval',
+            data: {
+                id,
+            },
+        });
+    }
+
     /**
      * List all incoming transactions of the account.
      *
@@ -834,6 +983,44 @@ export class Account {
         return plainToInstance(Transaction, parsed.payload);
     }
 
+    /**
+     * Get the sent and received transactions of the account ordered by timestamp,
+     * with their direction, counterparties and amounts.
+     *
+     * @param options Options to filter the transactions by timestamp.
+     * @returns The transaction history entries.
+     */
+    async transactionHistory(
+        options?: TransactionHistoryOptions,
+    ): Promise<TransactionHistoryEntry[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'transactionHistory',
+                data: { options },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Write the transaction history of the account to a file.
+     *
+     * @param destination The path of the file to write.
+     * @param format The file format, CSV by default.
+     * @param options Options to filter the transactions by timestamp.
+     */
+    async exportTransactionHistory(
+        destination: string,
+        format?: TransactionHistoryFormat,
+        options?: TransactionHistoryOptions,
+    ): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'exportTransactionHistory',
+            data: { destination, format, options },
+        });
+    }
+
     /**
      * List all the unspent outputs of the account.
      *
@@ -878,7 +1065,7 @@ export class Account {
         tokenId: TokenId,
         mintAmount: bigint,
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (
             await this.prepareMintNativeToken(
                 tokenId,
@@ -920,6 +1107,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -934,7 +1122,7 @@ export class Account {
     async createNativeToken(
         params: CreateNativeTokenParams,
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (
             await this.prepareCreateNativeToken(params, transactionOptions)
         ).send();
@@ -978,6 +1166,7 @@ export class Account {
                 parsed.payload,
             ),
             this,
+            transactionOptions,
         );
     }
 
@@ -992,7 +1181,7 @@ export class Account {
     async mintNfts(
         params: MintNftParams[],
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (await this.prepareMintNfts(params, transactionOptions)).send();
     }
 
@@ -1025,6 +1214,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -1097,6 +1287,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            options,
         );
     }
 
@@ -1111,7 +1302,7 @@ export class Account {
     async sendTransaction(
         outputs: Output[],
         options?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (await this.prepareTransaction(outputs, options)).send();
     }
 
@@ -1143,6 +1334,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            options,
         );
     }
 
@@ -1170,11 +1362,14 @@ export class Account {
     /**
      * Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
      * included (referenced by a milestone). Returns the included block id.
+     *
+     * @param operationId An optional id to cancel the retries with `Wallet.cancelOperation()`.
      */
     async retryTransactionUntilIncluded(
         transactionId: string,
         interval?: number,
         maxAttempts?: number,
+        operationId?: string,
     ): Promise<string> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
@@ -1186,6 +1381,7 @@ export class Account {
                     maxAttempts,
                 },
             },
+            operationId,
         );
         return JSON.parse(response).payload;
     }
@@ -1203,7 +1399,7 @@ export class Account {
         amount: bigint | NumericString,
         address: Bech32Address,
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         if (typeof amount === 'bigint') {
             amount = amount.toString(10);
         }
@@ -1218,8 +1414,7 @@ export class Account {
                 },
             },
         );
-        const parsed = JSON.parse(response) as Response<Transaction>;
-        return plainToInstance(Transaction, parsed.payload);
+        return this.parseSignedResponse(Transaction, response);
     }
 
     /**
@@ -1233,7 +1428,7 @@ export class Account {
     async sendWithParams(
         params: SendParams[],
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         for (let i = 0; i < params.length; i++) {
             if (typeof params[i].amount === 'bigint') {
                 params[i].amount = params[i].amount.toString(10);
@@ -1249,8 +1444,7 @@ export class Account {
                 },
             },
         );
-        const parsed = JSON.parse(response) as Response<Transaction>;
-        return plainToInstance(Transaction, parsed.payload);
+        return this.parseSignedResponse(Transaction, response);
     }
 
     /**
@@ -1264,7 +1458,7 @@ export class Account {
     async sendNativeTokens(
         params: SendNativeTokensParams[],
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (
             await this.prepareSendNativeTokens(params, transactionOptions)
         ).send();
@@ -1298,6 +1492,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -1312,7 +1507,7 @@ export class Account {
     async sendNft(
         params: SendNftParams[],
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         return (await this.prepareSendNft(params, transactionOptions)).send();
     }
 
@@ -1344,6 +1539,7 @@ export class Account {
         return new PreparedTransaction(
             plainToInstance(PreparedTransactionData, parsed.payload),
             this,
+            transactionOptions,
         );
     }
 
@@ -1358,7 +1554,7 @@ export class Account {
     async sendOutputs(
         outputs: Output[],
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
@@ -1370,8 +1566,7 @@ export class Account {
             },
         );
 
-        const parsed = JSON.parse(response) as Response<Transaction>;
-        return plainToInstance(Transaction, parsed.payload);
+        return this.parseSignedResponse(Transaction, response);
     }
 
     /**
@@ -1403,28 +1598,44 @@ export class Account {
         });
     }
 
+    /**
+     * Set the spending policy that prepared and signed transactions of the account have to satisfy.
+     * If storage is enabled, will persist during restarts.
+     *
+     * @param policy The spending policy to set.
+     */
+    async setSpendingPolicy(policy: SpendingPolicy): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setSpendingPolicy',
+            data: {
+                policy,
+            },
+        });
+    }
+
     /**
      * Sign a prepared transaction, useful for offline signing.
      *
      * @param preparedTransactionData The prepared transaction data to sign.
+     * @param transactionOptions The options the transaction was prepared with, to check
+     * the spending policy.
      * @returns The signed transaction essence.
      */
     async signTransactionEssence(
         preparedTransactionData: PreparedTransactionData,
-    ): Promise<SignedTransactionEssence> {
+        transactionOptions?: TransactionOptions,
+    ): Promise<SignedTransactionEssence | PendingApproval> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
                 name: 'signTransactionEssence',
                 data: {
                     preparedTransactionData,
+                    options: transactionOptions,
                 },
             },
         );
-        const parsed = JSON.parse(
-            response,
-        ) as Response<SignedTransactionEssence>;
-        return plainToInstance(SignedTransactionEssence, parsed.payload);
+        return this.parseSignedResponse(SignedTransactionEssence, response);
     }
 
     /**
@@ -1435,7 +1646,7 @@ export class Account {
      */
     async signAndSubmitTransaction(
         preparedTransactionData: PreparedTransactionData,
-    ): Promise<Transaction> {
+    ): Promise<Transaction | PendingApproval> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
@@ -1445,8 +1656,7 @@ export class Account {
                 },
             },
         );
-        const parsed = JSON.parse(response) as Response<Transaction>;
-        return plainToInstance(Transaction, parsed.payload);
+        return this.parseSignedResponse(Transaction, response);
     }
 
     /**
@@ -1477,9 +1687,10 @@ export class Account {
      * A custom default can be set using setDefaultSyncOptions.
      *
      * @param options Optional synchronization options.
+     * @param operationId An optional id to cancel the syncing with `Wallet.cancelOperation()`.
      * @returns The account balance.
      */
-    async sync(options?: SyncOptions): Promise<Balance> {
+    async sync(options?: SyncOptions, operationId?: string): Promise<Balance> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
@@ -1488,6 +1699,7 @@ export class Account {
                     options,
                 },
             },
+            operationId,
         );
         const payload = JSON.parse(response).payload;
         return this.adjustBalancePayload(payload);
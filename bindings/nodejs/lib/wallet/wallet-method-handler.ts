@@ -3,6 +3,7 @@
 
 import {
     callWalletMethodAsync,
+    cancelWalletOperation,
     createWallet,
     listenWalletAsync,
     destroyWallet,
@@ -20,6 +21,9 @@ import type {
 import { Client } from '../client';
 import { SecretManager } from '../secret_manager';
 
+// The version of the method shapes sent to the rust bindings.
+const PROTOCOL_VERSION = 1;
+
 // The WalletMethodHandler class interacts with methods with the rust bindings.
 export class WalletMethodHandler {
     methodHandler: any;
@@ -42,17 +46,24 @@ export class WalletMethodHandler {
      * Call a wallet method on the Rust backend.
      *
      * @param method The wallet method to call.
+     * @param operationId An optional id to cancel the method with `cancelOperation()` while it runs.
      */
-    async callMethod(method: __Method__): Promise<string> {
+    async callMethod(
+        method: __Method__,
+        operationId?: string,
+    ): Promise<string> {
         return callWalletMethodAsync(
             // mapToObject is required to convert maps to array since they otherwise get serialized as `[{}]` even if not empty
-            JSON.stringify(method, function mapToObject(_key, value) {
-                if (value instanceof Map) {
-                    return Object.fromEntries(value);
-                } else {
-                    return value;
-                }
-            }),
+            JSON.stringify(
+                { ...method, protocolVersion: PROTOCOL_VERSION, operationId },
+                function mapToObject(_key, value) {
+                    if (value instanceof Map) {
+                        return Object.fromEntries(value);
+                    } else {
+                        return value;
+                    }
+                },
+            ),
             this.methodHandler,
         ).catch((error: Error) => {
             try {
@@ -73,18 +84,23 @@ export class WalletMethodHandler {
      *
      * @param accountIndex The account index.
      * @param method The account method to call.
+     * @param operationId An optional id to cancel the method with `cancelOperation()` while it runs.
      */
     async callAccountMethod(
         accountIndex: AccountId,
         method: __AccountMethod__,
+        operationId?: string,
     ): Promise<string> {
-        return this.callMethod({
-            name: 'callAccountMethod',
-            data: {
-                accountId: accountIndex,
-                method,
+        return this.callMethod(
+            {
+                name: 'callAccountMethod',
+                data: {
+                    accountId: accountIndex,
+                    method,
+                },
             },
-        });
+            operationId,
+        );
     }
 
     /**
@@ -100,6 +116,16 @@ export class WalletMethodHandler {
         return listenWalletAsync(eventTypes, callback, this.methodHandler);
     }
 
+    /**
+     * Cancel a method that was called with an operation id, it stops at its next cancellation point.
+     *
+     * @param operationId The operation id the method was called with.
+     * @returns Whether a running operation with this id was found.
+     */
+    async cancelOperation(operationId: string): Promise<boolean> {
+        return cancelWalletOperation(this.methodHandler, operationId);
+    }
+
     async destroy(): Promise<void> {
         return destroyWallet(this.methodHandler);
     }
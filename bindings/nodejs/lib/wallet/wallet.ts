@@ -6,6 +6,7 @@ import { Account } from './account';
 
 import type {
     AccountId,
+    AddressBookEntry,
     WalletOptions,
     CreateAccountPayload,
     WalletEventType,
@@ -15,6 +16,7 @@ import type {
     Event,
 } from '../types/wallet';
 import { IAuth, IClientOptions, LedgerNanoStatus } from '../types/client';
+import type { Bech32Address, NftId, OutputId } from '../types/block';
 import { Client } from '../client';
 import { SecretManager } from '../secret_manager';
 
@@ -78,6 +80,16 @@ export class Wallet {
         return new Account(JSON.parse(response).payload, this.methodHandler);
     }
 
+    /**
+     * Cancel a method that was called with an operation id, it stops at its next cancellation point.
+     *
+     * @param operationId The operation id the method was called with.
+     * @returns Whether a running operation with this id was found.
+     */
+    async cancelOperation(operationId: string): Promise<boolean> {
+        return this.methodHandler.cancelOperation(operationId);
+    }
+
     /**
      * Destroy the Wallet and drop its database connection.
      */
@@ -95,6 +107,20 @@ export class Wallet {
         });
     }
 
+    /**
+     * Export the coin type, client options and accounts in the portable JSON format, encrypted if a password is
+     * provided. The secret manager is not exported.
+     */
+    async exportData(destination: string, password?: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'exportData',
+            data: {
+                destination,
+                password,
+            },
+        });
+    }
+
     /**
      * Get an account by its alias or index.
      */
@@ -141,6 +167,31 @@ export class Wallet {
         return accounts;
     }
 
+    /**
+     * Get the entries of the address book.
+     */
+    async getAddressBook(): Promise<AddressBookEntry[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getAddressBook',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the address book entry of an address.
+     */
+    async getAddressBookEntry(
+        address: Bech32Address,
+    ): Promise<AddressBookEntry | undefined> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getAddressBookEntry',
+            data: { address },
+        });
+
+        return JSON.parse(response).payload ?? undefined;
+    }
+
     /**
      * Get client.
      */
@@ -159,6 +210,28 @@ export class Wallet {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the labels of NFTs.
+     */
+    async getNftLabels(): Promise<Record<NftId, string>> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getNftLabels',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the labels of outputs.
+     */
+    async getOutputLabels(): Promise<Record<OutputId, string>> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getOutputLabels',
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get secret manager.
      */
@@ -197,6 +270,20 @@ export class Wallet {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Import data exported with `exportData` into a wallet without accounts, which has to use the same seed. Replaces
+     * the coin type and client options.
+     */
+    async importData(source: string, password?: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'importData',
+            data: {
+                source,
+                password,
+            },
+        });
+    }
+
     /**
      * Check if the Stronghold password has been set.
      */
@@ -230,22 +317,28 @@ export class Wallet {
 
     /**
      * Find accounts with unspent outputs.
+     *
+     * @param operationId An optional id to cancel the recovery with `Wallet.cancelOperation()`.
      */
     async recoverAccounts(
         accountStartIndex: number,
         accountGapLimit: number,
         addressGapLimit: number,
         syncOptions: SyncOptions,
+        operationId?: string,
     ): Promise<Account[]> {
-        const response = await this.methodHandler.callMethod({
-            name: 'recoverAccounts',
-            data: {
-                accountStartIndex,
-                accountGapLimit,
-                addressGapLimit,
-                syncOptions,
+        const response = await this.methodHandler.callMethod(
+            {
+                name: 'recoverAccounts',
+                data: {
+                    accountStartIndex,
+                    accountGapLimit,
+                    addressGapLimit,
+                    syncOptions,
+                },
             },
-        });
+            operationId,
+        );
         const accounts: Account[] = [];
 
         for (const account of JSON.parse(response).payload) {
@@ -254,6 +347,20 @@ export class Wallet {
         return accounts;
     }
 
+    /**
+     * Remove the address book entry of an address and return it.
+     */
+    async removeAddressBookEntry(
+        address: Bech32Address,
+    ): Promise<AddressBookEntry | undefined> {
+        const response = await this.methodHandler.callMethod({
+            name: 'removeAddressBookEntry',
+            data: { address },
+        });
+
+        return JSON.parse(response).payload ?? undefined;
+    }
+
     /**
      * Delete the latest account.
      */
@@ -290,6 +397,17 @@ export class Wallet {
         });
     }
 
+    /**
+     * Add an entry to the address book or replace the entry of its address. The label must not be used for another
+     * address.
+     */
+    async setAddressBookEntry(entry: AddressBookEntry): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'setAddressBookEntry',
+            data: { entry },
+        });
+    }
+
     /**
      * Set ClientOptions.
      */
@@ -300,6 +418,26 @@ export class Wallet {
         });
     }
 
+    /**
+     * Set the label of an NFT, or remove it if no label is provided.
+     */
+    async setNftLabel(nftId: NftId, label?: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'setNftLabel',
+            data: { nftId, label },
+        });
+    }
+
+    /**
+     * Set the label of an output, or remove it if no label is provided.
+     */
+    async setOutputLabel(outputId: OutputId, label?: string): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'setOutputLabel',
+            data: { outputId, label },
+        });
+    }
+
     /**
      * Set the Stronghold password.
      */
@@ -60,6 +60,7 @@ fn main(mut cx: ModuleContext) -> NeonResult<()> {
 
     // Wallet
     cx.export_function("callWalletMethod", wallet::call_wallet_method)?;
+    cx.export_function("cancelWalletOperation", wallet::cancel_wallet_operation)?;
     cx.export_function("createWallet", wallet::create_wallet)?;
     cx.export_function("destroyWallet", wallet::destroy_wallet)?;
     cx.export_function("getClientFromWallet", wallet::get_client)?;
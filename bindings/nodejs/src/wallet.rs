@@ -4,13 +4,13 @@
 use std::sync::Arc;
 
 use iota_sdk_bindings_core::{
-    call_wallet_method as rust_call_wallet_method,
+    call_versioned_wallet_method,
     iota_sdk::wallet::{
         events::types::{Event, WalletEventType},
         migration::migrate_db_chrysalis_to_stardust as rust_migrate_db_chrysalis_to_stardust,
         Wallet,
     },
-    Response, Result, WalletMethod, WalletOptions,
+    Operations, Response, Result, VersionedMethod, WalletMethod, WalletOptions,
 };
 use neon::prelude::*;
 use tokio::sync::RwLock;
@@ -29,6 +29,7 @@ impl Finalize for WalletMethodHandlerWrapper {}
 pub struct WalletMethodHandler {
     channel: Channel,
     wallet: Wallet,
+    operations: Operations,
 }
 
 type JsCallback = Root<JsFunction<JsObject>>;
@@ -39,14 +40,18 @@ impl WalletMethodHandler {
 
         let wallet = crate::RUNTIME.block_on(async move { wallet_options.build().await })?;
 
-        Ok(Self { channel, wallet })
+        Ok(Self {
+            channel,
+            wallet,
+            operations: Operations::default(),
+        })
     }
 
     async fn call_method(&self, method: String) -> (String, bool) {
-        match serde_json::from_str::<WalletMethod>(&method) {
+        match VersionedMethod::<WalletMethod>::from_json(&method) {
             Ok(method) => {
-                let res = rust_call_wallet_method(&self.wallet, method).await;
-                let mut is_err = matches!(res, Response::Error(_) | Response::Panic(_));
+                let res = call_versioned_wallet_method(&self.wallet, &self.operations, method).await;
+                let mut is_err = matches!(res.response, Response::Error(_) | Response::Panic(_));
 
                 let msg = match serde_json::to_string(&res) {
                     Ok(msg) => msg,
@@ -61,7 +66,7 @@ impl WalletMethodHandler {
             Err(e) => {
                 log::error!("{:?}", e);
                 (
-                    serde_json::to_string(&Response::Error(e.into())).expect("json to string error"),
+                    serde_json::to_string(&Response::Error(e)).expect("json to string error"),
                     true,
                 )
             }
@@ -173,6 +178,23 @@ pub fn destroy_wallet(mut cx: FunctionContext) -> JsResult<JsPromise> {
     Ok(promise)
 }
 
+pub fn cancel_wallet_operation(mut cx: FunctionContext) -> JsResult<JsPromise> {
+    let method_handler = Arc::clone(&cx.argument::<JsBox<WalletMethodHandlerWrapper>>(0)?.0);
+    let operation_id = cx.argument::<JsString>(1)?.value(&mut cx);
+    let channel = cx.channel();
+
+    let (deferred, promise) = cx.promise();
+    crate::RUNTIME.spawn(async move {
+        let cancelled = match &*method_handler.read().await {
+            Some(method_handler) => method_handler.operations.cancel(&operation_id),
+            None => false,
+        };
+        deferred.settle_with(&channel, move |mut cx| Ok(cx.boolean(cancelled)));
+    });
+
+    Ok(promise)
+}
+
 pub fn get_client(mut cx: FunctionContext) -> JsResult<JsPromise> {
     let method_handler = Arc::clone(&cx.argument::<JsBox<WalletMethodHandlerWrapper>>(0)?.0);
     let channel = cx.channel();
@@ -11,6 +11,7 @@ from .wallet.sync_options import AccountSyncOptions, AliasSyncOptions, NftSyncOp
 from .secret_manager.secret_manager import *
 from .prefix_hex import *
 from .types.address import *
+from .types.address_book import *
 from .types.balance import *
 from .types.block import *
 from .types.block_builder_options import *
@@ -31,9 +32,12 @@ from .types.output_data import *
 from .types.output_id import *
 from .types.output_params import *
 from .types.payload import *
+from .types.pending_approval import *
 from .types.send_params import *
+from .types.spending_policy import *
 from .types.token_scheme import *
 from .types.transaction import *
+from .types.transaction_history import *
 from .types.transaction_data import *
 from .types.transaction_options import *
 from .types.unlock import *
@@ -0,0 +1,22 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass, field
+from typing import List, Optional
+
+
+@dataclass
+class AddressBookEntry:
+    """A labeled address in the address book of the wallet.
+
+    Attributes:
+        address: The bech32 address.
+        label: The label, unique in the address book.
+        tags: Tags to group addresses.
+        note: A note about the address.
+    """
+    address: str
+    label: str
+    tags: List[str] = field(default_factory=list)
+    note: Optional[str] = None
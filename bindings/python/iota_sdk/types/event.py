@@ -14,6 +14,18 @@ class WalletEventType(IntEnum):
         SpentOutput (3): An output was spent.
         TransactionInclusion (4): A transaction was included into the ledger.
         TransactionProgress (5): A progress update while submitting a transaction.
+        BalanceChange (6): The balance of the account changed.
+        NativeTokensReceived (7): Native tokens were received.
+        NativeTokensSent (8): Native tokens were sent.
+        NftReceived (9): An NFT was received.
+        NftSent (10): An NFT was sent.
+        ExpirationApproaching (11): An output with an expiration unlock condition is about to expire.
+        OutputExpired (12): An output with an expiration unlock condition expired.
+        NodeHealthChange (13): The health of a node changed.
+        SyncStarted (14): Syncing an account started.
+        SyncFinished (15): Syncing an account finished.
+        StorageError (16): Writing to the storage failed.
+        OperationProgress (17): A progress update of a long running operation.
     """
     ConsolidationRequired = 0,
     LedgerAddressGeneration = 1,
@@ -21,3 +33,15 @@ class WalletEventType(IntEnum):
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    BalanceChange = 6,
+    NativeTokensReceived = 7,
+    NativeTokensSent = 8,
+    NftReceived = 9,
+    NftSent = 10,
+    ExpirationApproaching = 11,
+    OutputExpired = 12,
+    NodeHealthChange = 13,
+    SyncStarted = 14,
+    SyncFinished = 15,
+    StorageError = 16,
+    OperationProgress = 17,
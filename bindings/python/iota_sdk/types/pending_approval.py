@@ -0,0 +1,28 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import Any, Dict, Optional
+from iota_sdk.types.transaction_data import PreparedTransactionData
+
+
+@dataclass
+class PendingApproval:
+    """A prepared transaction that waits for an approver to sign and submit or reject it.
+    Its inputs stay locked in the meantime.
+
+    Attributes:
+        id: The id, the hex encoded hash of the transaction essence.
+        creator: Who requested the approval.
+        timestamp: Milliseconds since the Unix epoch when the approval was requested.
+        preparedTransactionData: The prepared transaction.
+        metadata: Information for the approver, e.g. a reason or a ticket reference.
+        options: The options the transaction is submitted with.
+    """
+    id: str
+    creator: str
+    timestamp: str
+    preparedTransactionData: PreparedTransactionData
+    metadata: Optional[str] = None
+    options: Optional[Dict[str, Any]] = None
@@ -0,0 +1,41 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass
+from typing import List, Optional
+
+
+@dataclass
+class RollingSpendingLimit:
+    """The maximum amount all transactions of an account can spend in a rolling window.
+
+    Attributes:
+        amount: The maximum amount.
+        windowInMilliseconds: The length of the window in milliseconds, e.g. 86400000 for a day.
+    """
+    amount: str
+    windowInMilliseconds: int
+
+
+@dataclass
+class SpendingPolicy:
+    """Rules that transactions of an account have to satisfy before they're prepared and signed.
+    The spent amount of a transaction is the amount of the base coin in outputs that aren't owned by the account.
+
+    Attributes:
+        maxAmountPerTransaction: The maximum amount a single transaction can spend.
+        rollingLimit: The maximum amount all transactions in a rolling window can spend.
+        allowedDestinations: The only addresses outputs can be sent to, besides the addresses of the account.
+        requireNote: Whether transactions need a note.
+        forbidBurning: Whether burning is forbidden.
+        maxNativeTokensPerOutput: The maximum number of native tokens an output can hold.
+        requireApproval: Whether transactions are persisted as pending approvals instead of being signed.
+    """
+    maxAmountPerTransaction: Optional[str] = None
+    rollingLimit: Optional[RollingSpendingLimit] = None
+    allowedDestinations: Optional[List[str]] = None
+    requireNote: bool = False
+    forbidBurning: bool = False
+    maxNativeTokensPerOutput: Optional[int] = None
+    requireApproval: bool = False
@@ -38,6 +38,7 @@ class Transaction:
         incoming: Indicates whether the transaction was created by the wallet or whether it was sent by someone else and is incoming.
         inputs: The inputs of the transaction.
         note: A note attached to the transaction.
+        approver: Who approved the transaction, if the spending policy required an approval.
         blockId: The ID of the block that holds the transaction.
     """
     payload: TransactionPayload
@@ -48,6 +49,7 @@ class Transaction:
     incoming: bool
     inputs = List[OutputWithMetadata]
     note: Optional[str] = None
+    approver: Optional[str] = None
     blockId: Optional[HexStr] = None
 
     @classmethod
@@ -0,0 +1,93 @@
+# Copyright 2023 IOTA Stiftung
+# SPDX-License-Identifier: Apache-2.0
+
+from __future__ import annotations
+from dataclasses import dataclass, field
+from enum import Enum
+from typing import Dict, List, Optional
+from iota_sdk.types.common import HexStr
+
+
+class TransactionHistoryFormat(str, Enum):
+    """The file format of an exported transaction history.
+
+    Attributes:
+        Csv: Comma separated values with a row per transaction and asset, the base coin row has an empty token id.
+        JsonLines: A JSON serialized transaction history entry per line.
+    """
+    Csv = 'csv'
+    JsonLines = 'jsonLines'
+
+
+class TransactionDirection(str, Enum):
+    """The direction of a transaction from the point of view of the account.
+
+    Attributes:
+        Incoming: Sent by someone else to the account.
+        Outgoing: Sent by the account to someone else.
+        SelfTransfer: Sent by the account to its own addresses.
+    """
+    Incoming = 'incoming'
+    Outgoing = 'outgoing'
+    SelfTransfer = 'selfTransfer'
+
+
+@dataclass
+class TransactionHistoryOptions:
+    """Options to filter the transaction history.
+
+    Attributes:
+        lowerBoundTimestamp: Only include transactions with a timestamp in milliseconds at or after this one.
+        upperBoundTimestamp: Only include transactions with a timestamp in milliseconds at or before this one.
+    """
+    lowerBoundTimestamp: Optional[str] = None
+    upperBoundTimestamp: Optional[str] = None
+
+
+@dataclass
+class NativeTokenMovement:
+    """The received and sent amount of a native token in a transaction history entry.
+
+    Attributes:
+        tokenId: The token id.
+        received: The received amount.
+        sent: The sent amount.
+    """
+    tokenId: HexStr
+    received: HexStr
+    sent: HexStr
+
+
+@dataclass
+class TransactionHistoryEntry:
+    """A transaction in the history of an account.
+    Received and sent amounts are the net change of the account balance, only one of them is non zero.
+
+    Attributes:
+        transactionId: The transaction id.
+        inclusionState: The inclusion state of the transaction.
+        timestamp: The timestamp in milliseconds, when the account created or received the transaction.
+        direction: The direction of the transaction.
+        counterparties: The recipients of an outgoing transaction or the senders of an incoming one.
+        baseCoinReceived: The received amount of the base coin.
+        baseCoinSent: The sent amount of the base coin.
+        nativeTokens: The received and sent native tokens.
+        storageDepositSent: The storage deposit sent to counterparties, which has to be returned to the account.
+        storageDepositReceived: The storage deposit received from counterparties, which the account has to return.
+        blockId: The id of the block the transaction was sent in.
+        counterpartyLabels: The address book labels of the counterparties.
+        note: The note of the transaction.
+    """
+    transactionId: HexStr
+    inclusionState: str
+    timestamp: str
+    direction: TransactionDirection
+    counterparties: List[str]
+    baseCoinReceived: str
+    baseCoinSent: str
+    nativeTokens: List[NativeTokenMovement]
+    storageDepositSent: str
+    storageDepositReceived: str
+    blockId: Optional[HexStr] = None
+    counterpartyLabels: Dict[str, str] = field(default_factory=dict)
+    note: Optional[str] = None
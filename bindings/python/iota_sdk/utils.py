@@ -9,7 +9,7 @@ from iota_sdk.types.common import HexStr
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.output import Output
 from json import dumps, loads
-from typing import TYPE_CHECKING, List
+from typing import TYPE_CHECKING, Any, Dict, List
 from dacite import from_dict
 
 # Required to prevent circular import
@@ -205,6 +205,12 @@ class Utils():
             'message': message,
         })
 
+    @staticmethod
+    def get_capabilities() -> Dict[str, Any]:
+        """Returns the protocol versions and methods supported by the bindings.
+        """
+        return _call_method('getCapabilities')
+
 
 class UtilsError(Exception):
     """A utils error."""
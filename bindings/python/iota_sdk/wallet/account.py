@@ -14,16 +14,29 @@ from iota_sdk.types.output_data import OutputData
 from iota_sdk.types.output_id import OutputId
 from iota_sdk.types.output import BasicOutput, NftOutput, Output, output_from_dict
 from iota_sdk.types.output_params import OutputParams
+from iota_sdk.types.pending_approval import PendingApproval
+from iota_sdk.types.spending_policy import SpendingPolicy
 from iota_sdk.types.transaction_data import PreparedTransactionData, SignedTransactionData
 from iota_sdk.types.send_params import CreateAliasOutputParams, CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams
 from iota_sdk.types.transaction import Transaction
+from iota_sdk.types.transaction_history import TransactionDirection, TransactionHistoryEntry, TransactionHistoryFormat, TransactionHistoryOptions
 from iota_sdk.types.transaction_options import TransactionOptions
 from iota_sdk.types.consolidation_params import ConsolidationParams
 from typing import List, Optional, Union
-from dacite import from_dict
+from dacite import Config, from_dict
 from dataclasses import dataclass
 
 
+def _transaction_or_approval(
+        payload: dict) -> Union[Transaction, PendingApproval]:
+    """Converts the payload of a method that signs a transaction, which is a pending approval
+    if the spending policy of the account requires approvals.
+    """
+    if 'preparedTransactionData' in payload:
+        return from_dict(PendingApproval, payload)
+    return Transaction.from_dict(payload)
+
+
 @dataclass
 class AccountMetadata:
     """Account metadata.
@@ -57,7 +70,7 @@ class Account:
         self.handle = handle
 
     @_call_method_routine
-    def _call_account_method(self, method, data=None):
+    def _call_account_method(self, method, data=None, operation_id=None):
         message = {
             'name': 'callAccountMethod',
             'data': {
@@ -69,6 +82,8 @@ class Account:
         }
         if data:
             message['data']['method']['data'] = data
+        if operation_id:
+            message['operationId'] = operation_id
 
         return message
 
@@ -79,7 +94,7 @@ class Account:
             self.meta["alias"], self.meta["coinType"], self.meta["index"])
 
     def burn(
-            self, burn: Burn, options: Optional[TransactionOptions] = None) -> Transaction:
+            self, burn: Burn, options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """A generic function that can be used to burn native tokens, nfts, foundries and aliases.
         """
         return self.prepare_burn(burn, options).send()
@@ -94,7 +109,7 @@ class Account:
                 'options': options
             },
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def prepare_burn_native_token(self,
                                   token_id: HexStr,
@@ -110,7 +125,7 @@ class Account:
                 'options': options
             },
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def prepare_burn_nft(self,
                          nft_id: HexStr,
@@ -123,10 +138,10 @@ class Account:
                 'options': options
             },
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def consolidate_outputs(
-            self, params: ConsolidationParams) -> Transaction:
+            self, params: ConsolidationParams) -> Union[Transaction, PendingApproval]:
         """Consolidate outputs.
         """
         return self.prepare_consolidate_outputs(params).send()
@@ -144,7 +159,7 @@ class Account:
 
     def create_alias_output(self,
                             params: Optional[CreateAliasOutputParams] = None,
-                            options: Optional[TransactionOptions] = None) -> Transaction:
+                            options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Create an alias output.
         """
         return self.prepare_create_alias_output(params, options).send()
@@ -160,7 +175,7 @@ class Account:
                 'options': options
             }
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def prepare_destroy_alias(self,
                               alias_id: HexStr,
@@ -173,7 +188,7 @@ class Account:
                 'options': options
             },
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def prepare_destroy_foundry(self,
                                 foundry_id: HexStr,
@@ -186,7 +201,7 @@ class Account:
                 'options': options
             },
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def generate_ed25519_addresses(
             self, amount: int, options=None) -> List[AccountAddress]:
@@ -227,6 +242,13 @@ class Account:
             }
         ))
 
+    def get_spending_policy(self) -> SpendingPolicy:
+        """Get the spending policy of the account.
+        """
+        return from_dict(SpendingPolicy, self._call_account_method(
+            'getSpendingPolicy'
+        ))
+
     def addresses(self) -> List[AccountAddress]:
         """List addresses.
         """
@@ -283,6 +305,31 @@ class Account:
         )
         return [Transaction.from_dict(tx) for tx in transactions]
 
+    def transaction_history(
+            self, options: Optional[TransactionHistoryOptions] = None) -> List[TransactionHistoryEntry]:
+        """Returns the sent and received transactions of the account ordered by timestamp,
+        with their direction, counterparties and amounts.
+        """
+        entries = self._call_account_method(
+            'transactionHistory', {
+                'options': options
+            }
+        )
+        return [from_dict(TransactionHistoryEntry, entry, Config(
+            cast=[TransactionDirection])) for entry in entries]
+
+    def export_transaction_history(self, destination: str, format: Optional[TransactionHistoryFormat] = None,
+                                   options: Optional[TransactionHistoryOptions] = None):
+        """Writes the transaction history of the account to a file, as CSV by default.
+        """
+        return self._call_account_method(
+            'exportTransactionHistory', {
+                'destination': destination,
+                'format': format or TransactionHistoryFormat.Csv,
+                'options': options
+            }
+        )
+
     def pending_transactions(self):
         """Returns all pending transactions of the account.
         """
@@ -291,8 +338,66 @@ class Account:
         )
         return [Transaction.from_dict(tx) for tx in transactions]
 
+    def pending_approvals(self) -> List[PendingApproval]:
+        """Returns all pending approvals of the account.
+        """
+        pending_approvals = self._call_account_method(
+            'pendingApprovals'
+        )
+        return [from_dict(PendingApproval, approval)
+                for approval in pending_approvals]
+
+    def get_pending_approval(
+            self, approval_id: str) -> Optional[PendingApproval]:
+        """Get a pending approval of the account.
+        """
+        pending_approval = self._call_account_method(
+            'getPendingApproval', {
+                'id': approval_id
+            }
+        )
+        if pending_approval is None:
+            return None
+        return from_dict(PendingApproval, pending_approval)
+
+    def request_approval(self, prepared_transaction_data: Union[PreparedTransaction, PreparedTransactionData], creator: str,
+                         metadata: Optional[str] = None, options: Optional[TransactionOptions] = None) -> PendingApproval:
+        """Persist a prepared transaction as pending approval instead of signing it.
+        Its inputs stay locked until the approval is approved or rejected.
+        """
+        if isinstance(prepared_transaction_data, PreparedTransaction):
+            prepared_transaction_data = prepared_transaction_data.prepared_transaction_data()
+        return from_dict(PendingApproval, self._call_account_method(
+            'requestApproval', {
+                'preparedTransactionData': prepared_transaction_data,
+                'options': options,
+                'creator': creator,
+                'metadata': metadata
+            }
+        ))
+
+    def approve_pending_approval(
+            self, approval_id: str, approver: str) -> Transaction:
+        """Sign and submit the transaction of a pending approval. The approver can't be its creator.
+        """
+        return Transaction.from_dict(self._call_account_method(
+            'approvePendingApproval', {
+                'id': approval_id,
+                'approver': approver
+            }
+        ))
+
+    def reject_pending_approval(self, approval_id: str):
+        """Remove a pending approval and unlock its inputs.
+        """
+        return self._call_account_method(
+            'rejectPendingApproval', {
+                'id': approval_id
+            }
+        )
+
     def create_native_token(self, params: CreateNativeTokenParams,
-                            options: Optional[TransactionOptions] = None) -> Transaction:
+                            options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Create native token.
         """
         return self.prepare_create_native_token(params, options).send()
@@ -308,12 +413,12 @@ class Account:
             }
         )
         return PreparedCreateTokenTransaction(
-            account=self, prepared_transaction_data=prepared)
+            account=self, prepared_transaction_data=prepared, options=options)
 
     def melt_native_token(self,
                           token_id: HexStr,
                           melt_amount: int,
-                          options: Optional[TransactionOptions] = None) -> Transaction:
+                          options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Melt native tokens. This happens with the foundry output which minted them, by increasing it's
         `melted_tokens` field.
         """
@@ -334,10 +439,10 @@ class Account:
                 'options': options
             }
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def mint_native_token(self, token_id: HexStr, mint_amount: int,
-                          options: Optional[TransactionOptions] = None) -> Transaction:
+                          options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Mint additional native tokens.
         """
         return self.prepare_mint_native_token(
@@ -354,10 +459,10 @@ class Account:
                 'options': options
             }
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def mint_nfts(self, params: List[MintNftParams],
-                  options: Optional[TransactionOptions] = None) -> Transaction:
+                  options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Mint NFTs.
         """
         return self.prepare_mint_nfts(params, options).send()
@@ -372,7 +477,7 @@ class Account:
                 'options': options
             }
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def get_balance(self) -> Balance:
         """Get account balance information.
@@ -407,10 +512,10 @@ class Account:
                 'options': options
             }
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def send_transaction(
-            self, outputs: List[Output], options: Optional[TransactionOptions] = None) -> Transaction:
+            self, outputs: List[Output], options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Send a transaction.
         """
         return self.prepare_transaction(outputs, options).send()
@@ -425,37 +530,43 @@ class Account:
                 'options': options
             }
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def retry_transaction_until_included(
-            self, transaction_id: HexStr, interval=None, max_attempts=None) -> HexStr:
+            self, transaction_id: HexStr, interval=None, max_attempts=None,
+            operation_id: Optional[str] = None) -> HexStr:
         """Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
         included (referenced by a milestone). Returns the included block id.
+        The retries can be cancelled with `Wallet.cancel_operation(operation_id)`.
         """
         return self._call_account_method(
             'retryTransactionUntilIncluded', {
                 'transactionId': transaction_id,
                 'interval': interval,
                 'maxAttempts': max_attempts
-            }
+            },
+            operation_id
         )
 
-    def sync(self, options: Optional[SyncOptions] = None) -> Balance:
+    def sync(self, options: Optional[SyncOptions] = None,
+             operation_id: Optional[str] = None) -> Balance:
         """Sync the account by fetching new information from the nodes.
         Will also retry pending transactions and consolidate outputs if necessary.
         A custom default can be set using set_default_sync_options.
+        The syncing can be cancelled with `Wallet.cancel_operation(operation_id)`.
         """
         return from_dict(Balance, self._call_account_method(
             'sync', {
                 'options': options,
-            }
+            },
+            operation_id
         ))
 
     def send(self, amount: str, address: str,
-             options: Optional[TransactionOptions] = None) -> Transaction:
+             options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Send base coins.
         """
-        return Transaction.from_dict(self._call_account_method(
+        return _transaction_or_approval(self._call_account_method(
             'send', {
                 'amount': str(amount),
                 'address': address,
@@ -464,10 +575,10 @@ class Account:
         ))
 
     def send_with_params(
-            self, params: List[SendParams], options: Optional[TransactionOptions] = None) -> Transaction:
+            self, params: List[SendParams], options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Send base coins to multiple addresses or with additional parameters.
         """
-        return Transaction.from_dict(self._call_account_method(
+        return _transaction_or_approval(self._call_account_method(
             'sendWithParams', {
                 'params': params,
                 'options': options
@@ -475,7 +586,7 @@ class Account:
         ))
 
     def send_native_tokens(
-            self, params: List[SendNativeTokensParams], options: Optional[TransactionOptions] = None) -> Transaction:
+            self, params: List[SendNativeTokensParams], options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Send native tokens.
         """
         return self.prepare_send_native_tokens(params, options).send()
@@ -490,10 +601,10 @@ class Account:
                 'options': options
             }
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def send_nft(self, params: List[SendNftParams],
-                 options: Optional[TransactionOptions] = None) -> Transaction:
+                 options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Send nft.
         """
         return self.prepare_send_nft(params, options).send()
@@ -508,7 +619,7 @@ class Account:
                 'options': options
             }
         )
-        return PreparedTransaction(self, prepared)
+        return PreparedTransaction(self, prepared, options)
 
     def set_alias(self, alias: str):
         """Set alias.
@@ -529,21 +640,35 @@ class Account:
             }
         )
 
+    def set_spending_policy(self, policy: SpendingPolicy):
+        """Set the spending policy that prepared and signed transactions of the account have to satisfy.
+        If storage is enabled, will persist during restarts.
+        """
+        return self._call_account_method(
+            'setSpendingPolicy', {
+                'policy': policy
+            }
+        )
+
     def sign_transaction_essence(
-            self, prepared_transaction_data: PreparedTransactionData) -> SignedTransactionData:
-        """Sign a transaction essence.
+            self, prepared_transaction_data: PreparedTransactionData, options: Optional[TransactionOptions] = None) -> Union[SignedTransactionData, PendingApproval]:
+        """Sign a transaction essence. The spending policy is checked with the options the transaction was prepared with.
         """
-        return from_dict(SignedTransactionData, self._call_account_method(
+        signed = self._call_account_method(
             'signTransactionEssence', {
-                'preparedTransactionData': prepared_transaction_data
+                'preparedTransactionData': prepared_transaction_data,
+                'options': options
             }
-        ))
+        )
+        if 'preparedTransactionData' in signed:
+            return from_dict(PendingApproval, signed)
+        return from_dict(SignedTransactionData, signed)
 
     def sign_and_submit_transaction(
-            self, prepared_transaction_data: PreparedTransactionData) -> Transaction:
+            self, prepared_transaction_data: PreparedTransactionData) -> Union[Transaction, PendingApproval]:
         """Validate the transaction, sign it, submit it to a node and store it in the account.
         """
-        return Transaction.from_dict(self._call_account_method(
+        return _transaction_or_approval(self._call_account_method(
             'signAndSubmitTransaction', {
                 'preparedTransactionData': prepared_transaction_data
             }
@@ -560,20 +685,20 @@ class Account:
         ))
 
     def claim_outputs(
-            self, output_ids_to_claim: List[OutputId]) -> Transaction:
+            self, output_ids_to_claim: List[OutputId]) -> Union[Transaction, PendingApproval]:
         """Claim outputs.
         """
-        return Transaction.from_dict(self._call_account_method(
+        return _transaction_or_approval(self._call_account_method(
             'claimOutputs', {
                 'outputIdsToClaim': output_ids_to_claim
             }
         ))
 
     def send_outputs(
-            self, outputs: List[Output], options: Optional[TransactionOptions] = None) -> Transaction:
+            self, outputs: List[Output], options: Optional[TransactionOptions] = None) -> Union[Transaction, PendingApproval]:
         """Send outputs in a transaction.
         """
-        return Transaction.from_dict(self._call_account_method(
+        return _transaction_or_approval(self._call_account_method(
             'sendOutputs', {
                 'outputs': outputs,
                 'options': options,
@@ -7,6 +7,9 @@ import json
 from json import dumps, JSONEncoder
 from enum import Enum
 
+# The version of the method shapes sent to the Rust library.
+PROTOCOL_VERSION = 1
+
 
 def _call_method_routine(func):
     """The routine of dump json string and call call_wallet_method().
@@ -48,6 +51,7 @@ def _call_method_routine(func):
             else:
                 return obj
         message_null_filtered = remove_none(message)
+        message_null_filtered['protocolVersion'] = PROTOCOL_VERSION
         message = dumps(humps.camelize(message_null_filtered))
         # Send message to the Rust library
         response = call_wallet_method(args[0].handle, message)
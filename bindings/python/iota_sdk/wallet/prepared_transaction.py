@@ -2,10 +2,12 @@
 # SPDX-License-Identifier: Apache-2.0
 
 from __future__ import annotations
+from iota_sdk.types.pending_approval import PendingApproval
 from iota_sdk.types.transaction import Transaction
 from iota_sdk.types.transaction_data import PreparedTransactionData
+from iota_sdk.types.transaction_options import TransactionOptions
 from dacite import from_dict
-from typing import TYPE_CHECKING, Dict, Union
+from typing import TYPE_CHECKING, Dict, Optional, Union
 # Required to prevent circular import
 if TYPE_CHECKING:
     from iota_sdk.wallet.wallet import Account
@@ -17,17 +19,20 @@ class PreparedTransaction:
     Attributes:
         account: An account object used to continue building this transaction.
         prepared_transaction_data_dto: A prepared transaction data object.
+        options: The options the transaction was prepared with.
     """
 
     def __init__(
         self,
         account: Account,
-        prepared_transaction_data: Union[PreparedTransactionData, Dict]
+        prepared_transaction_data: Union[PreparedTransactionData, Dict],
+        options: Optional[TransactionOptions] = None
     ):
         """Initalize `Self`.
         """
         self.account = account
         self.prepared_transaction_data_dto = prepared_transaction_data
+        self.options = options
 
     """
     The function returns the prepared transaction data.
@@ -48,7 +53,7 @@ class PreparedTransaction:
     :returns: The send() method is returning a Transaction object after it has been signed and submitted.
     """
 
-    def send(self) -> Transaction:
+    def send(self) -> Union[Transaction, PendingApproval]:
         """Send a transaction. Internally just calls `sign_and_submit_transaction`.
 
         Returns:
@@ -58,12 +63,13 @@ class PreparedTransaction:
 
     def sign(self):
         """Sign a prepared transaction essence using the account's private key and returns
-        the signed transaction essence.
+        the signed transaction essence. The spending policy is checked with the options the transaction was prepared with.
         """
         return self.account.sign_transaction_essence(
-            self.prepared_transaction_data())
+            self.prepared_transaction_data(), self.options)
 
-    def sign_and_submit_transaction(self) -> Transaction:
+    def sign_and_submit_transaction(
+            self) -> Union[Transaction, PendingApproval]:
         """Sign and submit a transaction using prepared transaction data.
 
         Returns:
@@ -100,6 +100,11 @@ class SyncOptions():
         This will overwrite the `account`, `alias` and `nft` options.
     sync_native_token_foundries :
         Sync native token foundries, so their metadata can be returned in the balance.
+    incremental :
+        Only request output ids that were created since the last sync of an address.
+    address_gap_limit :
+        The number of unused public and internal addresses that should follow the last used address.
+        Missing addresses are generated and synced, 0 disables it.
     """
 
     def __init__(self,
@@ -113,7 +118,9 @@ class SyncOptions():
                  alias: Optional[AliasSyncOptions] = None,
                  nft: Optional[NftSyncOptions] = None,
                  sync_only_most_basic_outputs: Optional[bool] = None,
-                 sync_native_token_foundries: Optional[bool] = None):
+                 sync_native_token_foundries: Optional[bool] = None,
+                 incremental: Optional[bool] = None,
+                 address_gap_limit: Optional[int] = None):
         """Initialize `Self`.
         """
         self.addresses = addresses
@@ -127,6 +134,8 @@ class SyncOptions():
         self.nft = nft
         self.syncOnlyMostBasicOutputs = sync_only_most_basic_outputs
         self.syncNativeTokenFoundries = sync_native_token_foundries
+        self.incremental = incremental
+        self.addressGapLimit = address_gap_limit
 
     def as_dict(self):
         return dict(self.__dict__)
@@ -1,12 +1,16 @@
 # Copyright 2023 IOTA Stiftung
 # SPDX-License-Identifier: Apache-2.0
 
-from iota_sdk import destroy_wallet, create_wallet, listen_wallet, get_client_from_wallet, get_secret_manager_from_wallet, Client
+from iota_sdk import destroy_wallet, create_wallet, listen_wallet, cancel_wallet_operation, get_client_from_wallet, get_secret_manager_from_wallet, Client
 from iota_sdk.secret_manager.secret_manager import LedgerNanoSecretManager, MnemonicSecretManager, StrongholdSecretManager, SeedSecretManager, SecretManager
 from iota_sdk.types.client_options import ClientOptions
 from iota_sdk.types.address import AccountAddress
+from iota_sdk.types.address_book import AddressBookEntry
+from iota_sdk.types.common import HexStr
+from iota_sdk.types.output_id import OutputId
 from iota_sdk.wallet.account import Account, _call_method_routine
 from iota_sdk.wallet.sync_options import SyncOptions
+from dacite import from_dict
 from json import dumps
 from typing import Any, Dict, List, Optional, Union
 
@@ -84,12 +88,14 @@ class Wallet():
             secret_manager_handle=get_secret_manager_from_wallet(self.handle))
 
     @_call_method_routine
-    def _call_method(self, name: str, data=None):
+    def _call_method(self, name: str, data=None, operation_id=None):
         message = {
             'name': name
         }
         if data:
             message['data'] = data
+        if operation_id:
+            message['operationId'] = operation_id
         return message
 
     def get_account_data(self, account_id: Union[str, int]):
@@ -145,8 +151,10 @@ class Wallet():
         )
 
     def recover_accounts(self, account_start_index: int, account_gap_limit: int,
-                         address_gap_limit: int, sync_options: Optional[SyncOptions] = None):
+                         address_gap_limit: int, sync_options: Optional[SyncOptions] = None,
+                         operation_id: Optional[str] = None):
         """Recover accounts.
+        The recovery can be cancelled with `Wallet.cancel_operation(operation_id)`.
         """
         return self._call_method(
             'recoverAccounts', {
@@ -154,7 +162,8 @@ class Wallet():
                 'accountGapLimit': account_gap_limit,
                 'addressGapLimit': address_gap_limit,
                 'syncOptions': sync_options
-            }
+            },
+            operation_id
         )
 
     def remove_latest_account(self):
@@ -178,6 +187,102 @@ class Wallet():
             }
         )
 
+    def export_data(self, destination: str, password: Optional[str] = None):
+        """Export the coin type, client options and accounts in the portable JSON format,
+        encrypted if a password is provided. The secret manager is not exported.
+        """
+        return self._call_method(
+            'exportData', {
+                'destination': destination,
+                'password': password
+            }
+        )
+
+    def import_data(self, source: str, password: Optional[str] = None):
+        """Import data exported with `export_data` into a wallet without accounts,
+        which has to use the same seed. Replaces `client_options` and `coin_type`.
+        """
+        return self._call_method(
+            'importData', {
+                'source': source,
+                'password': password
+            }
+        )
+
+    def get_address_book(self) -> List[AddressBookEntry]:
+        """Get the entries of the address book.
+        """
+        entries = self._call_method(
+            'getAddressBook'
+        )
+        return [from_dict(AddressBookEntry, entry) for entry in entries]
+
+    def get_address_book_entry(self, address: str) -> Optional[AddressBookEntry]:
+        """Get the address book entry of an address.
+        """
+        entry = self._call_method(
+            'getAddressBookEntry', {
+                'address': address
+            }
+        )
+        return from_dict(AddressBookEntry, entry) if entry else None
+
+    def set_address_book_entry(self, entry: AddressBookEntry):
+        """Add an entry to the address book or replace the entry of its address.
+        The label must not be used for another address.
+        """
+        return self._call_method(
+            'setAddressBookEntry', {
+                'entry': entry
+            }
+        )
+
+    def remove_address_book_entry(self, address: str) -> Optional[AddressBookEntry]:
+        """Remove the address book entry of an address and return it.
+        """
+        entry = self._call_method(
+            'removeAddressBookEntry', {
+                'address': address
+            }
+        )
+        return from_dict(AddressBookEntry, entry) if entry else None
+
+    def get_output_labels(self) -> Dict[HexStr, str]:
+        """Get the labels of outputs.
+        """
+        return self._call_method(
+            'getOutputLabels'
+        )
+
+    def set_output_label(self, output_id: Union[OutputId, HexStr], label: Optional[str] = None):
+        """Set the label of an output, or remove it if no label is provided.
+        """
+        output_id_str = output_id.output_id if isinstance(
+            output_id, OutputId) else output_id
+        return self._call_method(
+            'setOutputLabel', {
+                'outputId': output_id_str,
+                'label': label
+            }
+        )
+
+    def get_nft_labels(self) -> Dict[HexStr, str]:
+        """Get the labels of NFTs.
+        """
+        return self._call_method(
+            'getNftLabels'
+        )
+
+    def set_nft_label(self, nft_id: HexStr, label: Optional[str] = None):
+        """Set the label of an NFT, or remove it if no label is provided.
+        """
+        return self._call_method(
+            'setNftLabel', {
+                'nftId': nft_id,
+                'label': label
+            }
+        )
+
     def set_client_options(self, client_options):
         """Update the client options for all accounts.
         """
@@ -268,6 +373,12 @@ class Wallet():
             }
         )
 
+    def cancel_operation(self, operation_id: str) -> bool:
+        """Cancels a method that was called with an operation id, it stops at its next cancellation point.
+        Returns whether a running operation with this id was found.
+        """
+        return cancel_wallet_operation(self.handle, operation_id)
+
     def destroy(self):
         """Destroys the wallet instance.
         """
@@ -84,6 +84,7 @@ fn iota_sdk(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
 
     m.add_function(wrap_pyfunction!(create_wallet, m)?).unwrap();
     m.add_function(wrap_pyfunction!(call_wallet_method, m)?).unwrap();
+    m.add_function(wrap_pyfunction!(cancel_wallet_operation, m)?).unwrap();
     m.add_function(wrap_pyfunction!(destroy_wallet, m)?).unwrap();
     m.add_function(wrap_pyfunction!(get_client_from_wallet, m)?).unwrap();
     m.add_function(wrap_pyfunction!(get_secret_manager_from_wallet, m)?)
@@ -4,9 +4,8 @@
 use std::sync::Arc;
 
 use iota_sdk_bindings_core::{
-    call_wallet_method as rust_call_wallet_method,
     iota_sdk::wallet::{events::types::WalletEventType, Wallet as RustWallet},
-    Response, WalletMethod, WalletOptions,
+    CallMethod, Operations, Response, VersionedMethod, VersionedResponse, WalletMethod, WalletOptions,
 };
 use pyo3::{prelude::*, types::PyTuple};
 use tokio::sync::RwLock;
@@ -20,6 +19,7 @@ use crate::{
 #[pyclass]
 pub struct Wallet {
     pub wallet: Arc<RwLock<Option<RustWallet>>>,
+    operations: Operations,
 }
 
 /// Destroys the wallet instance.
@@ -39,23 +39,33 @@ pub fn create_wallet(options: String) -> Result<Wallet> {
 
     Ok(Wallet {
         wallet: Arc::new(RwLock::new(Some(wallet))),
+        operations: Operations::default(),
     })
 }
 
 /// Call a wallet method.
 #[pyfunction]
-pub fn call_wallet_method(wallet: &Wallet, method: String) -> Result<String> {
-    let method = serde_json::from_str::<WalletMethod>(&method)?;
-    let response = crate::block_on(async {
-        match wallet.wallet.read().await.as_ref() {
-            Some(wallet) => rust_call_wallet_method(wallet, method).await,
-            None => Response::Panic("wallet got destroyed".into()),
-        }
+pub fn call_wallet_method(py: Python<'_>, wallet: &Wallet, method: String) -> Result<String> {
+    let method = VersionedMethod::<WalletMethod>::from_json(&method)?;
+    // Release the GIL, so the operation can be cancelled and events can be handled from other threads
+    let response = py.allow_threads(|| {
+        crate::block_on(async {
+            match wallet.wallet.read().await.as_ref() {
+                Some(rust_wallet) => rust_wallet.call_versioned_method(&wallet.operations, method).await,
+                None => VersionedResponse::from(Response::Panic("wallet got destroyed".into())),
+            }
+        })
     });
 
     Ok(serde_json::to_string(&response)?)
 }
 
+/// Cancel a wallet method that was called with an operation id.
+#[pyfunction]
+pub fn cancel_wallet_operation(wallet: &Wallet, operation_id: String) -> bool {
+    wallet.operations.cancel(&operation_id)
+}
+
 /// Listen to wallet events.
 #[pyfunction]
 pub fn listen_wallet(wallet: &Wallet, events: Vec<u8>, handler: PyObject) {
@@ -9,7 +9,7 @@ use iota_sdk_bindings_core::{
         events::types::{Event, WalletEventType},
         Wallet,
     },
-    Response, WalletMethod, WalletOptions,
+    Response, VersionedMethod, VersionedResponse, WalletMethod, WalletOptions,
 };
 use tokio::sync::{
     mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
@@ -80,10 +80,13 @@ pub async fn get_secret_manager(method_handler: &WalletMethodHandler) -> Result<
 #[wasm_bindgen(js_name = callWalletMethodAsync)]
 pub async fn call_wallet_method_async(method: String, method_handler: &WalletMethodHandler) -> Result<String, JsValue> {
     let wallet = method_handler.wallet.lock().await;
-    let method: WalletMethod = serde_json::from_str(&method).map_err(|err| err.to_string())?;
+    let method = VersionedMethod::<WalletMethod>::from_json(&method).map_err(|err| err.to_string())?;
 
-    let response = call_wallet_method(wallet.as_ref().expect("wallet got destroyed"), method).await;
-    match response {
+    let response = VersionedResponse {
+        response: call_wallet_method(wallet.as_ref().expect("wallet got destroyed"), method.method).await,
+        deprecations: method.deprecations,
+    };
+    match response.response {
         Response::Error(e) => Err(e.to_string().into()),
         Response::Panic(p) => Err(p.into()),
         _ => Ok(serde_json::to_string(&response).map_err(|e| e.to_string())?),
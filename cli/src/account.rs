@@ -9,14 +9,17 @@ use rustyline::{error::ReadlineError, history::MemHistory, Config, Editor};
 use crate::{
     command::{
         account::{
-            addresses_command, balance_command, burn_native_token_command, burn_nft_command, claim_command,
-            claimable_outputs_command, consolidate_command, create_alias_outputs_command, create_native_token_command,
-            decrease_voting_power_command, destroy_alias_command, destroy_foundry_command, faucet_command,
+            address_book_command, addresses_command, approvals_command, approve_command, balance_command,
+            burn_native_token_command, burn_nft_command, claim_command, claimable_outputs_command, consolidate_command,
+            create_alias_outputs_command, create_native_token_command, decrease_voting_power_command,
+            destroy_alias_command, destroy_foundry_command, export_transactions_command, faucet_command,
             increase_voting_power_command, melt_native_token_command, mint_native_token, mint_nft_command,
             new_address_command, node_info_command, output_command, outputs_command, participation_overview_command,
-            send_command, send_native_token_command, send_nft_command, stop_participating_command, sync_command,
-            transaction_command, transactions_command, unspent_outputs_command, vote_command, voting_output_command,
-            voting_power_command, AccountCli, AccountCommand,
+            reject_command, remove_address_label_command, require_approval_command, resolve_address, send_command,
+            send_native_token_command, send_nft_command, set_address_label_command, set_nft_label_command,
+            set_output_label_command, stop_participating_command, sync_command, transaction_command,
+            transactions_command, unspent_outputs_command, vote_command, voting_output_command, voting_power_command,
+            AccountCli, AccountCommand,
         },
         account_completion::AccountPromptHelper,
     },
@@ -103,7 +106,10 @@ pub async fn account_prompt_internal(
                         }
                     };
                     match account_cli.command {
+                        AccountCommand::AddressBook => address_book_command(wallet).await,
                         AccountCommand::Addresses => addresses_command(account).await,
+                        AccountCommand::Approvals { show_details } => approvals_command(account, show_details).await,
+                        AccountCommand::Approve { id, approver } => approve_command(account, id, approver).await,
                         AccountCommand::Balance { addresses } => balance_command(account, addresses).await,
                         AccountCommand::BurnNativeToken { token_id, amount } => {
                             burn_native_token_command(account, token_id, amount).await
@@ -134,6 +140,9 @@ pub async fn account_prompt_internal(
                         AccountCommand::Exit => {
                             return Ok(AccountPromptResponse::Done);
                         }
+                        AccountCommand::ExportTransactions { path, format, from, to } => {
+                            export_transactions_command(account, path, format, from, to).await
+                        }
                         AccountCommand::Faucet { address, url } => faucet_command(account, address, url).await,
                         AccountCommand::MeltNativeToken { token_id, amount } => {
                             melt_native_token_command(account, token_id, amount).await
@@ -166,6 +175,11 @@ pub async fn account_prompt_internal(
                         AccountCommand::NodeInfo => node_info_command(account).await,
                         AccountCommand::Output { output_id } => output_command(account, output_id).await,
                         AccountCommand::Outputs => outputs_command(account).await,
+                        AccountCommand::RemoveAddressLabel { address } => {
+                            remove_address_label_command(wallet, address).await
+                        }
+                        AccountCommand::Reject { id } => reject_command(account, id).await,
+                        AccountCommand::RequireApproval { enabled } => require_approval_command(account, enabled).await,
                         AccountCommand::Send {
                             address,
                             amount,
@@ -178,6 +192,7 @@ pub async fn account_prompt_internal(
                             } else {
                                 allow_micro_amount
                             };
+                            let address = resolve_address(wallet, &address).await?;
                             send_command(
                                 account,
                                 address,
@@ -193,8 +208,26 @@ pub async fn account_prompt_internal(
                             token_id,
                             amount,
                             gift_storage_deposit,
-                        } => send_native_token_command(account, address, token_id, amount, gift_storage_deposit).await,
-                        AccountCommand::SendNft { address, nft_id } => send_nft_command(account, address, nft_id).await,
+                        } => {
+                            let address = resolve_address(wallet, &address).await?;
+                            send_native_token_command(account, address, token_id, amount, gift_storage_deposit).await
+                        }
+                        AccountCommand::SendNft { address, nft_id } => {
+                            let address = resolve_address(wallet, &address).await?;
+                            send_nft_command(account, address, nft_id).await
+                        }
+                        AccountCommand::SetAddressLabel {
+                            address,
+                            label,
+                            tags,
+                            note,
+                        } => set_address_label_command(wallet, address, label, tags, note).await,
+                        AccountCommand::SetNftLabel { nft_id, label } => {
+                            set_nft_label_command(wallet, nft_id, label).await
+                        }
+                        AccountCommand::SetOutputLabel { output_id, label } => {
+                            set_output_label_command(wallet, output_id, label).await
+                        }
                         AccountCommand::Switch { account_id } => {
                             return Ok(AccountPromptResponse::Switch(wallet.get_account(account_id).await?));
                         }
@@ -203,7 +236,7 @@ pub async fn account_prompt_internal(
                         AccountCommand::Transactions { show_details } => {
                             transactions_command(account, show_details).await
                         }
-                        AccountCommand::UnspentOutputs => unspent_outputs_command(account).await,
+                        AccountCommand::UnspentOutputs => unspent_outputs_command(wallet, account).await,
                         AccountCommand::Vote { event_id, answers } => vote_command(account, event_id, answers).await,
                         AccountCommand::StopParticipating { event_id } => {
                             stop_participating_command(account, event_id).await
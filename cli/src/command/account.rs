@@ -1,9 +1,13 @@
 // Copyright 2020-2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::str::FromStr;
+use std::{
+    collections::{BTreeSet, HashMap},
+    str::FromStr,
+};
 
-use clap::{CommandFactory, Parser, Subcommand};
+use chrono::NaiveDate;
+use clap::{ArgAction, CommandFactory, Parser, Subcommand, ValueEnum};
 use iota_sdk::{
     client::request_funds_from_faucet,
     types::{
@@ -21,9 +25,11 @@ use iota_sdk::{
     wallet::{
         account::{
             types::{AccountAddress, AccountIdentifier},
-            Account, ConsolidationParams, OutputsToClaim, SyncOptions, TransactionOptions,
+            Account, ConsolidationParams, OutputsToClaim, SyncOptions, TransactionHistoryFormat,
+            TransactionHistoryOptions, TransactionOptions,
         },
-        CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams,
+        core::AddressBookEntry,
+        CreateNativeTokenParams, MintNftParams, SendNativeTokensParams, SendNftParams, SendParams, Wallet,
     },
     U256,
 };
@@ -47,8 +53,23 @@ impl AccountCli {
 #[derive(Debug, Subcommand)]
 #[allow(clippy::large_enum_variant)]
 pub enum AccountCommand {
+    /// List the entries of the address book.
+    AddressBook,
     /// List the account addresses.
     Addresses,
+    /// List the transactions waiting for approval.
+    Approvals {
+        /// List pending approvals with all details.
+        #[arg(long, default_value_t = false)]
+        show_details: bool,
+    },
+    /// Sign and submit a transaction waiting for approval.
+    Approve {
+        /// ID of the pending approval, e.g. 0x84fe6b1796bddc022c9bc40206f0a692f4536b02aa8c13140264e2e01a3b7e4b.
+        id: String,
+        /// Name of the approver, which can't be the creator of the pending approval.
+        approver: String,
+    },
     /// Print the account balance.
     Balance {
         /// Addresses to compute the balance for.
@@ -103,6 +124,20 @@ pub enum AccountCommand {
     },
     /// Exit the CLI wallet.
     Exit,
+    /// Export the account transaction history to a file.
+    ExportTransactions {
+        /// Path of the file to write, e.g. ./transactions.csv.
+        path: String,
+        /// File format, either `csv` or `jsonl`.
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+        /// Only export transactions from this day on, e.g. 2023-01-01.
+        #[arg(long)]
+        from: Option<NaiveDate>,
+        /// Only export transactions up to and including this day, e.g. 2023-12-31.
+        #[arg(long)]
+        to: Option<NaiveDate>,
+    },
     /// Request funds from the faucet.
     Faucet {
         /// Address the faucet sends the funds to, defaults to the latest address.
@@ -162,10 +197,27 @@ pub enum AccountCommand {
     },
     /// List all outputs.
     Outputs,
+    /// Remove an address from the address book.
+    RemoveAddressLabel {
+        /// Address or label of the address book entry to remove.
+        address: String,
+    },
+    /// Reject a transaction waiting for approval and unlock its inputs.
+    Reject {
+        /// ID of the pending approval, e.g. 0x84fe6b1796bddc022c9bc40206f0a692f4536b02aa8c13140264e2e01a3b7e4b.
+        id: String,
+    },
+    /// Require approvals for transactions of the account instead of signing them right away.
+    RequireApproval {
+        /// Whether approvals are required, `true` or `false`.
+        #[arg(action = ArgAction::Set)]
+        enabled: bool,
+    },
     /// Send an amount.
     Send {
-        /// Address to send funds to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
-        address: Bech32Address,
+        /// Address or address book label to send funds to, e.g.
+        /// rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: String,
         /// Amount to send, e.g. 1000000.
         amount: u64,
         /// Bech32 encoded return address, to which the storage deposit will be returned if one is necessary
@@ -186,8 +238,9 @@ pub enum AccountCommand {
     /// Send native tokens.
     /// This will create an output with an expiration and storage deposit return unlock condition.
     SendNativeToken {
-        /// Address to send the native tokens to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
-        address: Bech32Address,
+        /// Address or address book label to send the native tokens to, e.g.
+        /// rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: String,
         /// Token ID to be sent, e.g. 0x087d205988b733d97fb145ae340e27a8b19554d1ceee64574d7e5ff66c45f69e7a0100000000.
         token_id: String,
         /// Amount to send, e.g. 1000000.
@@ -198,11 +251,39 @@ pub enum AccountCommand {
     },
     /// Send an NFT.
     SendNft {
-        /// Address to send the NFT to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
-        address: Bech32Address,
+        /// Address or address book label to send the NFT to, e.g.
+        /// rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: String,
         /// NFT ID to be sent, e.g. 0xecadf10e6545aa82da4df2dfd2a496b457c8850d2cab49b7464cb273d3dffb07.
         nft_id: String,
     },
+    /// Add an address to the address book or update its entry.
+    SetAddressLabel {
+        /// Address to label, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: Bech32Address,
+        /// Label of the address, unique in the address book, e.g. exchange.
+        label: String,
+        /// Tags to group the address, e.g. --tag cex --tag deposit.
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+        /// Note about the address.
+        #[arg(long)]
+        note: Option<String>,
+    },
+    /// Set the label of an NFT, or remove it if no label is provided.
+    SetNftLabel {
+        /// NFT ID to label, e.g. 0xecadf10e6545aa82da4df2dfd2a496b457c8850d2cab49b7464cb273d3dffb07.
+        nft_id: String,
+        /// Label of the NFT.
+        label: Option<String>,
+    },
+    /// Set the label of an output, or remove it if no label is provided.
+    SetOutputLabel {
+        /// Output ID to label, e.g. 0xbce525324af12eda02bf7927e92cea3a8e8322d0f41966271443e6c3b245a4400000.
+        output_id: String,
+        /// Label of the output.
+        label: Option<String>,
+    },
     /// Switch to a different account.
     Switch {
         /// The identifier (alias or index) of the account you want to switch to.
@@ -262,6 +343,24 @@ pub enum AccountCommand {
     VotingOutput,
 }
 
+/// File format of an exported transaction history
+#[derive(Debug, Copy, Clone, ValueEnum)]
+pub enum ExportFormat {
+    /// Comma separated values.
+    Csv,
+    /// JSON lines.
+    Jsonl,
+}
+
+impl From<ExportFormat> for TransactionHistoryFormat {
+    fn from(format: ExportFormat) -> Self {
+        match format {
+            ExportFormat::Csv => Self::Csv,
+            ExportFormat::Jsonl => Self::JsonLines,
+        }
+    }
+}
+
 /// Select by transaction ID or list index
 #[derive(Debug, Copy, Clone)]
 pub enum TransactionSelector {
@@ -281,6 +380,24 @@ impl FromStr for TransactionSelector {
     }
 }
 
+/// `address-book` command
+pub async fn address_book_command(wallet: &Wallet) -> Result<(), Error> {
+    let entries = wallet.address_book().await;
+
+    if entries.is_empty() {
+        println_log_info!("No address book entries found");
+    } else {
+        for entry in entries {
+            let tags = entry.tags.into_iter().collect::<Vec<_>>().join(", ");
+            let note = entry.note.unwrap_or_default();
+
+            println_log_info!("{:<20}{}\t{tags}\t{note}", entry.label, entry.address);
+        }
+    }
+
+    Ok(())
+}
+
 /// `addresses` command
 pub async fn addresses_command(account: &Account) -> Result<(), Error> {
     let addresses = account.addresses().await?;
@@ -296,6 +413,48 @@ pub async fn addresses_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
+/// `approvals` command
+pub async fn approvals_command(account: &Account, show_details: bool) -> Result<(), Error> {
+    let pending_approvals = account.pending_approvals().await;
+
+    if pending_approvals.is_empty() {
+        println_log_info!("No pending approvals found");
+    } else {
+        for pending_approval in pending_approvals {
+            if show_details {
+                println_log_info!("{:#?}", pending_approval);
+            } else {
+                let requested_time = to_utc_date_time(pending_approval.timestamp)?;
+                let formatted_time = requested_time.format("%Y-%m-%d %H:%M:%S").to_string();
+                let metadata = pending_approval.metadata.unwrap_or_default();
+
+                println_log_info!(
+                    "{}\t{}\t{}\t{}",
+                    pending_approval.id,
+                    formatted_time,
+                    pending_approval.creator,
+                    metadata
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// `approve` command
+pub async fn approve_command(account: &Account, id: String, approver: String) -> Result<(), Error> {
+    let transaction = account.approve_pending_approval(&id, approver).await?;
+
+    println_log_info!(
+        "Transaction sent:\n{:?}\n{:?}",
+        transaction.transaction_id,
+        transaction.block_id
+    );
+
+    Ok(())
+}
+
 // `balance` command
 pub async fn balance_command(account: &Account, addresses: Option<Vec<Bech32Address>>) -> Result<(), Error> {
     let balance = if let Some(addresses) = addresses {
@@ -530,6 +689,45 @@ pub async fn destroy_foundry_command(account: &Account, foundry_id: String) -> R
     Ok(())
 }
 
+/// `export-transactions` command
+pub async fn export_transactions_command(
+    account: &Account,
+    path: String,
+    format: ExportFormat,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> Result<(), Error> {
+    let options = TransactionHistoryOptions {
+        lower_bound_timestamp: from.map(start_of_day_millis).transpose()?,
+        upper_bound_timestamp: to
+            .map(|date| {
+                let next_day = date
+                    .succ_opt()
+                    .ok_or_else(|| Error::Miscellaneous(format!("Invalid date {date}")))?;
+                Ok::<_, Error>(start_of_day_millis(next_day)?.saturating_sub(1))
+            })
+            .transpose()?,
+    };
+
+    account
+        .export_transaction_history(&path, format.into(), options)
+        .await?;
+
+    println_log_info!("Exported the transaction history to {path}");
+
+    Ok(())
+}
+
+fn start_of_day_millis(date: NaiveDate) -> Result<u128, Error> {
+    let millis = date
+        .and_hms_opt(0, 0, 0)
+        .expect("midnight is a valid time")
+        .and_utc()
+        .timestamp_millis();
+
+    u128::try_from(millis).map_err(|e| Error::Miscellaneous(format!("Failed to convert date to timestamp: {e}")))
+}
+
 // `faucet` command
 pub async fn faucet_command(
     account: &Account,
@@ -671,6 +869,43 @@ pub async fn outputs_command(account: &Account) -> Result<(), Error> {
     Ok(())
 }
 
+// `remove-address-label` command
+pub async fn remove_address_label_command(wallet: &Wallet, address: String) -> Result<(), Error> {
+    let address = resolve_address(wallet, &address).await?;
+
+    if let Some(entry) = wallet.remove_address_book_entry(address).await? {
+        println_log_info!("Removed {} from the address book", entry.label);
+    } else {
+        println_log_info!("{address} is not in the address book");
+    }
+
+    Ok(())
+}
+
+// `reject` command
+pub async fn reject_command(account: &Account, id: String) -> Result<(), Error> {
+    account.reject_pending_approval(&id).await?;
+
+    println_log_info!("Rejected {id}");
+
+    Ok(())
+}
+
+// `require-approval` command
+pub async fn require_approval_command(account: &Account, enabled: bool) -> Result<(), Error> {
+    let mut policy = account.spending_policy().await;
+    policy.require_approval = enabled;
+    account.set_spending_policy(policy).await?;
+
+    if enabled {
+        println_log_info!("Transactions now wait for approval");
+    } else {
+        println_log_info!("Transactions don't wait for approval anymore");
+    }
+
+    Ok(())
+}
+
 // `send` command
 pub async fn send_command(
     account: &Account,
@@ -766,6 +1001,40 @@ pub async fn send_nft_command(
     Ok(())
 }
 
+// `set-address-label` command
+pub async fn set_address_label_command(
+    wallet: &Wallet,
+    address: Bech32Address,
+    label: String,
+    tags: Vec<String>,
+    note: Option<String>,
+) -> Result<(), Error> {
+    let entry = AddressBookEntry::new(address, &label)?.with_tags(tags).with_note(note);
+    wallet.set_address_book_entry(entry).await?;
+
+    println_log_info!("Labeled {address} as {label}");
+
+    Ok(())
+}
+
+// `set-nft-label` command
+pub async fn set_nft_label_command(wallet: &Wallet, nft_id: String, label: Option<String>) -> Result<(), Error> {
+    wallet.set_nft_label(NftId::from_str(&nft_id)?, label).await?;
+
+    println_log_info!("Updated the label of NFT {nft_id}");
+
+    Ok(())
+}
+
+// `set-output-label` command
+pub async fn set_output_label_command(wallet: &Wallet, output_id: String, label: Option<String>) -> Result<(), Error> {
+    wallet.set_output_label(OutputId::from_str(&output_id)?, label).await?;
+
+    println_log_info!("Updated the label of output {output_id}");
+
+    Ok(())
+}
+
 // `sync` command
 pub async fn sync_command(account: &Account) -> Result<(), Error> {
     let balance = account
@@ -804,6 +1073,17 @@ pub async fn transaction_command(account: &Account, selector: TransactionSelecto
 pub async fn transactions_command(account: &Account, show_details: bool) -> Result<(), Error> {
     let mut transactions = account.transactions().await;
     transactions.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+    // The transaction history resolves the counterparties and their address book labels
+    let counterparty_labels = account
+        .transaction_history(None)
+        .await?
+        .into_iter()
+        .filter(|entry| !entry.counterparty_labels.is_empty())
+        .map(|entry| {
+            let labels = entry.counterparty_labels.into_values().collect::<BTreeSet<_>>();
+            (entry.transaction_id, labels.into_iter().collect::<Vec<_>>().join(", "))
+        })
+        .collect::<HashMap<_, _>>();
 
     if transactions.is_empty() {
         println_log_info!("No transactions found");
@@ -815,7 +1095,12 @@ pub async fn transactions_command(account: &Account, show_details: bool) -> Resu
                 let transaction_time = to_utc_date_time(tx.timestamp)?;
                 let formatted_time = transaction_time.format("%Y-%m-%d %H:%M:%S").to_string();
 
-                println_log_info!("{:<5}{}\t{}", i, tx.transaction_id, formatted_time);
+                let labels = counterparty_labels
+                    .get(&tx.transaction_id)
+                    .map(String::as_str)
+                    .unwrap_or_default();
+
+                println_log_info!("{:<5}{}\t{}\t{}", i, tx.transaction_id, formatted_time, labels);
             }
         }
     }
@@ -824,15 +1109,27 @@ pub async fn transactions_command(account: &Account, show_details: bool) -> Resu
 }
 
 /// `unspent-outputs` command
-pub async fn unspent_outputs_command(account: &Account) -> Result<(), Error> {
+pub async fn unspent_outputs_command(wallet: &Wallet, account: &Account) -> Result<(), Error> {
     let outputs = account.unspent_outputs(None).await?;
+    let output_labels = wallet.output_labels().await;
 
     if outputs.is_empty() {
         println_log_info!("No outputs found");
     } else {
         println_log_info!("Unspent outputs:");
         for (i, output_data) in outputs.into_iter().enumerate() {
-            println_log_info!("{}\t{}\t{}", i, &output_data.output_id, output_data.output.kind_str());
+            let label = output_labels
+                .get(&output_data.output_id)
+                .map(String::as_str)
+                .unwrap_or_default();
+
+            println_log_info!(
+                "{}\t{}\t{}\t{}",
+                i,
+                &output_data.output_id,
+                output_data.output.kind_str(),
+                label
+            );
         }
     }
 
@@ -992,3 +1289,20 @@ async fn print_address(account: &Account, address: &AccountAddress) -> Result<()
 
     Ok(())
 }
+
+/// Resolves a bech32 address or the label of an address book entry to an address.
+pub async fn resolve_address(wallet: &Wallet, address_or_label: &str) -> Result<Bech32Address, Error> {
+    if let Ok(address) = Bech32Address::try_from_str(address_or_label) {
+        return Ok(address);
+    }
+
+    wallet
+        .get_address_book_entry_by_label(address_or_label)
+        .await
+        .map(|entry| entry.address)
+        .ok_or_else(|| {
+            Error::Miscellaneous(format!(
+                "{address_or_label} is neither a bech32 address nor an address book label"
+            ))
+        })
+}
@@ -13,7 +13,10 @@ pub struct AccountCompleter;
 
 const ACCOUNT_COMMANDS: &[&str] = &[
     "accounts",
+    "address-book",
     "addresses",
+    "approvals",
+    "approve",
     "balance",
     "burn-native-token",
     "burn-nft",
@@ -26,6 +29,7 @@ const ACCOUNT_COMMANDS: &[&str] = &[
     "destroy-alias",
     "destroy-foundry",
     "exit",
+    "export-transactions",
     "faucet",
     "melt-native-token",
     "mint-native-token",
@@ -34,9 +38,15 @@ const ACCOUNT_COMMANDS: &[&str] = &[
     "node-info",
     "output",
     "outputs",
+    "reject",
+    "remove-address-label",
+    "require-approval",
     "send",
     "send-native-token",
     "send-nft",
+    "set-address-label",
+    "set-nft-label",
+    "set-output-label",
     "switch",
     "sync",
     "transaction",
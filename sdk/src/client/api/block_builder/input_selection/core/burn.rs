@@ -127,6 +127,7 @@ impl From<NativeToken> for Burn {
 
 /// A DTO for [`Burn`].
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct BurnDto {
     /// Aliases to burn.
@@ -139,6 +140,7 @@ pub struct BurnDto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) foundries: Option<HashSet<FoundryId>>,
     /// Amounts of native tokens to burn.
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<BTreeMap<TokenId, String>>"))]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) native_tokens: Option<BTreeMap<TokenId, U256>>,
 }
@@ -53,6 +53,7 @@ pub struct ClientBlockBuilder<'a> {
 
 /// Block output address
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ClientBlockBuilderOutputAddress {
     /// Address
@@ -64,6 +65,7 @@ pub struct ClientBlockBuilderOutputAddress {
 
 /// Options for generating block
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ClientBlockBuilderOptions {
     /// Coin type
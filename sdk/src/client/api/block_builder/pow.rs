@@ -8,7 +8,10 @@ use crate::pow::miner::{Miner, MinerBuilder, MinerCancel};
 #[cfg(target_family = "wasm")]
 use crate::pow::wasm_miner::{SingleThreadedMiner, SingleThreadedMinerBuilder};
 use crate::{
-    client::{ClientInner, Error, Result},
+    client::{
+        operation::{Operation, OperationProgress},
+        ClientInner, Error, Result,
+    },
     types::block::{parent::Parents, payload::Payload, Block, BlockBuilder, Error as BlockError},
 };
 
@@ -47,16 +50,21 @@ impl ClientInner {
         let pow_worker_count = *self.pow_worker_count.read().await;
         let min_pow_score = self.get_min_pow_score().await?;
         let tips_interval = self.get_tips_interval().await;
-
+        let operation = Operation::current();
+        let mut attempt = 0;
         loop {
+            attempt += 1;
+            Operation::check_cancelled()?;
+            Operation::report_progress(OperationProgress::PerformingPow { attempt });
             let cancel = MinerCancel::new();
             let cancel_2 = cancel.clone();
+            let operation_ = operation.clone();
             let payload_ = payload.clone();
             let parents = match &parents {
                 Some(parents) => parents.clone(),
                 None => Parents::from_vec(self.get_tips().await?)?,
             };
-            let time_thread = std::thread::spawn(move || Ok(pow_timeout(tips_interval, cancel)));
+            let time_thread = std::thread::spawn(move || Ok(pow_timeout(tips_interval, cancel, operation_)));
             let pow_thread = std::thread::spawn(move || {
                 let mut client_miner = MinerBuilder::new().with_cancel(cancel_2);
                 if let Some(worker_count) = pow_worker_count {
@@ -90,7 +98,11 @@ impl ClientInner {
         let min_pow_score: u32 = self.get_min_pow_score().await?;
         let tips_interval: u64 = self.get_tips_interval().await;
 
+        let mut attempt = 0;
         loop {
+            attempt += 1;
+            Operation::check_cancelled()?;
+            Operation::report_progress(OperationProgress::PerformingPow { attempt });
             let parents = match &parents {
                 Some(parents) => parents.clone(),
                 None => Parents::from_vec(self.get_tips().await?)?,
@@ -127,9 +139,19 @@ fn do_pow(
 }
 
 // PoW timeout, if we reach this we will restart the PoW with new tips, so the final block will never be lazy.
+// Stops the miner earlier if the operation was cancelled.
 #[cfg(not(target_family = "wasm"))]
-fn pow_timeout(after_seconds: u64, cancel: MinerCancel) -> Option<Block> {
-    std::thread::sleep(std::time::Duration::from_secs(after_seconds));
+fn pow_timeout(after_seconds: u64, cancel: MinerCancel, operation: Option<Operation>) -> Option<Block> {
+    const CANCELLATION_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(100);
+
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(after_seconds);
+    while !cancel.is_cancelled() && !operation.as_ref().is_some_and(Operation::is_cancelled) {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        std::thread::sleep(remaining.min(CANCELLATION_CHECK_INTERVAL));
+    }
 
     cancel.trigger();
 
@@ -13,6 +13,7 @@ use crate::{
         },
         error::{Error, Result},
         node_api::indexer::query_parameters::QueryParameter,
+        operation::{Operation, OperationProgress},
         Client,
     },
     types::{
@@ -97,20 +98,24 @@ impl Client {
         let mut block_ids = vec![*block_id];
         // Reattached Blocks that get returned
         let mut blocks_with_id = Vec::new();
-        for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
+        for attempt in 1..=max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
             #[cfg(target_family = "wasm")]
-            gloo_timers::future::TimeoutFuture::new(
+            Operation::cancellable(gloo_timers::future::TimeoutFuture::new(
                 (interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL) * 1000)
                     .try_into()
                     .unwrap(),
-            )
-            .await;
+            ))
+            .await?;
 
             #[cfg(not(target_family = "wasm"))]
-            tokio::time::sleep(std::time::Duration::from_secs(
+            Operation::cancellable(tokio::time::sleep(std::time::Duration::from_secs(
                 interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL),
-            ))
-            .await;
+            )))
+            .await?;
+            Operation::report_progress(OperationProgress::RetryingBlock {
+                block_id: *block_id,
+                attempt,
+            });
 
             // Check inclusion state for each attachment
             let block_ids_len = block_ids.len();
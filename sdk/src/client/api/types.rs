@@ -37,6 +37,7 @@ pub struct PreparedTransactionData {
 
 /// PreparedTransactionData Dto
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PreparedTransactionDataDto {
     /// Transaction essence
@@ -93,6 +94,7 @@ pub struct SignedTransactionData {
 
 /// SignedTransactionData Dto
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct SignedTransactionDataDto {
     /// Transaction essence
@@ -141,10 +143,15 @@ pub struct RemainderData {
 
 /// Data for a remainder output, used for ledger nano
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct RemainderDataDto {
     /// The remainder output
     pub output: OutputDto,
     /// The chain derived from seed, for the remainder addresses
+    #[cfg_attr(
+        feature = "json_schema",
+        schemars(with = "Option<crate::utils::serde::bip44::Bip44Def>")
+    )]
     #[serde(with = "option_bip44", default)]
     pub chain: Option<Bip44>,
     /// The remainder address
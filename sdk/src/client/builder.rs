@@ -6,16 +6,18 @@ use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use serde::{Deserialize, Serialize};
 
-use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
+use super::{cache::ResponseCache, node_manager::builder::NodeManagerBuilder, ClientInner};
 #[cfg(feature = "mqtt")]
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
 use crate::{
     client::{
+        cache::ResponseCacheOptions,
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
         error::Result,
         node_manager::{
             builder::validate_url,
             node::{Node, NodeAuth},
+            request_policy::RequestPolicy,
         },
         Client,
     },
@@ -24,6 +26,7 @@ use crate::{
 
 /// Builder to construct client instance with sensible default values
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[must_use]
 pub struct ClientBuilder {
@@ -44,6 +47,12 @@ pub struct ClientBuilder {
     /// Timeout when sending a block that requires remote proof of work
     #[serde(default = "default_remote_pow_timeout")]
     pub remote_pow_timeout: Duration,
+    /// Retry, timeout and circuit-breaker policy for node requests
+    #[serde(default)]
+    pub request_policy: RequestPolicy,
+    /// Options for the response cache, which is disabled if not set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_cache: Option<ResponseCacheOptions>,
     /// The amount of threads to be used for proof of work
     #[cfg(not(target_family = "wasm"))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -89,6 +98,8 @@ impl Default for ClientBuilder {
             network_info: NetworkInfo::default(),
             api_timeout: DEFAULT_API_TIMEOUT,
             remote_pow_timeout: DEFAULT_REMOTE_POW_API_TIMEOUT,
+            request_policy: RequestPolicy::default(),
+            response_cache: None,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: None,
             #[cfg(not(target_family = "wasm"))]
@@ -241,6 +252,18 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the retry, timeout and circuit-breaker policy for node requests.
+    pub fn with_request_policy(mut self, request_policy: RequestPolicy) -> Self {
+        self.request_policy = request_policy;
+        self
+    }
+
+    /// Enables the response cache for node API requests.
+    pub fn with_response_cache(mut self, options: impl Into<Option<ResponseCacheOptions>>) -> Self {
+        self.response_cache = options.into();
+        self
+    }
+
     /// Set User-Agent header for requests
     /// Default is "iota-client/{version}"
     pub fn with_user_agent(mut self, user_agent: String) -> Self {
@@ -260,6 +283,8 @@ impl ClientBuilder {
     pub async fn finish(self) -> Result<Client> {
         use tokio::sync::RwLock;
 
+        use crate::client::constants::NODE_HEALTH_CHANNEL_CAPACITY;
+
         let node_sync_interval = self.node_manager_builder.node_sync_interval;
         let ignore_node_health = self.node_manager_builder.ignore_node_health;
         let nodes = self
@@ -278,16 +303,20 @@ impl ClientBuilder {
             network_info: RwLock::new(self.network_info),
             api_timeout: RwLock::new(self.api_timeout),
             remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+            request_policy: RwLock::new(self.request_policy),
+            response_cache: ResponseCache::new(self.response_cache),
             pow_worker_count: RwLock::new(self.pow_worker_count),
             #[cfg(feature = "mqtt")]
             mqtt: super::MqttInner {
                 client: Default::default(),
                 topic_handlers: Default::default(),
+                stream_handlers: Default::default(),
                 broker_options: RwLock::new(self.broker_options),
                 sender: RwLock::new(mqtt_event_tx),
                 receiver: RwLock::new(mqtt_event_rx),
             },
             request_pool: crate::client::request_pool::RequestPool::new(self.max_parallel_api_requests),
+            node_health_sender: tokio::sync::broadcast::channel(NODE_HEALTH_CHANNEL_CAPACITY).0,
         });
 
         client_inner.sync_nodes(&nodes, ignore_node_health).await?;
@@ -321,10 +350,13 @@ impl ClientBuilder {
                 network_info: RwLock::new(self.network_info),
                 api_timeout: RwLock::new(self.api_timeout),
                 remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
+                request_policy: RwLock::new(self.request_policy),
+                response_cache: ResponseCache::new(self.response_cache),
                 #[cfg(feature = "mqtt")]
                 mqtt: super::MqttInner {
                     client: Default::default(),
                     topic_handlers: Default::default(),
+                    stream_handlers: Default::default(),
                     broker_options: RwLock::new(self.broker_options),
                     sender: RwLock::new(mqtt_event_tx),
                     receiver: RwLock::new(mqtt_event_rx),
@@ -344,6 +376,8 @@ impl ClientBuilder {
             network_info: client.network_info.read().await.clone(),
             api_timeout: client.get_timeout().await,
             remote_pow_timeout: client.get_remote_pow_timeout().await,
+            request_policy: client.request_policy.read().await.clone(),
+            response_cache: client.response_cache.options(),
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: *client.pow_worker_count.read().await,
             #[cfg(not(target_family = "wasm"))]
@@ -354,6 +388,7 @@ impl ClientBuilder {
 
 /// Struct containing network and PoW related information
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NetworkInfo {
     /// Protocol parameters.
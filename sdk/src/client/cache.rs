@@ -0,0 +1,424 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cache for node API responses.
+//!
+//! Immutable resources (blocks, milestones, transaction-included blocks and spent outputs) are kept until they get
+//! evicted. Mutable resources (metadata and unspent outputs) are only kept for a short time and are invalidated as
+//! soon as the confirmed milestone index advances. The index is taken from the node info, which is requested by the
+//! periodic node syncing and `Client::get_info()`.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::client::constants::{
+    DEFAULT_RESPONSE_CACHE_MAX_BYTES, DEFAULT_RESPONSE_CACHE_MAX_ENTRIES, DEFAULT_RESPONSE_CACHE_MUTABLE_TTL,
+};
+
+const CORE_API_PREFIX: &str = "api/core/v2/";
+
+/// Options for the response cache.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseCacheOptions {
+    /// Maximum amount of cached responses.
+    #[serde(default = "default_max_entries")]
+    pub max_entries: usize,
+    /// Maximum accumulated size of the cached responses in bytes.
+    #[serde(default = "default_max_bytes")]
+    pub max_bytes: usize,
+    /// How long responses for mutable resources are cached at most, if the confirmed milestone index doesn't advance
+    /// before.
+    #[serde(default = "default_mutable_ttl")]
+    pub mutable_ttl: Duration,
+}
+
+fn default_max_entries() -> usize {
+    DEFAULT_RESPONSE_CACHE_MAX_ENTRIES
+}
+
+fn default_max_bytes() -> usize {
+    DEFAULT_RESPONSE_CACHE_MAX_BYTES
+}
+
+fn default_mutable_ttl() -> Duration {
+    DEFAULT_RESPONSE_CACHE_MUTABLE_TTL
+}
+
+impl Default for ResponseCacheOptions {
+    fn default() -> Self {
+        Self {
+            max_entries: DEFAULT_RESPONSE_CACHE_MAX_ENTRIES,
+            max_bytes: DEFAULT_RESPONSE_CACHE_MAX_BYTES,
+            mutable_ttl: DEFAULT_RESPONSE_CACHE_MUTABLE_TTL,
+        }
+    }
+}
+
+/// Hit/miss statistics of the response cache.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseCacheMetrics {
+    /// Amount of requests answered from the cache.
+    pub hits: u64,
+    /// Amount of cacheable requests that had to be sent to a node.
+    pub misses: u64,
+    /// Amount of responses that were removed to stay within the size limits.
+    pub evictions: u64,
+    /// Amount of mutable responses that were removed because they expired or a new milestone got confirmed.
+    pub invalidations: u64,
+    /// Amount of responses currently cached.
+    pub entries: usize,
+    /// Accumulated size of the currently cached responses in bytes.
+    pub bytes: usize,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Lifetime {
+    Immutable,
+    Mutable,
+}
+
+impl Lifetime {
+    /// Returns how long the response for `path` can be cached, `None` if it can't be cached at all.
+    fn of(path: &str, response: &Value) -> Option<Self> {
+        let segments = path
+            .trim_start_matches('/')
+            .strip_prefix(CORE_API_PREFIX)?
+            .split('/')
+            .collect::<Vec<_>>();
+        let is_spent = |metadata: &Value| metadata.get("isSpent").and_then(Value::as_bool).unwrap_or(false);
+
+        match segments.as_slice() {
+            ["blocks", _]
+            | ["milestones", _]
+            | ["milestones", _, "utxo-changes"]
+            | ["milestones", "by-index", _]
+            | ["milestones", "by-index", _, "utxo-changes"]
+            | ["transactions", _, "included-block"] => Some(Self::Immutable),
+            ["blocks", _, "metadata"] | ["transactions", _, "included-block", "metadata"] => Some(Self::Mutable),
+            ["outputs", _] => Some(match response.get("metadata") {
+                Some(metadata) if is_spent(metadata) => Self::Immutable,
+                _ => Self::Mutable,
+            }),
+            ["outputs", _, "metadata"] => Some(if is_spent(response) {
+                Self::Immutable
+            } else {
+                Self::Mutable
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    response: String,
+    lifetime: Lifetime,
+    milestone_index: u32,
+    inserted_at: instant::Instant,
+    tick: u64,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    // Least recently used order, maps the tick of the last access to the key
+    lru: BTreeMap<u64, String>,
+    tick: u64,
+    bytes: usize,
+    confirmed_milestone_index: u32,
+    metrics: ResponseCacheMetrics,
+}
+
+impl CacheState {
+    fn remove(&mut self, key: &str) -> Option<CacheEntry> {
+        let entry = self.entries.remove(key)?;
+        self.lru.remove(&entry.tick);
+        self.bytes -= entry.response.len();
+        Some(entry)
+    }
+
+    fn is_valid(&self, entry: &CacheEntry, options: &ResponseCacheOptions) -> bool {
+        entry.lifetime == Lifetime::Immutable
+            || (entry.milestone_index == self.confirmed_milestone_index
+                && entry.inserted_at.elapsed() < options.mutable_ttl)
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.lru.clear();
+        self.bytes = 0;
+    }
+}
+
+/// Cache for node API responses, disabled if no options are set.
+#[derive(Debug, Default)]
+pub(crate) struct ResponseCache {
+    options: Mutex<Option<ResponseCacheOptions>>,
+    state: Mutex<CacheState>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(options: Option<ResponseCacheOptions>) -> Self {
+        Self {
+            options: Mutex::new(options),
+            state: Default::default(),
+        }
+    }
+
+    pub(crate) fn options(&self) -> Option<ResponseCacheOptions> {
+        *self.lock_options()
+    }
+
+    /// Sets new options, disabling the cache clears it.
+    pub(crate) fn set_options(&self, options: Option<ResponseCacheOptions>) {
+        *self.lock_options() = options;
+        match options {
+            Some(options) => self.shrink(&options),
+            None => self.clear(),
+        }
+    }
+
+    /// Returns whether responses for `path` might be cached.
+    pub(crate) fn is_cacheable(&self, path: &str) -> bool {
+        // Unspent outputs are cacheable too, so any value works to check the path
+        self.options().is_some() && Lifetime::of(path, &Value::Null).is_some()
+    }
+
+    pub(crate) fn get(&self, path: &str) -> Option<String> {
+        let options = self.options()?;
+        let mut state = self.lock_state();
+
+        match state.entries.get(path) {
+            Some(entry) if state.is_valid(entry, &options) => {}
+            Some(_) => {
+                state.remove(path);
+                state.metrics.invalidations += 1;
+                state.metrics.misses += 1;
+                return None;
+            }
+            None => {
+                state.metrics.misses += 1;
+                return None;
+            }
+        }
+
+        let tick = state.next_tick();
+        let entry = state.entries.get_mut(path)?;
+        let old_tick = std::mem::replace(&mut entry.tick, tick);
+        let response = entry.response.clone();
+        state.lru.remove(&old_tick);
+        state.lru.insert(tick, path.to_owned());
+        state.metrics.hits += 1;
+
+        Some(response)
+    }
+
+    pub(crate) fn insert(&self, path: &str, response: String) {
+        let Some(options) = self.options() else {
+            return;
+        };
+        let Ok(value) = serde_json::from_str::<Value>(&response) else {
+            return;
+        };
+        let Some(lifetime) = Lifetime::of(path, &value) else {
+            return;
+        };
+        if response.len() > options.max_bytes {
+            return;
+        }
+        let mut state = self.lock_state();
+
+        state.remove(path);
+        let tick = state.next_tick();
+        state.bytes += response.len();
+        let entry = CacheEntry {
+            response,
+            lifetime,
+            milestone_index: state.confirmed_milestone_index,
+            inserted_at: instant::Instant::now(),
+            tick,
+        };
+        state.entries.insert(path.to_owned(), entry);
+        state.lru.insert(tick, path.to_owned());
+        drop(state);
+
+        self.shrink(&options);
+    }
+
+    /// Updates the confirmed milestone index, which invalidates all mutable responses if it advanced.
+    pub(crate) fn update_confirmed_milestone_index(&self, index: u32) {
+        let mut state = self.lock_state();
+        if index <= state.confirmed_milestone_index {
+            return;
+        }
+        state.confirmed_milestone_index = index;
+
+        let outdated = state
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.lifetime == Lifetime::Mutable)
+            .map(|(key, _)| key.clone())
+            .collect::<Vec<_>>();
+        for key in outdated {
+            state.remove(&key);
+            state.metrics.invalidations += 1;
+        }
+    }
+
+    pub(crate) fn clear(&self) {
+        self.lock_state().clear();
+    }
+
+    pub(crate) fn metrics(&self) -> ResponseCacheMetrics {
+        let state = self.lock_state();
+        ResponseCacheMetrics {
+            entries: state.entries.len(),
+            bytes: state.bytes,
+            ..state.metrics
+        }
+    }
+
+    /// Evicts the least recently used responses until the size limits are met.
+    fn shrink(&self, options: &ResponseCacheOptions) {
+        let mut state = self.lock_state();
+        while state.entries.len() > options.max_entries || state.bytes > options.max_bytes {
+            let Some((_, key)) = state.lru.pop_first() else {
+                break;
+            };
+            state.remove(&key);
+            state.metrics.evictions += 1;
+        }
+    }
+
+    fn lock_options(&self) -> MutexGuard<'_, Option<ResponseCacheOptions>> {
+        self.options.lock().unwrap_or_else(|poisoned| {
+            log::warn!("[ResponseCache] options lock was poisoned, recovering it");
+            self.options.clear_poison();
+            poisoned.into_inner()
+        })
+    }
+
+    /// Locks the cache state. A panic while the lock was held could have left the cached responses and their size
+    /// accounting inconsistent, so they get cleared in that case.
+    fn lock_state(&self) -> MutexGuard<'_, CacheState> {
+        self.state.lock().unwrap_or_else(|poisoned| {
+            log::warn!("[ResponseCache] state lock was poisoned, clearing the cached responses");
+            self.state.clear_poison();
+            let mut state = poisoned.into_inner();
+            state.clear();
+            state
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLOCK_PATH: &str = "api/core/v2/blocks/0x01";
+    const METADATA_PATH: &str = "api/core/v2/blocks/0x01/metadata";
+
+    fn cache(max_entries: usize) -> ResponseCache {
+        ResponseCache::new(Some(ResponseCacheOptions {
+            max_entries,
+            ..Default::default()
+        }))
+    }
+
+    #[test]
+    fn lifetimes() {
+        let unspent = serde_json::json!({ "metadata": { "isSpent": false } });
+        let spent = serde_json::json!({ "metadata": { "isSpent": true } });
+
+        assert_eq!(Lifetime::of(BLOCK_PATH, &Value::Null), Some(Lifetime::Immutable));
+        assert_eq!(Lifetime::of(METADATA_PATH, &Value::Null), Some(Lifetime::Mutable));
+        assert_eq!(
+            Lifetime::of("api/core/v2/milestones/by-index/5", &Value::Null),
+            Some(Lifetime::Immutable)
+        );
+        assert_eq!(
+            Lifetime::of("api/core/v2/outputs/0x01", &unspent),
+            Some(Lifetime::Mutable)
+        );
+        assert_eq!(
+            Lifetime::of("api/core/v2/outputs/0x01", &spent),
+            Some(Lifetime::Immutable)
+        );
+        assert_eq!(Lifetime::of("api/core/v2/info", &Value::Null), None);
+        assert_eq!(Lifetime::of("api/indexer/v1/outputs/basic", &Value::Null), None);
+    }
+
+    #[test]
+    fn hit_miss_and_invalidation() {
+        let cache = cache(10);
+
+        assert_eq!(cache.get(BLOCK_PATH), None);
+        cache.insert(BLOCK_PATH, "{}".to_owned());
+        cache.insert(METADATA_PATH, "{}".to_owned());
+        assert_eq!(cache.get(BLOCK_PATH).as_deref(), Some("{}"));
+        assert_eq!(cache.get(METADATA_PATH).as_deref(), Some("{}"));
+
+        cache.update_confirmed_milestone_index(1);
+        assert_eq!(cache.get(BLOCK_PATH).as_deref(), Some("{}"));
+        assert_eq!(cache.get(METADATA_PATH), None);
+
+        let metrics = cache.metrics();
+        assert_eq!(metrics.hits, 3);
+        assert_eq!(metrics.misses, 2);
+        assert_eq!(metrics.invalidations, 1);
+        assert_eq!(metrics.entries, 1);
+    }
+
+    #[test]
+    fn lru_eviction() {
+        let cache = cache(2);
+        let paths = [
+            "api/core/v2/blocks/0x01",
+            "api/core/v2/blocks/0x02",
+            "api/core/v2/blocks/0x03",
+        ];
+
+        cache.insert(paths[0], "{}".to_owned());
+        cache.insert(paths[1], "{}".to_owned());
+        // Use the first one, so the second one is the least recently used
+        cache.get(paths[0]);
+        cache.insert(paths[2], "{}".to_owned());
+
+        assert!(cache.get(paths[0]).is_some());
+        assert!(cache.get(paths[1]).is_none());
+        assert!(cache.get(paths[2]).is_some());
+        assert_eq!(cache.metrics().evictions, 1);
+    }
+
+    #[test]
+    fn poisoned_lock() {
+        let cache = cache(10);
+        cache.insert(BLOCK_PATH, "{}".to_owned());
+
+        std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _state = cache.state.lock().unwrap();
+            panic!("poison the lock");
+        }))
+        .unwrap_err();
+
+        // The cache is cleared and usable again
+        assert_eq!(cache.get(BLOCK_PATH), None);
+        cache.insert(BLOCK_PATH, "{}".to_owned());
+        assert_eq!(cache.get(BLOCK_PATH).as_deref(), Some("{}"));
+        assert_eq!(cache.metrics().entries, 1);
+    }
+}
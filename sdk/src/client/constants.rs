@@ -17,8 +17,23 @@ pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
 pub(crate) const DEFAULT_TIPS_INTERVAL: u64 = 5;
 /// Interval in which the node info will be requested and healthy nodes will be added to the healthy node pool
 pub(crate) const NODE_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+/// Amount of node health changes that are buffered for each receiver
+#[cfg(not(target_family = "wasm"))]
+pub(crate) const NODE_HEALTH_CHANNEL_CAPACITY: usize = 100;
 pub(crate) const DEFAULT_MIN_QUORUM_SIZE: usize = 3;
 pub(crate) const DEFAULT_QUORUM_THRESHOLD: usize = 66;
+/// HTTP status codes for which requests are retried by default
+pub(crate) const DEFAULT_RETRYABLE_STATUS_CODES: [u16; 4] = [429, 502, 503, 504];
+pub(crate) const DEFAULT_RETRY_INITIAL_DELAY: Duration = Duration::from_millis(200);
+pub(crate) const DEFAULT_RETRY_MAX_DELAY: Duration = Duration::from_secs(5);
+pub(crate) const DEFAULT_RETRY_MULTIPLIER: u32 = 2;
+/// Amount of consecutive failed requests after which a node is excluded by the circuit breaker
+pub(crate) const DEFAULT_CIRCUIT_BREAKER_FAILURE_THRESHOLD: u32 = 5;
+pub(crate) const DEFAULT_CIRCUIT_BREAKER_RESET_TIMEOUT: Duration = Duration::from_secs(30);
+pub(crate) const DEFAULT_RESPONSE_CACHE_MAX_ENTRIES: usize = 10_000;
+pub(crate) const DEFAULT_RESPONSE_CACHE_MAX_BYTES: usize = 50 * 1024 * 1024;
+/// Maximum time responses for mutable resources are cached, usually a new milestone invalidates them before
+pub(crate) const DEFAULT_RESPONSE_CACHE_MUTABLE_TTL: Duration = Duration::from_secs(10);
 pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 #[cfg(not(target_family = "wasm"))]
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
@@ -8,8 +8,9 @@ use std::{sync::Arc, time::Duration};
 use tokio::sync::RwLock;
 #[cfg(feature = "mqtt")]
 use {
-    crate::client::node_api::mqtt::{BrokerOptions, MqttEvent, TopicHandlerMap},
+    crate::client::node_api::mqtt::{BrokerOptions, MqttEvent, TopicHandler, TopicHandlerMap},
     rumqttc::AsyncClient as MqttClient,
+    std::sync::Weak,
     tokio::sync::watch::{Receiver as WatchReceiver, Sender as WatchSender},
 };
 
@@ -20,8 +21,13 @@ use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
         builder::{ClientBuilder, NetworkInfo},
+        cache::{ResponseCache, ResponseCacheMetrics},
         error::Result,
-        node_manager::NodeManager,
+        node_manager::{
+            node::NodeHealthChange,
+            request_policy::{RequestPolicy, REQUEST_POLICY_OVERRIDE},
+            NodeManager,
+        },
         Error,
     },
     types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters},
@@ -51,6 +57,10 @@ pub struct ClientInner {
     pub(crate) api_timeout: RwLock<Duration>,
     /// HTTP request timeout for remote PoW API call.
     pub(crate) remote_pow_timeout: RwLock<Duration>,
+    /// Retry, timeout and circuit-breaker policy for node requests.
+    pub(crate) request_policy: RwLock<RequestPolicy>,
+    /// Cache for node API responses.
+    pub(crate) response_cache: ResponseCache,
     /// pow_worker_count for local PoW.
     #[cfg(not(target_family = "wasm"))]
     pub(crate) pow_worker_count: RwLock<Option<usize>>,
@@ -60,6 +70,9 @@ pub struct ClientInner {
     pub(crate) last_sync: tokio::sync::Mutex<Option<u32>>,
     #[cfg(not(target_family = "wasm"))]
     pub(crate) request_pool: RequestPool,
+    /// Notifies about changes of the node health.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) node_health_sender: tokio::sync::broadcast::Sender<NodeHealthChange>,
 }
 
 #[derive(Default)]
@@ -79,6 +92,8 @@ pub(crate) struct MqttInner {
     /// A MQTT client to subscribe/unsubscribe to topics.
     pub(crate) client: RwLock<Option<MqttClient>>,
     pub(crate) topic_handlers: RwLock<TopicHandlerMap>,
+    /// The handlers of the [`TopicStream`](crate::client::mqtt::TopicStream)s in `topic_handlers`.
+    pub(crate) stream_handlers: RwLock<Vec<Weak<TopicHandler>>>,
     pub(crate) broker_options: RwLock<BrokerOptions>,
     pub(crate) sender: RwLock<WatchSender<MqttEvent>>,
     pub(crate) receiver: RwLock<WatchReceiver<MqttEvent>>,
@@ -102,6 +117,20 @@ impl Client {
     pub fn builder() -> ClientBuilder {
         ClientBuilder::new()
     }
+
+    /// Runs `future` with a request policy that overrides the configured one for all node requests made by it.
+    ///
+    /// ```no_run
+    /// # use iota_sdk::client::{node_manager::request_policy::RequestPolicy, Client, Result};
+    /// # async fn example(client: &Client) -> Result<()> {
+    /// let info = client
+    ///     .with_request_policy(RequestPolicy::new().with_max_retries(5), client.get_info())
+    ///     .await?;
+    /// # Ok(())}
+    /// ```
+    pub async fn with_request_policy<F: futures::Future>(&self, policy: RequestPolicy, future: F) -> F::Output {
+        REQUEST_POLICY_OVERRIDE.scope(policy, future).await
+    }
 }
 
 impl ClientInner {
@@ -191,6 +220,23 @@ impl ClientInner {
         *self.remote_pow_timeout.read().await
     }
 
+    /// Returns the request policy for the current call, which is either the one set with
+    /// [`Client::with_request_policy()`] or the one configured for the client.
+    pub async fn get_request_policy(&self) -> RequestPolicy {
+        match REQUEST_POLICY_OVERRIDE.try_with(Clone::clone) {
+            Ok(policy) => policy,
+            Err(_) => self.request_policy.read().await.clone(),
+        }
+    }
+
+    /// Returns the timeout for a route, falling back to the API timeout if the policy doesn't define one.
+    pub(crate) async fn get_timeout_for(&self, policy: &RequestPolicy, path: &str) -> Duration {
+        match policy.route_timeout(path) {
+            Some(timeout) => timeout,
+            None => self.get_timeout().await,
+        }
+    }
+
     /// returns the fallback_to_local_pow
     pub async fn get_fallback_to_local_pow(&self) -> bool {
         self.network_info.read().await.fallback_to_local_pow
@@ -208,6 +254,16 @@ impl ClientInner {
         Ok(())
     }
 
+    /// Returns the hit/miss statistics of the response cache.
+    pub fn response_cache_metrics(&self) -> ResponseCacheMetrics {
+        self.response_cache.metrics()
+    }
+
+    /// Removes all responses from the response cache.
+    pub fn clear_response_cache(&self) {
+        self.response_cache.clear()
+    }
+
     /// Resize the client's request pool
     #[cfg(not(target_family = "wasm"))]
     pub async fn resize_request_pool(&self, new_size: usize) {
@@ -56,6 +56,9 @@ pub enum Error {
     /// Invalid amount in API response
     #[error("invalid amount in API response: {0}")]
     InvalidAmount(String),
+    /// Contradicting or malformed indexer query parameters
+    #[error("invalid query parameters: {0}")]
+    InvalidQueryParameters(String),
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
@@ -90,6 +93,17 @@ pub enum Error {
     /// Requested output id not found for this type
     #[error("No output found for {0}")]
     NoOutput(String),
+    /// The operation was cancelled
+    #[error("operation cancelled")]
+    OperationCancelled,
+    /// The indexer returned a page for an older ledger state than the resumed cursor or the previous pages
+    #[error("outdated ledger index {found} returned by the indexer, expected at least {expected}")]
+    OutdatedLedgerIndex {
+        /// The minimum expected ledger index.
+        expected: u32,
+        /// The ledger index returned by the indexer.
+        found: u32,
+    },
     /// PlaceholderSecretManager can't be used for address generation or signing
     #[error("placeholderSecretManager can't be used for address generation or signing")]
     PlaceholderSecretManager,
@@ -37,11 +37,13 @@ macro_rules! lazy_static {
 
 pub mod api;
 pub mod builder;
+pub mod cache;
 pub mod constants;
 pub mod core;
 pub mod error;
 pub mod node_api;
 pub mod node_manager;
+pub mod operation;
 #[cfg(not(target_family = "wasm"))]
 pub(crate) mod request_pool;
 pub mod secret;
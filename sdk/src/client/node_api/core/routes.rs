@@ -35,6 +35,7 @@ pub(crate) static INFO_PATH: &str = "api/core/v2/info";
 
 /// NodeInfo wrapper which contains the node info and the url from the node (useful when multiple nodes are used)
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NodeInfoWrapper {
     /// The returned node info
@@ -82,7 +83,12 @@ impl ClientInner {
     /// Returns general information about the node.
     /// GET /api/core/v2/info
     pub async fn get_info(&self) -> Result<NodeInfoWrapper> {
-        self.get_request(INFO_PATH, None, false, false).await
+        let info: NodeInfoWrapper = self.get_request(INFO_PATH, None, false, false).await?;
+
+        self.response_cache
+            .update_confirmed_milestone_index(info.node_info.status.confirmed_milestone.index);
+
+        Ok(info)
     }
 
     // Tangle routes.
@@ -104,8 +110,9 @@ impl ClientInner {
     pub async fn post_block(&self, block: &Block) -> Result<BlockId> {
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow().await;
+        let policy = self.get_request_policy().await;
         let timeout = if local_pow {
-            self.get_timeout().await
+            self.get_timeout_for(&policy, path).await
         } else {
             self.get_remote_pow_timeout().await
         };
@@ -116,11 +123,17 @@ impl ClientInner {
             .node_manager
             .read()
             .await
-            .post_request_json::<SubmitBlockResponse>(path, timeout, serde_json::to_value(block_dto)?, local_pow)
+            .post_request_json::<SubmitBlockResponse>(
+                path,
+                timeout,
+                &policy,
+                serde_json::to_value(block_dto)?,
+                local_pow,
+            )
             .await
         {
             Ok(res) => res,
-            Err(Error::Node(crate::client::node_api::error::Error::UnavailablePow)) => {
+            Err(Error::Node(e)) if matches!(e.root_cause(), crate::client::node_api::error::Error::UnavailablePow) => {
                 if !self.get_fallback_to_local_pow().await {
                     return Err(Error::Node(crate::client::node_api::error::Error::UnavailablePow));
                 }
@@ -146,7 +159,7 @@ impl ClientInner {
                 self.node_manager
                     .read()
                     .await
-                    .post_request_json(path, timeout, serde_json::to_value(block_dto)?, true)
+                    .post_request_json(path, timeout, &policy, serde_json::to_value(block_dto)?, true)
                     .await?
             }
             Err(e) => return Err(e),
@@ -160,8 +173,9 @@ impl ClientInner {
     pub async fn post_block_raw(&self, block: &Block) -> Result<BlockId> {
         let path = "api/core/v2/blocks";
         let local_pow = self.get_local_pow().await;
+        let policy = self.get_request_policy().await;
         let timeout = if local_pow {
-            self.get_timeout().await
+            self.get_timeout_for(&policy, path).await
         } else {
             self.get_remote_pow_timeout().await
         };
@@ -171,11 +185,11 @@ impl ClientInner {
             .node_manager
             .read()
             .await
-            .post_request_bytes::<SubmitBlockResponse>(path, timeout, &block.pack_to_vec(), local_pow)
+            .post_request_bytes::<SubmitBlockResponse>(path, timeout, &policy, &block.pack_to_vec(), local_pow)
             .await
         {
             Ok(res) => res,
-            Err(Error::Node(crate::client::node_api::error::Error::UnavailablePow)) => {
+            Err(Error::Node(e)) if matches!(e.root_cause(), crate::client::node_api::error::Error::UnavailablePow) => {
                 if !self.get_fallback_to_local_pow().await {
                     return Err(Error::Node(crate::client::node_api::error::Error::UnavailablePow));
                 }
@@ -198,7 +212,7 @@ impl ClientInner {
                 self.node_manager
                     .read()
                     .await
-                    .post_request_bytes(path, timeout, &block_with_local_pow.pack_to_vec(), true)
+                    .post_request_bytes(path, timeout, &policy, &block_with_local_pow.pack_to_vec(), true)
                     .await?
             }
             Err(e) => return Err(e),
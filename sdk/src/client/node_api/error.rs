@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::client::node_manager::request_policy::RequestAttempt;
+
 /// Type alias of `Result` in Node errors
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -29,4 +31,33 @@ pub enum Error {
     /// We made a call to the node but the protocol was unsupported
     #[error("Call to {0} is not supported on this node")]
     NotSupported(String),
+    /// The circuit breaker excludes all nodes for this request
+    #[error("No node available, circuit open for {0}")]
+    CircuitOpen(String),
+    /// The request failed on every attempt permitted by the request policy
+    #[error("{error} (after {} attempts)", attempts.len())]
+    AttemptsExhausted {
+        /// The error of the last attempt.
+        error: Box<Self>,
+        /// All failed attempts, in the order they were made.
+        attempts: Vec<RequestAttempt>,
+    },
+}
+
+impl Error {
+    /// Returns the error of the last attempt, unwrapping the attempt history if there is one.
+    pub fn root_cause(&self) -> &Self {
+        match self {
+            Self::AttemptsExhausted { error, .. } => error.root_cause(),
+            _ => self,
+        }
+    }
+
+    /// Returns the attempt history of the request, empty if the request was only attempted once.
+    pub fn attempts(&self) -> &[RequestAttempt] {
+        match self {
+            Self::AttemptsExhausted { attempts, .. } => attempts,
+            _ => &[],
+        }
+    }
 }
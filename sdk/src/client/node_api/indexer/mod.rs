@@ -6,32 +6,107 @@
 pub mod query_parameters;
 pub mod routes;
 
+use futures::{Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+
 pub(crate) use self::query_parameters::{QueryParameter, QueryParameters};
 use crate::{
-    client::{ClientInner, Result},
-    types::api::plugins::indexer::OutputIdsResponse,
+    client::{ClientInner, Error, Result},
+    types::{api::plugins::indexer::OutputIdsResponse, block::output::OutputWithMetadata},
 };
 
+/// A position in a paginated indexer query that can be stored to resume the query later.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexerCursor {
+    /// The cursor of the next page.
+    pub cursor: String,
+    /// The ledger index of the page the cursor was returned with.
+    pub ledger_index: u32,
+}
+
+impl IndexerCursor {
+    /// Returns the cursor to the page following `response`, if there is one.
+    pub fn from_response(response: &OutputIdsResponse) -> Option<Self> {
+        response.cursor.as_ref().map(|cursor| Self {
+            cursor: cursor.clone(),
+            ledger_index: response.ledger_index,
+        })
+    }
+}
+
+/// A page of outputs returned by an indexer query.
+#[derive(Clone, Debug)]
+pub struct OutputsPage {
+    /// The ledger index at which the outputs were collected.
+    pub ledger_index: u32,
+    /// Cursor confirmationMS+outputId.pageSize, to get the next page.
+    pub cursor: Option<String>,
+    /// The outputs of the page.
+    pub items: Vec<OutputWithMetadata>,
+}
+
 impl ClientInner {
     /// Get all output ids for a provided URL route and query parameters.
     /// If a `QueryParameter::Cursor(_)` is provided, only a single page will be queried.
     pub async fn get_output_ids(
         &self,
         route: &str,
-        mut query_parameters: QueryParameters,
+        query_parameters: QueryParameters,
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<OutputIdsResponse> {
-        let mut merged_output_ids_response = OutputIdsResponse {
-            ledger_index: 0,
-            cursor: None,
-            items: Vec::new(),
-        };
-
         // Return early with only a single page if a `QueryParameter::Cursor(_)` is provided.
-        let return_early = query_parameters.contains(QueryParameter::Cursor(String::new()).kind());
+        if query_parameters.contains(QueryParameter::Cursor(String::new()).kind()) {
+            return self
+                .get_request::<OutputIdsResponse>(
+                    route,
+                    query_parameters.to_query_string().as_deref(),
+                    need_quorum,
+                    prefer_permanode,
+                )
+                .await;
+        }
+
+        self.get_output_ids_stream(route, query_parameters, None, need_quorum, prefer_permanode)
+            .try_fold(
+                OutputIdsResponse {
+                    ledger_index: 0,
+                    cursor: None,
+                    items: Vec::new(),
+                },
+                |mut merged_output_ids_response, output_ids_response| async move {
+                    merged_output_ids_response.ledger_index = output_ids_response.ledger_index;
+                    merged_output_ids_response.cursor = output_ids_response.cursor;
+                    merged_output_ids_response.items.extend(output_ids_response.items);
+                    Ok(merged_output_ids_response)
+                },
+            )
+            .await
+    }
+
+    /// Get the output ids for a provided URL route and query parameters page by page. The next page is only requested
+    /// once the previous one was consumed. If `resume_from` is provided, the query continues from that cursor and
+    /// pages from a ledger index older than the one of the cursor or of a previous page are rejected with
+    /// [`Error::OutdatedLedgerIndex`], as they wouldn't line up with the pages seen so far.
+    pub fn get_output_ids_stream<'a>(
+        &'a self,
+        route: &'a str,
+        mut query_parameters: QueryParameters,
+        resume_from: Option<IndexerCursor>,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> impl Stream<Item = Result<OutputIdsResponse>> + 'a {
+        let min_ledger_index = resume_from.map(|IndexerCursor { cursor, ledger_index }| {
+            query_parameters.replace(QueryParameter::Cursor(cursor));
+            ledger_index
+        });
+
+        futures::stream::try_unfold(Some((query_parameters, min_ledger_index)), move |state| async move {
+            let Some((mut query_parameters, min_ledger_index)) = state else {
+                return Ok(None);
+            };
 
-        while let Some(cursor) = {
             let output_ids_response = self
                 .get_request::<OutputIdsResponse>(
                     route,
@@ -41,19 +116,79 @@ impl ClientInner {
                 )
                 .await?;
 
-            if return_early {
-                return Ok(output_ids_response);
-            }
+            check_ledger_index(min_ledger_index, &output_ids_response)?;
 
-            merged_output_ids_response.ledger_index = output_ids_response.ledger_index;
-            merged_output_ids_response.cursor = output_ids_response.cursor;
-            merged_output_ids_response.items.extend(output_ids_response.items);
+            let next_state = output_ids_response.cursor.clone().map(|cursor| {
+                query_parameters.replace(QueryParameter::Cursor(cursor));
+                (
+                    query_parameters,
+                    min_ledger_index.map(|_| output_ids_response.ledger_index),
+                )
+            });
+
+            Ok(Some((output_ids_response, next_state)))
+        })
+    }
+
+    /// Turns a stream of output id pages into a stream of output pages. The outputs of a page are requested
+    /// concurrently, with at most `max_parallel_api_requests` requests in flight.
+    pub fn get_outputs_stream<'a>(
+        &'a self,
+        output_ids: impl Stream<Item = Result<OutputIdsResponse>> + 'a,
+    ) -> impl Stream<Item = Result<OutputsPage>> + 'a {
+        output_ids.and_then(move |output_ids_response| async move {
+            #[cfg(not(target_family = "wasm"))]
+            let max_parallel_api_requests = self.request_pool.size().await.max(1);
+            #[cfg(target_family = "wasm")]
+            let max_parallel_api_requests = crate::client::constants::MAX_PARALLEL_API_REQUESTS;
+
+            let items = futures::stream::iter(output_ids_response.items.iter())
+                .map(|output_id| self.get_output(output_id))
+                .buffered(max_parallel_api_requests)
+                .try_collect()
+                .await?;
 
-            &merged_output_ids_response.cursor
-        } {
-            query_parameters.replace(QueryParameter::Cursor(cursor.to_string()));
+            Ok(OutputsPage {
+                ledger_index: output_ids_response.ledger_index,
+                cursor: output_ids_response.cursor,
+                items,
+            })
+        })
+    }
+}
+
+// Only checked for resumed queries, a node that is behind would return pages that don't line up with the ones seen so
+// far.
+fn check_ledger_index(min_ledger_index: Option<u32>, output_ids_response: &OutputIdsResponse) -> Result<()> {
+    match min_ledger_index {
+        Some(expected) if output_ids_response.ledger_index < expected => Err(Error::OutdatedLedgerIndex {
+            expected,
+            found: output_ids_response.ledger_index,
+        }),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(ledger_index: u32) -> OutputIdsResponse {
+        OutputIdsResponse {
+            ledger_index,
+            cursor: None,
+            items: Vec::new(),
         }
+    }
 
-        Ok(merged_output_ids_response)
+    #[test]
+    fn outdated_ledger_index_only_for_resumed_queries() {
+        assert!(check_ledger_index(None, &response(1)).is_ok());
+        assert!(check_ledger_index(Some(5), &response(5)).is_ok());
+        assert!(check_ledger_index(Some(5), &response(6)).is_ok());
+        assert!(matches!(
+            check_ledger_index(Some(5), &response(4)),
+            Err(Error::OutdatedLedgerIndex { expected: 5, found: 4 })
+        ));
     }
 }
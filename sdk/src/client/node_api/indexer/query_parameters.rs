@@ -15,7 +15,7 @@ use crate::{
 // https://github.com/iotaledger/inx-indexer/tree/develop/pkg/indexer
 
 /// Query parameters for output_id requests.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct QueryParameters(Vec<QueryParameter>);
 
 impl QueryParameters {
@@ -56,6 +56,82 @@ impl QueryParameters {
         self.0.iter().any(f)
     }
 
+    /// Checks that ranges, presence filters and the cursor don't contradict each other.
+    pub(crate) fn validate(&self) -> Result<()> {
+        macro_rules! find {
+            ($variant:ident) => {
+                self.0.iter().find_map(|qp| match qp {
+                    QueryParameter::$variant(v) => Some(v),
+                    _ => None,
+                })
+            };
+        }
+
+        for (after, before, name) in [
+            (find!(CreatedAfter), find!(CreatedBefore), "created"),
+            (find!(ExpiresAfter), find!(ExpiresBefore), "expires"),
+            (find!(TimelockedAfter), find!(TimelockedBefore), "timelocked"),
+        ] {
+            if let (Some(after), Some(before)) = (after, before) {
+                if after >= before {
+                    return Err(Error::InvalidQueryParameters(format!(
+                        "{name}After ({after}) must be smaller than {name}Before ({before})"
+                    )));
+                }
+            }
+        }
+
+        if let (Some(min), Some(max)) = (find!(MinNativeTokenCount), find!(MaxNativeTokenCount)) {
+            if min > max {
+                return Err(Error::InvalidQueryParameters(format!(
+                    "minNativeTokenCount ({min}) must not be larger than maxNativeTokenCount ({max})"
+                )));
+            }
+        }
+
+        // Filters on something that a `has...=false` parameter already excludes can't match any output.
+        for (excluded, conflicting, name) in [
+            (
+                find!(HasNativeTokens),
+                find!(MinNativeTokenCount).is_some_and(|min| *min > 0),
+                "hasNativeTokens",
+            ),
+            (
+                find!(HasExpiration),
+                find!(ExpiresAfter).is_some()
+                    || find!(ExpiresBefore).is_some()
+                    || find!(ExpirationReturnAddress).is_some(),
+                "hasExpiration",
+            ),
+            (
+                find!(HasTimelock),
+                find!(TimelockedAfter).is_some() || find!(TimelockedBefore).is_some(),
+                "hasTimelock",
+            ),
+            (
+                find!(HasStorageDepositReturn),
+                find!(StorageDepositReturnAddress).is_some(),
+                "hasStorageDepositReturn",
+            ),
+        ] {
+            if excluded == Some(&false) && conflicting {
+                return Err(Error::InvalidQueryParameters(format!(
+                    "{name}=false conflicts with filters that require it"
+                )));
+            }
+        }
+
+        if find!(PageSize) == Some(&0) {
+            return Err(Error::InvalidQueryParameters("pageSize must not be 0".to_string()));
+        }
+
+        if let Some(cursor) = find!(Cursor) {
+            validate_cursor(cursor)?;
+        }
+
+        Ok(())
+    }
+
     /// Converts parameters to a single String.
     pub fn to_query_string(&self) -> Option<String> {
         if self.0.is_empty() {
@@ -72,8 +148,15 @@ impl QueryParameters {
     }
 }
 
+impl From<QueryParameters> for Vec<QueryParameter> {
+    fn from(query_parameters: QueryParameters) -> Self {
+        query_parameters.0
+    }
+}
+
 /// Query parameter for output requests.
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[non_exhaustive]
 pub enum QueryParameter {
@@ -196,6 +279,24 @@ impl fmt::Display for QueryParameter {
     }
 }
 
+/// Checks that a cursor has the `confirmationMS+outputId.pageSize` form returned by the indexer, i.e. hex encoded
+/// bytes followed by a page size.
+fn validate_cursor(cursor: &str) -> Result<()> {
+    let invalid = || Error::InvalidQueryParameters(format!("malformed cursor `{cursor}`"));
+    let (position, page_size) = cursor.rsplit_once('.').ok_or_else(invalid)?;
+    let position = position.strip_prefix("0x").unwrap_or(position);
+
+    if position.is_empty()
+        || position.len() % 2 != 0
+        || !position.chars().all(|c| c.is_ascii_hexdigit())
+        || page_size.parse::<usize>().map_or(true, |page_size| page_size == 0)
+    {
+        return Err(invalid());
+    }
+
+    Ok(())
+}
+
 macro_rules! verify_query_parameters {
     ($query_parameters:ident, $first:path $(, $rest:path)*) => {
         if let Some(qp) = $query_parameters.iter().find(|qp| {
@@ -208,6 +309,276 @@ macro_rules! verify_query_parameters {
     };
 }
 
+macro_rules! query_setters {
+    ($query:ident { $($(#[$meta:meta])* $setter:ident($ty:ty) => $variant:ident;)* }) => {
+        impl $query {
+            /// Creates an empty query.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            $(
+                $(#[$meta])*
+                #[must_use]
+                pub fn $setter(mut self, value: $ty) -> Self {
+                    self.0.replace(QueryParameter::$variant(value.into()));
+                    self
+                }
+            )*
+
+            /// Converts the query to query parameters, checking that ranges, presence filters and the cursor don't
+            /// contradict each other.
+            pub fn finish(self) -> Result<QueryParameters> {
+                self.0.validate()?;
+                Ok(self.0)
+            }
+        }
+    };
+}
+
+macro_rules! route_query {
+    ($(#[$meta:meta])* $trait:ident: $query:ident, $verify:ident) => {
+        $(#[$meta])*
+        pub trait $trait {
+            /// Converts to the query parameters of the route.
+            fn into_query_parameters(self) -> Result<QueryParameters>;
+        }
+
+        impl $trait for $query {
+            fn into_query_parameters(self) -> Result<QueryParameters> {
+                self.finish()
+            }
+        }
+
+        impl $trait for QueryParameters {
+            fn into_query_parameters(self) -> Result<QueryParameters> {
+                $verify(self.0)
+            }
+        }
+
+        impl $trait for Vec<QueryParameter> {
+            fn into_query_parameters(self) -> Result<QueryParameters> {
+                $verify(self)
+            }
+        }
+
+        impl<const N: usize> $trait for [QueryParameter; N] {
+            fn into_query_parameters(self) -> Result<QueryParameters> {
+                $verify(self.into())
+            }
+        }
+
+        impl $trait for &[QueryParameter] {
+            fn into_query_parameters(self) -> Result<QueryParameters> {
+                $verify(self.to_vec())
+            }
+        }
+    };
+}
+
+/// Query for `api/indexer/v1/outputs`, only accepting the parameters supported by that route.
+#[derive(Debug, Clone, Default)]
+pub struct OutputQuery(QueryParameters);
+
+query_setters!(OutputQuery {
+    /// Filters outputs based on the presence of native tokens.
+    with_has_native_tokens(bool) => HasNativeTokens;
+    /// Filters outputs that have at least a certain number of distinct native tokens.
+    with_min_native_token_count(u32) => MinNativeTokenCount;
+    /// Filters outputs that have at most a certain number of distinct native tokens.
+    with_max_native_token_count(u32) => MaxNativeTokenCount;
+    /// Returns outputs that were created before a certain Unix timestamp.
+    with_created_before(u32) => CreatedBefore;
+    /// Returns outputs that were created after a certain Unix timestamp.
+    with_created_after(u32) => CreatedAfter;
+    /// The maximum amount of items returned in one call.
+    with_page_size(usize) => PageSize;
+    /// Starts the search from the cursor (confirmationMS+outputId.pageSize).
+    with_cursor(impl Into<String>) => Cursor;
+    /// Returns outputs that are unlockable by the bech32 address.
+    with_unlockable_by_address(impl Into<Bech32Address>) => UnlockableByAddress;
+});
+
+/// Query for `api/indexer/v1/outputs/basic`, only accepting the parameters supported by that route.
+#[derive(Debug, Clone, Default)]
+pub struct BasicOutputQuery(QueryParameters);
+
+query_setters!(BasicOutputQuery {
+    /// Bech32-encoded address that should be searched for.
+    with_address(impl Into<Bech32Address>) => Address;
+    /// Filters outputs based on the presence of native tokens.
+    with_has_native_tokens(bool) => HasNativeTokens;
+    /// Filters outputs that have at least a certain number of distinct native tokens.
+    with_min_native_token_count(u32) => MinNativeTokenCount;
+    /// Filters outputs that have at most a certain number of distinct native tokens.
+    with_max_native_token_count(u32) => MaxNativeTokenCount;
+    /// Filters outputs based on the presence of storage deposit return unlock condition.
+    with_has_storage_deposit_return(bool) => HasStorageDepositReturn;
+    /// Filters outputs based on the return address in the storage deposit return unlock condition.
+    with_storage_deposit_return_address(impl Into<Bech32Address>) => StorageDepositReturnAddress;
+    /// Filters outputs based on the presence of timelock unlock condition.
+    with_has_timelock(bool) => HasTimelock;
+    /// Returns outputs that are timelocked before a certain Unix timestamp.
+    with_timelocked_before(u32) => TimelockedBefore;
+    /// Returns outputs that are timelocked after a certain Unix timestamp.
+    with_timelocked_after(u32) => TimelockedAfter;
+    /// Filters outputs based on the presence of expiration unlock condition.
+    with_has_expiration(bool) => HasExpiration;
+    /// Returns outputs that expire before a certain Unix timestamp.
+    with_expires_before(u32) => ExpiresBefore;
+    /// Returns outputs that expire after a certain Unix timestamp.
+    with_expires_after(u32) => ExpiresAfter;
+    /// Filters outputs based on the return address in the expiration unlock condition.
+    with_expiration_return_address(impl Into<Bech32Address>) => ExpirationReturnAddress;
+    /// Filters outputs based on the presence of validated Sender.
+    with_sender(impl Into<Bech32Address>) => Sender;
+    /// Filters outputs based on matching Tag Block.
+    with_tag(impl Into<String>) => Tag;
+    /// Returns outputs that were created before a certain Unix timestamp.
+    with_created_before(u32) => CreatedBefore;
+    /// Returns outputs that were created after a certain Unix timestamp.
+    with_created_after(u32) => CreatedAfter;
+    /// The maximum amount of items returned in one call.
+    with_page_size(usize) => PageSize;
+    /// Starts the search from the cursor (confirmationMS+outputId.pageSize).
+    with_cursor(impl Into<String>) => Cursor;
+    /// Returns outputs that are unlockable by the bech32 address.
+    with_unlockable_by_address(impl Into<Bech32Address>) => UnlockableByAddress;
+});
+
+/// Query for `api/indexer/v1/outputs/alias`, only accepting the parameters supported by that route.
+#[derive(Debug, Clone, Default)]
+pub struct AliasOutputQuery(QueryParameters);
+
+query_setters!(AliasOutputQuery {
+    /// Filters outputs based on bech32-encoded state controller address.
+    with_state_controller(impl Into<Bech32Address>) => StateController;
+    /// Filters outputs based on bech32-encoded governor (governance controller) address.
+    with_governor(impl Into<Bech32Address>) => Governor;
+    /// Filters outputs based on bech32-encoded issuer address.
+    with_issuer(impl Into<Bech32Address>) => Issuer;
+    /// Filters outputs based on the presence of validated Sender.
+    with_sender(impl Into<Bech32Address>) => Sender;
+    /// Filters outputs based on the presence of native tokens.
+    with_has_native_tokens(bool) => HasNativeTokens;
+    /// Filters outputs that have at least a certain number of distinct native tokens.
+    with_min_native_token_count(u32) => MinNativeTokenCount;
+    /// Filters outputs that have at most a certain number of distinct native tokens.
+    with_max_native_token_count(u32) => MaxNativeTokenCount;
+    /// Returns outputs that were created before a certain Unix timestamp.
+    with_created_before(u32) => CreatedBefore;
+    /// Returns outputs that were created after a certain Unix timestamp.
+    with_created_after(u32) => CreatedAfter;
+    /// The maximum amount of items returned in one call.
+    with_page_size(usize) => PageSize;
+    /// Starts the search from the cursor (confirmationMS+outputId.pageSize).
+    with_cursor(impl Into<String>) => Cursor;
+    /// Returns outputs that are unlockable by the bech32 address.
+    with_unlockable_by_address(impl Into<Bech32Address>) => UnlockableByAddress;
+});
+
+/// Query for `api/indexer/v1/outputs/foundry`, only accepting the parameters supported by that route.
+#[derive(Debug, Clone, Default)]
+pub struct FoundryOutputQuery(QueryParameters);
+
+query_setters!(FoundryOutputQuery {
+    /// Filters foundry outputs based on bech32-encoded address of the controlling alias.
+    with_alias_address(impl Into<Bech32Address>) => AliasAddress;
+    /// Filters outputs based on the presence of native tokens.
+    with_has_native_tokens(bool) => HasNativeTokens;
+    /// Filters outputs that have at least a certain number of distinct native tokens.
+    with_min_native_token_count(u32) => MinNativeTokenCount;
+    /// Filters outputs that have at most a certain number of distinct native tokens.
+    with_max_native_token_count(u32) => MaxNativeTokenCount;
+    /// Returns outputs that were created before a certain Unix timestamp.
+    with_created_before(u32) => CreatedBefore;
+    /// Returns outputs that were created after a certain Unix timestamp.
+    with_created_after(u32) => CreatedAfter;
+    /// The maximum amount of items returned in one call.
+    with_page_size(usize) => PageSize;
+    /// Starts the search from the cursor (confirmationMS+outputId.pageSize).
+    with_cursor(impl Into<String>) => Cursor;
+});
+
+/// Query for `api/indexer/v1/outputs/nft`, only accepting the parameters supported by that route.
+#[derive(Debug, Clone, Default)]
+pub struct NftOutputQuery(QueryParameters);
+
+query_setters!(NftOutputQuery {
+    /// Bech32-encoded address that should be searched for.
+    with_address(impl Into<Bech32Address>) => Address;
+    /// Filters outputs based on the presence of native tokens.
+    with_has_native_tokens(bool) => HasNativeTokens;
+    /// Filters outputs that have at least a certain number of distinct native tokens.
+    with_min_native_token_count(u32) => MinNativeTokenCount;
+    /// Filters outputs that have at most a certain number of distinct native tokens.
+    with_max_native_token_count(u32) => MaxNativeTokenCount;
+    /// Filters outputs based on the presence of storage deposit return unlock condition.
+    with_has_storage_deposit_return(bool) => HasStorageDepositReturn;
+    /// Filters outputs based on the return address in the storage deposit return unlock condition.
+    with_storage_deposit_return_address(impl Into<Bech32Address>) => StorageDepositReturnAddress;
+    /// Filters outputs based on the presence of timelock unlock condition.
+    with_has_timelock(bool) => HasTimelock;
+    /// Returns outputs that are timelocked before a certain Unix timestamp.
+    with_timelocked_before(u32) => TimelockedBefore;
+    /// Returns outputs that are timelocked after a certain Unix timestamp.
+    with_timelocked_after(u32) => TimelockedAfter;
+    /// Filters outputs based on the presence of expiration unlock condition.
+    with_has_expiration(bool) => HasExpiration;
+    /// Returns outputs that expire before a certain Unix timestamp.
+    with_expires_before(u32) => ExpiresBefore;
+    /// Returns outputs that expire after a certain Unix timestamp.
+    with_expires_after(u32) => ExpiresAfter;
+    /// Filters outputs based on the return address in the expiration unlock condition.
+    with_expiration_return_address(impl Into<Bech32Address>) => ExpirationReturnAddress;
+    /// Filters outputs based on bech32-encoded issuer address.
+    with_issuer(impl Into<Bech32Address>) => Issuer;
+    /// Filters outputs based on the presence of validated Sender.
+    with_sender(impl Into<Bech32Address>) => Sender;
+    /// Filters outputs based on matching Tag Block.
+    with_tag(impl Into<String>) => Tag;
+    /// Returns outputs that were created before a certain Unix timestamp.
+    with_created_before(u32) => CreatedBefore;
+    /// Returns outputs that were created after a certain Unix timestamp.
+    with_created_after(u32) => CreatedAfter;
+    /// The maximum amount of items returned in one call.
+    with_page_size(usize) => PageSize;
+    /// Starts the search from the cursor (confirmationMS+outputId.pageSize).
+    with_cursor(impl Into<String>) => Cursor;
+    /// Returns outputs that are unlockable by the bech32 address.
+    with_unlockable_by_address(impl Into<Bech32Address>) => UnlockableByAddress;
+});
+
+route_query!(
+    /// Query for `api/indexer/v1/outputs`, either a typed [`OutputQuery`] or a list of [`QueryParameter`]s that is
+    /// checked for unsupported parameters when the request is sent.
+    IntoOutputQuery: OutputQuery, verify_query_parameters_outputs
+);
+
+route_query!(
+    /// Query for `api/indexer/v1/outputs/basic`, either a typed [`BasicOutputQuery`] or a list of [`QueryParameter`]s
+    /// that is checked for unsupported parameters when the request is sent.
+    IntoBasicOutputQuery: BasicOutputQuery, verify_query_parameters_basic_outputs
+);
+
+route_query!(
+    /// Query for `api/indexer/v1/outputs/alias`, either a typed [`AliasOutputQuery`] or a list of [`QueryParameter`]s
+    /// that is checked for unsupported parameters when the request is sent.
+    IntoAliasOutputQuery: AliasOutputQuery, verify_query_parameters_alias_outputs
+);
+
+route_query!(
+    /// Query for `api/indexer/v1/outputs/foundry`, either a typed [`FoundryOutputQuery`] or a list of
+    /// [`QueryParameter`]s that is checked for unsupported parameters when the request is sent.
+    IntoFoundryOutputQuery: FoundryOutputQuery, verify_query_parameters_foundry_outputs
+);
+
+route_query!(
+    /// Query for `api/indexer/v1/outputs/nft`, either a typed [`NftOutputQuery`] or a list of [`QueryParameter`]s that
+    /// is checked for unsupported parameters when the request is sent.
+    IntoNftOutputQuery: NftOutputQuery, verify_query_parameters_nft_outputs
+);
+
 pub(crate) fn verify_query_parameters_outputs(query_parameters: Vec<QueryParameter>) -> Result<QueryParameters> {
     verify_query_parameters!(
         query_parameters,
@@ -349,4 +720,70 @@ mod tests {
         // Contains no cursor query parameter
         assert!(!query_parameters.any(|param| matches!(param, QueryParameter::Cursor(_))));
     }
+
+    #[test]
+    fn typed_query() {
+        let address =
+            Bech32Address::try_from_str("atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r").unwrap();
+
+        let typed = BasicOutputQuery::new()
+            .with_address(address)
+            .with_has_expiration(false)
+            .with_created_after(10)
+            .with_page_size(5)
+            .finish()
+            .unwrap();
+        let untyped = verify_query_parameters_basic_outputs(vec![
+            QueryParameter::PageSize(5),
+            QueryParameter::HasExpiration(false),
+            QueryParameter::CreatedAfter(10),
+            QueryParameter::Address(address),
+        ])
+        .unwrap();
+
+        assert_eq!(typed.to_query_string(), untyped.to_query_string());
+        assert_eq!(
+            typed.to_query_string().unwrap(),
+            "address=atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r&createdAfter=10&hasExpiration=false&pageSize=5"
+        );
+    }
+
+    #[test]
+    fn invalid_query_parameters() {
+        let invalid = |result: Result<QueryParameters>| matches!(result, Err(Error::InvalidQueryParameters(_)));
+
+        assert!(invalid(
+            NftOutputQuery::new()
+                .with_created_after(20)
+                .with_created_before(10)
+                .finish()
+        ));
+        assert!(invalid(
+            FoundryOutputQuery::new()
+                .with_min_native_token_count(3)
+                .with_max_native_token_count(2)
+                .finish()
+        ));
+        assert!(invalid(
+            BasicOutputQuery::new()
+                .with_has_timelock(false)
+                .with_timelocked_before(10)
+                .finish()
+        ));
+        assert!(invalid(OutputQuery::new().with_page_size(0).finish()));
+        assert!(invalid(AliasOutputQuery::new().with_cursor("not a cursor").finish()));
+        // Lists of query parameters are only checked for unsupported parameters, like before the typed queries
+        assert!(verify_query_parameters_basic_outputs(vec![
+            QueryParameter::HasExpiration(false),
+            QueryParameter::ExpiresAfter(10),
+        ])
+        .is_ok());
+
+        assert!(BasicOutputQuery::new()
+            .with_has_native_tokens(false)
+            .with_max_native_token_count(0)
+            .with_cursor("0x0000000a00.100")
+            .finish()
+            .is_ok());
+    }
 }
@@ -3,15 +3,15 @@
 
 //! IOTA node indexer routes
 
+use futures::Stream;
+
 use crate::{
     client::{
         node_api::indexer::{
             query_parameters::{
-                verify_query_parameters_alias_outputs, verify_query_parameters_basic_outputs,
-                verify_query_parameters_foundry_outputs, verify_query_parameters_nft_outputs,
-                verify_query_parameters_outputs, QueryParameter,
+                IntoAliasOutputQuery, IntoBasicOutputQuery, IntoFoundryOutputQuery, IntoNftOutputQuery, IntoOutputQuery,
             },
-            QueryParameters,
+            IndexerCursor, QueryParameters,
         },
         ClientInner, Error, Result,
     },
@@ -28,15 +28,14 @@ impl ClientInner {
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "hasNativeTokens", "minNativeTokenCount", "maxNativeTokenCount", "unlockableByAddress",
     /// "createdBefore", "createdAfter", "cursor", "pageSize".
+    /// Accepts a typed [`OutputQuery`](super::query_parameters::OutputQuery) or a list of query parameters, see
+    /// [`IntoOutputQuery`].
     /// Returns Err(Node(NotFound) if no results are found.
     /// api/indexer/v1/outputs
-    pub async fn output_ids(
-        &self,
-        query_parameters: impl Into<Vec<QueryParameter>> + Send,
-    ) -> Result<OutputIdsResponse> {
+    pub async fn output_ids(&self, query_parameters: impl IntoOutputQuery + Send) -> Result<OutputIdsResponse> {
         let route = "api/indexer/v1/outputs";
 
-        let query_parameters = verify_query_parameters_outputs(query_parameters.into())?;
+        let query_parameters = query_parameters.into_query_parameters()?;
 
         self.get_output_ids(route, query_parameters, true, false).await
     }
@@ -46,15 +45,17 @@ impl ClientInner {
     /// Query parameters: "address", "hasStorageDepositReturn", "storageDepositReturnAddress",
     /// "hasExpiration", "expiresBefore", "expiresAfter", "hasTimelock", "timelockedBefore",
     /// "timelockedAfter", "sender", "tag", "createdBefore" and "createdAfter".
+    /// Accepts a typed [`BasicOutputQuery`](super::query_parameters::BasicOutputQuery) or a list of query parameters,
+    /// see [`IntoBasicOutputQuery`].
     /// Returns Err(Node(NotFound) if no results are found.
     /// api/indexer/v1/outputs/basic
     pub async fn basic_output_ids(
         &self,
-        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+        query_parameters: impl IntoBasicOutputQuery + Send,
     ) -> Result<OutputIdsResponse> {
         let route = "api/indexer/v1/outputs/basic";
 
-        let query_parameters = verify_query_parameters_basic_outputs(query_parameters.into())?;
+        let query_parameters = query_parameters.into_query_parameters()?;
 
         self.get_output_ids(route, query_parameters, true, false).await
     }
@@ -62,15 +63,17 @@ impl ClientInner {
     /// Get alias outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "stateController", "governor", "issuer", "sender", "createdBefore", "createdAfter"
+    /// Accepts a typed [`AliasOutputQuery`](super::query_parameters::AliasOutputQuery) or a list of query parameters,
+    /// see [`IntoAliasOutputQuery`].
     /// Returns Err(Node(NotFound) if no results are found.
     /// api/indexer/v1/outputs/alias
     pub async fn alias_output_ids(
         &self,
-        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+        query_parameters: impl IntoAliasOutputQuery + Send,
     ) -> Result<OutputIdsResponse> {
         let route = "api/indexer/v1/outputs/alias";
 
-        let query_parameters = verify_query_parameters_alias_outputs(query_parameters.into())?;
+        let query_parameters = query_parameters.into_query_parameters()?;
 
         self.get_output_ids(route, query_parameters, true, false).await
     }
@@ -90,15 +93,17 @@ impl ClientInner {
     /// Get foundry outputs filtered by the given parameters.
     /// GET with query parameter returns all outputIDs that fit these filter criteria.
     /// Query parameters: "address", "createdBefore", "createdAfter"
+    /// Accepts a typed [`FoundryOutputQuery`](super::query_parameters::FoundryOutputQuery) or a list of query
+    /// parameters, see [`IntoFoundryOutputQuery`].
     /// Returns Err(Node(NotFound) if no results are found.
     /// api/indexer/v1/outputs/foundry
     pub async fn foundry_output_ids(
         &self,
-        query_parameters: impl Into<Vec<QueryParameter>> + Send,
+        query_parameters: impl IntoFoundryOutputQuery + Send,
     ) -> Result<OutputIdsResponse> {
         let route = "api/indexer/v1/outputs/foundry";
 
-        let query_parameters = verify_query_parameters_foundry_outputs(query_parameters.into())?;
+        let query_parameters = query_parameters.into_query_parameters()?;
 
         self.get_output_ids(route, query_parameters, true, false).await
     }
@@ -119,15 +124,14 @@ impl ClientInner {
     /// Query parameters: "address", "hasStorageDepositReturn", "storageDepositReturnAddress",
     /// "hasExpiration", "expiresBefore", "expiresAfter", "hasTimelock", "timelockedBefore",
     /// "timelockedAfter", "issuer", "sender", "tag", "createdBefore", "createdAfter"
+    /// Accepts a typed [`NftOutputQuery`](super::query_parameters::NftOutputQuery) or a list of query parameters, see
+    /// [`IntoNftOutputQuery`].
     /// Returns Err(Node(NotFound) if no results are found.
     /// api/indexer/v1/outputs/nft
-    pub async fn nft_output_ids(
-        &self,
-        query_parameters: impl Into<Vec<QueryParameter>> + Send,
-    ) -> Result<OutputIdsResponse> {
+    pub async fn nft_output_ids(&self, query_parameters: impl IntoNftOutputQuery + Send) -> Result<OutputIdsResponse> {
         let route = "api/indexer/v1/outputs/nft";
 
-        let query_parameters = verify_query_parameters_nft_outputs(query_parameters.into())?;
+        let query_parameters = query_parameters.into_query_parameters()?;
 
         self.get_output_ids(route, query_parameters, true, false).await
     }
@@ -143,4 +147,79 @@ impl ClientInner {
             .first()
             .ok_or_else(|| Error::NoOutput(format!("{nft_id:?}")))?))
     }
+
+    /// Get basic, alias, nft and foundry output ids filtered by the given parameters page by page, see
+    /// [`Self::output_ids`] for the supported query parameters. The query continues from `resume_from` if provided.
+    /// api/indexer/v1/outputs
+    pub fn output_ids_stream(
+        &self,
+        query_parameters: impl IntoOutputQuery,
+        resume_from: impl Into<Option<IndexerCursor>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + '_> {
+        let route = "api/indexer/v1/outputs";
+
+        let query_parameters = query_parameters.into_query_parameters()?;
+
+        Ok(self.get_output_ids_stream(route, query_parameters, resume_from.into(), true, false))
+    }
+
+    /// Get basic output ids filtered by the given parameters page by page, see [`Self::basic_output_ids`] for the
+    /// supported query parameters. The query continues from `resume_from` if provided.
+    /// api/indexer/v1/outputs/basic
+    pub fn basic_output_ids_stream(
+        &self,
+        query_parameters: impl IntoBasicOutputQuery,
+        resume_from: impl Into<Option<IndexerCursor>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + '_> {
+        let route = "api/indexer/v1/outputs/basic";
+
+        let query_parameters = query_parameters.into_query_parameters()?;
+
+        Ok(self.get_output_ids_stream(route, query_parameters, resume_from.into(), true, false))
+    }
+
+    /// Get alias output ids filtered by the given parameters page by page, see [`Self::alias_output_ids`] for the
+    /// supported query parameters. The query continues from `resume_from` if provided.
+    /// api/indexer/v1/outputs/alias
+    pub fn alias_output_ids_stream(
+        &self,
+        query_parameters: impl IntoAliasOutputQuery,
+        resume_from: impl Into<Option<IndexerCursor>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + '_> {
+        let route = "api/indexer/v1/outputs/alias";
+
+        let query_parameters = query_parameters.into_query_parameters()?;
+
+        Ok(self.get_output_ids_stream(route, query_parameters, resume_from.into(), true, false))
+    }
+
+    /// Get foundry output ids filtered by the given parameters page by page, see [`Self::foundry_output_ids`] for the
+    /// supported query parameters. The query continues from `resume_from` if provided.
+    /// api/indexer/v1/outputs/foundry
+    pub fn foundry_output_ids_stream(
+        &self,
+        query_parameters: impl IntoFoundryOutputQuery,
+        resume_from: impl Into<Option<IndexerCursor>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + '_> {
+        let route = "api/indexer/v1/outputs/foundry";
+
+        let query_parameters = query_parameters.into_query_parameters()?;
+
+        Ok(self.get_output_ids_stream(route, query_parameters, resume_from.into(), true, false))
+    }
+
+    /// Get NFT output ids filtered by the given parameters page by page, see [`Self::nft_output_ids`] for the supported
+    /// query parameters. The query continues from `resume_from` if provided.
+    /// api/indexer/v1/outputs/nft
+    pub fn nft_output_ids_stream(
+        &self,
+        query_parameters: impl IntoNftOutputQuery,
+        resume_from: impl Into<Option<IndexerCursor>>,
+    ) -> Result<impl Stream<Item = Result<OutputIdsResponse>> + '_> {
+        let route = "api/indexer/v1/outputs/nft";
+
+        let query_parameters = query_parameters.into_query_parameters()?;
+
+        Ok(self.get_output_ids_stream(route, query_parameters, resume_from.into(), true, false))
+    }
 }
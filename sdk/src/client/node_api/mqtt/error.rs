@@ -16,4 +16,13 @@ pub enum Error {
     /// Invalid topic.
     #[error("invalid topic {0}")]
     InvalidTopic(String),
+    /// Block error while decoding a payload.
+    #[error("{0}")]
+    Block(#[from] crate::types::block::Error),
+    /// JSON error while decoding a payload.
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+    /// A payload that doesn't match its topic.
+    #[error("unexpected payload for topic {0}")]
+    UnexpectedPayload(String),
 }
@@ -4,9 +4,11 @@
 //! IOTA node MQTT API
 
 mod error;
+pub mod stream;
+pub mod typed;
 pub mod types;
 
-use std::{sync::Arc, time::Instant};
+use std::{collections::HashMap, sync::Arc, time::Instant};
 
 use crypto::utils;
 use log::warn;
@@ -14,7 +16,7 @@ use packable::PackableExt;
 use rumqttc::{AsyncClient, Event, EventLoop, Incoming, MqttOptions, NetworkOptions, QoS, SubscribeFilter, Transport};
 use tokio::sync::watch::Receiver as WatchReceiver;
 
-pub use self::{error::Error, types::*};
+pub use self::{error::Error, stream::*, typed::*, types::*};
 use crate::{
     client::{Client, ClientInner},
     types::block::{
@@ -38,10 +40,103 @@ impl Client {
         MqttManager::new(self).with_topics(topics).subscribe(callback).await
     }
 
+    /// Subscribe to MQTT events as a stream, which unsubscribes when dropped.
+    pub async fn subscribe_stream(
+        &self,
+        topics: impl IntoIterator<Item = Topic> + Send,
+        options: StreamOptions,
+    ) -> Result<TopicStream, Error> {
+        MqttManager::new(self)
+            .with_topics(topics)
+            .subscribe_stream(options)
+            .await
+    }
+
     /// Unsubscribe from MQTT events.
     pub async fn unsubscribe(&self, topics: impl IntoIterator<Item = Topic> + Send) -> Result<(), Error> {
         MqttManager::new(self).with_topics(topics).unsubscribe().await
     }
+
+    /// Subscribe to typed MQTT topics with a callback that receives the decoded payloads.
+    /// Events that can't be decoded are logged and skipped.
+    pub async fn subscribe_typed<C: Fn(&TypedTopicEvent) + Send + Sync + 'static>(
+        &self,
+        topics: impl IntoIterator<Item = TypedTopic> + Send,
+        callback: C,
+    ) -> Result<(), Error> {
+        self.subscribe_typed_handler(topics, callback).await.map(|_| ())
+    }
+
+    /// Subscribe to typed MQTT topics like [`Client::subscribe_typed()`], returning the handler so it can be removed
+    /// again without affecting other subscriptions to the same topics.
+    pub(crate) async fn subscribe_typed_handler<C: Fn(&TypedTopicEvent) + Send + Sync + 'static>(
+        &self,
+        topics: impl IntoIterator<Item = TypedTopic> + Send,
+        callback: C,
+    ) -> Result<Arc<TopicHandler>, Error> {
+        let typed_topics = topics
+            .into_iter()
+            .map(|topic| Ok((topic.to_topic()?, topic)))
+            .collect::<Result<HashMap<_, _>, Error>>()?;
+        let topics = typed_topics.keys().cloned().collect::<Vec<_>>();
+
+        // The events are decoded in order by a task that ends once the handler is dropped. It reads the protocol
+        // parameters for each event, as they can change while subscribed.
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel::<TopicEvent>();
+        let client = Arc::downgrade(&self.inner);
+        crate::client::async_runtime::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                let Some(client) = client.upgrade() else {
+                    break;
+                };
+                let Some(topic) = typed_topics.get(&Topic::new_unchecked(event.topic.as_str())) else {
+                    continue;
+                };
+                let protocol_parameters = client.network_info.read().await.protocol_parameters.clone();
+                match TypedMqttPayload::decode(topic, &event.payload, &protocol_parameters) {
+                    Ok(payload) => callback(&TypedTopicEvent {
+                        topic: topic.clone(),
+                        payload,
+                    }),
+                    Err(e) => warn!("Decoding payload of {} failed: {:?}", event.topic, e),
+                }
+            }
+        });
+
+        let handler: Arc<TopicHandler> = Arc::new(Box::new(move |event: &TopicEvent| {
+            // The receiver is only dropped once the client is dropped
+            sender.send(event.clone()).ok();
+        }));
+        MqttManager::new(self)
+            .with_topics(topics)
+            .subscribe_handler(handler.clone())
+            .await?;
+
+        Ok(handler)
+    }
+
+    /// Unsubscribe from typed MQTT topics.
+    pub async fn unsubscribe_typed(&self, topics: impl IntoIterator<Item = TypedTopic> + Send) -> Result<(), Error> {
+        let topics = topics
+            .into_iter()
+            .map(|topic| topic.to_topic())
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.unsubscribe(topics).await
+    }
+
+    /// Removes a handler returned by [`Client::subscribe_typed_handler()`] from typed MQTT topics and unsubscribes from
+    /// the topics without handlers left.
+    pub(crate) async fn unsubscribe_typed_handler(
+        &self,
+        topics: impl IntoIterator<Item = TypedTopic> + Send,
+        handler: &Arc<TopicHandler>,
+    ) -> Result<(), Error> {
+        let topics = topics
+            .into_iter()
+            .map(|topic| topic.to_topic())
+            .collect::<Result<Vec<_>, Error>>()?;
+        MqttManager::new(self).with_topics(topics).remove_handler(handler).await
+    }
 }
 
 impl ClientInner {
@@ -143,8 +238,8 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
             // but the subscriptions are lost on reconnection, so we need to resubscribe
             // the `is_subscribed` flag is set to false on event error, so the ConnAck event
             // can perform the re-subscriptions and reset `is_subscribed` to true.
-            // we need the flag since the first ConnAck must be ignored.
-            let mut is_subscribed = true;
+            // the first ConnAck is ignored, unless there are subscriptions left from a connection that was given up.
+            let mut is_subscribed = client.mqtt.topic_handlers.read().await.is_empty();
             let mut error_instant = Instant::now();
             let mut connection_failure_count = 0;
 
@@ -267,6 +362,9 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
                         if connection_failure_count == client.mqtt.broker_options.read().await.max_reconnection_attempts
                         {
                             let _ = client.mqtt.sender.read().await.send(MqttEvent::Disconnected);
+                            // The streams would never receive events again, dropping their handlers ends them. The
+                            // callback subscriptions are kept and resubscribed once a new connection is established.
+                            remove_stream_handlers(&client).await;
                             break;
                         }
                         error_instant = Instant::now();
@@ -279,6 +377,21 @@ fn poll_mqtt(client: &Client, mut event_loop: EventLoop) {
     });
 }
 
+/// Removes the handlers of all streams, which ends them.
+async fn remove_stream_handlers(client: &Client) {
+    let stream_handlers = core::mem::take(&mut *client.mqtt.stream_handlers.write().await);
+    let mut mqtt_topic_handlers = client.mqtt.topic_handlers.write().await;
+
+    mqtt_topic_handlers.retain(|_, handlers| {
+        handlers.retain(|handler| {
+            !stream_handlers
+                .iter()
+                .any(|stream_handler| stream_handler.as_ptr() == Arc::as_ptr(handler))
+        });
+        !handlers.is_empty()
+    });
+}
+
 /// MQTT subscriber.
 pub struct MqttManager<'a> {
     client: &'a Client,
@@ -352,11 +465,26 @@ impl<'a> MqttTopicManager<'a> {
         self,
         callback: C,
     ) -> Result<(), Error> {
-        let cb =
-            Arc::new(Box::new(callback)
-                as Box<
-                    dyn Fn(&crate::client::node_api::mqtt::TopicEvent) + Send + Sync + 'static,
-                >);
+        self.subscribe_handler(Arc::new(Box::new(callback))).await
+    }
+
+    /// Subscribe to the given topics with a stream that buffers the events until they are consumed.
+    /// The stream unsubscribes from the topics when it's dropped and ends if the broker is disconnected.
+    pub async fn subscribe_stream(self, options: StreamOptions) -> Result<TopicStream, Error> {
+        let (mut stream, handler) = TopicStream::new(self.client, self.topics.clone(), options);
+        let handler = Arc::new(handler);
+        stream.set_handler(&handler);
+        {
+            let mut stream_handlers = self.client.mqtt.stream_handlers.write().await;
+            // Handlers removed by unsubscribing or disconnecting are gone already
+            stream_handlers.retain(|stream_handler| stream_handler.strong_count() > 0);
+            stream_handlers.push(Arc::downgrade(&handler));
+        }
+        self.subscribe_handler(handler).await?;
+        Ok(stream)
+    }
+
+    async fn subscribe_handler(self, cb: Arc<TopicHandler>) -> Result<(), Error> {
         set_mqtt_client(self.client).await?;
         self.client
             .inner
@@ -413,4 +541,44 @@ impl<'a> MqttTopicManager<'a> {
 
         Ok(())
     }
+
+    /// Removes a single handler from the given topics and unsubscribes from the topics without handlers left.
+    pub(crate) async fn remove_handler(self, handler: &Arc<TopicHandler>) -> Result<(), Error> {
+        self.client
+            .mqtt
+            .stream_handlers
+            .write()
+            .await
+            .retain(|stream_handler| stream_handler.as_ptr() != Arc::as_ptr(handler));
+        let (emptied_topics, empty_topic_handlers) = {
+            let mut mqtt_topic_handlers = self.client.mqtt.topic_handlers.write().await;
+            let mut emptied_topics = Vec::new();
+            for topic in self.topics {
+                if let Some(handlers) = mqtt_topic_handlers.get_mut(&topic) {
+                    handlers.retain(|h| !Arc::ptr_eq(h, handler));
+                    if handlers.is_empty() {
+                        mqtt_topic_handlers.remove(&topic);
+                        emptied_topics.push(topic);
+                    }
+                }
+            }
+            (emptied_topics, mqtt_topic_handlers.is_empty())
+        };
+
+        if emptied_topics.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(client) = &*self.client.mqtt.client.write().await {
+            for topic in &emptied_topics {
+                client.unsubscribe(topic.as_str()).await?;
+            }
+        }
+
+        if self.client.mqtt.broker_options.read().await.automatic_disconnect && empty_topic_handlers {
+            MqttManager::new(self.client).disconnect().await?;
+        }
+
+        Ok(())
+    }
 }
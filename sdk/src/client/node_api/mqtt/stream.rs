@@ -0,0 +1,253 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! MQTT subscriptions as streams
+
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{Arc, Mutex, Weak},
+    task::{Context, Poll, Waker},
+};
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+
+use super::{MqttTopicManager, Topic, TopicEvent, TopicHandler};
+use crate::client::Client;
+
+/// What happens to new events when the buffer of a [`TopicStream`] is full.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OverflowPolicy {
+    /// Drop the new event.
+    #[default]
+    DropNewest,
+    /// Drop the oldest buffered event to make room for the new one.
+    DropOldest,
+    /// End the stream after the buffered events.
+    Close,
+}
+
+/// Options of a [`TopicStream`].
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[must_use]
+pub struct StreamOptions {
+    #[serde(default = "default_buffer_size")]
+    pub(crate) buffer_size: usize,
+    #[serde(default)]
+    pub(crate) overflow_policy: OverflowPolicy,
+}
+
+fn default_buffer_size() -> usize {
+    100
+}
+
+impl Default for StreamOptions {
+    fn default() -> Self {
+        Self {
+            buffer_size: default_buffer_size(),
+            overflow_policy: OverflowPolicy::default(),
+        }
+    }
+}
+
+impl StreamOptions {
+    /// Creates the default stream options.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the maximum number of buffered events that weren't consumed yet. Values below 1 are treated as 1.
+    pub fn buffer_size(mut self, buffer_size: usize) -> Self {
+        self.buffer_size = buffer_size;
+        self
+    }
+
+    /// Sets what happens to new events when the buffer is full.
+    pub fn overflow_policy(mut self, overflow_policy: OverflowPolicy) -> Self {
+        self.overflow_policy = overflow_policy;
+        self
+    }
+}
+
+#[derive(Default)]
+struct Buffer {
+    events: VecDeque<TopicEvent>,
+    waker: Option<Waker>,
+    closed: bool,
+    dropped_events: usize,
+}
+
+impl Buffer {
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+/// The sending half of a [`TopicStream`], owned by its topic handler.
+struct StreamSender {
+    buffer: Arc<Mutex<Buffer>>,
+    options: StreamOptions,
+}
+
+impl StreamSender {
+    fn send(&self, event: &TopicEvent) {
+        let mut buffer = self.buffer.lock().expect("stream buffer lock poisoned");
+        if buffer.closed {
+            return;
+        }
+        if buffer.events.len() >= self.options.buffer_size.max(1) {
+            buffer.dropped_events += 1;
+            match self.options.overflow_policy {
+                OverflowPolicy::DropNewest => return,
+                OverflowPolicy::DropOldest => {
+                    buffer.events.pop_front();
+                }
+                OverflowPolicy::Close => {
+                    buffer.closed = true;
+                    buffer.wake();
+                    return;
+                }
+            }
+        }
+        buffer.events.push_back(event.clone());
+        buffer.wake();
+    }
+}
+
+impl Drop for StreamSender {
+    // The handler is dropped when the broker is disconnected or the connection is given up after the maximum number of
+    // reconnection attempts, which ends the stream.
+    fn drop(&mut self) {
+        if let Ok(mut buffer) = self.buffer.lock() {
+            buffer.closed = true;
+            buffer.wake();
+        }
+    }
+}
+
+/// A stream of the events of a MQTT subscription. Unsubscribes from its topics when dropped, without affecting other
+/// subscriptions to the same topics.
+#[must_use = "streams do nothing unless polled and unsubscribe when dropped"]
+pub struct TopicStream {
+    client: Client,
+    topics: Vec<Topic>,
+    handler: Weak<TopicHandler>,
+    buffer: Arc<Mutex<Buffer>>,
+}
+
+impl TopicStream {
+    pub(crate) fn new(client: &Client, topics: Vec<Topic>, options: StreamOptions) -> (Self, TopicHandler) {
+        let buffer = Arc::new(Mutex::new(Buffer::default()));
+        let sender = StreamSender {
+            buffer: buffer.clone(),
+            options,
+        };
+        let handler: TopicHandler = Box::new(move |event| sender.send(event));
+
+        (
+            Self {
+                client: client.clone(),
+                topics,
+                handler: Weak::new(),
+                buffer,
+            },
+            handler,
+        )
+    }
+
+    pub(crate) fn set_handler(&mut self, handler: &Arc<TopicHandler>) {
+        self.handler = Arc::downgrade(handler);
+    }
+
+    /// Returns the subscribed topics.
+    pub fn topics(&self) -> &[Topic] {
+        &self.topics
+    }
+
+    /// Returns the number of events that were dropped because the buffer was full.
+    pub fn dropped_events(&self) -> usize {
+        self.buffer.lock().expect("stream buffer lock poisoned").dropped_events
+    }
+}
+
+impl Stream for TopicStream {
+    type Item = TopicEvent;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut buffer = self.buffer.lock().expect("stream buffer lock poisoned");
+        if let Some(event) = buffer.events.pop_front() {
+            return Poll::Ready(Some(event));
+        }
+        if buffer.closed {
+            return Poll::Ready(None);
+        }
+        buffer.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl Drop for TopicStream {
+    fn drop(&mut self) {
+        if let Some(handler) = self.handler.upgrade() {
+            let client = self.client.clone();
+            let topics = core::mem::take(&mut self.topics);
+            crate::client::async_runtime::spawn(async move {
+                if let Err(e) = MqttTopicManager::new(&client)
+                    .with_topics(topics)
+                    .remove_handler(&handler)
+                    .await
+                {
+                    log::warn!("Unsubscribing dropped stream failed: {:?}", e);
+                }
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mqtt::MqttPayload;
+
+    fn event(index: u64) -> TopicEvent {
+        TopicEvent {
+            topic: "milestone-info/latest".to_string(),
+            payload: MqttPayload::Json(serde_json::json!({ "index": index })),
+        }
+    }
+
+    fn send_events(overflow_policy: OverflowPolicy) -> (Vec<TopicEvent>, usize, bool) {
+        let buffer = Arc::new(Mutex::new(Buffer::default()));
+        let sender = StreamSender {
+            buffer: buffer.clone(),
+            options: StreamOptions::new().buffer_size(2).overflow_policy(overflow_policy),
+        };
+        for index in 0..4 {
+            sender.send(&event(index));
+        }
+        let buffer = buffer.lock().unwrap();
+        (
+            buffer.events.iter().cloned().collect(),
+            buffer.dropped_events,
+            buffer.closed,
+        )
+    }
+
+    #[test]
+    fn overflow_policies() {
+        assert_eq!(
+            send_events(OverflowPolicy::DropNewest),
+            (vec![event(0), event(1)], 2, false)
+        );
+        assert_eq!(
+            send_events(OverflowPolicy::DropOldest),
+            (vec![event(2), event(3)], 2, false)
+        );
+        assert_eq!(send_events(OverflowPolicy::Close), (vec![event(0), event(1)], 1, true));
+    }
+}
@@ -0,0 +1,223 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed MQTT topics and payloads
+
+use core::fmt;
+
+use super::{Error, MqttPayload, Topic};
+use crate::types::{
+    api::core::response::{BlockMetadataResponse, LatestMilestoneResponse, OutputWithMetadataResponse},
+    block::{
+        address::Bech32Address,
+        output::{AliasId, FoundryId, NftId, OutputId},
+        payload::{milestone::ReceiptMilestoneOption, transaction::TransactionId, MilestonePayload},
+        protocol::ProtocolParameters,
+        Block, BlockId,
+    },
+    TryFromDto,
+};
+
+/// An unlock condition that outputs can be subscribed by.
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum UnlockConditionTopic {
+    /// Address unlock condition.
+    Address,
+    /// Storage deposit return unlock condition.
+    StorageReturn,
+    /// Expiration unlock condition.
+    Expiration,
+    /// State controller address unlock condition.
+    StateController,
+    /// Governor address unlock condition.
+    Governor,
+    /// Immutable alias address unlock condition.
+    ImmutableAlias,
+}
+
+impl UnlockConditionTopic {
+    /// Returns the topic segment of the unlock condition.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Address => "address",
+            Self::StorageReturn => "storage-return",
+            Self::Expiration => "expiration",
+            Self::StateController => "state-controller",
+            Self::Governor => "governor",
+            Self::ImmutableAlias => "immutable-alias",
+        }
+    }
+}
+
+/// A typed MQTT topic, rendering to its string form with [`fmt::Display`].
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+pub enum TypedTopic {
+    /// `milestone-info/latest`, delivers [`TypedMqttPayload::MilestoneInfo`].
+    LatestMilestone,
+    /// `milestone-info/confirmed`, delivers [`TypedMqttPayload::MilestoneInfo`].
+    ConfirmedMilestone,
+    /// `milestones`, delivers [`TypedMqttPayload::Milestone`].
+    Milestones,
+    /// `blocks`, delivers [`TypedMqttPayload::Block`].
+    Blocks,
+    /// `blocks/transaction`, delivers [`TypedMqttPayload::Block`].
+    TransactionBlocks,
+    /// `blocks/tagged-data[/{tag}]`, delivers [`TypedMqttPayload::Block`].
+    TaggedDataBlocks {
+        /// Only blocks with this tag, all tagged data blocks if `None`.
+        tag: Option<Vec<u8>>,
+    },
+    /// `blocks/transaction/tagged-data[/{tag}]`, delivers [`TypedMqttPayload::Block`].
+    TransactionTaggedDataBlocks {
+        /// Only blocks with this tag, all transaction blocks with tagged data if `None`.
+        tag: Option<Vec<u8>>,
+    },
+    /// `block-metadata/{block_id}`, delivers [`TypedMqttPayload::BlockMetadata`].
+    BlockMetadata(BlockId),
+    /// `block-metadata/referenced`, delivers [`TypedMqttPayload::BlockMetadata`].
+    ReferencedBlockMetadata,
+    /// `transactions/{transaction_id}/included-block`, delivers [`TypedMqttPayload::Block`].
+    TransactionIncludedBlock(TransactionId),
+    /// `outputs/{output_id}`, delivers [`TypedMqttPayload::Output`].
+    Output(OutputId),
+    /// `outputs/alias/{alias_id}`, delivers [`TypedMqttPayload::Output`].
+    AliasOutput(AliasId),
+    /// `outputs/nft/{nft_id}`, delivers [`TypedMqttPayload::Output`].
+    NftOutput(NftId),
+    /// `outputs/foundry/{foundry_id}`, delivers [`TypedMqttPayload::Output`].
+    FoundryOutput(FoundryId),
+    /// `outputs/unlock/{unlock_condition}/{address}[/spent]`, delivers [`TypedMqttPayload::Output`].
+    OutputsByUnlockCondition {
+        /// The unlock condition the address is in.
+        unlock_condition: UnlockConditionTopic,
+        /// The address.
+        address: Bech32Address,
+        /// Only outputs that got spent, otherwise only newly created ones.
+        spent: bool,
+    },
+    /// `receipts`, delivers [`TypedMqttPayload::Receipt`].
+    Receipts,
+}
+
+impl fmt::Display for TypedTopic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LatestMilestone => write!(f, "milestone-info/latest"),
+            Self::ConfirmedMilestone => write!(f, "milestone-info/confirmed"),
+            Self::Milestones => write!(f, "milestones"),
+            Self::Blocks => write!(f, "blocks"),
+            Self::TransactionBlocks => write!(f, "blocks/transaction"),
+            Self::TaggedDataBlocks { tag: None } => write!(f, "blocks/tagged-data"),
+            Self::TaggedDataBlocks { tag: Some(tag) } => write!(f, "blocks/tagged-data/{}", prefix_hex::encode(tag)),
+            Self::TransactionTaggedDataBlocks { tag: None } => write!(f, "blocks/transaction/tagged-data"),
+            Self::TransactionTaggedDataBlocks { tag: Some(tag) } => {
+                write!(f, "blocks/transaction/tagged-data/{}", prefix_hex::encode(tag))
+            }
+            Self::BlockMetadata(block_id) => write!(f, "block-metadata/{block_id}"),
+            Self::ReferencedBlockMetadata => write!(f, "block-metadata/referenced"),
+            Self::TransactionIncludedBlock(transaction_id) => write!(f, "transactions/{transaction_id}/included-block"),
+            Self::Output(output_id) => write!(f, "outputs/{output_id}"),
+            Self::AliasOutput(alias_id) => write!(f, "outputs/alias/{alias_id}"),
+            Self::NftOutput(nft_id) => write!(f, "outputs/nft/{nft_id}"),
+            Self::FoundryOutput(foundry_id) => write!(f, "outputs/foundry/{foundry_id}"),
+            Self::OutputsByUnlockCondition {
+                unlock_condition,
+                address,
+                spent,
+            } => {
+                write!(f, "outputs/unlock/{}/{address}", unlock_condition.as_str())?;
+                if *spent {
+                    write!(f, "/spent")?;
+                }
+                Ok(())
+            }
+            Self::Receipts => write!(f, "receipts"),
+        }
+    }
+}
+
+impl TypedTopic {
+    /// Returns the validated string form of the topic. Fails for tags that are empty or longer than 64 bytes.
+    pub fn to_topic(&self) -> Result<Topic, Error> {
+        Topic::new(self.to_string())
+    }
+}
+
+impl TryFrom<TypedTopic> for Topic {
+    type Error = Error;
+
+    fn try_from(topic: TypedTopic) -> Result<Self, Self::Error> {
+        topic.to_topic()
+    }
+}
+
+/// A decoded MQTT payload.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum TypedMqttPayload {
+    /// The index, timestamp and id of a milestone.
+    MilestoneInfo(LatestMilestoneResponse),
+    /// A milestone payload.
+    Milestone(Box<MilestonePayload>),
+    /// A block.
+    Block(Box<Block>),
+    /// The metadata of a block.
+    BlockMetadata(BlockMetadataResponse),
+    /// An output and its metadata.
+    Output(Box<OutputWithMetadataResponse>),
+    /// A receipt.
+    Receipt(Box<ReceiptMilestoneOption>),
+}
+
+impl TypedMqttPayload {
+    /// Decodes the payload of an event received for `topic`.
+    pub fn decode(
+        topic: &TypedTopic,
+        payload: &MqttPayload,
+        protocol_parameters: &ProtocolParameters,
+    ) -> Result<Self, Error> {
+        Ok(match (topic, payload) {
+            (TypedTopic::LatestMilestone | TypedTopic::ConfirmedMilestone, MqttPayload::Json(value)) => {
+                Self::MilestoneInfo(serde_json::from_value(value.clone())?)
+            }
+            (TypedTopic::Milestones, MqttPayload::MilestonePayload(milestone)) => Self::Milestone(Box::new(
+                MilestonePayload::try_from_dto_with_params(milestone.clone(), protocol_parameters)?,
+            )),
+            (
+                TypedTopic::Blocks
+                | TypedTopic::TransactionBlocks
+                | TypedTopic::TaggedDataBlocks { .. }
+                | TypedTopic::TransactionTaggedDataBlocks { .. }
+                | TypedTopic::TransactionIncludedBlock(_),
+                MqttPayload::Block(block),
+            ) => Self::Block(Box::new(Block::try_from_dto_with_params(
+                block.clone(),
+                protocol_parameters,
+            )?)),
+            (TypedTopic::BlockMetadata(_) | TypedTopic::ReferencedBlockMetadata, MqttPayload::Json(value)) => {
+                Self::BlockMetadata(serde_json::from_value(value.clone())?)
+            }
+            (
+                TypedTopic::Output(_)
+                | TypedTopic::AliasOutput(_)
+                | TypedTopic::NftOutput(_)
+                | TypedTopic::FoundryOutput(_)
+                | TypedTopic::OutputsByUnlockCondition { .. },
+                MqttPayload::Json(value),
+            ) => Self::Output(Box::new(serde_json::from_value(value.clone())?)),
+            (TypedTopic::Receipts, MqttPayload::Receipt(receipt)) => Self::Receipt(Box::new(
+                ReceiptMilestoneOption::try_from_dto_with_params(receipt.clone(), protocol_parameters)?,
+            )),
+            _ => return Err(Error::UnexpectedPayload(topic.to_string())),
+        })
+    }
+}
+
+/// A decoded event from a typed MQTT topic.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TypedTopicEvent {
+    /// The MQTT topic.
+    pub topic: TypedTopic,
+    /// The decoded MQTT event payload.
+    pub payload: TypedMqttPayload,
+}
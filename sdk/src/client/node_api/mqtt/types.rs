@@ -15,7 +15,7 @@ use crate::types::block::{
     BlockDto,
 };
 
-type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
+pub(crate) type TopicHandler = Box<dyn Fn(&TopicEvent) + Send + Sync>;
 
 pub(crate) type TopicHandlerMap = HashMap<Topic, Vec<Arc<TopicHandler>>>;
 
@@ -57,6 +57,7 @@ pub enum MqttEvent {
 /// The MQTT broker options.
 
 #[derive(Copy, Debug, Clone, serde::Deserialize, serde::Serialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[must_use]
 pub struct BrokerOptions {
@@ -143,6 +144,7 @@ impl BrokerOptions {
 
 /// A MQTT topic.
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct Topic(String);
 
 impl<'de> Deserialize<'de> for Topic {
@@ -191,7 +193,7 @@ impl Topic {
                 // Transaction topics.
                 r"^transactions/0x([a-f0-9]{64})/included-block$",
                 // Output topics.
-                r"^outputs/0x([a-f0-9]{64})(\d{4})$",
+                r"^outputs/0x([a-f0-9]{64})([a-f0-9]{4})$",
                 r"^outputs/alias/0x([a-f0-9]{64})$",
                 r"^outputs/nft/0x([a-f0-9]{64})$",
                 r"^outputs/foundry/0x([a-f0-9]{76})$",
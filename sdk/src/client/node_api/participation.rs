@@ -1,7 +1,8 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-//! IOTA node public participation routes.
+//! IOTA node public and admin participation routes.
+//!
 //! <https://github.com/iota-community/treasury/blob/main/specifications/hornet-participation-plugin.md#public-node-endpoints>
 //! <https://github.com/iotaledger/inx-participation/blob/develop/components/participation/routes.go>
 
@@ -9,7 +10,9 @@ use crate::{
     client::{ClientInner, Result},
     types::{
         api::plugins::participation::{
-            responses::{AddressOutputsResponse, EventsResponse, OutputStatusResponse},
+            responses::{
+                AddressOutputsResponse, CreateEventResponse, EventsResponse, OutputStatusResponse, RewardsResponse,
+            },
             types::{
                 AddressStakingStatus, ParticipationEventData, ParticipationEventId, ParticipationEventStatus,
                 ParticipationEventType,
@@ -84,4 +87,43 @@ impl ClientInner {
 
         self.get_request(&route, None, false, false).await
     }
+
+    /// RouteAdminCreateEvent is the route to register a participation event on the node. The event data is validated
+    /// before it is sent. Requires the node to accept the JWT of the client for admin routes.
+    pub async fn create_event(&self, event_data: &ParticipationEventData) -> Result<ParticipationEventId> {
+        let route = "api/participation/v1/admin/events";
+
+        event_data.validate()?;
+
+        Ok(self
+            .post_request_json::<CreateEventResponse>(route, serde_json::to_value(event_data)?, false)
+            .await?
+            .event_id)
+    }
+
+    /// RouteAdminDeleteEvent is the route to remove a participation event from the node.
+    pub async fn delete_event(&self, event_id: &ParticipationEventId) -> Result<()> {
+        let route = format!("api/participation/v1/admin/events/{event_id}");
+
+        self.delete_request(&route).await
+    }
+
+    /// RouteAdminRewards is the route to get the rewards of all addresses for a staking event.
+    pub async fn event_rewards(
+        &self,
+        event_id: &ParticipationEventId,
+        milestone_index: Option<u32>,
+    ) -> Result<RewardsResponse> {
+        let route = format!("api/participation/v1/admin/events/{event_id}/rewards");
+
+        self.get_request(
+            &route,
+            milestone_index
+                .map(|index| format!("milestoneIndex={index}"))
+                .as_deref(),
+            false,
+            false,
+        )
+        .await
+    }
 }
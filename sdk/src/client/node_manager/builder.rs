@@ -27,6 +27,7 @@ use crate::{
 
 /// Node manager builder
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NodeManagerBuilder {
     /// Node which will be tried first for all requests
@@ -224,6 +225,7 @@ impl NodeManagerBuilder {
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
             http_client: HttpClient::new(self.user_agent),
+            circuit_breakers: Default::default(),
         }
     }
 }
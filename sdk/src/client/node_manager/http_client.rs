@@ -117,6 +117,13 @@ impl HttpClient {
         Self::parse_response(request_builder.json(&json).send().await?, &node.url).await
     }
 
+    #[cfg(feature = "participation")]
+    pub(crate) async fn delete(&self, node: Node, timeout: Duration) -> Result<Response> {
+        let mut request_builder = self.client.delete(node.url.clone());
+        request_builder = self.build_request(request_builder, &node, timeout);
+        Self::parse_response(request_builder.send().await?, &node.url).await
+    }
+
     pub(crate) async fn post_bytes(&self, node: Node, timeout: Duration, body: &[u8]) -> Result<Response> {
         let mut request_builder = self.client.post(node.url.clone());
         request_builder = self.build_request(request_builder, &node, timeout);
@@ -7,6 +7,7 @@ pub mod builder;
 pub(crate) mod http_client;
 /// Structs for nodes
 pub mod node;
+pub mod request_policy;
 pub(crate) mod syncing;
 
 use std::{
@@ -16,16 +17,22 @@ use std::{
     time::Duration,
 };
 
+use futures::Future;
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 
-use self::{http_client::HttpClient, node::Node};
+use self::{
+    http_client::{HttpClient, Response},
+    node::Node,
+    request_policy::{node_origin, CircuitBreakers, RequestAttempt, RequestPolicy},
+};
 use super::ClientInner;
 #[cfg(not(target_family = "wasm"))]
 use crate::client::request_pool::RateLimitExt;
 use crate::{
     client::{
         error::{Error, Result},
+        node_api::error::Error as NodeApiError,
         node_manager::builder::NodeManagerBuilder,
     },
     types::api::core::response::InfoResponse,
@@ -45,6 +52,7 @@ pub struct NodeManager {
     min_quorum_size: usize,
     quorum_threshold: usize,
     pub(crate) http_client: HttpClient,
+    circuit_breakers: CircuitBreakers,
 }
 
 impl Debug for NodeManager {
@@ -59,7 +67,8 @@ impl Debug for NodeManager {
         d.field("healthy_nodes", &self.healthy_nodes);
         d.field("quorum", &self.quorum);
         d.field("min_quorum_size", &self.min_quorum_size);
-        d.field("quorum_threshold", &self.quorum_threshold).finish()
+        d.field("quorum_threshold", &self.quorum_threshold);
+        d.field("circuit_breakers", &self.circuit_breakers).finish()
     }
 }
 
@@ -71,16 +80,33 @@ impl ClientInner {
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<T> {
+        let cacheable = query.is_none() && self.response_cache.is_cacheable(path);
+        if cacheable {
+            if let Some(response) = self.response_cache.get(path) {
+                return Ok(serde_json::from_str(&response)?);
+            }
+        }
+
         let node_manager = self.node_manager.read().await;
-        let request = node_manager.get_request(path, query, self.get_timeout().await, need_quorum, prefer_permanode);
+        let policy = self.get_request_policy().await;
+        let timeout = self.get_timeout_for(&policy, path).await;
+        let request = node_manager.get_request(path, query, timeout, &policy, need_quorum, prefer_permanode);
         #[cfg(not(target_family = "wasm"))]
         let request = request.rate_limit(&self.request_pool);
-        request.await
+        let response: T = request.await?;
+
+        if cacheable {
+            self.response_cache.insert(path, serde_json::to_string(&response)?);
+        }
+
+        Ok(response)
     }
 
     pub(crate) async fn get_request_bytes(&self, path: &str, query: Option<&str>) -> Result<Vec<u8>> {
         let node_manager = self.node_manager.read().await;
-        let request = node_manager.get_request_bytes(path, query, self.get_timeout().await);
+        let policy = self.get_request_policy().await;
+        let timeout = self.get_timeout_for(&policy, path).await;
+        let request = node_manager.get_request_bytes(path, query, timeout, &policy);
         #[cfg(not(target_family = "wasm"))]
         let request = request.rate_limit(&self.request_pool);
         request.await
@@ -93,7 +119,20 @@ impl ClientInner {
         local_pow: bool,
     ) -> Result<T> {
         let node_manager = self.node_manager.read().await;
-        let request = node_manager.post_request_json(path, self.get_timeout().await, json, local_pow);
+        let policy = self.get_request_policy().await;
+        let timeout = self.get_timeout_for(&policy, path).await;
+        let request = node_manager.post_request_json(path, timeout, &policy, json, local_pow);
+        #[cfg(not(target_family = "wasm"))]
+        let request = request.rate_limit(&self.request_pool);
+        request.await
+    }
+
+    #[cfg(feature = "participation")]
+    pub(crate) async fn delete_request(&self, path: &str) -> Result<()> {
+        let node_manager = self.node_manager.read().await;
+        let policy = self.get_request_policy().await;
+        let timeout = self.get_timeout_for(&policy, path).await;
+        let request = node_manager.delete_request(path, timeout, &policy);
         #[cfg(not(target_family = "wasm"))]
         let request = request.rate_limit(&self.request_pool);
         request.await
@@ -105,6 +144,51 @@ impl NodeManager {
         NodeManagerBuilder::new()
     }
 
+    /// Sends a request to a single node and retries it as long as the request policy allows. Every failed attempt is
+    /// appended to `attempts`.
+    async fn send_with_policy<F, Fut>(
+        &self,
+        node: &Node,
+        policy: &RequestPolicy,
+        attempts: &mut Vec<RequestAttempt>,
+        send: F,
+    ) -> std::result::Result<Response, NodeApiError>
+    where
+        F: Fn(Node) -> Fut,
+        Fut: Future<Output = std::result::Result<Response, NodeApiError>>,
+    {
+        if let Some(options) = &policy.circuit_breaker {
+            if !self.circuit_breakers.allows(options, &node.url) {
+                return Err(NodeApiError::CircuitOpen(node_origin(&node.url)));
+            }
+        }
+
+        let mut retry = 0;
+        loop {
+            let start_time = instant::Instant::now();
+            match send(node.clone()).await {
+                Ok(res) => {
+                    self.circuit_breakers.record_success(&node.url);
+                    return Ok(res);
+                }
+                Err(e) => {
+                    attempts.push(RequestAttempt::new(&node.url, retry, start_time.elapsed(), &e));
+                    // Only failures of the node itself count for the circuit breaker, not e.g. a 404
+                    let node_failure = matches!(e, NodeApiError::Reqwest(_))
+                        || matches!(e, NodeApiError::ResponseError { code, .. } if code >= 500 || code == 429);
+                    if let (Some(options), true) = (&policy.circuit_breaker, node_failure) {
+                        self.circuit_breakers.record_failure(options, &node.url);
+                    }
+                    if retry >= policy.max_retries || !policy.is_retryable(&e) {
+                        return Err(e);
+                    }
+                    request_policy::sleep(policy.backoff.delay(retry)).await;
+                    retry += 1;
+                }
+            }
+        }
+    }
+
     fn get_nodes(
         &self,
         path: &str,
@@ -211,10 +295,12 @@ impl NodeManager {
         path: &str,
         query: Option<&str>,
         timeout: Duration,
+        policy: &RequestPolicy,
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<T> {
         let mut result: HashMap<String, usize> = HashMap::new();
+        let mut attempts = Vec::new();
         // primary_pow_node should only be used for post request with remote PoW
         // Get node urls and set path
         let nodes = self.get_nodes(path, query, false, prefer_permanode)?;
@@ -236,14 +322,19 @@ impl NodeManager {
         if !wasm && self.quorum && need_quorum && query.is_none() {
             #[cfg(not(target_family = "wasm"))]
             {
-                let mut tasks = Vec::new();
-                for (index, node) in nodes.into_iter().enumerate() {
-                    if index < self.min_quorum_size {
-                        let client_ = self.http_client.clone();
-                        tasks.push(async move { tokio::spawn(async move { client_.get(node, timeout).await }).await });
-                    }
-                }
-                for res in futures::future::try_join_all(tasks).await? {
+                // The requests run concurrently in the current task, so the request policy override and the current
+                // operation still apply to them
+                let requests = nodes.into_iter().take(self.min_quorum_size).map(|node| async move {
+                    let mut node_attempts = Vec::new();
+                    let res = self
+                        .send_with_policy(&node, policy, &mut node_attempts, |node| {
+                            self.http_client.get(node, timeout)
+                        })
+                        .await;
+                    (res, node_attempts)
+                });
+                for (res, node_attempts) in futures::future::join_all(requests).await {
+                    attempts.extend(node_attempts);
                     match res {
                         Ok(res) => (res.into_text().await).map_or_else(
                             |_| {
@@ -264,7 +355,10 @@ impl NodeManager {
         } else {
             // Send requests
             for node in nodes {
-                match self.http_client.get(node.clone(), timeout).await {
+                match self
+                    .send_with_policy(&node, policy, &mut attempts, |node| self.http_client.get(node, timeout))
+                    .await
+                {
                     Ok(res) => {
                         // Handle node_info extra because we also want to return the url
                         if path == crate::client::node_api::core::routes::INFO_PATH {
@@ -306,7 +400,10 @@ impl NodeManager {
 
         // Safe unwrap, there are nodes because we throw on empty nodepool.
         // Each node will throw an error or return Ok()
-        let res = result.into_iter().max_by_key(|v| v.1).ok_or_else(|| error.unwrap())?;
+        let res = result
+            .into_iter()
+            .max_by_key(|v| v.1)
+            .ok_or_else(|| with_attempts(error.unwrap(), attempts))?;
 
         // Return if quorum is false or check if quorum was reached
         if !self.quorum
@@ -330,14 +427,21 @@ impl NodeManager {
         path: &str,
         query: Option<&str>,
         timeout: Duration,
+        policy: &RequestPolicy,
     ) -> Result<Vec<u8>> {
         // primary_pow_node should only be used for post request with remote Pow
         // Get node urls and set path
         let nodes = self.get_nodes(path, query, false, false)?;
         let mut error = None;
+        let mut attempts = Vec::new();
         // Send requests
         for node in nodes {
-            match self.http_client.get_bytes(node, timeout).await {
+            match self
+                .send_with_policy(&node, policy, &mut attempts, |node| {
+                    self.http_client.get_bytes(node, timeout)
+                })
+                .await
+            {
                 Ok(res) => {
                     match res.into_bytes().await {
                         Ok(res_text) => return Ok(res_text),
@@ -351,22 +455,29 @@ impl NodeManager {
         }
         // Safe unwrap, there are nodes because we throw on empty nodepool.
         // Each node will throw an error or return Ok()
-        Err(error.unwrap())
+        Err(with_attempts(error.unwrap(), attempts))
     }
 
     pub(crate) async fn post_request_bytes<T: DeserializeOwned>(
         &self,
         path: &str,
         timeout: Duration,
+        policy: &RequestPolicy,
         body: &[u8],
         local_pow: bool,
     ) -> Result<T> {
         // primary_pow_node should only be used for post request with remote PoW
         let nodes = self.get_nodes(path, None, !local_pow, false)?;
         let mut error = None;
+        let mut attempts = Vec::new();
         // Send requests
         for node in nodes {
-            match self.http_client.post_bytes(node, timeout, body).await {
+            match self
+                .send_with_policy(&node, policy, &mut attempts, |node| {
+                    self.http_client.post_bytes(node, timeout, body)
+                })
+                .await
+            {
                 Ok(res) => {
                     match res.into_json::<T>().await {
                         Ok(res) => return Ok(res),
@@ -380,22 +491,29 @@ impl NodeManager {
         }
         // Safe unwrap, there are nodes because we throw on empty nodepool.
         // Each node will throw an error or return Ok()
-        Err(error.unwrap())
+        Err(with_attempts(error.unwrap(), attempts))
     }
 
     pub(crate) async fn post_request_json<T: DeserializeOwned>(
         &self,
         path: &str,
         timeout: Duration,
+        policy: &RequestPolicy,
         json: Value,
         local_pow: bool,
     ) -> Result<T> {
         // primary_pow_node should only be used for post request with remote PoW
         let nodes = self.get_nodes(path, None, !local_pow, false)?;
         let mut error = None;
+        let mut attempts = Vec::new();
         // Send requests
         for node in nodes {
-            match self.http_client.post_json(node, timeout, json.clone()).await {
+            match self
+                .send_with_policy(&node, policy, &mut attempts, |node| {
+                    self.http_client.post_json(node, timeout, json.clone())
+                })
+                .await
+            {
                 Ok(res) => {
                     match res.into_json::<T>().await {
                         Ok(res) => return Ok(res),
@@ -409,6 +527,41 @@ impl NodeManager {
         }
         // Safe unwrap, there are nodes because we throw on empty nodepool.
         // Each node will throw an error or return Ok()
-        Err(error.unwrap())
+        Err(with_attempts(error.unwrap(), attempts))
+    }
+
+    #[cfg(feature = "participation")]
+    pub(crate) async fn delete_request(&self, path: &str, timeout: Duration, policy: &RequestPolicy) -> Result<()> {
+        let nodes = self.get_nodes(path, None, false, false)?;
+        let mut error = None;
+        let mut attempts = Vec::new();
+        // Send requests
+        for node in nodes {
+            match self
+                .send_with_policy(&node, policy, &mut attempts, |node| {
+                    self.http_client.delete(node, timeout)
+                })
+                .await
+            {
+                Ok(_) => return Ok(()),
+                Err(e) => {
+                    error.replace(Error::Node(e));
+                }
+            }
+        }
+        // Safe unwrap, there are nodes because we throw on empty nodepool.
+        // Each node will throw an error or return Ok()
+        Err(with_attempts(error.unwrap(), attempts))
+    }
+}
+
+/// Attaches the attempt history to a node error if the request was attempted more than once.
+fn with_attempts(error: Error, attempts: Vec<RequestAttempt>) -> Error {
+    match error {
+        Error::Node(error) if attempts.len() > 1 => Error::Node(NodeApiError::AttemptsExhausted {
+            error: Box::new(error),
+            attempts,
+        }),
+        error => error,
     }
 }
@@ -9,6 +9,7 @@ use crate::Url;
 
 /// Node authentication object.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NodeAuth {
     /// JWT.
@@ -19,6 +20,7 @@ pub struct NodeAuth {
 
 /// Node definition.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct Node {
     /// Node url.
     pub url: Url,
@@ -42,6 +44,7 @@ impl From<Url> for Node {
 
 /// NodeDto enum to accept just a single url or also provided auth options or set if the node should be disabled.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum NodeDto {
     /// Node url
@@ -67,3 +70,14 @@ impl From<&NodeDto> for Node {
         }
     }
 }
+
+/// A change of the health of a node, detected by the node syncing process.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NodeHealthChange {
+    /// Node url.
+    pub url: Url,
+    /// Whether the node is healthy now.
+    pub healthy: bool,
+}
@@ -3,8 +3,12 @@
 
 #[cfg(not(target_family = "wasm"))]
 use {
+    super::node::NodeHealthChange,
     crate::types::api::core::response::InfoResponse,
-    std::{collections::HashSet, time::Duration},
+    std::{
+        collections::{HashMap, HashSet},
+        time::Duration,
+    },
     tokio::time::sleep,
 };
 
@@ -62,8 +66,6 @@ impl ClientInner {
     }
 
     pub(crate) async fn sync_nodes(&self, nodes: &HashSet<Node>, ignore_node_health: bool) -> Result<()> {
-        use std::collections::HashMap;
-
         log::debug!("sync_nodes");
         let mut healthy_nodes = HashMap::new();
         let mut network_nodes: HashMap<String, Vec<(InfoResponse, Node)>> = HashMap::new();
@@ -102,6 +104,14 @@ impl ClientInner {
         }
 
         if let Some(nodes) = network_nodes.get(most_nodes.0) {
+            // Invalidate cached mutable responses as soon as any of the nodes confirmed a new milestone
+            if let Some(index) = nodes
+                .iter()
+                .map(|(info, _)| info.status.confirmed_milestone.index)
+                .max()
+            {
+                self.response_cache.update_confirmed_milestone_index(index);
+            }
             if let Some((info, _node_url)) = nodes.first() {
                 let mut network_info = self.network_info.write().await;
 
@@ -115,16 +125,50 @@ impl ClientInner {
         }
 
         // Update the sync list.
-        *self
-            .node_manager
-            .read()
-            .await
-            .healthy_nodes
-            .write()
-            .map_err(|_| crate::client::Error::PoisonError)? = healthy_nodes;
+        let previous_healthy_nodes = core::mem::replace(
+            &mut *self
+                .node_manager
+                .read()
+                .await
+                .healthy_nodes
+                .write()
+                .map_err(|_| crate::client::Error::PoisonError)?,
+            healthy_nodes.clone(),
+        );
+        self.notify_node_health_changes(nodes, &previous_healthy_nodes, &healthy_nodes);
 
         Ok(())
     }
+
+    fn notify_node_health_changes(
+        &self,
+        nodes: &HashSet<Node>,
+        previous_healthy_nodes: &HashMap<Node, InfoResponse>,
+        healthy_nodes: &HashMap<Node, InfoResponse>,
+    ) {
+        // Without receivers there is nobody to notify
+        if self.node_health_sender.receiver_count() == 0 {
+            return;
+        }
+        for node in nodes {
+            let healthy = healthy_nodes.contains_key(node);
+            if healthy != previous_healthy_nodes.contains_key(node) {
+                log::debug!("{} is {}healthy now", node.url, if healthy { "" } else { "not " });
+                self.node_health_sender
+                    .send(NodeHealthChange {
+                        url: node.url.clone(),
+                        healthy,
+                    })
+                    .ok();
+            }
+        }
+    }
+
+    /// Returns a receiver for changes of the node health, which are detected every `node_sync_interval`. Lagging
+    /// receivers miss the oldest changes.
+    pub fn node_health_receiver(&self) -> tokio::sync::broadcast::Receiver<NodeHealthChange> {
+        self.node_health_sender.subscribe()
+    }
 }
 
 impl Client {
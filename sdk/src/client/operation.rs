@@ -0,0 +1,183 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cancellation and progress reporting of long-running operations, like syncing, retrying transactions until they're
+//! included or proof of work.
+
+use std::{fmt, future::Future, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::watch;
+
+use crate::{
+    client::{Error, Result},
+    types::block::BlockId,
+};
+
+tokio::task_local! {
+    static CURRENT_OPERATION: Operation;
+}
+
+/// The progress of an [`Operation`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum OperationProgress {
+    /// An account is synced.
+    #[serde(rename_all = "camelCase")]
+    SyncingAccount { account_index: u32 },
+    /// An account is searched for unspent outputs while recovering accounts.
+    #[serde(rename_all = "camelCase")]
+    RecoveringAccount { account_index: u32 },
+    /// The inclusion of a block is checked, it's promoted or reattached if needed.
+    #[serde(rename_all = "camelCase")]
+    RetryingBlock { block_id: BlockId, attempt: u64 },
+    /// Proof of work is performed, restarted with new tips after every tips interval.
+    #[serde(rename_all = "camelCase")]
+    PerformingPow { attempt: u32 },
+}
+
+impl OperationProgress {
+    /// Returns the index of the account the progress is related to, if any.
+    pub fn account_index(&self) -> Option<u32> {
+        match self {
+            Self::SyncingAccount { account_index } | Self::RecoveringAccount { account_index } => Some(*account_index),
+            Self::RetryingBlock { .. } | Self::PerformingPow { .. } => None,
+        }
+    }
+}
+
+type ProgressHandler = Arc<dyn Fn(OperationProgress) + Send + Sync>;
+
+/// A long-running operation that can be cancelled and reports its progress.
+///
+/// Futures run with [`Operation::run()`] check for cancellation in the syncing, retry and proof of work loops, also in
+/// the tasks they spawn, and return [`Error::OperationCancelled`] once the operation was cancelled.
+#[derive(Clone)]
+pub struct Operation {
+    cancel: Arc<watch::Sender<bool>>,
+    progress_handler: Option<ProgressHandler>,
+}
+
+impl Operation {
+    /// Creates a new [`Operation`].
+    pub fn new() -> Self {
+        Self {
+            cancel: Arc::new(watch::channel(false).0),
+            progress_handler: None,
+        }
+    }
+
+    /// Sets a handler that is called with the progress of the operation.
+    pub fn with_progress_handler(mut self, handler: impl Fn(OperationProgress) + Send + Sync + 'static) -> Self {
+        self.progress_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Cancels the operation.
+    pub fn cancel(&self) {
+        self.cancel.send_replace(true);
+    }
+
+    /// Returns whether the operation was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        *self.cancel.borrow()
+    }
+
+    /// Completes once the operation was cancelled.
+    pub async fn cancelled(&self) {
+        // The sender lives as long as self
+        self.cancel.subscribe().wait_for(|cancelled| *cancelled).await.ok();
+    }
+
+    /// Runs a future as this operation.
+    pub async fn run<F: Future>(&self, future: F) -> F::Output {
+        CURRENT_OPERATION.scope(self.clone(), future).await
+    }
+
+    /// Returns the operation the current task runs as.
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT_OPERATION.try_with(Clone::clone).ok()
+    }
+
+    /// Returns [`Error::OperationCancelled`] if the current operation was cancelled.
+    pub(crate) fn check_cancelled() -> Result<()> {
+        match CURRENT_OPERATION.try_with(Self::is_cancelled) {
+            Ok(true) => Err(Error::OperationCancelled),
+            _ => Ok(()),
+        }
+    }
+
+    /// Reports the progress of the current operation.
+    pub(crate) fn report_progress(progress: OperationProgress) {
+        CURRENT_OPERATION
+            .try_with(|operation| {
+                if let Some(handler) = &operation.progress_handler {
+                    handler(progress);
+                }
+            })
+            .ok();
+    }
+
+    /// Awaits a future, or returns [`Error::OperationCancelled`] as soon as the current operation is cancelled.
+    pub(crate) async fn cancellable<F: Future>(future: F) -> Result<F::Output> {
+        match Self::current() {
+            Some(operation) => {
+                tokio::select! {
+                    output = future => Ok(output),
+                    _ = operation.cancelled() => Err(Error::OperationCancelled),
+                }
+            }
+            None => Ok(future.await),
+        }
+    }
+}
+
+impl Default for Operation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Operation")
+            .field("cancelled", &self.is_cancelled())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Mutex, time::Duration};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cancel_and_report_progress() {
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let progress_ = progress.clone();
+        let operation =
+            Operation::new().with_progress_handler(move |progress| progress_.lock().unwrap().push(progress));
+
+        assert!(Operation::check_cancelled().is_ok());
+        let result = operation
+            .run(async {
+                Operation::report_progress(OperationProgress::PerformingPow { attempt: 1 });
+                Operation::check_cancelled()?;
+                Operation::current().unwrap().cancel();
+                Operation::cancellable(tokio::time::sleep(Duration::from_secs(60))).await
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::OperationCancelled)));
+        assert!(operation.is_cancelled());
+        assert_eq!(
+            *progress.lock().unwrap(),
+            [OperationProgress::PerformingPow { attempt: 1 }]
+        );
+        // Outside of the operation, nothing is cancelled
+        assert!(Operation::check_cancelled().is_ok());
+    }
+}
@@ -206,6 +206,7 @@ impl FromStr for SecretManager {
 
 /// DTO for secret manager types with required data.
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub enum SecretManagerDto {
     /// Stronghold
@@ -219,14 +220,17 @@ pub enum SecretManagerDto {
     #[serde(alias = "ledgerNano")]
     LedgerNano(bool),
     /// Mnemonic
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(alias = "mnemonic")]
     Mnemonic(Zeroizing<String>),
     /// Private Key
     #[cfg(feature = "private_key_secret_manager")]
     #[cfg_attr(docsrs, doc(cfg(feature = "private_key_secret_manager")))]
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(alias = "privateKey")]
     PrivateKey(Zeroizing<String>),
     /// Hex seed
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(alias = "hexSeed")]
     HexSeed(Zeroizing<String>),
     /// Placeholder
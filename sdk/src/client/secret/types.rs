@@ -21,6 +21,7 @@ use crate::{
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct StrongholdDto {
     /// The Stronghold password
@@ -54,6 +55,7 @@ pub struct AccountAddress {
 
 /// Options provided to generate addresses.
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase", default)]
 pub struct GenerateAddressOptions {
     pub internal: bool,
@@ -72,6 +74,7 @@ impl GenerateAddressOptions {
 
 /// The Ledger device status.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct LedgerApp {
     /// Opened app name.
     pub(crate) name: String,
@@ -92,6 +95,7 @@ impl LedgerApp {
 
 /// Ledger Device Type
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub enum LedgerDeviceType {
     /// Device Type Nano S
@@ -107,6 +111,7 @@ pub enum LedgerDeviceType {
 
 /// The Ledger device status.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct LedgerNanoStatus {
     /// Ledger is available and ready to be used.
@@ -170,6 +175,7 @@ impl InputSigningData {
 
 /// Dto for data for transaction inputs for signing and ordering of unlock blocks
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct InputSigningDataDto {
     /// The output
@@ -177,6 +183,10 @@ pub struct InputSigningDataDto {
     /// The output metadata
     pub output_metadata: OutputMetadata,
     /// The chain derived from seed, only for ed25519 addresses
+    #[cfg_attr(
+        feature = "json_schema",
+        schemars(with = "Option<crate::utils::serde::bip44::Bip44Def>")
+    )]
     #[serde(with = "option_bip44", default)]
     pub chain: Option<Bip44>,
 }
@@ -190,6 +190,7 @@ impl Client {
 
 /// A password wrapper that takes care of zeroing the memory when being dropped.
 #[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Zeroize, ZeroizeOnDrop, derive_more::From)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct Password(String);
 
 impl Password {
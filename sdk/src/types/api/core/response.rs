@@ -15,6 +15,7 @@ use crate::types::block::{
 /// Response of GET /api/core/v2/info.
 /// Returns general information about the node.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct InfoResponse {
     pub name: String,
@@ -37,6 +38,7 @@ impl core::fmt::Display for InfoResponse {
 /// Returned in [`InfoResponse`].
 /// Status information about the node.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct StatusResponse {
     pub is_healthy: bool,
@@ -48,6 +50,7 @@ pub struct StatusResponse {
 /// Returned in [`StatusResponse`].
 /// Information about the latest milestone.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct LatestMilestoneResponse {
     pub index: u32,
@@ -60,6 +63,7 @@ pub struct LatestMilestoneResponse {
 /// Returned in [`StatusResponse`].
 /// Information about the confirmed milestone.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ConfirmedMilestoneResponse {
     pub index: u32,
@@ -72,6 +76,7 @@ pub struct ConfirmedMilestoneResponse {
 /// Returned in [`InfoResponse`].
 /// Pending protocol parameters.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PendingProtocolParameter {
     #[serde(rename = "type")]
@@ -84,6 +89,7 @@ pub struct PendingProtocolParameter {
 /// Returned in [`InfoResponse`].
 /// Information about the base token.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct BaseTokenResponse {
     pub name: String,
@@ -98,6 +104,7 @@ pub struct BaseTokenResponse {
 /// Returned in [`InfoResponse`].
 /// Metric information about the node.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct MetricsResponse {
     pub blocks_per_second: f64,
@@ -123,6 +130,7 @@ pub struct SubmitBlockResponse {
 
 /// Describes the ledger inclusion state of a transaction.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum LedgerInclusionState {
     Conflicting,
@@ -133,6 +141,7 @@ pub enum LedgerInclusionState {
 /// Response of GET /api/core/v2/blocks/{block_id}/metadata.
 /// Returns the metadata of a block.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct BlockMetadataResponse {
     pub block_id: BlockId,
@@ -157,6 +166,7 @@ pub struct BlockMetadataResponse {
 /// Response of GET /api/core/v2/outputs/{output_id}.
 /// Returns an output and its metadata.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct OutputWithMetadataResponse {
     pub metadata: OutputMetadata,
@@ -180,6 +190,7 @@ impl From<OutputWithMetadata> for OutputWithMetadataResponse {
 
 /// Describes a receipt.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ReceiptResponse {
     pub receipt: ReceiptMilestoneOptionDto,
@@ -197,6 +208,7 @@ pub struct ReceiptsResponse {
 /// Response of GET /api/core/v2/treasury.
 /// Returns all information about the treasury.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TreasuryResponse {
     pub milestone_id: MilestoneId,
@@ -206,6 +218,7 @@ pub struct TreasuryResponse {
 /// Response of GET /api/core/v2/milestone/{milestone_index}/utxo-changes.
 /// Returns all UTXO changes that happened at a specific milestone.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct UtxoChangesResponse {
     pub index: u32,
@@ -215,6 +228,7 @@ pub struct UtxoChangesResponse {
 
 /// Describes the heartbeat of a node.
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Heartbeat {
     pub solid_milestone_index: u32,
@@ -226,6 +240,7 @@ pub struct Heartbeat {
 
 /// Describes metrics of a gossip stream.
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Metrics {
     pub new_blocks: u64,
@@ -243,6 +258,7 @@ pub struct Metrics {
 
 /// Returns all information about the gossip stream with the peer.
 #[derive(Clone, Debug, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct Gossip {
     pub heartbeat: Heartbeat,
     pub metrics: Metrics,
@@ -250,6 +266,7 @@ pub struct Gossip {
 
 /// Describes the relation with the peer.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub enum Relation {
     Known,
@@ -262,6 +279,7 @@ pub enum Relation {
 /// - POST /api/core/v2/peers
 /// Returns information about a peer.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PeerResponse {
     pub id: String,
@@ -16,6 +16,7 @@ use crate::types::block::output::OutputId;
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct OutputIdsResponse {
     /// The ledger index at which the outputs were collected
     pub ledger_index: u32,
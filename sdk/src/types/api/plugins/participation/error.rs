@@ -5,6 +5,28 @@
 pub enum Error {
     /// Invalid participations error
     InvalidParticipations,
+    /// A text field exceeds its maximum length
+    InvalidTextLength {
+        field: &'static str,
+        length: usize,
+        max: usize,
+    },
+    /// Milestone indexes not in the order commence <= start < end
+    InvalidMilestoneIndexes { commence: u32, start: u32, end: u32 },
+    /// Invalid amount of questions in a ballot
+    InvalidQuestionCount(usize),
+    /// Invalid amount of answers for a question
+    InvalidAnswerCount(usize),
+    /// An answer uses a value that is reserved for skipped or invalid answers
+    ReservedAnswerValue(u8),
+    /// Two answers of a question share the same value
+    DuplicateAnswerValue(u8),
+    /// Invalid length of a staking symbol
+    InvalidStakingSymbol(usize),
+    /// Invalid staking reward ratio
+    InvalidStakingRatio { numerator: u64, denominator: u64 },
+    /// The event status doesn't match the questions of the event
+    QuestionCountMismatch { expected: usize, found: usize },
     /// IO error
     #[cfg(feature = "std")]
     Io(std::io::Error),
@@ -14,6 +36,24 @@ impl core::fmt::Display for Error {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::InvalidParticipations => write!(f, "invalid participations"),
+            Self::InvalidTextLength { field, length, max } => {
+                write!(f, "invalid {field} length {length}, max {max}")
+            }
+            Self::InvalidMilestoneIndexes { commence, start, end } => write!(
+                f,
+                "invalid milestone indexes: commence {commence}, start {start}, end {end}, expected commence <= start < end"
+            ),
+            Self::InvalidQuestionCount(count) => write!(f, "invalid question count {count}"),
+            Self::InvalidAnswerCount(count) => write!(f, "invalid answer count {count}"),
+            Self::ReservedAnswerValue(value) => write!(f, "answer value {value} is reserved"),
+            Self::DuplicateAnswerValue(value) => write!(f, "duplicate answer value {value}"),
+            Self::InvalidStakingSymbol(length) => write!(f, "invalid staking symbol length {length}"),
+            Self::InvalidStakingRatio { numerator, denominator } => {
+                write!(f, "invalid staking ratio {numerator}/{denominator}")
+            }
+            Self::QuestionCountMismatch { expected, found } => {
+                write!(f, "question count mismatch: expected {expected}, found {found}")
+            }
             #[cfg(feature = "std")]
             Self::Io(error) => write!(f, "{error}"),
         }
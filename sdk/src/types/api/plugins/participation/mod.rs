@@ -6,4 +6,5 @@
 
 pub mod error;
 pub mod responses;
+pub mod results;
 pub mod types;
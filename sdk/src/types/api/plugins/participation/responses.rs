@@ -4,7 +4,7 @@
 //! Response types for the public participation endpoints.
 //! Types from <https://github.com/iotaledger/inx-participation/blob/d3b994f74a8bb948b18a89b04ed6c9bb271c7166/core/participation/types.go>
 
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 
 use hashbrown::HashMap;
 
@@ -32,6 +32,7 @@ pub struct EventsResponse {
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct TrackedParticipation {
     /// ID of the block that included the transaction that created the output the participation was made.
     pub block_id: BlockId,
@@ -61,6 +62,38 @@ pub struct AddressOutputsResponse {
     pub outputs: HashMap<OutputId, OutputStatusResponse>,
 }
 
+/// CreateEventResponse defines the response of a POST RouteAdminCreateEvent REST API call.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct CreateEventResponse {
+    /// The hex encoded ID of the created participation event.
+    pub event_id: ParticipationEventId,
+}
+
+/// RewardsResponse defines the response of a GET RouteAdminRewards REST API call.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct RewardsResponse {
+    /// The symbol of the rewarded tokens.
+    pub symbol: String,
+    /// The milestone index the rewards were calculated for.
+    pub milestone_index: u32,
+    /// The sum of all rewards.
+    pub total_rewards: u64,
+    /// The checksum of the rewards.
+    pub checksum: String,
+    /// The rewards per bech32 address.
+    pub rewards: HashMap<String, u64>,
+}
+
 #[cfg(test)]
 impl OutputStatusResponse {
     pub fn mock() -> Self {
@@ -0,0 +1,197 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Aggregation of participation event results and staking reward projections.
+
+use alloc::{string::String, vec::Vec};
+
+use crate::types::api::plugins::participation::{
+    error::Error,
+    types::{
+        ParticipationEventData, ParticipationEventPayload, ParticipationEventStatus, StakingEventPayload,
+        VotingEventPayload, ANSWER_VALUE_INVALID, ANSWER_VALUE_SKIPPED,
+    },
+};
+
+/// Tally of a ballot at a milestone.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct BallotTally {
+    /// The milestone index the tally was computed for.
+    pub milestone_index: u32,
+    /// The tallies of the questions, in ballot order.
+    pub questions: Vec<QuestionTally>,
+}
+
+/// Tally of a single question of a ballot.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct QuestionTally {
+    /// The question text, if the event data was provided.
+    pub text: Option<String>,
+    /// The answers, ordered by accumulated votes, highest first. Skipped and invalid answers are not included.
+    pub answers: Vec<AnswerTally>,
+    /// Sum of the current votes of all answers.
+    pub total_current: u64,
+    /// Sum of the accumulated votes of all answers.
+    pub total_accumulated: u64,
+}
+
+impl QuestionTally {
+    /// Returns the answer with the most accumulated votes, or `None` if there are no votes or a tie.
+    pub fn winner(&self) -> Option<&AnswerTally> {
+        match self.answers.as_slice() {
+            [first, second, ..] if first.accumulated == second.accumulated => None,
+            [first, ..] if first.accumulated > 0 => Some(first),
+            _ => None,
+        }
+    }
+}
+
+/// Votes for a single answer.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct AnswerTally {
+    /// The answer value.
+    pub value: u8,
+    /// The answer text, if the event data was provided.
+    pub text: Option<String>,
+    /// Votes currently held by outputs voting for this answer.
+    pub current: u64,
+    /// Votes accumulated over all milestones of the event.
+    pub accumulated: u64,
+}
+
+impl AnswerTally {
+    /// Returns the share of the accumulated votes of the question in basis points (1/100 of a percent).
+    pub fn accumulated_share_bps(&self, question: &QuestionTally) -> u16 {
+        if question.total_accumulated == 0 {
+            return 0;
+        }
+        (self.accumulated as u128 * 10_000 / question.total_accumulated as u128) as u16
+    }
+}
+
+impl ParticipationEventStatus {
+    /// Computes the tally of a ballot from its status. If the voting payload of the event is provided, the
+    /// question and answer texts are included and the amount of questions is checked. Votes for the reserved
+    /// [`ANSWER_VALUE_SKIPPED`] and [`ANSWER_VALUE_INVALID`] values are not counted.
+    pub fn tally(&self, payload: Option<&VotingEventPayload>) -> Result<BallotTally, Error> {
+        let statuses = self.questions().as_deref().unwrap_or_default();
+
+        if let Some(payload) = payload {
+            if payload.questions().len() != statuses.len() {
+                return Err(Error::QuestionCountMismatch {
+                    expected: payload.questions().len(),
+                    found: statuses.len(),
+                });
+            }
+        }
+
+        let questions = statuses
+            .iter()
+            .enumerate()
+            .map(|(i, status)| {
+                let question = payload.map(|payload| &payload.questions()[i]);
+                let mut answers = status
+                    .answers()
+                    .iter()
+                    .filter(|answer| ![ANSWER_VALUE_SKIPPED, ANSWER_VALUE_INVALID].contains(answer.value()))
+                    .map(|answer| AnswerTally {
+                        value: *answer.value(),
+                        text: question.and_then(|question| {
+                            question
+                                .answers()
+                                .iter()
+                                .find(|a| a.value() == answer.value())
+                                .map(|a| a.text().clone())
+                        }),
+                        current: *answer.current(),
+                        accumulated: *answer.accumulated(),
+                    })
+                    .collect::<Vec<_>>();
+                answers.sort_by(|a, b| b.accumulated.cmp(&a.accumulated).then(a.value.cmp(&b.value)));
+
+                QuestionTally {
+                    text: question.map(|question| question.text().clone()),
+                    total_current: answers.iter().map(|a| a.current).sum(),
+                    total_accumulated: answers.iter().map(|a| a.accumulated).sum(),
+                    answers,
+                }
+            })
+            .collect();
+
+        Ok(BallotTally {
+            milestone_index: *self.milestone_index(),
+            questions,
+        })
+    }
+}
+
+/// Projected rewards of a staking event.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct StakingRewardProjection {
+    /// Currency symbol of the rewards.
+    pub symbol: String,
+    /// Amount of milestones the rewards were projected for.
+    pub milestones: u32,
+    /// The projected rewards.
+    pub rewards: u64,
+    /// If the projected rewards reach the required minimum rewards of the event.
+    pub minimum_reached: bool,
+}
+
+impl StakingEventPayload {
+    /// Returns the rewards for staking `amount` during `milestones` milestones.
+    pub fn rewards(&self, amount: u64, milestones: u32) -> u64 {
+        if *self.denominator() == 0 {
+            return 0;
+        }
+        let per_milestone = amount as u128 * *self.numerator() as u128 / *self.denominator() as u128;
+
+        u64::try_from(per_milestone * milestones as u128).unwrap_or(u64::MAX)
+    }
+}
+
+impl ParticipationEventData {
+    /// Projects the rewards of staking `amount` from `milestone_index` until the end of the event, on top of
+    /// `accumulated_rewards` that were already received. Returns `None` if the event is not a staking event.
+    pub fn project_staking_rewards(
+        &self,
+        amount: u64,
+        milestone_index: u32,
+        accumulated_rewards: u64,
+    ) -> Option<StakingRewardProjection> {
+        let ParticipationEventPayload::StakingEventPayload(payload) = self.payload() else {
+            return None;
+        };
+
+        let from = milestone_index.max(*self.milestone_index_start());
+        let milestones = self.milestone_index_end().saturating_sub(from);
+        let rewards = accumulated_rewards.saturating_add(payload.rewards(amount, milestones));
+
+        Some(StakingRewardProjection {
+            symbol: payload.symbol().clone(),
+            milestones,
+            rewards,
+            minimum_reached: rewards >= *payload.required_minimum_rewards(),
+        })
+    }
+}
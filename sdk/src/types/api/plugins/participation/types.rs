@@ -19,6 +19,42 @@ use crate::types::{api::plugins::participation::error::Error, block::impl_id};
 /// Participation tag.
 pub const PARTICIPATION_TAG: &str = "PARTICIPATE";
 
+/// Maximum length of an event name.
+pub const EVENT_NAME_MAX_LENGTH: usize = 255;
+/// Maximum length of the additional info of an event.
+pub const EVENT_ADDITIONAL_INFO_MAX_LENGTH: usize = 2000;
+/// Minimum amount of questions in a ballot.
+pub const BALLOT_MIN_QUESTIONS: usize = 1;
+/// Maximum amount of questions in a ballot.
+pub const BALLOT_MAX_QUESTIONS: usize = 10;
+/// Maximum length of a question or answer text.
+pub const TEXT_MAX_LENGTH: usize = 255;
+/// Maximum length of the additional info of a question, an answer or a staking payload.
+pub const ADDITIONAL_INFO_MAX_LENGTH: usize = 500;
+/// Minimum amount of answers for a question.
+pub const QUESTION_MIN_ANSWERS: usize = 2;
+/// Maximum amount of answers for a question.
+pub const QUESTION_MAX_ANSWERS: usize = 10;
+/// Answer value of a skipped question.
+pub const ANSWER_VALUE_SKIPPED: u8 = 0;
+/// Answer value of an invalid answer.
+pub const ANSWER_VALUE_INVALID: u8 = 255;
+/// Minimum length of a staking symbol.
+pub const STAKING_SYMBOL_MIN_LENGTH: usize = 3;
+/// Maximum length of a staking symbol.
+pub const STAKING_SYMBOL_MAX_LENGTH: usize = 10;
+
+fn verify_text_length(field: &'static str, text: &str, max: usize) -> Result<(), Error> {
+    if text.len() > max {
+        return Err(Error::InvalidTextLength {
+            field,
+            length: text.len(),
+            max,
+        });
+    }
+    Ok(())
+}
+
 /// Possible participation event types.
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(
@@ -26,6 +62,7 @@ pub const PARTICIPATION_TAG: &str = "PARTICIPATE";
     derive(serde_repr::Serialize_repr, serde_repr::Deserialize_repr),
     serde(untagged)
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema_repr))]
 #[repr(u8)]
 pub enum ParticipationEventType {
     /// Voting event.
@@ -59,6 +96,7 @@ string_serde_impl!(ParticipationEventId);
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get = "pub")]
 pub struct ParticipationEventData {
     name: String,
@@ -69,9 +107,92 @@ pub struct ParticipationEventData {
     additional_info: String,
 }
 
+impl ParticipationEventData {
+    /// Checks the milestone indexes, text lengths and the payload of the event.
+    pub fn validate(&self) -> Result<(), Error> {
+        verify_text_length("name", &self.name, EVENT_NAME_MAX_LENGTH)?;
+        verify_text_length(
+            "additional info",
+            &self.additional_info,
+            EVENT_ADDITIONAL_INFO_MAX_LENGTH,
+        )?;
+
+        if self.milestone_index_commence > self.milestone_index_start
+            || self.milestone_index_start >= self.milestone_index_end
+        {
+            return Err(Error::InvalidMilestoneIndexes {
+                commence: self.milestone_index_commence,
+                start: self.milestone_index_start,
+                end: self.milestone_index_end,
+            });
+        }
+
+        match &self.payload {
+            ParticipationEventPayload::VotingEventPayload(payload) => payload.validate(),
+            ParticipationEventPayload::StakingEventPayload(payload) => payload.validate(),
+        }
+    }
+}
+
+/// Builder for [`ParticipationEventData`].
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct ParticipationEventDataBuilder {
+    name: String,
+    milestone_index_commence: u32,
+    milestone_index_start: u32,
+    milestone_index_end: u32,
+    payload: ParticipationEventPayload,
+    additional_info: String,
+}
+
+impl ParticipationEventDataBuilder {
+    /// Creates a new builder for an event with the given name and payload.
+    pub fn new(name: impl Into<String>, payload: impl Into<ParticipationEventPayload>) -> Self {
+        Self {
+            name: name.into(),
+            milestone_index_commence: 0,
+            milestone_index_start: 0,
+            milestone_index_end: 0,
+            payload: payload.into(),
+            additional_info: String::new(),
+        }
+    }
+
+    /// Sets the milestone indexes at which the event is announced, starts counting and ends.
+    pub fn with_milestone_indexes(mut self, commence: u32, start: u32, end: u32) -> Self {
+        self.milestone_index_commence = commence;
+        self.milestone_index_start = start;
+        self.milestone_index_end = end;
+        self
+    }
+
+    /// Sets the additional info.
+    pub fn with_additional_info(mut self, additional_info: impl Into<String>) -> Self {
+        self.additional_info = additional_info.into();
+        self
+    }
+
+    /// Finishes the builder into a validated [`ParticipationEventData`].
+    pub fn finish(self) -> Result<ParticipationEventData, Error> {
+        let data = ParticipationEventData {
+            name: self.name,
+            milestone_index_commence: self.milestone_index_commence,
+            milestone_index_start: self.milestone_index_start,
+            milestone_index_end: self.milestone_index_end,
+            payload: self.payload,
+            additional_info: self.additional_info,
+        };
+        data.validate()?;
+
+        Ok(data)
+    }
+}
+
 /// Event payload types.
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(untagged))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub enum ParticipationEventPayload {
     /// Voting payload.
     VotingEventPayload(VotingEventPayload),
@@ -79,6 +200,18 @@ pub enum ParticipationEventPayload {
     StakingEventPayload(StakingEventPayload),
 }
 
+impl From<VotingEventPayload> for ParticipationEventPayload {
+    fn from(payload: VotingEventPayload) -> Self {
+        Self::VotingEventPayload(payload)
+    }
+}
+
+impl From<StakingEventPayload> for ParticipationEventPayload {
+    fn from(payload: StakingEventPayload) -> Self {
+        Self::StakingEventPayload(payload)
+    }
+}
+
 /// Payload for a voting event.
 #[derive(Debug, Clone, Eq, PartialEq, Getters)]
 #[cfg_attr(
@@ -86,6 +219,7 @@ pub enum ParticipationEventPayload {
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get = "pub")]
 pub struct VotingEventPayload {
     #[cfg_attr(feature = "serde", serde(rename = "type"))]
@@ -93,6 +227,30 @@ pub struct VotingEventPayload {
     questions: Vec<Question>,
 }
 
+impl VotingEventPayload {
+    /// The type of a voting event payload.
+    pub const KIND: u32 = 0;
+
+    /// Creates a new validated [`VotingEventPayload`].
+    pub fn new(questions: impl Into<Vec<Question>>) -> Result<Self, Error> {
+        let payload = Self {
+            kind: Self::KIND,
+            questions: questions.into(),
+        };
+        payload.validate()?;
+
+        Ok(payload)
+    }
+
+    /// Checks the amount of questions and each question.
+    pub fn validate(&self) -> Result<(), Error> {
+        if !(BALLOT_MIN_QUESTIONS..=BALLOT_MAX_QUESTIONS).contains(&self.questions.len()) {
+            return Err(Error::InvalidQuestionCount(self.questions.len()));
+        }
+        self.questions.iter().try_for_each(Question::validate)
+    }
+}
+
 /// Question for a voting event.
 #[derive(Debug, Clone, Eq, PartialEq, Getters)]
 #[cfg_attr(
@@ -100,6 +258,7 @@ pub struct VotingEventPayload {
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get = "pub")]
 pub struct Question {
     text: String,
@@ -107,6 +266,47 @@ pub struct Question {
     additional_info: String,
 }
 
+impl Question {
+    /// Creates a new validated [`Question`].
+    pub fn new(
+        text: impl Into<String>,
+        answers: impl Into<Vec<Answer>>,
+        additional_info: impl Into<String>,
+    ) -> Result<Self, Error> {
+        let question = Self {
+            text: text.into(),
+            answers: answers.into(),
+            additional_info: additional_info.into(),
+        };
+        question.validate()?;
+
+        Ok(question)
+    }
+
+    /// Checks the text lengths and that there are enough answers with distinct, non-reserved values.
+    pub fn validate(&self) -> Result<(), Error> {
+        verify_text_length("question text", &self.text, TEXT_MAX_LENGTH)?;
+        verify_text_length(
+            "question additional info",
+            &self.additional_info,
+            ADDITIONAL_INFO_MAX_LENGTH,
+        )?;
+
+        if !(QUESTION_MIN_ANSWERS..=QUESTION_MAX_ANSWERS).contains(&self.answers.len()) {
+            return Err(Error::InvalidAnswerCount(self.answers.len()));
+        }
+
+        for (i, answer) in self.answers.iter().enumerate() {
+            answer.validate()?;
+            if self.answers[..i].iter().any(|a| a.value == answer.value) {
+                return Err(Error::DuplicateAnswerValue(answer.value));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Answer in a voting event.
 #[derive(Debug, Clone, Eq, PartialEq, Getters)]
 #[cfg_attr(
@@ -114,6 +314,7 @@ pub struct Question {
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get = "pub")]
 pub struct Answer {
     value: u8,
@@ -121,6 +322,33 @@ pub struct Answer {
     additional_info: String,
 }
 
+impl Answer {
+    /// Creates a new validated [`Answer`].
+    pub fn new(value: u8, text: impl Into<String>, additional_info: impl Into<String>) -> Result<Self, Error> {
+        let answer = Self {
+            value,
+            text: text.into(),
+            additional_info: additional_info.into(),
+        };
+        answer.validate()?;
+
+        Ok(answer)
+    }
+
+    /// Checks the value and the text lengths.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.value == ANSWER_VALUE_SKIPPED || self.value == ANSWER_VALUE_INVALID {
+            return Err(Error::ReservedAnswerValue(self.value));
+        }
+        verify_text_length("answer text", &self.text, TEXT_MAX_LENGTH)?;
+        verify_text_length(
+            "answer additional info",
+            &self.additional_info,
+            ADDITIONAL_INFO_MAX_LENGTH,
+        )
+    }
+}
+
 /// Payload for a staking event.
 #[derive(Debug, Clone, Eq, PartialEq, Getters)]
 #[cfg_attr(
@@ -128,6 +356,7 @@ pub struct Answer {
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get = "pub")]
 pub struct StakingEventPayload {
     #[cfg_attr(feature = "serde", serde(rename = "type"))]
@@ -140,6 +369,57 @@ pub struct StakingEventPayload {
     additional_info: String,
 }
 
+impl StakingEventPayload {
+    /// The type of a staking event payload.
+    pub const KIND: u32 = 1;
+
+    /// Creates a new validated [`StakingEventPayload`]. Every milestone, stakers are rewarded with
+    /// `amount * numerator / denominator` of `symbol`.
+    pub fn new(
+        text: impl Into<String>,
+        symbol: impl Into<String>,
+        numerator: u64,
+        denominator: u64,
+        required_minimum_rewards: u64,
+        additional_info: impl Into<String>,
+    ) -> Result<Self, Error> {
+        let payload = Self {
+            kind: Self::KIND,
+            text: text.into(),
+            symbol: symbol.into(),
+            numerator,
+            denominator,
+            required_minimum_rewards,
+            additional_info: additional_info.into(),
+        };
+        payload.validate()?;
+
+        Ok(payload)
+    }
+
+    /// Checks the text lengths, the symbol and the reward ratio.
+    pub fn validate(&self) -> Result<(), Error> {
+        verify_text_length("staking text", &self.text, TEXT_MAX_LENGTH)?;
+        verify_text_length(
+            "staking additional info",
+            &self.additional_info,
+            ADDITIONAL_INFO_MAX_LENGTH,
+        )?;
+
+        if !(STAKING_SYMBOL_MIN_LENGTH..=STAKING_SYMBOL_MAX_LENGTH).contains(&self.symbol.len()) {
+            return Err(Error::InvalidStakingSymbol(self.symbol.len()));
+        }
+        if self.numerator == 0 || self.denominator == 0 {
+            return Err(Error::InvalidStakingRatio {
+                numerator: self.numerator,
+                denominator: self.denominator,
+            });
+        }
+
+        Ok(())
+    }
+}
+
 /// Event status.
 #[derive(Debug, Clone, Eq, PartialEq, Getters)]
 #[cfg_attr(
@@ -147,6 +427,7 @@ pub struct StakingEventPayload {
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get = "pub")]
 pub struct ParticipationEventStatus {
     milestone_index: u32,
@@ -158,6 +439,7 @@ pub struct ParticipationEventStatus {
 /// Question status.
 #[derive(Debug, Clone, Eq, PartialEq, Getters)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get = "pub")]
 pub struct QuestionStatus {
     answers: Vec<AnswerStatus>,
@@ -166,6 +448,7 @@ pub struct QuestionStatus {
 /// Answer status.
 #[derive(Debug, Clone, Eq, PartialEq, Getters)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get = "pub")]
 pub struct AnswerStatus {
     value: u8,
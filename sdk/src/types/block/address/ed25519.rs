@@ -60,6 +60,7 @@ pub(crate) mod dto {
 
     /// Describes an Ed25519 address.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct Ed25519AddressDto {
         #[serde(rename = "type")]
@@ -282,6 +282,9 @@ pub mod dto {
         }
     }
 
+    #[cfg(feature = "json_schema")]
+    impl_json_schema_one_of!(AddressDto, [Ed25519AddressDto, AliasAddressDto, NftAddressDto]);
+
     impl Serialize for AddressDto {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
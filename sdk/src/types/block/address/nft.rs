@@ -71,6 +71,7 @@ pub(crate) mod dto {
 
     /// Describes an NFT address.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct NftAddressDto {
         #[serde(rename = "type")]
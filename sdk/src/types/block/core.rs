@@ -264,6 +264,7 @@ pub(crate) mod dto {
 
     /// The block object that nodes gossip around in the network.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct BlockDto {
         ///
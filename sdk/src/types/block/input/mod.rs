@@ -92,6 +92,7 @@ pub mod dto {
 
     /// Describes all the different input types.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, From)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(untagged)]
     pub enum InputDto {
         Utxo(UtxoInputDto),
@@ -61,6 +61,7 @@ pub(crate) mod dto {
 
     /// Describes an input which references an unspent treasury output to consume.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct TreasuryInputDto {
         #[serde(rename = "type")]
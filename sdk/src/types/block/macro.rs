@@ -140,11 +140,50 @@ macro_rules! string_serde_impl {
                 deserializer.deserialize_str(StringVisitor)
             }
         }
+
+        #[cfg(feature = "json_schema")]
+        impl schemars::JsonSchema for $type {
+            fn schema_name() -> alloc::string::String {
+                stringify!($type).into()
+            }
+
+            fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                schemars::schema::SchemaObject {
+                    instance_type: Some(schemars::schema::InstanceType::String.into()),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
     };
 }
 #[cfg(feature = "serde")]
 pub(crate) use string_serde_impl;
 
+/// Convenience macro to describe the JSON schema of a manually serialized DTO enum as one of the schemas of its
+/// variants.
+#[cfg(feature = "json_schema")]
+macro_rules! impl_json_schema_one_of {
+    ($type:ident, [$($variant:ty),+ $(,)?]) => {
+        impl schemars::JsonSchema for $type {
+            fn schema_name() -> alloc::string::String {
+                stringify!($type).into()
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                schemars::schema::SchemaObject {
+                    subschemas: Some(alloc::boxed::Box::new(schemars::schema::SubschemaValidation {
+                        one_of: Some(alloc::vec![$(gen.subschema_for::<$variant>()),+]),
+                        ..Default::default()
+                    })),
+                    ..Default::default()
+                }
+                .into()
+            }
+        }
+    };
+}
+
 /// Convenience macro to work around the fact the `[bitflags]` crate does not yet support iterating over the
 /// individual flags. This macro essentially creates the `[bitflags]` and puts the individual flags into an associated
 /// constant `pub const ALL_FLAGS: &'static []`.
@@ -731,6 +731,7 @@ pub(crate) mod dto {
 
     /// Describes an alias account in the ledger that can be controlled by the state and governance controllers.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct AliasOutputDto {
         #[serde(rename = "type")]
@@ -745,6 +746,7 @@ pub(crate) mod dto {
         // A counter that must increase by 1 every time the alias is state transitioned.
         pub state_index: u32,
         // Metadata that can only be changed by the state controller.
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         #[serde(skip_serializing_if = "<[_]>::is_empty", default, with = "prefix_hex_bytes")]
         pub state_metadata: Box<[u8]>,
         // A counter that denotes the number of foundries created by this alias account.
@@ -355,6 +355,7 @@ pub(crate) mod dto {
 
     /// Describes a basic output.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct BasicOutputDto {
         #[serde(rename = "type")]
@@ -346,6 +346,12 @@ pub mod dto {
         }
     }
 
+    #[cfg(feature = "json_schema")]
+    impl_json_schema_one_of!(
+        FeatureDto,
+        [SenderFeatureDto, IssuerFeatureDto, MetadataFeatureDto, TagFeatureDto]
+    );
+
     impl Serialize for FeatureDto {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
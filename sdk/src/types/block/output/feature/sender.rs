@@ -33,6 +33,7 @@ pub(crate) mod dto {
     use crate::types::block::address::dto::AddressDto;
 
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct SenderFeatureDto {
         #[serde(rename = "type")]
         pub kind: u8,
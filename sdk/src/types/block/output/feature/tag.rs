@@ -75,9 +75,11 @@ pub(crate) mod dto {
     use crate::utils::serde::prefix_hex_bytes;
 
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct TagFeatureDto {
         #[serde(rename = "type")]
         pub kind: u8,
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         #[serde(skip_serializing_if = "<[_]>::is_empty", default, with = "prefix_hex_bytes")]
         pub tag: Box<[u8]>,
     }
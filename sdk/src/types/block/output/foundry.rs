@@ -633,6 +633,7 @@ pub(crate) mod dto {
 
     /// Describes a foundry output that is controlled by an alias.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct FoundryOutputDto {
         #[serde(rename = "type")]
@@ -121,6 +121,7 @@ mod dto {
     use super::*;
 
     #[derive(Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     struct OutputMetadataDto {
         block_id: BlockId,
@@ -187,4 +188,15 @@ mod dto {
             OutputMetadataDto::from(self).serialize(s)
         }
     }
+
+    #[cfg(feature = "json_schema")]
+    impl schemars::JsonSchema for OutputMetadata {
+        fn schema_name() -> alloc::string::String {
+            "OutputMetadata".into()
+        }
+
+        fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+            OutputMetadataDto::json_schema(gen)
+        }
+    }
 }
@@ -617,6 +617,18 @@ pub mod dto {
         }
     }
 
+    #[cfg(feature = "json_schema")]
+    impl_json_schema_one_of!(
+        OutputDto,
+        [
+            TreasuryOutputDto,
+            BasicOutputDto,
+            AliasOutputDto,
+            FoundryOutputDto,
+            NftOutputDto
+        ]
+    );
+
     impl Serialize for OutputDto {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
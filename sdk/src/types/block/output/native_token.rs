@@ -17,12 +17,14 @@ use crate::types::block::{output::TokenId, Error};
 ///
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Packable)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[packable(unpack_error = Error)]
 pub struct NativeToken {
     // Identifier of the native token.
     #[cfg_attr(feature = "serde", serde(rename = "id"))]
     token_id: TokenId,
     // Amount of native tokens.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[packable(verify_with = verify_amount)]
     amount: U256,
 }
@@ -505,6 +505,7 @@ pub(crate) mod dto {
 
     /// Describes an NFT output, a globally unique token with metadata attached.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct NftOutputDto {
         #[serde(rename = "type")]
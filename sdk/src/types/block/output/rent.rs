@@ -33,6 +33,7 @@ type ConfirmationUnixTimestamp = u32;
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct RentStructure {
     /// Cost in tokens per virtual byte.
     v_byte_cost: u32,
@@ -55,6 +55,7 @@ pub(crate) mod dto {
     use crate::types::block::Error;
 
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, From)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(untagged)]
     pub enum TokenSchemeDto {
         /// A simple token scheme.
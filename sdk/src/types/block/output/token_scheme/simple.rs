@@ -125,15 +125,19 @@ pub(crate) mod dto {
 
     /// Describes a foundry output that is controlled by an alias.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct SimpleTokenSchemeDto {
         #[serde(rename = "type")]
         pub kind: u8,
         // Amount of tokens minted by a foundry.
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         pub minted_tokens: U256,
         // Amount of tokens melted by a foundry.
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         pub melted_tokens: U256,
         // Maximum supply of tokens controlled by a foundry.
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         pub maximum_supply: U256,
     }
 
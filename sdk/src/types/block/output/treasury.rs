@@ -60,6 +60,7 @@ pub(crate) mod dto {
 
     /// Describes a treasury output.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct TreasuryOutputDto {
         #[serde(rename = "type")]
         pub kind: u8,
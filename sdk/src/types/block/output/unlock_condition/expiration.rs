@@ -72,6 +72,7 @@ pub(crate) mod dto {
     use crate::types::block::{address::dto::AddressDto, Error};
 
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct ExpirationUnlockConditionDto {
         #[serde(rename = "type")]
@@ -36,6 +36,7 @@ pub(crate) mod dto {
     use crate::types::block::{address::dto::AddressDto, Error};
 
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct GovernorAddressUnlockConditionDto {
         #[serde(rename = "type")]
         pub kind: u8,
@@ -55,6 +55,7 @@ pub(crate) mod dto {
     use crate::types::block::{address::dto::AddressDto, Error};
 
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct ImmutableAliasAddressUnlockConditionDto {
         #[serde(rename = "type")]
         pub kind: u8,
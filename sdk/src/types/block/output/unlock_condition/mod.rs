@@ -585,6 +585,20 @@ pub mod dto {
         }
     }
 
+    #[cfg(feature = "json_schema")]
+    impl_json_schema_one_of!(
+        UnlockConditionDto,
+        [
+            AddressUnlockConditionDto,
+            StorageDepositReturnUnlockConditionDto,
+            TimelockUnlockConditionDto,
+            ExpirationUnlockConditionDto,
+            StateControllerAddressUnlockConditionDto,
+            GovernorAddressUnlockConditionDto,
+            ImmutableAliasAddressUnlockConditionDto
+        ]
+    );
+
     impl Serialize for UnlockConditionDto {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
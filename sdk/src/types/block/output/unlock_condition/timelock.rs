@@ -46,6 +46,7 @@ pub(crate) mod dto {
     use crate::types::block::Error;
 
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct TimelockUnlockConditionDto {
         #[serde(rename = "type")]
         pub kind: u8,
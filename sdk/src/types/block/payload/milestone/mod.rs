@@ -179,6 +179,7 @@ pub mod dto {
 
     /// The payload type to define a milestone.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct MilestonePayloadDto {
         #[serde(rename = "type")]
@@ -192,6 +193,7 @@ pub mod dto {
         pub applied_merkle_root: String,
         #[serde(skip_serializing_if = "Vec::is_empty", default)]
         pub options: Vec<MilestoneOptionDto>,
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         #[serde(skip_serializing_if = "<[_]>::is_empty", default, with = "prefix_hex_bytes")]
         pub metadata: Box<[u8]>,
         pub signatures: Vec<SignatureDto>,
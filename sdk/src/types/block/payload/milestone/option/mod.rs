@@ -211,6 +211,12 @@ pub mod dto {
         }
     }
 
+    #[cfg(feature = "json_schema")]
+    impl_json_schema_one_of!(
+        MilestoneOptionDto,
+        [ReceiptMilestoneOptionDto, ParametersMilestoneOptionDto]
+    );
+
     impl Serialize for MilestoneOptionDto {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
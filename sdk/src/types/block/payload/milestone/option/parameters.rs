@@ -76,6 +76,7 @@ pub(crate) mod dto {
 
     ///
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct ParametersMilestoneOptionDto {
         #[serde(rename = "type")]
@@ -83,6 +84,7 @@ pub(crate) mod dto {
         pub target_milestone_index: u32,
         pub protocol_version: u8,
         #[serde(rename = "params")]
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
         #[serde(skip_serializing_if = "<[_]>::is_empty", default, with = "prefix_hex_bytes")]
         pub binary_parameters: Box<[u8]>,
     }
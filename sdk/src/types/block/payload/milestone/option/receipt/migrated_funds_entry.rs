@@ -86,6 +86,7 @@ pub(crate) mod dto {
     };
 
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct MigratedFundsEntryDto {
         pub tail_transaction_hash: String,
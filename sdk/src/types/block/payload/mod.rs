@@ -226,6 +226,7 @@ pub mod dto {
 
     /// Describes all the different payload types.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(untagged)]
     pub enum PayloadDto {
         Transaction(Box<TransactionPayloadDto>),
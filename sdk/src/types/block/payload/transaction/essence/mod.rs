@@ -57,6 +57,7 @@ pub(crate) mod dto {
 
     /// Describes all the different essence types.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, From)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(untagged)]
     pub enum TransactionEssenceDto {
         Regular(RegularTransactionEssenceDto),
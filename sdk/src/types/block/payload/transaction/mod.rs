@@ -111,6 +111,7 @@ pub mod dto {
 
     /// The payload type to define a value transaction.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct TransactionPayloadDto {
         #[serde(rename = "type")]
         pub kind: u32,
@@ -81,6 +81,7 @@ pub mod dto {
 
     /// The payload type to define a treasury transaction.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct TreasuryTransactionPayloadDto {
         #[serde(rename = "type")]
         pub kind: u32,
@@ -17,12 +17,14 @@ use crate::types::block::{helper::network_name_to_id, output::RentStructure, Con
     derive(serde::Serialize, serde::Deserialize),
     serde(rename_all = "camelCase")
 )]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct ProtocolParameters {
     // The version of the protocol running.
     #[cfg_attr(feature = "serde", serde(rename = "version"))]
     protocol_version: u8,
     // The human friendly name of the network.
     #[packable(unpack_error_with = |err| Error::InvalidNetworkName(err.into_item_err()))]
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde::string_prefix"))]
     network_name: StringPrefix<u8>,
     // The HRP prefix used for Bech32 addresses in the network.
@@ -34,6 +36,7 @@ pub struct ProtocolParameters {
     // The rent structure used by given node/network.
     rent_structure: RentStructure,
     // TokenSupply defines the current token supply on the network.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[cfg_attr(feature = "serde", serde(with = "crate::utils::serde::string"))]
     token_supply: u64,
 }
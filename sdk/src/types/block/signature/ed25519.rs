@@ -138,6 +138,7 @@ pub(crate) mod dto {
 
     /// Defines an Ed25519 signature.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(rename_all = "camelCase")]
     pub struct Ed25519SignatureDto {
         #[serde(rename = "type")]
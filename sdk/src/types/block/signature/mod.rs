@@ -56,6 +56,7 @@ pub mod dto {
 
     /// Describes all the different signature types.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, From)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(untagged)]
     pub enum SignatureDto {
         Ed25519(Box<Ed25519SignatureDto>),
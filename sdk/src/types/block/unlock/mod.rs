@@ -238,6 +238,12 @@ pub mod dto {
         }
     }
 
+    #[cfg(feature = "json_schema")]
+    impl_json_schema_one_of!(
+        UnlockDto,
+        [SignatureUnlockDto, ReferenceUnlockDto, AliasUnlockDto, NftUnlockDto]
+    );
+
     impl Serialize for UnlockDto {
         fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where
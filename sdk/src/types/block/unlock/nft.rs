@@ -42,6 +42,7 @@ pub(crate) mod dto {
 
     /// Points to the unlock of a consumed NFT output.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct NftUnlockDto {
         #[serde(rename = "type")]
         pub kind: u8,
@@ -40,6 +40,7 @@ pub(crate) mod dto {
     /// References a previous unlock in order to substitute the duplication of the same unlock data for inputs which
     /// unlock through the same data.
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct ReferenceUnlockDto {
         #[serde(rename = "type")]
         pub kind: u8,
@@ -35,6 +35,7 @@ pub(crate) mod dto {
 
     /// Defines an unlock containing signature(s) unlocking input(s).
     #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     pub struct SignatureUnlockDto {
         #[serde(rename = "type")]
         pub kind: u8,
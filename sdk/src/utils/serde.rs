@@ -135,6 +135,7 @@ pub mod bip44 {
     use serde::{Deserialize, Serialize};
 
     #[derive(Default, Serialize, Deserialize)]
+    #[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
     #[serde(default = "default_bip44", rename_all = "camelCase", remote = "Bip44")]
     pub struct Bip44Def {
         coin_type: u32,
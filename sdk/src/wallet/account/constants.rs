@@ -17,3 +17,9 @@ pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
+
+/// Seconds before a claimable output expires in which
+/// [`WalletEvent::ExpirationApproaching`](crate::wallet::events::types::WalletEvent::ExpirationApproaching) is
+/// emitted, one day
+#[cfg(feature = "events")]
+pub(crate) const EXPIRATION_APPROACHING_PERIOD: u32 = 86400;
@@ -34,7 +34,7 @@ pub use self::{
         output_consolidation::ConsolidationParams,
         syncing::{
             options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
-            SyncOptions,
+            SyncCursor, SyncCursorKind, SyncOptions,
         },
         transaction::{
             high_level::{
@@ -48,7 +48,12 @@ pub use self::{
                 },
             },
             prepare_output::{Assets, Features, OutputParams, ReturnStrategy, StorageDeposit, Unlocks},
-            RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
+            PendingApproval, PolicyViolation, RemainderValueStrategy, RollingSpendingLimit, SpendingPolicy,
+            TransactionOptions, TransactionOptionsDto,
+        },
+        transaction_history::{
+            NativeTokenMovement, TransactionDirection, TransactionHistoryEntry, TransactionHistoryFormat,
+            TransactionHistoryOptions,
         },
     },
     types::OutputDataDto,
@@ -62,6 +67,7 @@ use crate::{
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
+            address::Bech32Address,
             output::{dto::FoundryOutputDto, AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, TokenId},
             payload::{
                 transaction::{TransactionEssence, TransactionId},
@@ -75,6 +81,7 @@ use crate::{
 
 /// Options to filter outputs
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct FilterOptions {
     /// Filter all outputs where the booked milestone index is below the specified timestamp
@@ -167,6 +174,16 @@ pub struct AccountInner {
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    pub(crate) spending_policy: Mutex<SpendingPolicy>,
+    pub(crate) pending_approvals: Mutex<Vec<PendingApproval>>,
+    // unix timestamp in seconds up to which expiration events were emitted, kept across restarts so they aren't
+    // emitted again
+    #[cfg(feature = "events")]
+    pub(crate) expiration_events_time: Mutex<Option<u32>>,
+    // the positions up to which the addresses were synced, used for incremental syncing
+    pub(crate) sync_cursors: Mutex<HashMap<(Bech32Address, SyncCursorKind), SyncCursor>>,
+    // ledger indexes returned by the indexer during the current sync, they only become cursors once it succeeded
+    pub(crate) pending_sync_cursors: Mutex<HashMap<(Bech32Address, SyncCursorKind), u32>>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -195,12 +212,64 @@ where
         #[cfg(not(feature = "storage"))]
         let default_sync_options = Default::default();
 
+        #[cfg(feature = "storage")]
+        let sync_cursors = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_sync_cursors(*details.index())
+            .await?
+            .unwrap_or_default()
+            .into_iter()
+            .map(|cursor| ((cursor.address, cursor.kind), cursor))
+            .collect();
+        #[cfg(not(feature = "storage"))]
+        let sync_cursors = Default::default();
+
+        #[cfg(feature = "storage")]
+        let spending_policy = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_spending_policy(*details.index())
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let spending_policy = Default::default();
+
+        #[cfg(feature = "storage")]
+        let pending_approvals = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_pending_approvals(*details.index())
+            .await?
+            .unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let pending_approvals = Default::default();
+
+        #[cfg(all(feature = "events", feature = "storage"))]
+        let expiration_events_time = wallet
+            .storage_manager
+            .read()
+            .await
+            .get_expiration_events_time(*details.index())
+            .await?;
+        #[cfg(all(feature = "events", not(feature = "storage")))]
+        let expiration_events_time = None;
+
         Ok(Self {
             wallet,
             inner: Arc::new(AccountInner {
                 details: RwLock::new(details),
                 last_synced: Default::default(),
                 default_sync_options: Mutex::new(default_sync_options),
+                spending_policy: Mutex::new(spending_policy),
+                pending_approvals: Mutex::new(pending_approvals),
+                #[cfg(feature = "events")]
+                expiration_events_time: Mutex::new(expiration_events_time),
+                sync_cursors: Mutex::new(sync_cursors),
+                pending_sync_cursors: Default::default(),
             }),
         })
     }
@@ -231,25 +300,36 @@ where
     }
 
     /// Save the account to the database, accepts the updated_account as option so we don't need to drop it before
-    /// saving
+    /// saving. Failures are also emitted as [`WalletEvent::StorageError`](super::events::types::WalletEvent).
     #[cfg(feature = "storage")]
     pub(crate) async fn save(&self, updated_account: Option<&AccountDetails>) -> Result<()> {
         log::debug!("[save] saving account to database");
-        match updated_account {
+        let result = match updated_account {
             Some(account) => {
                 let mut storage_manager = self.wallet.storage_manager.write().await;
-                storage_manager.save_account(account).await?;
-                drop(storage_manager);
+                storage_manager.save_account(account).await
             }
             None => {
                 let account_details = self.details().await;
                 let mut storage_manager = self.wallet.storage_manager.write().await;
-                storage_manager.save_account(&account_details).await?;
-                drop(storage_manager);
-                drop(account_details);
+                storage_manager.save_account(&account_details).await
             }
+        };
+        #[cfg(feature = "events")]
+        if let Err(err) = &result {
+            let account_index = match updated_account {
+                Some(account) => account.index,
+                None => self.details().await.index,
+            };
+            self.emit(
+                account_index,
+                super::events::types::WalletEvent::StorageError(super::events::types::StorageErrorEvent {
+                    error: err.to_string(),
+                }),
+            )
+            .await;
         }
-        Ok(())
+        result
     }
 
     #[cfg(feature = "events")]
@@ -463,12 +543,14 @@ pub(crate) fn build_transaction_from_payload_and_inputs(
         network_id: tx_essence.network_id(),
         incoming: true,
         note: None,
+        approver: None,
         inputs,
     })
 }
 
 /// Dto for an Account.
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct AccountDetailsDto {
     /// The account index
@@ -659,6 +741,7 @@ fn serialize() {
         inclusion_state: InclusionState::Pending,
         incoming: false,
         note: None,
+        approver: None,
         inputs: Vec::new(),
     };
 
@@ -23,6 +23,7 @@ use crate::{
 
 /// Enum to specify which outputs should be claimed
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub enum OutputsToClaim {
     MicroTransactions,
@@ -38,6 +38,7 @@ use crate::wallet::{
 };
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ConsolidationParams {
     /// Ignores the output_threshold if set to `true`.
@@ -33,6 +33,7 @@ use crate::{
 
 /// An object containing an account's entire participation overview.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct AccountParticipationOverview {
     /// Output participations for events.
     pub participations: HashMap<ParticipationEventId, HashMap<OutputId, TrackedParticipation>>,
@@ -40,6 +41,7 @@ pub struct AccountParticipationOverview {
 
 /// A participation event with the provided client nodes.
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct ParticipationEventWithNodes {
     /// The event id.
     pub id: ParticipationEventId,
@@ -204,7 +206,8 @@ where
                             }
                         }
                     }
-                    Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {}
+                    Err(crate::client::Error::Node(e))
+                        if matches!(e.root_cause(), crate::client::node_api::error::Error::NotFound(_)) => {}
                     Err(e) => return Err(crate::wallet::Error::Client(e.into())),
                 }
             }
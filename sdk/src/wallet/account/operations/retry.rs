@@ -2,7 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    client::{secret::SecretManage, Error as ClientError},
+    client::{
+        operation::{Operation, OperationProgress},
+        secret::SecretManage,
+        Error as ClientError,
+    },
     types::{
         api::core::response::LedgerInclusionState,
         block::{
@@ -77,15 +81,16 @@ where
 
             // Attachments of the Block to check inclusion state
             let mut block_ids = vec![block_id];
-            for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
+            for attempt in 1..=max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
                 let duration =
                     std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
 
                 #[cfg(target_family = "wasm")]
-                gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+                Operation::cancellable(gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32)).await?;
 
                 #[cfg(not(target_family = "wasm"))]
-                tokio::time::sleep(duration).await;
+                Operation::cancellable(tokio::time::sleep(duration)).await?;
+                Operation::report_progress(OperationProgress::RetryingBlock { block_id, attempt });
 
                 // Check inclusion state for each attachment
                 let block_ids_len = block_ids.len();
@@ -121,7 +126,7 @@ where
                 // and confirmed
                 if conflicting {
                     let included_block = self.client().get_included_block(transaction_id).await.map_err(|e| {
-                        if matches!(e, ClientError::Node(crate::client::node_api::error::Error::NotFound(_))) {
+                        if matches!(&e, ClientError::Node(e) if matches!(e.root_cause(), crate::client::node_api::error::Error::NotFound(_))) {
                             // If no block was found with this transaction id, then it can't get included
                             ClientError::TangleInclusion(block_id.to_string())
                         } else {
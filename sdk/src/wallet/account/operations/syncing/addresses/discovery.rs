@@ -0,0 +1,93 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::secret::{GenerateAddressOptions, SecretManage},
+    wallet::account::{operations::syncing::SyncOptions, types::address::AccountAddress, Account},
+};
+
+/// Returns the number of addresses that need to be generated, so `address_gap_limit` unused addresses follow the last
+/// used one.
+fn missing_addresses(addresses: &[AccountAddress], address_gap_limit: u32) -> u32 {
+    let next_index = addresses.len() as u32;
+    let first_unused_index = addresses
+        .iter()
+        .filter(|a| a.used)
+        .map(|a| a.key_index + 1)
+        .max()
+        .unwrap_or(0);
+    address_gap_limit.saturating_sub(next_index.saturating_sub(first_unused_index))
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Generates and syncs new public and internal addresses until `address_gap_limit` unused addresses follow the
+    /// last used one of each kind. Returns the number of synced addresses and unspent outputs.
+    pub(crate) async fn discover_addresses(&self, options: &SyncOptions) -> crate::wallet::Result<(usize, usize)> {
+        log::debug!("[SYNC] discover_addresses");
+        let (mut synced_addresses, mut synced_outputs) = (0, 0);
+
+        loop {
+            let (public_start_index, public_amount, internal_start_index, internal_amount) = {
+                let account_details = self.details().await;
+                (
+                    account_details.public_addresses.len() as u32,
+                    missing_addresses(&account_details.public_addresses, options.address_gap_limit),
+                    account_details.internal_addresses.len() as u32,
+                    missing_addresses(&account_details.internal_addresses, options.address_gap_limit),
+                )
+            };
+            log::debug!("[SYNC] missing addresses: {public_amount}, internal: {internal_amount}");
+            // Stop once the new addresses didn't receive outputs
+            if public_amount == 0 && internal_amount == 0 {
+                break;
+            }
+
+            self.generate_ed25519_addresses(public_amount, None).await?;
+            self.generate_ed25519_addresses(internal_amount, Some(GenerateAddressOptions::internal()))
+                .await?;
+
+            // Only sync the new addresses
+            let sync_options = SyncOptions {
+                address_start_index: public_start_index,
+                address_start_index_internal: internal_start_index,
+                ..options.clone()
+            };
+            let (addresses, outputs) = self.sync_internal(&sync_options).await?;
+            synced_addresses += addresses;
+            synced_outputs += outputs;
+        }
+
+        Ok((synced_addresses, synced_outputs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::address::{Bech32Address, Ed25519Address};
+
+    fn addresses(used: &[bool]) -> Vec<AccountAddress> {
+        used.iter()
+            .enumerate()
+            .map(|(key_index, used)| AccountAddress {
+                address: Bech32Address::new("rms".parse().unwrap(), Ed25519Address::new([0; 32])),
+                key_index: key_index as u32,
+                internal: false,
+                used: *used,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn missing_addresses_to_gap_limit() {
+        assert_eq!(missing_addresses(&[], 5), 5);
+        assert_eq!(missing_addresses(&addresses(&[false]), 5), 4);
+        assert_eq!(missing_addresses(&addresses(&[true]), 5), 5);
+        assert_eq!(missing_addresses(&addresses(&[true, false, true, false]), 5), 4);
+        assert_eq!(missing_addresses(&addresses(&[true, false, false, false]), 2), 0);
+        assert_eq!(missing_addresses(&addresses(&[true, false]), 0), 0);
+    }
+}
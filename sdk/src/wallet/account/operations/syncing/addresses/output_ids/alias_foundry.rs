@@ -5,16 +5,13 @@ use std::collections::HashSet;
 
 use crate::{
     client::{node_api::indexer::query_parameters::QueryParameter, secret::SecretManage},
-    types::{
-        api::plugins::indexer::OutputIdsResponse,
-        block::{
-            address::{AliasAddress, Bech32Address, ToBech32Ext},
-            output::{Output, OutputId},
-            ConvertTo,
-        },
+    types::block::{
+        address::{AliasAddress, Bech32Address, ToBech32Ext},
+        output::{Output, OutputId},
+        ConvertTo,
     },
     wallet::{
-        account::{Account, SyncOptions},
+        account::{operations::syncing::SyncCursorKind, Account, SyncOptions},
         task,
     },
 };
@@ -28,30 +25,56 @@ where
         &self,
         bech32_address: impl ConvertTo<Bech32Address>,
         sync_options: &SyncOptions,
-    ) -> crate::wallet::Result<Vec<OutputId>> {
+    ) -> crate::wallet::Result<(Vec<OutputId>, bool)> {
         log::debug!("[SYNC] get_alias_and_foundry_output_ids");
         let bech32_address = bech32_address.convert()?;
 
-        let mut output_ids = self
-            .client()
-            .alias_output_ids([QueryParameter::UnlockableByAddress(bech32_address)])
-            .await?
-            .items;
+        let (mut output_ids, mut incremental) = self
+            .query_output_ids(
+                bech32_address,
+                SyncCursorKind::Alias,
+                vec![QueryParameter::UnlockableByAddress(bech32_address)],
+                sync_options.incremental,
+            )
+            .await?;
 
         // Get all results
         if sync_options.alias.foundry_outputs {
-            let foundry_output_ids = self.get_foundry_output_ids(&output_ids).await?;
+            let mut alias_output_ids = output_ids.clone();
+            if incremental {
+                // Known aliases could have created new foundries too
+                alias_output_ids.extend(
+                    self.details()
+                        .await
+                        .unspent_outputs
+                        .values()
+                        .filter(|output_data| match &output_data.output {
+                            Output::Alias(alias_output) => {
+                                alias_output.state_controller_address() == bech32_address.inner()
+                                    || alias_output.governor_address() == bech32_address.inner()
+                            }
+                            _ => false,
+                        })
+                        .map(|output_data| output_data.output_id)
+                        .filter(|output_id| !output_ids.contains(output_id)),
+                );
+            }
+            let (foundry_output_ids, foundries_incremental) = self
+                .get_foundry_output_ids(&alias_output_ids, sync_options.incremental)
+                .await?;
             output_ids.extend(foundry_output_ids);
+            incremental |= foundries_incremental;
         }
 
-        Ok(output_ids.into_iter().collect())
+        Ok((output_ids.into_iter().collect(), incremental))
     }
 
     /// Returns output ids of foundries controlled by the provided aliases
     pub(crate) async fn get_foundry_output_ids(
         &self,
         alias_output_ids: &[OutputId],
-    ) -> crate::wallet::Result<Vec<OutputId>> {
+        incremental: bool,
+    ) -> crate::wallet::Result<(Vec<OutputId>, bool)> {
         log::debug!("[SYNC] get_foundry_output_ids");
         // Get alias outputs, so we can then get the foundry outputs with the alias addresses
         let alias_outputs_with_meta = self.get_outputs(alias_output_ids.to_vec()).await?;
@@ -65,24 +88,30 @@ where
                 let alias_address =
                     AliasAddress::from(alias_output.alias_id_non_null(alias_output_with_meta.metadata().output_id()));
                 let alias_bech32_address = alias_address.to_bech32(bech32_hrp);
-                let client = self.client().clone();
+                let account = self.clone();
                 tasks.push(Box::pin(task::spawn(async move {
-                    client
-                        .foundry_output_ids([QueryParameter::AliasAddress(alias_bech32_address)])
+                    account
+                        .query_output_ids(
+                            alias_bech32_address,
+                            SyncCursorKind::Foundry,
+                            vec![QueryParameter::AliasAddress(alias_bech32_address)],
+                            incremental,
+                        )
                         .await
-                        .map_err(From::from)
                 })));
             }
         }
 
         let mut output_ids = HashSet::new();
-        let results: Vec<crate::wallet::Result<OutputIdsResponse>> = futures::future::try_join_all(tasks).await?;
+        let mut foundries_incremental = false;
+        let results: Vec<crate::wallet::Result<(Vec<OutputId>, bool)>> = futures::future::try_join_all(tasks).await?;
 
         for res in results {
-            let foundry_output_ids = res?;
-            output_ids.extend(foundry_output_ids.items);
+            let (foundry_output_ids, found_incremental) = res?;
+            output_ids.extend(foundry_output_ids);
+            foundries_incremental |= found_incremental;
         }
 
-        Ok(output_ids.into_iter().collect())
+        Ok((output_ids.into_iter().collect(), foundries_incremental))
     }
 }
@@ -4,7 +4,7 @@
 use crate::{
     client::{node_api::indexer::query_parameters::QueryParameter, secret::SecretManage},
     types::block::{address::Bech32Address, output::OutputId, ConvertTo},
-    wallet::Account,
+    wallet::{account::operations::syncing::SyncCursorKind, Account},
 };
 
 impl<S: 'static + SecretManage> Account<S>
@@ -15,19 +15,22 @@ where
     pub(crate) async fn get_basic_output_ids_with_address_unlock_condition_only(
         &self,
         bech32_address: impl ConvertTo<Bech32Address>,
-    ) -> crate::client::Result<Vec<OutputId>> {
+        incremental: bool,
+    ) -> crate::wallet::Result<(Vec<OutputId>, bool)> {
         let bech32_address = bech32_address.convert()?;
         // Only request basic outputs with `AddressUnlockCondition` only
-        Ok(self
-            .client()
-            .basic_output_ids([
+        self.query_output_ids(
+            bech32_address,
+            SyncCursorKind::BasicAddressOnly,
+            vec![
                 QueryParameter::Address(bech32_address),
                 QueryParameter::HasExpiration(false),
                 QueryParameter::HasTimelock(false),
                 QueryParameter::HasStorageDepositReturn(false),
-            ])
-            .await?
-            .items)
+            ],
+            incremental,
+        )
+        .await
     }
 
     /// Returns output ids of basic outputs that have the address in the `AddressUnlockCondition`,
@@ -35,13 +38,16 @@ where
     pub(crate) async fn get_basic_output_ids_with_any_unlock_condition(
         &self,
         bech32_address: impl ConvertTo<Bech32Address>,
-    ) -> crate::wallet::Result<Vec<OutputId>> {
+        incremental: bool,
+    ) -> crate::wallet::Result<(Vec<OutputId>, bool)> {
         let bech32_address = bech32_address.convert()?;
 
-        Ok(self
-            .client()
-            .basic_output_ids([QueryParameter::UnlockableByAddress(bech32_address)])
-            .await?
-            .items)
+        self.query_output_ids(
+            bech32_address,
+            SyncCursorKind::Basic,
+            vec![QueryParameter::UnlockableByAddress(bech32_address)],
+            incremental,
+        )
+        .await
     }
 }
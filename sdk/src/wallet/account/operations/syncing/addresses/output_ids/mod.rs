@@ -11,6 +11,8 @@ use std::collections::HashSet;
 use futures::FutureExt;
 use instant::Instant;
 
+#[cfg(not(target_family = "wasm"))]
+use crate::wallet::task;
 use crate::{
     client::{node_api::indexer::QueryParameter, secret::SecretManage},
     types::block::{
@@ -18,8 +20,10 @@ use crate::{
         output::OutputId,
     },
     wallet::account::{
-        constants::PARALLEL_REQUESTS_AMOUNT, operations::syncing::SyncOptions,
-        types::address::AddressWithUnspentOutputs, Account,
+        constants::PARALLEL_REQUESTS_AMOUNT,
+        operations::syncing::{SyncCursorKind, SyncOptions},
+        types::address::AddressWithUnspentOutputs,
+        Account,
     },
 };
 
@@ -29,19 +33,18 @@ where
 {
     /// Returns output ids for outputs that are directly (Ed25519 address in AddressUnlockCondition) or indirectly
     /// (alias/nft address in AddressUnlockCondition and the alias/nft output is controlled with the Ed25519 address)
-    /// connected to
+    /// connected to. Also returns whether only output ids created since the last sync were requested.
     pub(crate) async fn get_output_ids_for_address(
         &self,
         address: Address,
         sync_options: &SyncOptions,
-    ) -> crate::wallet::Result<Vec<OutputId>> {
+    ) -> crate::wallet::Result<(Vec<OutputId>, bool)> {
         let bech32_address = Bech32Address::new(self.client().get_bech32_hrp().await?, address);
 
         if sync_options.sync_only_most_basic_outputs {
-            let output_ids = self
-                .get_basic_output_ids_with_address_unlock_condition_only(bech32_address)
-                .await?;
-            return Ok(output_ids);
+            return self
+                .get_basic_output_ids_with_address_unlock_condition_only(bech32_address, sync_options.incremental)
+                .await;
         }
 
         // If interested in alias, basic, NFT and foundry outputs, get them all at once
@@ -49,11 +52,14 @@ where
             || (address.is_nft() && sync_options.nft.all_outputs())
             || (address.is_alias() && sync_options.alias.all_outputs())
         {
-            return Ok(self
-                .client()
-                .output_ids([QueryParameter::UnlockableByAddress(bech32_address)])
-                .await?
-                .items);
+            return self
+                .query_output_ids(
+                    bech32_address,
+                    SyncCursorKind::Outputs,
+                    vec![QueryParameter::UnlockableByAddress(bech32_address)],
+                    sync_options.incremental,
+                )
+                .await;
         }
 
         #[cfg(target_family = "wasm")]
@@ -70,7 +76,7 @@ where
             #[cfg(target_family = "wasm")]
             {
                 results.push(
-                    self.get_basic_output_ids_with_any_unlock_condition(bech32_address)
+                    self.get_basic_output_ids_with_any_unlock_condition(bech32_address, sync_options.incremental)
                         .await,
                 )
             }
@@ -80,9 +86,10 @@ where
                 tasks.push(
                     async move {
                         let account = self.clone();
-                        tokio::spawn(async move {
+                        let incremental = sync_options.incremental;
+                        task::spawn(async move {
                             account
-                                .get_basic_output_ids_with_any_unlock_condition(bech32_address)
+                                .get_basic_output_ids_with_any_unlock_condition(bech32_address, incremental)
                                 .await
                         })
                         .await
@@ -99,7 +106,10 @@ where
             // nfts
             #[cfg(target_family = "wasm")]
             {
-                results.push(self.get_nft_output_ids_with_any_unlock_condition(bech32_address).await)
+                results.push(
+                    self.get_nft_output_ids_with_any_unlock_condition(bech32_address, sync_options.incremental)
+                        .await,
+                )
             }
 
             #[cfg(not(target_family = "wasm"))]
@@ -107,9 +117,10 @@ where
                 tasks.push(
                     async move {
                         let account = self.clone();
-                        tokio::spawn(async move {
+                        let incremental = sync_options.incremental;
+                        task::spawn(async move {
                             account
-                                .get_nft_output_ids_with_any_unlock_condition(bech32_address)
+                                .get_nft_output_ids_with_any_unlock_condition(bech32_address, incremental)
                                 .await
                         })
                         .await
@@ -138,7 +149,7 @@ where
                     async move {
                         let sync_options = sync_options.clone();
                         let account = self.clone();
-                        tokio::spawn(async move {
+                        task::spawn(async move {
                             account
                                 .get_alias_and_foundry_output_ids(bech32_address, &sync_options)
                                 .await
@@ -152,23 +163,32 @@ where
             // foundries
             #[cfg(target_family = "wasm")]
             {
-                results.push(Ok(self
-                    .client()
-                    .foundry_output_ids([QueryParameter::AliasAddress(bech32_address)])
-                    .await?
-                    .items))
+                results.push(
+                    self.query_output_ids(
+                        bech32_address,
+                        SyncCursorKind::Foundry,
+                        vec![QueryParameter::AliasAddress(bech32_address)],
+                        sync_options.incremental,
+                    )
+                    .await,
+                )
             }
 
             #[cfg(not(target_family = "wasm"))]
             {
                 tasks.push(
                     async move {
-                        let client = self.client().clone();
-                        tokio::spawn(async move {
-                            Ok(client
-                                .foundry_output_ids([QueryParameter::AliasAddress(bech32_address)])
-                                .await?
-                                .items)
+                        let account = self.clone();
+                        let incremental = sync_options.incremental;
+                        task::spawn(async move {
+                            account
+                                .query_output_ids(
+                                    bech32_address,
+                                    SyncCursorKind::Foundry,
+                                    vec![QueryParameter::AliasAddress(bech32_address)],
+                                    incremental,
+                                )
+                                .await
                         })
                         .await
                     }
@@ -182,12 +202,40 @@ where
 
         // Get all results
         let mut output_ids = HashSet::new();
+        let mut incremental = false;
         for res in results {
-            let found_output_ids = res?;
+            let (found_output_ids, found_incremental) = res?;
             output_ids.extend(found_output_ids);
+            incremental |= found_incremental;
         }
 
-        Ok(output_ids.into_iter().collect())
+        Ok((output_ids.into_iter().collect(), incremental))
+    }
+
+    /// An incremental sync only returns new output ids, so the known ones that are still unspent are added to them
+    async fn add_unspent_known_output_ids(
+        &self,
+        known_output_ids: &[OutputId],
+        mut output_ids: Vec<OutputId>,
+    ) -> crate::wallet::Result<Vec<OutputId>> {
+        let unchecked_output_ids = known_output_ids
+            .iter()
+            .filter(|output_id| !output_ids.contains(output_id))
+            .copied()
+            .collect::<Vec<_>>();
+        if !unchecked_output_ids.is_empty() {
+            let outputs_metadata = self
+                .client()
+                .get_outputs_metadata_ignore_errors(&unchecked_output_ids)
+                .await?;
+            output_ids.extend(
+                outputs_metadata
+                    .iter()
+                    .filter(|output_metadata| !output_metadata.is_spent())
+                    .map(|output_metadata| *output_metadata.output_id()),
+            );
+        }
+        Ok(output_ids)
     }
 
     /// Get the current output ids for provided addresses and only returns addresses that have unspent outputs and
@@ -213,7 +261,13 @@ where
             {
                 let mut tasks = Vec::new();
                 for address in addresses_chunk {
-                    let output_ids = self.get_output_ids_for_address(address.address.inner, &options).await?;
+                    let (mut output_ids, incremental) =
+                        self.get_output_ids_for_address(address.address.inner, &options).await?;
+                    if incremental {
+                        output_ids = self
+                            .add_unspent_known_output_ids(&address.output_ids, output_ids)
+                            .await?;
+                    }
                     tasks.push(crate::wallet::Result::Ok((address, output_ids)));
                 }
                 results = tasks;
@@ -226,10 +280,15 @@ where
                     let account = self.clone();
                     let sync_options = options.clone();
                     tasks.push(async move {
-                        tokio::spawn(async move {
-                            let output_ids = account
+                        task::spawn(async move {
+                            let (mut output_ids, incremental) = account
                                 .get_output_ids_for_address(address.address.inner, &sync_options)
                                 .await?;
+                            if incremental {
+                                output_ids = account
+                                    .add_unspent_known_output_ids(&address.output_ids, output_ids)
+                                    .await?;
+                            }
                             crate::wallet::Result::Ok((address, output_ids))
                         })
                         .await
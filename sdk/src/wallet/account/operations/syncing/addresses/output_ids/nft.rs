@@ -4,7 +4,7 @@
 use crate::{
     client::{node_api::indexer::query_parameters::QueryParameter, secret::SecretManage},
     types::block::{address::Bech32Address, output::OutputId, ConvertTo},
-    wallet::Account,
+    wallet::{account::operations::syncing::SyncCursorKind, Account},
 };
 
 impl<S: 'static + SecretManage> Account<S>
@@ -15,13 +15,16 @@ where
     pub(crate) async fn get_nft_output_ids_with_any_unlock_condition(
         &self,
         bech32_address: impl ConvertTo<Bech32Address>,
-    ) -> crate::wallet::Result<Vec<OutputId>> {
+        incremental: bool,
+    ) -> crate::wallet::Result<(Vec<OutputId>, bool)> {
         let bech32_address = bech32_address.convert()?;
 
-        Ok(self
-            .client()
-            .nft_output_ids([QueryParameter::UnlockableByAddress(bech32_address)])
-            .await?
-            .items)
+        self.query_output_ids(
+            bech32_address,
+            SyncCursorKind::Nft,
+            vec![QueryParameter::UnlockableByAddress(bech32_address)],
+            incremental,
+        )
+        .await
     }
 }
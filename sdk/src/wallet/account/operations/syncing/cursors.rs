@@ -0,0 +1,140 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{node_api::indexer::query_parameters::QueryParameter, secret::SecretManage},
+    types::block::{address::Bech32Address, output::OutputId},
+    wallet::account::Account,
+};
+
+/// The indexer query a [`SyncCursor`] belongs to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SyncCursorKind {
+    /// Basic, alias, NFT and foundry outputs unlockable by the address.
+    Outputs,
+    /// Basic outputs unlockable by the address.
+    Basic,
+    /// Basic outputs with only an address unlock condition.
+    BasicAddressOnly,
+    /// Alias outputs unlockable by the address.
+    Alias,
+    /// NFT outputs unlockable by the address.
+    Nft,
+    /// Foundry outputs controlled by the alias address.
+    Foundry,
+}
+
+/// The position up to which the outputs of an address were synced.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncCursor {
+    /// The synced address.
+    pub address: Bech32Address,
+    /// The synced output kind.
+    pub kind: SyncCursorKind,
+    /// The ledger index at which the indexer returned the output ids.
+    pub ledger_index: u32,
+    /// The milestone timestamp of the ledger index.
+    pub timestamp: u32,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Returns the positions up to which the addresses of the account were synced.
+    pub async fn sync_cursors(&self) -> Vec<SyncCursor> {
+        self.sync_cursors.lock().await.values().cloned().collect()
+    }
+
+    /// Requests the output ids for an address. If `incremental` is set and the address was synced before, only the
+    /// output ids created after its cursor are requested. Returns the output ids and whether the request was
+    /// incremental.
+    pub(crate) async fn query_output_ids(
+        &self,
+        address: Bech32Address,
+        kind: SyncCursorKind,
+        mut query_parameters: Vec<QueryParameter>,
+        incremental: bool,
+    ) -> crate::wallet::Result<(Vec<OutputId>, bool)> {
+        let timestamp = if incremental {
+            self.sync_cursors
+                .lock()
+                .await
+                .get(&(address, kind))
+                .map(|cursor| cursor.timestamp)
+        } else {
+            None
+        };
+        if let Some(timestamp) = timestamp {
+            // Outputs of a later milestone with the same timestamp must not be missed, requesting the ones of the
+            // cursor milestone again doesn't matter
+            query_parameters.push(QueryParameter::CreatedAfter(timestamp.saturating_sub(1)));
+        }
+
+        let client = self.client();
+        let response = match kind {
+            SyncCursorKind::Outputs => client.output_ids(query_parameters).await?,
+            SyncCursorKind::Basic | SyncCursorKind::BasicAddressOnly => {
+                client.basic_output_ids(query_parameters).await?
+            }
+            SyncCursorKind::Alias => client.alias_output_ids(query_parameters).await?,
+            SyncCursorKind::Nft => client.nft_output_ids(query_parameters).await?,
+            SyncCursorKind::Foundry => client.foundry_output_ids(query_parameters).await?,
+        };
+
+        // The ledger index is 0 if no page was returned, then there is nothing to remember
+        if response.ledger_index != 0 {
+            self.pending_sync_cursors
+                .lock()
+                .await
+                .insert((address, kind), response.ledger_index);
+        }
+
+        Ok((response.items, timestamp.is_some()))
+    }
+
+    /// Turns the ledger indexes returned during the current sync into cursors and stores them.
+    pub(crate) async fn update_sync_cursors(&self) -> crate::wallet::Result<()> {
+        let pending_sync_cursors = std::mem::take(&mut *self.pending_sync_cursors.lock().await);
+
+        let mut timestamps = HashMap::new();
+        for ledger_index in pending_sync_cursors.values() {
+            if !timestamps.contains_key(ledger_index) {
+                let milestone = self.client().get_milestone_by_index(*ledger_index).await?;
+                timestamps.insert(*ledger_index, milestone.essence().timestamp());
+            }
+        }
+
+        let mut sync_cursors = self.sync_cursors.lock().await;
+        for ((address, kind), ledger_index) in pending_sync_cursors {
+            sync_cursors.insert(
+                (address, kind),
+                SyncCursor {
+                    address,
+                    kind,
+                    ledger_index,
+                    timestamp: timestamps[&ledger_index],
+                },
+            );
+        }
+
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            self.wallet
+                .storage_manager
+                .read()
+                .await
+                .set_sync_cursors(index, &sync_cursors.values().cloned().collect::<Vec<_>>())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::address::Bech32Address,
+    wallet::{
+        account::{constants::EXPIRATION_APPROACHING_PERIOD, Account, Balance},
+        events::types::{BalanceChangeEvent, ExpirationEvent, NativeTokensEvent, NftEvent, WalletEvent},
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Emits the native tokens and NFTs that were received or sent between the two balances, followed by a
+    /// [`WalletEvent::BalanceChange`]. Nothing is emitted if the balance didn't change.
+    pub(crate) async fn emit_balance_change_events(&self, account_index: u32, previous: Balance, current: &Balance) {
+        if &previous == current {
+            return;
+        }
+
+        for native_token in current.native_tokens.iter() {
+            let previous_total = previous
+                .native_tokens
+                .iter()
+                .find(|previous_native_token| previous_native_token.token_id == native_token.token_id)
+                .map(|previous_native_token| previous_native_token.total)
+                .unwrap_or_default();
+            if native_token.total > previous_total {
+                self.emit(
+                    account_index,
+                    WalletEvent::NativeTokensReceived(NativeTokensEvent {
+                        token_id: native_token.token_id,
+                        amount: native_token.total - previous_total,
+                    }),
+                )
+                .await;
+            }
+        }
+        for previous_native_token in previous.native_tokens.iter() {
+            let current_total = current
+                .native_tokens
+                .iter()
+                .find(|native_token| native_token.token_id == previous_native_token.token_id)
+                .map(|native_token| native_token.total)
+                .unwrap_or_default();
+            if previous_native_token.total > current_total {
+                self.emit(
+                    account_index,
+                    WalletEvent::NativeTokensSent(NativeTokensEvent {
+                        token_id: previous_native_token.token_id,
+                        amount: previous_native_token.total - current_total,
+                    }),
+                )
+                .await;
+            }
+        }
+
+        for nft_id in current.nfts.iter().filter(|nft_id| !previous.nfts.contains(nft_id)) {
+            let label = self.wallet.nft_label(nft_id).await;
+            self.emit(
+                account_index,
+                WalletEvent::NftReceived(NftEvent { nft_id: *nft_id, label }),
+            )
+            .await;
+        }
+        for nft_id in previous.nfts.iter().filter(|nft_id| !current.nfts.contains(nft_id)) {
+            let label = self.wallet.nft_label(nft_id).await;
+            self.emit(account_index, WalletEvent::NftSent(NftEvent { nft_id: *nft_id, label }))
+                .await;
+        }
+
+        self.emit(
+            account_index,
+            WalletEvent::BalanceChange(Box::new(BalanceChangeEvent {
+                previous,
+                current: current.clone(),
+            })),
+        )
+        .await;
+    }
+
+    /// Emits [`WalletEvent::ExpirationApproaching`] for claimable outputs which will expire within
+    /// [`EXPIRATION_APPROACHING_PERIOD`] and [`WalletEvent::OutputExpired`] for the ones that expired, if it happened
+    /// since the last call and `current_time`, a unix timestamp in seconds. The time of the last call is persisted, so
+    /// events aren't emitted again after a restart; nothing is emitted on the first call.
+    pub(crate) async fn emit_expiration_events(&self, account_index: u32, current_time: u32) {
+        let mut expiration_events_time = self.expiration_events_time.lock().await;
+        let previous_time = expiration_events_time.unwrap_or(current_time);
+        *expiration_events_time = Some(current_time);
+        #[cfg(feature = "storage")]
+        if let Err(err) = self
+            .wallet
+            .storage_manager
+            .read()
+            .await
+            .set_expiration_events_time(account_index, current_time)
+            .await
+        {
+            log::warn!("[SYNC] failed to save the expiration events time: {err}");
+        }
+        drop(expiration_events_time);
+
+        let mut events = Vec::new();
+        {
+            let account_details = self.details().await;
+            let Some(bech32_hrp) = account_details.public_addresses.first().map(|a| *a.address.hrp()) else {
+                return;
+            };
+            let account_addresses = account_details
+                .public_addresses
+                .iter()
+                .chain(account_details.internal_addresses.iter())
+                .map(|a| *a.address.inner())
+                .collect::<HashSet<_>>();
+
+            for output_data in account_details.unspent_outputs.values() {
+                let Some(expiration) = output_data
+                    .output
+                    .unlock_conditions()
+                    .and_then(|unlock_conditions| unlock_conditions.expiration())
+                else {
+                    continue;
+                };
+                // Outputs that return to the account aren't claimable by it
+                if account_addresses.contains(expiration.return_address()) {
+                    continue;
+                }
+
+                let expires_at = expiration.timestamp();
+                let event = ExpirationEvent {
+                    output_id: output_data.output_id,
+                    expires_at,
+                    return_address: Bech32Address::new(bech32_hrp, *expiration.return_address()),
+                    return_address_label: self.wallet.address_label(expiration.return_address()).await,
+                };
+                let approaching_at = expires_at.saturating_sub(EXPIRATION_APPROACHING_PERIOD);
+                if previous_time < expires_at && expires_at <= current_time {
+                    events.push(WalletEvent::OutputExpired(event));
+                } else if current_time < expires_at && previous_time < approaching_at && approaching_at <= current_time
+                {
+                    events.push(WalletEvent::ExpirationApproaching(event));
+                }
+            }
+        }
+
+        for event in events {
+            self.emit(account_index, event).await;
+        }
+    }
+}
@@ -2,16 +2,29 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod addresses;
+pub(crate) mod cursors;
+#[cfg(feature = "events")]
+pub(crate) mod events;
 pub(crate) mod foundries;
 pub(crate) mod options;
 pub(crate) mod outputs;
+#[cfg(feature = "mqtt")]
+pub(crate) mod push;
 pub(crate) mod transactions;
 
 use std::collections::{HashMap, HashSet};
 
-pub use self::options::SyncOptions;
+pub use self::{
+    cursors::{SyncCursor, SyncCursorKind},
+    options::SyncOptions,
+};
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{SyncFinishedEvent, WalletEvent};
 use crate::{
-    client::secret::SecretManage,
+    client::{
+        operation::{Operation, OperationProgress},
+        secret::SecretManage,
+    },
     types::block::{
         address::{Address, AliasAddress, NftAddress, ToBech32Ext},
         output::{FoundryId, Output, OutputId, OutputMetadata},
@@ -71,32 +84,75 @@ where
             return self.balance().await;
         }
 
-        self.sync_internal(&options).await?;
+        let account_index = self.details().await.index;
+        Operation::report_progress(OperationProgress::SyncingAccount { account_index });
+        Operation::check_cancelled()?;
+
+        #[cfg(feature = "events")]
+        let previous_balance = {
+            self.emit(account_index, WalletEvent::SyncStarted).await;
+            self.balance().await?
+        };
+
+        let (mut synced_addresses, mut synced_outputs) = self.sync_internal(&options).await?;
+
+        if options.address_gap_limit > 0 && options.addresses.is_empty() {
+            Operation::check_cancelled()?;
+            let (addresses, outputs) = self.discover_addresses(&options).await?;
+            synced_addresses += addresses;
+            synced_outputs += outputs;
+        }
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
         // status
         if options.sync_pending_transactions {
+            Operation::check_cancelled()?;
             let confirmed_tx_with_unknown_output = self.sync_pending_transactions().await?;
             // Sync again if we don't know the output yet, to prevent having no unspent outputs after syncing
             if confirmed_tx_with_unknown_output {
                 log::debug!("[SYNC] a transaction for which no output is known got confirmed, syncing outputs again");
-                self.sync_internal(&options).await?;
+                let (addresses, outputs) = self.sync_internal(&options).await?;
+                synced_addresses += addresses;
+                synced_outputs += outputs;
             }
         };
 
         let balance = self.balance().await?;
         // Update last_synced mutex
         let time_now = crate::utils::unix_timestamp_now().as_millis();
+        #[cfg(feature = "events")]
+        {
+            self.emit_balance_change_events(account_index, previous_balance, &balance)
+                .await;
+            self.emit_expiration_events(account_index, (time_now / 1000) as u32)
+                .await;
+            self.emit(
+                account_index,
+                WalletEvent::SyncFinished(SyncFinishedEvent {
+                    duration_ms: syc_start_time.elapsed().as_millis() as u64,
+                    synced_addresses,
+                    synced_outputs,
+                }),
+            )
+            .await;
+        }
         *last_synced = time_now;
-        log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
+        log::debug!(
+            "[SYNC] finished syncing {synced_addresses} addresses and {synced_outputs} outputs in {:.2?}",
+            syc_start_time.elapsed()
+        );
         Ok(balance)
     }
 
-    async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<()> {
+    /// Returns the number of synced addresses and unspent outputs.
+    async fn sync_internal(&self, options: &SyncOptions) -> crate::wallet::Result<(usize, usize)> {
         log::debug!("[SYNC] sync_internal");
 
+        // Drop the ledger indexes of a previously failed sync
+        self.pending_sync_cursors.lock().await.clear();
         let addresses_to_sync = self.get_addresses_to_sync(options).await?;
         log::debug!("[SYNC] addresses_to_sync {}", addresses_to_sync.len());
+        let synced_addresses = addresses_to_sync.len();
 
         let (spent_or_not_synced_output_ids, addresses_with_unspent_outputs, outputs_data): (
             Vec<OutputId>,
@@ -144,6 +200,10 @@ where
             self.request_and_store_foundry_outputs(native_token_foundry_ids).await?;
         }
 
+        let synced_outputs = outputs_data.len();
+
+        // Stop before storing a partial state
+        Operation::check_cancelled()?;
         // Updates account with balances, output ids, outputs
         self.update_account(
             addresses_with_unspent_outputs,
@@ -151,7 +211,15 @@ where
             spent_or_unsynced_output_metadata_map,
             options,
         )
-        .await
+        .await?;
+
+        // The cursors are only updated once the outputs are stored, a failure just makes the next incremental sync
+        // request more outputs again
+        if let Err(err) = self.update_sync_cursors().await {
+            log::warn!("[SYNC] failed to update the sync cursors: {err}");
+        }
+
+        Ok((synced_addresses, synced_outputs))
     }
 
     // First request all outputs directly related to the ed25519 addresses, then for each nft and alias output we got,
@@ -186,7 +254,7 @@ where
                 let bech32_hrp = self.client().get_bech32_hrp().await?;
                 let mut new_outputs_data = Vec::new();
                 for (alias_or_nft_address, ed25519_address) in new_alias_and_nft_addresses {
-                    let output_ids = self.get_output_ids_for_address(alias_or_nft_address, options).await?;
+                    let (mut output_ids, _) = self.get_output_ids_for_address(alias_or_nft_address, options).await?;
 
                     // Update address with unspent outputs
                     let address_with_unspent_outputs = addresses_with_unspent_outputs
@@ -195,6 +263,8 @@ where
                         .ok_or_else(|| {
                             crate::wallet::Error::AddressNotFoundInAccount(ed25519_address.to_bech32(bech32_hrp))
                         })?;
+                    // Known outputs are already added by an incremental sync
+                    output_ids.retain(|output_id| !address_with_unspent_outputs.output_ids.contains(output_id));
                     address_with_unspent_outputs.output_ids.extend(output_ids.clone());
 
                     let new_outputs_data_inner = self.get_outputs(output_ids).await?;
@@ -11,9 +11,12 @@ const DEFAULT_SYNC_INCOMING_TRANSACTIONS: bool = false;
 const DEFAULT_SYNC_ONLY_MOST_BASIC_OUTPUTS: bool = false;
 const DEFAULT_SYNC_PENDING_TRANSACTIONS: bool = true;
 const DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES: bool = false;
+const DEFAULT_INCREMENTAL: bool = false;
+const DEFAULT_ADDRESS_GAP_LIMIT: u32 = 0;
 
 /// The synchronization options
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct SyncOptions {
     /// Specific Bech32 encoded addresses of the account to sync, if addresses are provided, then `address_start_index`
@@ -56,6 +59,15 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Only request output ids that were created since the last sync of an address, based on the stored
+    /// [`SyncCursor`](super::SyncCursor)s. Known unspent outputs are then checked by their metadata instead.
+    #[serde(default = "default_incremental")]
+    pub incremental: bool,
+    /// The number of unused public and internal addresses that should follow the last used address. Missing addresses
+    /// are generated and synced, so outputs sent to addresses generated by another instance of the same seed are
+    /// found. 0 by default, which disables it. Ignored if `addresses` are provided.
+    #[serde(default = "default_address_gap_limit")]
+    pub address_gap_limit: u32,
 }
 
 fn default_address_start_index() -> u32 {
@@ -82,6 +94,14 @@ fn default_sync_native_token_foundries() -> bool {
     DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES
 }
 
+fn default_incremental() -> bool {
+    DEFAULT_INCREMENTAL
+}
+
+fn default_address_gap_limit() -> u32 {
+    DEFAULT_ADDRESS_GAP_LIMIT
+}
+
 impl Default for SyncOptions {
     fn default() -> Self {
         Self {
@@ -96,12 +116,15 @@ impl Default for SyncOptions {
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            incremental: default_incremental(),
+            address_gap_limit: default_address_gap_limit(),
         }
     }
 }
 
 /// Sync options for Ed25519 addresses from the account
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(default, rename_all = "camelCase")]
 pub struct AccountSyncOptions {
     pub basic_outputs: bool,
@@ -127,6 +150,7 @@ impl AccountSyncOptions {
 
 /// Sync options for addresses from alias outputs
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(default, rename_all = "camelCase")]
 pub struct AliasSyncOptions {
     pub basic_outputs: bool,
@@ -155,6 +179,7 @@ impl AliasSyncOptions {
 
 /// Sync options for addresses from NFT outputs
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(default, rename_all = "camelCase")]
 pub struct NftSyncOptions {
     pub basic_outputs: bool,
@@ -163,7 +163,9 @@ where
                                         Ok((transaction_id, None))
                                     }
                                 }
-                                Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                                Err(crate::client::Error::Node(e))
+                                    if matches!(e.root_cause(), crate::client::node_api::error::Error::NotFound(_)) =>
+                                {
                                     Ok((transaction_id, None))
                                 }
                                 Err(e) => Err(crate::wallet::Error::Client(e.into())),
@@ -0,0 +1,180 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::Bech32Address,
+        output::{FoundryId, OutputWithMetadata},
+    },
+    wallet::account::{operations::syncing::SyncOptions, types::AddressWithUnspentOutputs, Account},
+};
+#[cfg(feature = "events")]
+use crate::{
+    types::block::payload::transaction::dto::TransactionPayloadDto,
+    wallet::{
+        account::types::OutputDataDto,
+        events::types::{NewOutputEvent, SpentOutputEvent, WalletEvent},
+    },
+};
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Applies an output that the node pushed for an address of the account, without syncing the other outputs.
+    /// Returns false if the address doesn't belong to the account.
+    pub(crate) async fn apply_pushed_output(
+        &self,
+        address: &Bech32Address,
+        output_with_metadata: OutputWithMetadata,
+        options: &SyncOptions,
+    ) -> crate::wallet::Result<bool> {
+        let associated_address = {
+            let account_details = self.details().await;
+            let Some(account_address) = account_details
+                .public_addresses
+                .iter()
+                .chain(account_details.internal_addresses.iter())
+                .find(|a| &a.address == address)
+            else {
+                return Ok(false);
+            };
+            AddressWithUnspentOutputs {
+                address: account_address.address,
+                key_index: account_address.key_index,
+                internal: account_address.internal,
+                output_ids: Vec::new(),
+            }
+        };
+        let output_id = *output_with_metadata.metadata().output_id();
+        log::debug!("[SYNC] pushed output {output_id} for {address}");
+
+        if !output_with_metadata.metadata().is_spent() {
+            if options.sync_incoming_transactions {
+                self.request_incoming_transaction_data(vec![*output_id.transaction_id()])
+                    .await?;
+            }
+            if options.sync_native_token_foundries {
+                if let Some(native_tokens) = output_with_metadata.output().native_tokens() {
+                    let foundry_ids = native_tokens
+                        .iter()
+                        .map(|native_token| FoundryId::from(*native_token.token_id()))
+                        .collect::<HashSet<_>>();
+                    self.request_and_store_foundry_outputs(foundry_ids).await?;
+                }
+            }
+        }
+
+        let Some(output_data) = self
+            .output_response_to_output_data(vec![output_with_metadata], &associated_address)
+            .await?
+            .pop()
+        else {
+            return Ok(true);
+        };
+
+        #[cfg(feature = "events")]
+        let previous_balance = self.balance().await?;
+        let mut account_details = self.details_mut().await;
+        #[cfg(feature = "events")]
+        let account_index = account_details.index;
+
+        if output_data.is_spent {
+            account_details.unspent_outputs.remove(&output_id);
+            account_details.locked_outputs.remove(&output_id);
+            for address_with_unspent_outputs in account_details.addresses_with_unspent_outputs.iter_mut() {
+                address_with_unspent_outputs.output_ids.retain(|id| id != &output_id);
+            }
+            account_details
+                .addresses_with_unspent_outputs
+                .retain(|a| !a.output_ids.is_empty());
+
+            #[cfg(feature = "events")]
+            let newly_spent = account_details
+                .outputs
+                .get(&output_id)
+                .is_some_and(|output_data| !output_data.is_spent);
+            account_details.outputs.insert(output_id, output_data);
+            #[cfg(feature = "events")]
+            if newly_spent {
+                if let Some(output_data) = account_details.outputs.get(&output_id) {
+                    self.emit(
+                        account_index,
+                        WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
+                            output: OutputDataDto::from(output_data),
+                        })),
+                    )
+                    .await;
+                }
+            }
+        } else {
+            let addresses = if associated_address.internal {
+                &mut account_details.internal_addresses
+            } else {
+                &mut account_details.public_addresses
+            };
+            if let Some(account_address) = addresses.iter_mut().find(|a| &a.address == address) {
+                account_address.used = true;
+            }
+
+            match account_details
+                .addresses_with_unspent_outputs
+                .iter_mut()
+                .find(|a| &a.address == address)
+            {
+                Some(address_with_unspent_outputs) => {
+                    if !address_with_unspent_outputs.output_ids.contains(&output_id) {
+                        address_with_unspent_outputs.output_ids.push(output_id);
+                    }
+                }
+                None => account_details
+                    .addresses_with_unspent_outputs
+                    .push(AddressWithUnspentOutputs {
+                        output_ids: vec![output_id],
+                        ..associated_address
+                    }),
+            }
+
+            // Insert output, if it's unknown emit the NewOutputEvent
+            if account_details.outputs.insert(output_id, output_data.clone()).is_none() {
+                #[cfg(feature = "events")]
+                {
+                    let transaction = account_details
+                        .incoming_transactions
+                        .get(output_data.output_id.transaction_id());
+                    let sender_label = match output_data.output.features().and_then(|features| features.sender()) {
+                        Some(sender) => self.wallet.address_label(sender.address()).await,
+                        None => None,
+                    };
+                    self.emit(
+                        account_index,
+                        WalletEvent::NewOutput(Box::new(NewOutputEvent {
+                            output: OutputDataDto::from(&output_data),
+                            transaction: transaction.as_ref().map(|tx| TransactionPayloadDto::from(&tx.payload)),
+                            transaction_inputs: transaction.as_ref().map(|tx| tx.inputs.clone()),
+                            sender_label,
+                        })),
+                    )
+                    .await;
+                }
+            }
+            account_details.unspent_outputs.insert(output_id, output_data);
+        }
+
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        drop(account_details);
+
+        #[cfg(feature = "events")]
+        {
+            let balance = self.balance().await?;
+            self.emit_balance_change_events(account_index, previous_balance, &balance)
+                .await;
+        }
+
+        Ok(true)
+    }
+}
@@ -169,7 +169,9 @@ where
                             }
                         }
                     }
-                    Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                    Err(crate::client::Error::Node(e))
+                        if matches!(e.root_cause(), crate::client::node_api::error::Error::NotFound(_)) =>
+                    {
                         // no need to reattach if one input got spent
                         if input_got_spent {
                             process_transaction_with_unknown_state(
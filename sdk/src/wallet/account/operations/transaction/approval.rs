@@ -0,0 +1,187 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        api::{PreparedTransactionData, PreparedTransactionDataDto},
+        secret::SecretManage,
+    },
+    types::TryFromDto,
+    wallet::{
+        account::{
+            operations::transaction::{TransactionOptions, TransactionOptionsDto},
+            types::Transaction,
+            Account,
+        },
+        Error, Result,
+    },
+};
+
+/// A prepared transaction that waits for an approver to sign and submit or reject it. Its inputs stay locked in the
+/// meantime.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PendingApproval {
+    /// The id, the hex encoded hash of the transaction essence.
+    pub id: String,
+    /// Who requested the approval.
+    pub creator: String,
+    /// Information for the approver, e.g. a reason or a ticket reference.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<String>,
+    /// Milliseconds since the Unix epoch when the approval was requested.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    #[serde(with = "crate::utils::serde::string")]
+    pub timestamp: u128,
+    /// The prepared transaction.
+    pub prepared_transaction_data: PreparedTransactionDataDto,
+    /// The options the transaction is submitted with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub options: Option<TransactionOptionsDto>,
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    Error: From<S::Error>,
+{
+    /// Persists a prepared transaction as pending approval instead of signing it. Its inputs stay locked until the
+    /// approval is approved or rejected. If the [`SpendingPolicy`](super::SpendingPolicy) requires approvals,
+    /// [`Account::sign_transaction_essence()`] calls this with the alias of the account as creator.
+    pub async fn request_approval(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+        options: impl Into<Option<TransactionOptions>> + Send,
+        creator: impl Into<String> + Send,
+        metadata: impl Into<Option<String>> + Send,
+    ) -> Result<PendingApproval> {
+        let pending_approval = PendingApproval {
+            id: prefix_hex::encode(prepared_transaction_data.essence.hash()),
+            creator: creator.into(),
+            metadata: metadata.into(),
+            timestamp: crate::utils::unix_timestamp_now().as_millis(),
+            prepared_transaction_data: PreparedTransactionDataDto::from(&prepared_transaction_data),
+            options: options.into().as_ref().map(TransactionOptionsDto::from),
+        };
+        log::debug!("[TRANSACTION] requesting approval {}", pending_approval.id);
+
+        let mut account_details = self.details_mut().await;
+        for input in &prepared_transaction_data.inputs_data {
+            account_details.locked_outputs.insert(*input.output_id());
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        drop(account_details);
+
+        let mut pending_approvals = self.pending_approvals.lock().await;
+        pending_approvals.retain(|approval| approval.id != pending_approval.id);
+        pending_approvals.push(pending_approval.clone());
+        self.save_pending_approvals(&pending_approvals).await?;
+
+        Ok(pending_approval)
+    }
+
+    /// Returns the pending approvals of the account.
+    pub async fn pending_approvals(&self) -> Vec<PendingApproval> {
+        self.pending_approvals.lock().await.clone()
+    }
+
+    /// Returns a pending approval of the account.
+    pub async fn get_pending_approval(&self, id: &str) -> Option<PendingApproval> {
+        self.pending_approvals
+            .lock()
+            .await
+            .iter()
+            .find(|approval| approval.id == id)
+            .cloned()
+    }
+
+    /// Signs and submits the transaction of a pending approval and records the approver, who can't be its creator.
+    /// The transaction still has to satisfy the spending policy. The approval is only removed once the transaction is
+    /// signed, so it can be approved again if signing fails, e.g. because the Stronghold is locked.
+    pub async fn approve_pending_approval(&self, id: &str, approver: impl Into<String> + Send) -> Result<Transaction> {
+        log::debug!("[TRANSACTION] approving {id}");
+        let approver = approver.into();
+        let pending_approval = self
+            .get_pending_approval(id)
+            .await
+            .ok_or_else(|| Error::PendingApprovalNotFound(id.to_owned()))?;
+        if approver == pending_approval.creator {
+            return Err(Error::ApproverIsCreator {
+                id: pending_approval.id,
+                creator: pending_approval.creator,
+            });
+        }
+
+        let prepared_transaction_data = PreparedTransactionData::try_from_dto_with_params(
+            pending_approval.prepared_transaction_data,
+            self.client().get_protocol_parameters().await?,
+        )?;
+        let options = pending_approval
+            .options
+            .map(TransactionOptions::try_from_dto)
+            .transpose()?;
+
+        self.check_spending_policy(&prepared_transaction_data, options.as_ref())
+            .await?;
+        let signed_transaction_data = self
+            .sign_approved_transaction_essence(&prepared_transaction_data)
+            .await?;
+        self.remove_pending_approval(id).await?;
+
+        self.submit_and_store_approved_transaction(signed_transaction_data, options, Some(approver))
+            .await
+    }
+
+    /// Removes a pending approval and unlocks its inputs.
+    pub async fn reject_pending_approval(&self, id: &str) -> Result<PendingApproval> {
+        log::debug!("[TRANSACTION] rejecting {id}");
+        let pending_approval = self.remove_pending_approval(id).await?;
+        self.unlock_pending_approval_inputs(&pending_approval).await?;
+
+        Ok(pending_approval)
+    }
+
+    async fn remove_pending_approval(&self, id: &str) -> Result<PendingApproval> {
+        let mut pending_approvals = self.pending_approvals.lock().await;
+        let position = pending_approvals
+            .iter()
+            .position(|approval| approval.id == id)
+            .ok_or_else(|| Error::PendingApprovalNotFound(id.to_owned()))?;
+        let pending_approval = pending_approvals.remove(position);
+        self.save_pending_approvals(&pending_approvals).await?;
+
+        Ok(pending_approval)
+    }
+
+    async fn unlock_pending_approval_inputs(&self, pending_approval: &PendingApproval) -> Result<()> {
+        let mut account_details = self.details_mut().await;
+        for input in &pending_approval.prepared_transaction_data.inputs_data {
+            let output_id = input.output_metadata.output_id();
+            account_details.locked_outputs.remove(output_id);
+            log::debug!(
+                "[TRANSACTION] Unlocked output {output_id} of pending approval {}",
+                pending_approval.id
+            );
+        }
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+
+    async fn save_pending_approvals(&self, pending_approvals: &[PendingApproval]) -> Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_pending_approvals(index, pending_approvals).await?;
+        }
+        #[cfg(not(feature = "storage"))]
+        let _ = pending_approvals;
+
+        Ok(())
+    }
+}
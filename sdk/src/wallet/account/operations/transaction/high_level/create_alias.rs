@@ -18,18 +18,22 @@ use crate::{
 
 /// Params `create_alias_output()`
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CreateAliasParams {
     /// Bech32 encoded address which will control the alias. Default will use the first
     /// address of the account
     pub address: Option<Bech32Address>,
     /// Immutable alias metadata
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
     #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
     pub immutable_metadata: Option<Vec<u8>>,
     /// Alias metadata
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
     #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
     pub metadata: Option<Vec<u8>>,
     /// Alias state metadata
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
     #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
     pub state_metadata: Option<Vec<u8>>,
 }
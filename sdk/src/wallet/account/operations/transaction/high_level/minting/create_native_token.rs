@@ -24,15 +24,19 @@ use crate::{
 
 /// Address and foundry data for `create_native_token()`
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct CreateNativeTokenParams {
     /// The alias id which should be used to create the foundry.
     pub alias_id: Option<AliasId>,
     /// Circulating supply
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     pub circulating_supply: U256,
     /// Maximum supply
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     pub maximum_supply: U256,
     /// Foundry metadata
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
     #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
     pub foundry_metadata: Option<Vec<u8>>,
 }
@@ -70,6 +74,7 @@ pub struct PreparedCreateNativeTokenTransaction {
 
 /// Dto for PreparedNativeTokenTransaction
 #[derive(Debug, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct PreparedCreateNativeTokenTransactionDto {
     pub token_id: TokenId,
@@ -23,6 +23,7 @@ use crate::{
 
 /// Address and NFT for `send_nft()`.
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Getters)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct MintNftParams {
     /// Bech32 encoded address to which the NFT will be minted. Default will use the
@@ -34,10 +35,12 @@ pub struct MintNftParams {
     sender: Option<Bech32Address>,
     /// NFT metadata feature.
     #[getset(get = "pub")]
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
     #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
     metadata: Option<Vec<u8>>,
     /// NFT tag feature.
     #[getset(get = "pub")]
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
     #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
     tag: Option<Vec<u8>>,
     /// NFT issuer feature.
@@ -45,6 +48,7 @@ pub struct MintNftParams {
     issuer: Option<Bech32Address>,
     /// NFT immutable metadata feature.
     #[getset(get = "pub")]
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
     #[serde(default, with = "crate::utils::serde::option_prefix_hex_bytes")]
     immutable_metadata: Option<Vec<u8>>,
 }
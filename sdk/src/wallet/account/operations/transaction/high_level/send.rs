@@ -26,8 +26,10 @@ use crate::{
 
 /// Parameters for `send()`
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct SendParams {
     /// Amount
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(with = "crate::utils::serde::string")]
     #[getset(get = "pub")]
     amount: u64,
@@ -27,12 +27,14 @@ use crate::{
 
 /// Params for `send_native_tokens()`
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct SendNativeTokensParams {
     /// Bech32 encoded address
     #[getset(get = "pub")]
     address: Bech32Address,
     /// Native tokens
+    #[cfg_attr(feature = "json_schema", schemars(with = "Vec<(TokenId, String)>"))]
     #[getset(get = "pub")]
     native_tokens: Vec<(TokenId, U256)>,
     /// Bech32 encoded address return address, to which the storage deposit will be returned. Default will use the
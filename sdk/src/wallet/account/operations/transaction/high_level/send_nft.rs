@@ -16,6 +16,7 @@ use crate::{
 
 /// Params for `send_nft()`
 #[derive(Debug, Clone, Serialize, Deserialize, Getters)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct SendNftParams {
     /// Bech32 encoded address
@@ -1,16 +1,22 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+mod approval;
 mod build_transaction;
 pub(crate) mod high_level;
 mod input_selection;
 mod options;
+mod policy;
 pub(crate) mod prepare_output;
 mod prepare_transaction;
 mod sign_transaction;
 pub(crate) mod submit_transaction;
 
-pub use self::options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
+pub use self::{
+    approval::PendingApproval,
+    options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto},
+    policy::{PolicyViolation, RollingSpendingLimit, SpendingPolicy},
+};
 use crate::{
     client::{
         api::{verify_semantic, PreparedTransactionData, SignedTransactionData},
@@ -98,22 +104,20 @@ where
             .await
     }
 
-    /// Signs a transaction, submit it to a node and store it in the account
+    /// Signs a transaction, submit it to a node and store it in the account. If the spending policy requires
+    /// approvals, [`Error::ApprovalRequired`](crate::wallet::Error::ApprovalRequired) is returned with the pending
+    /// approval instead.
     pub async fn sign_and_submit_transaction(
         &self,
         prepared_transaction_data: PreparedTransactionData,
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<Transaction> {
         log::debug!("[TRANSACTION] sign_and_submit_transaction");
+        let options = options.into();
 
-        let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
-            Ok(res) => res,
-            Err(err) => {
-                // unlock outputs so they are available for a new transaction
-                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
-                return Err(err);
-            }
-        };
+        let signed_transaction_data = self
+            .sign_transaction_essence(&prepared_transaction_data, options.clone())
+            .await?;
 
         self.submit_and_store_transaction(signed_transaction_data, options)
             .await
@@ -124,12 +128,23 @@ where
         &self,
         signed_transaction_data: SignedTransactionData,
         options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        self.submit_and_store_approved_transaction(signed_transaction_data, options.into(), None)
+            .await
+    }
+
+    /// Validates the transaction, submit it to a node and store it in the account with the approver of its pending
+    /// approval
+    pub(crate) async fn submit_and_store_approved_transaction(
+        &self,
+        signed_transaction_data: SignedTransactionData,
+        options: Option<TransactionOptions>,
+        approver: Option<String>,
     ) -> crate::wallet::Result<Transaction> {
         log::debug!(
             "[TRANSACTION] submit_and_store_transaction {}",
             signed_transaction_data.transaction_payload.id()
         );
-        let options = options.into();
 
         // Validate transaction before sending and storing it
         let local_time = self.client().get_time_checked().await?;
@@ -185,6 +200,7 @@ where
             inclusion_state: InclusionState::Pending,
             incoming: false,
             note: options.and_then(|o| o.note),
+            approver,
             inputs,
         };
 
@@ -42,8 +42,23 @@ impl TransactionOptions {
     }
 }
 
+impl From<&TransactionOptions> for TransactionOptionsDto {
+    fn from(value: &TransactionOptions) -> Self {
+        Self {
+            remainder_value_strategy: value.remainder_value_strategy.clone(),
+            tagged_data_payload: value.tagged_data_payload.as_ref().map(TaggedDataPayloadDto::from),
+            custom_inputs: value.custom_inputs.clone(),
+            mandatory_inputs: value.mandatory_inputs.clone(),
+            burn: value.burn.as_ref().map(BurnDto::from),
+            note: value.note.clone(),
+            allow_micro_amount: value.allow_micro_amount,
+        }
+    }
+}
+
 /// Dto for transaction options
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionOptionsDto {
     #[serde(default)]
@@ -65,6 +80,7 @@ pub struct TransactionOptionsDto {
 #[allow(clippy::enum_variant_names)]
 /// The strategy to use for the remainder value management when sending funds.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(tag = "strategy", content = "value")]
 pub enum RemainderValueStrategy {
     /// Keep the remainder value on the source address.
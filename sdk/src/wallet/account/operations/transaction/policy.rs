@@ -0,0 +1,365 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeSet, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{api::PreparedTransactionData, secret::SecretManage},
+    types::block::{
+        address::{Address, Bech32Address, Hrp},
+        output::Output,
+        payload::transaction::TransactionEssence,
+    },
+    wallet::{
+        account::{
+            operations::{transaction::TransactionOptions, transaction_history::owner_address},
+            types::InclusionState,
+            Account,
+        },
+        Error,
+    },
+};
+
+/// Rules that transactions of an account have to satisfy before they're prepared and signed. The default policy
+/// doesn't restrict anything.
+///
+/// The spent amount of a transaction is the amount of the base coin in outputs that aren't owned by the account.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SpendingPolicy {
+    /// The maximum amount a single transaction can spend.
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub max_amount_per_transaction: Option<u64>,
+    /// The maximum amount all transactions in a rolling window can spend.
+    #[serde(default)]
+    pub rolling_limit: Option<RollingSpendingLimit>,
+    /// The only addresses outputs can be sent to, besides the addresses of the account.
+    #[serde(default)]
+    pub allowed_destinations: Option<BTreeSet<Bech32Address>>,
+    /// Whether transactions need a note.
+    #[serde(default)]
+    pub require_note: bool,
+    /// Whether burning is forbidden.
+    #[serde(default)]
+    pub forbid_burning: bool,
+    /// The maximum number of native tokens an output can hold.
+    #[serde(default)]
+    pub max_native_tokens_per_output: Option<u8>,
+    /// Whether transactions are persisted as [`PendingApproval`](super::PendingApproval) instead of being signed.
+    #[serde(default)]
+    pub require_approval: bool,
+}
+
+/// The maximum amount all transactions of an account can spend in a rolling window.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct RollingSpendingLimit {
+    /// The maximum amount.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The length of the window in milliseconds, e.g. 86400000 for a day.
+    pub window_in_milliseconds: u64,
+}
+
+/// A rule of a [`SpendingPolicy`] that a transaction doesn't satisfy.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, thiserror::Error)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(tag = "rule", rename_all = "camelCase")]
+pub enum PolicyViolation {
+    /// The transaction spends more than the maximum amount per transaction.
+    #[error("spending {amount} exceeds the limit of {limit} per transaction")]
+    #[serde(rename_all = "camelCase")]
+    MaxAmountPerTransaction {
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+        #[serde(with = "crate::utils::serde::string")]
+        amount: u64,
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+        #[serde(with = "crate::utils::serde::string")]
+        limit: u64,
+    },
+    /// The transaction and the previous transactions in the window spend more than the rolling limit.
+    #[error("spending {amount} after {spent} in the rolling window exceeds the limit of {limit}")]
+    #[serde(rename_all = "camelCase")]
+    RollingLimit {
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+        #[serde(with = "crate::utils::serde::string")]
+        amount: u64,
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+        #[serde(with = "crate::utils::serde::string")]
+        spent: u64,
+        #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+        #[serde(with = "crate::utils::serde::string")]
+        limit: u64,
+    },
+    /// An output is sent to an address that isn't allowed.
+    #[error("destination {address} isn't allowed")]
+    DestinationNotAllowed { address: Bech32Address },
+    /// The transaction has no note.
+    #[error("a note is required")]
+    NoteRequired,
+    /// The transaction burns something.
+    #[error("burning is forbidden")]
+    BurningForbidden,
+    /// An output holds more native tokens than allowed.
+    #[error("output {output_index} holds {count} native tokens, more than the maximum of {max}")]
+    #[serde(rename_all = "camelCase")]
+    TooManyNativeTokens { output_index: usize, count: usize, max: u8 },
+}
+
+impl SpendingPolicy {
+    /// Returns the rules the outputs of a transaction violate. `spent_in_window` is the amount spent by previous
+    /// transactions in the window of the rolling limit.
+    pub(crate) fn violations(
+        &self,
+        outputs: &[Output],
+        options: Option<&TransactionOptions>,
+        account_addresses: &HashSet<Address>,
+        spent_in_window: u64,
+        bech32_hrp: Hrp,
+    ) -> Vec<PolicyViolation> {
+        let mut violations = Vec::new();
+        let amount = spent_amount(outputs, account_addresses);
+
+        if let Some(limit) = self.max_amount_per_transaction {
+            if amount > limit {
+                violations.push(PolicyViolation::MaxAmountPerTransaction { amount, limit });
+            }
+        }
+        if let Some(rolling_limit) = self.rolling_limit {
+            if spent_in_window.saturating_add(amount) > rolling_limit.amount {
+                violations.push(PolicyViolation::RollingLimit {
+                    amount,
+                    spent: spent_in_window,
+                    limit: rolling_limit.amount,
+                });
+            }
+        }
+        if let Some(allowed_destinations) = &self.allowed_destinations {
+            let mut not_allowed = BTreeSet::new();
+            for address in outputs.iter().filter_map(owner_address) {
+                if !account_addresses.contains(&address)
+                    && !allowed_destinations.iter().any(|allowed| allowed.inner() == &address)
+                {
+                    not_allowed.insert(Bech32Address::new(bech32_hrp, address));
+                }
+            }
+            violations.extend(
+                not_allowed
+                    .into_iter()
+                    .map(|address| PolicyViolation::DestinationNotAllowed { address }),
+            );
+        }
+        let has_note = options
+            .and_then(|options| options.note.as_deref())
+            .is_some_and(|note| !note.is_empty());
+        if self.require_note && !has_note {
+            violations.push(PolicyViolation::NoteRequired);
+        }
+        if self.forbid_burning && options.is_some_and(|options| options.burn.is_some()) {
+            violations.push(PolicyViolation::BurningForbidden);
+        }
+        if let Some(max) = self.max_native_tokens_per_output {
+            for (output_index, output) in outputs.iter().enumerate() {
+                let count = output.native_tokens().map_or(0, |native_tokens| native_tokens.len());
+                if count > max as usize {
+                    violations.push(PolicyViolation::TooManyNativeTokens {
+                        output_index,
+                        count,
+                        max,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    Error: From<S::Error>,
+{
+    /// Sets the spending policy of the account. If storage is enabled, it will persist during restarts.
+    pub async fn set_spending_policy(&self, policy: SpendingPolicy) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        {
+            let index = *self.details().await.index();
+            let storage_manager = self.wallet.storage_manager.read().await;
+            storage_manager.set_spending_policy(index, &policy).await?;
+        }
+
+        *self.spending_policy.lock().await = policy;
+        Ok(())
+    }
+
+    /// Returns the spending policy of the account.
+    pub async fn spending_policy(&self) -> SpendingPolicy {
+        self.spending_policy.lock().await.clone()
+    }
+
+    /// Checks a prepared transaction against the spending policy of the account and returns
+    /// [`Error::SpendingPolicyViolated`] with all violated rules.
+    pub async fn check_spending_policy(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<()> {
+        let policy = self.spending_policy().await;
+        if policy == SpendingPolicy::default() {
+            return Ok(());
+        }
+
+        let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+        let account_details = self.details().await;
+        let account_addresses = account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .map(|address| *address.address.inner())
+            .collect::<HashSet<_>>();
+        let bech32_hrp = match account_details.public_addresses.first() {
+            Some(address) => *address.address.hrp(),
+            None => self.client().get_bech32_hrp().await?,
+        };
+
+        let spent_in_window = policy.rolling_limit.map_or(0, |rolling_limit| {
+            let window_start = crate::utils::unix_timestamp_now()
+                .as_millis()
+                .saturating_sub(rolling_limit.window_in_milliseconds as u128);
+            account_details
+                .transactions
+                .values()
+                .filter(|transaction| {
+                    !transaction.incoming
+                        && transaction.inclusion_state != InclusionState::Conflicting
+                        && transaction.timestamp >= window_start
+                })
+                .map(|transaction| {
+                    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                    spent_amount(essence.outputs(), &account_addresses)
+                })
+                .fold(0, u64::saturating_add)
+        });
+
+        let violations = policy.violations(
+            essence.outputs(),
+            options,
+            &account_addresses,
+            spent_in_window,
+            bech32_hrp,
+        );
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            log::debug!("[TRANSACTION] spending policy violated: {violations:?}");
+            Err(Error::SpendingPolicyViolated(violations))
+        }
+    }
+}
+
+/// Returns the amount of the base coin in outputs that aren't owned by the account.
+fn spent_amount(outputs: &[Output], account_addresses: &HashSet<Address>) -> u64 {
+    outputs
+        .iter()
+        .filter(|output| !owner_address(output).is_some_and(|address| account_addresses.contains(&address)))
+        .map(Output::amount)
+        .fold(0, u64::saturating_add)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::api::input_selection::Burn,
+        types::block::{
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeToken, TokenId},
+            rand::address::rand_ed25519_address,
+        },
+    };
+
+    fn basic_output(address: Address, amount: u64, native_tokens: usize) -> Output {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .with_native_tokens((0..native_tokens).map(|i| NativeToken::new(TokenId::new([i as u8; 38]), 1u8).unwrap()))
+            .finish_output(1_813_620_509_061_365)
+            .unwrap()
+    }
+
+    #[test]
+    fn spending_policy_violations() {
+        let hrp = Hrp::from_str_unchecked("rms");
+        let own_address = Address::from(rand_ed25519_address());
+        let recipient = Address::from(rand_ed25519_address());
+        let account_addresses = HashSet::from([own_address]);
+        let outputs = [
+            basic_output(recipient, 1_000_000, 2),
+            basic_output(own_address, 5_000_000, 0),
+        ];
+
+        // The default policy doesn't restrict anything
+        assert!(SpendingPolicy::default()
+            .violations(&outputs, None, &account_addresses, 0, hrp)
+            .is_empty());
+
+        let policy = SpendingPolicy {
+            max_amount_per_transaction: Some(500_000),
+            rolling_limit: Some(RollingSpendingLimit {
+                amount: 2_000_000,
+                window_in_milliseconds: 86_400_000,
+            }),
+            allowed_destinations: Some(BTreeSet::new()),
+            require_note: true,
+            forbid_burning: true,
+            max_native_tokens_per_output: Some(1),
+            require_approval: false,
+        };
+        let options = TransactionOptions {
+            burn: Some(Burn::new()),
+            ..Default::default()
+        };
+        assert_eq!(
+            policy.violations(&outputs, Some(&options), &account_addresses, 1_500_000, hrp),
+            [
+                PolicyViolation::MaxAmountPerTransaction {
+                    amount: 1_000_000,
+                    limit: 500_000
+                },
+                PolicyViolation::RollingLimit {
+                    amount: 1_000_000,
+                    spent: 1_500_000,
+                    limit: 2_000_000
+                },
+                PolicyViolation::DestinationNotAllowed {
+                    address: Bech32Address::new(hrp, recipient)
+                },
+                PolicyViolation::NoteRequired,
+                PolicyViolation::BurningForbidden,
+                PolicyViolation::TooManyNativeTokens {
+                    output_index: 0,
+                    count: 2,
+                    max: 1
+                },
+            ]
+        );
+
+        let policy = SpendingPolicy {
+            max_amount_per_transaction: Some(1_000_000),
+            allowed_destinations: Some(BTreeSet::from([Bech32Address::new(hrp, recipient)])),
+            require_note: true,
+            ..Default::default()
+        };
+        let options = TransactionOptions {
+            note: Some("invoice 42".to_owned()),
+            ..Default::default()
+        };
+        assert!(policy
+            .violations(&outputs, Some(&options), &account_addresses, 0, hrp)
+            .is_empty());
+    }
+}
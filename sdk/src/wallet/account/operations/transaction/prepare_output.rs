@@ -312,9 +312,11 @@ where
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct OutputParams {
     pub recipient_address: Bech32Address,
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(with = "crate::utils::serde::string")]
     pub amount: u64,
     #[serde(default)]
@@ -328,6 +330,7 @@ pub struct OutputParams {
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Assets {
     pub native_tokens: Option<Vec<NativeToken>>,
@@ -335,6 +338,7 @@ pub struct Assets {
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct Features {
     pub tag: Option<String>,
     pub metadata: Option<String>,
@@ -343,6 +347,7 @@ pub struct Features {
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Unlocks {
     pub expiration_unix_time: Option<u32>,
@@ -350,6 +355,7 @@ pub struct Unlocks {
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct StorageDeposit {
     pub return_strategy: Option<ReturnStrategy>,
@@ -359,6 +365,7 @@ pub struct StorageDeposit {
 }
 
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub enum ReturnStrategy {
     // A storage deposit return unlock condition will be added with the required minimum storage deposit
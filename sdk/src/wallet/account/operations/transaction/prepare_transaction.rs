@@ -117,7 +117,7 @@ where
             .await?;
 
         let prepared_transaction_data = match self
-            .build_transaction_essence(selected_transaction_data.clone(), options)
+            .build_transaction_essence(selected_transaction_data.clone(), options.clone())
             .await
         {
             Ok(res) => res,
@@ -128,6 +128,15 @@ where
             }
         };
 
+        if let Err(err) = self
+            .check_spending_policy(&prepared_transaction_data, options.as_ref())
+            .await
+        {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&selected_transaction_data.inputs).await?;
+            return Err(err);
+        }
+
         log::debug!(
             "[TRANSACTION] finished prepare_transaction in {:.2?}",
             prepare_transaction_start_time.elapsed()
@@ -17,19 +17,60 @@ use crate::{
         api::{transaction::validate_transaction_payload_length, PreparedTransactionData, SignedTransactionData},
         secret::SecretManage,
     },
-    wallet::account::{operations::transaction::TransactionPayload, Account},
+    wallet::account::{
+        operations::transaction::{TransactionOptions, TransactionPayload},
+        Account,
+    },
 };
 
 impl<S: 'static + SecretManage> Account<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Signs a transaction essence.
+    /// Signs a transaction essence, which has to satisfy the spending policy of the account with the options it's
+    /// submitted with. If the spending policy requires approvals, the transaction is persisted as
+    /// [`PendingApproval`](super::PendingApproval) with the alias of the account as creator and
+    /// [`Error::ApprovalRequired`](crate::wallet::Error::ApprovalRequired) is returned instead.
     pub async fn sign_transaction_essence(
         &self,
         prepared_transaction_data: &PreparedTransactionData,
+        options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
+        let options = options.into();
+
+        if let Err(err) = self
+            .check_spending_policy(prepared_transaction_data, options.as_ref())
+            .await
+        {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+            return Err(err);
+        }
+
+        if self.spending_policy.lock().await.require_approval {
+            // The inputs stay locked until the approval is approved or rejected
+            let pending_approval = self
+                .request_approval(prepared_transaction_data.clone(), options, self.alias().await, None)
+                .await?;
+            return Err(crate::wallet::Error::ApprovalRequired(Box::new(pending_approval)));
+        }
+
+        match self.sign_approved_transaction_essence(prepared_transaction_data).await {
+            Ok(res) => Ok(res),
+            Err(err) => {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                Err(err)
+            }
+        }
+    }
+
+    /// Signs a transaction essence without checking the spending policy or requesting an approval.
+    pub(crate) async fn sign_approved_transaction_essence(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
         #[cfg(feature = "events")]
         self.emit(
@@ -74,21 +115,13 @@ where
             }
         }
 
-        let unlocks = match self
+        let unlocks = self
             .wallet
             .secret_manager
             .read()
             .await
             .sign_transaction_essence(prepared_transaction_data, None)
-            .await
-        {
-            Ok(res) => res,
-            Err(err) => {
-                // unlock outputs so they are available for a new transaction
-                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
-                return Err(err.into());
-            }
-        };
+            .await?;
         let transaction_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
 
         log::debug!("[TRANSACTION] signed transaction: {:?}", transaction_payload);
@@ -0,0 +1,532 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    io::Write,
+    path::Path,
+};
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::{
+    client::secret::SecretManage,
+    types::{
+        block::{
+            address::{Address, Bech32Address, Hrp},
+            output::{Output, TokenId},
+            payload::transaction::{TransactionEssence, TransactionId},
+            BlockId,
+        },
+        TryFromDto,
+    },
+    wallet::{
+        account::{
+            types::{InclusionState, Transaction},
+            Account,
+        },
+        Error,
+    },
+};
+
+const CSV_HEADER: &str =
+    "date,timestamp,transactionId,blockId,inclusionState,direction,counterparties,counterpartyLabels,\
+                          tokenId,received,sent,storageDepositSent,storageDepositReceived,note";
+
+/// The file format of an exported transaction history.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionHistoryFormat {
+    /// Comma separated values with a header and a row per transaction and asset, the base coin row has an empty
+    /// `tokenId`.
+    #[default]
+    Csv,
+    /// A JSON serialized [`TransactionHistoryEntry`] per line.
+    JsonLines,
+}
+
+/// Options to filter the transaction history.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionHistoryOptions {
+    /// Only include transactions with a timestamp in milliseconds at or after this one.
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub lower_bound_timestamp: Option<u128>,
+    /// Only include transactions with a timestamp in milliseconds at or before this one.
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub upper_bound_timestamp: Option<u128>,
+}
+
+/// The direction of a transaction from the point of view of the account.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum TransactionDirection {
+    /// Sent by someone else to the account.
+    Incoming,
+    /// Sent by the account to someone else.
+    Outgoing,
+    /// Sent by the account to its own addresses.
+    SelfTransfer,
+}
+
+/// A transaction in the history of an account.
+///
+/// Received and sent amounts are the net change of the account balance, only one of them is non zero.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionHistoryEntry {
+    /// The transaction id.
+    pub transaction_id: TransactionId,
+    /// The id of the block the transaction was sent in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<BlockId>,
+    /// The inclusion state of the transaction.
+    pub inclusion_state: InclusionState,
+    /// The timestamp in milliseconds, when the account created or received the transaction.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    #[serde(with = "crate::utils::serde::string")]
+    pub timestamp: u128,
+    /// The direction of the transaction.
+    pub direction: TransactionDirection,
+    /// The recipients of an outgoing transaction or the senders of an incoming one, resolved from the
+    /// [`SenderFeature`](crate::types::block::output::feature::SenderFeature)s and the inputs.
+    pub counterparties: Vec<Bech32Address>,
+    /// The address book labels of the counterparties.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub counterparty_labels: BTreeMap<Bech32Address, String>,
+    /// The received amount of the base coin.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    #[serde(with = "crate::utils::serde::string")]
+    pub base_coin_received: u64,
+    /// The sent amount of the base coin.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    #[serde(with = "crate::utils::serde::string")]
+    pub base_coin_sent: u64,
+    /// The received and sent native tokens.
+    pub native_tokens: Vec<NativeTokenMovement>,
+    /// The storage deposit sent to counterparties, which has to be returned to the account.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    #[serde(with = "crate::utils::serde::string")]
+    pub storage_deposit_sent: u64,
+    /// The storage deposit received from counterparties, which the account has to return.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    #[serde(with = "crate::utils::serde::string")]
+    pub storage_deposit_received: u64,
+    /// The note of the transaction.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// The received and sent amount of a native token in a [`TransactionHistoryEntry`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTokenMovement {
+    /// The token id.
+    pub token_id: TokenId,
+    /// The received amount.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    pub received: U256,
+    /// The sent amount.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    pub sent: U256,
+}
+
+impl TransactionHistoryEntry {
+    fn from_transaction(
+        transaction: &Transaction,
+        account_addresses: &HashSet<Address>,
+        hrp: Hrp,
+    ) -> crate::wallet::Result<Self> {
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let is_own = |address: &Address| account_addresses.contains(address);
+
+        let mut counterparties = Vec::new();
+        let mut add_counterparty = |address: &Address| {
+            let address = Bech32Address::new(hrp, *address);
+            if !counterparties.contains(&address) {
+                counterparties.push(address);
+            }
+        };
+        let mut base_coin_received = 0;
+        let mut base_coin_sent = 0;
+        // Received and sent amounts per token
+        let mut native_tokens = BTreeMap::<TokenId, (U256, U256)>::new();
+        let mut storage_deposit_sent = 0;
+        let mut storage_deposit_received = 0;
+        let mut sent_to_others = false;
+
+        for input in &transaction.inputs {
+            let output = Output::try_from_dto(input.output.clone())?;
+            match owner_address(&output) {
+                Some(address) if is_own(&address) => {
+                    base_coin_sent += output.amount();
+                    for native_token in output.native_tokens().into_iter().flat_map(|tokens| tokens.iter()) {
+                        native_tokens.entry(*native_token.token_id()).or_default().1 += native_token.amount();
+                    }
+                }
+                Some(address) if transaction.incoming => add_counterparty(&address),
+                _ => {}
+            }
+        }
+
+        for output in essence.outputs() {
+            let storage_deposit_return = output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return());
+            match owner_address(output) {
+                Some(address) if is_own(&address) => {
+                    base_coin_received += output.amount();
+                    for native_token in output.native_tokens().into_iter().flat_map(|tokens| tokens.iter()) {
+                        native_tokens.entry(*native_token.token_id()).or_default().0 += native_token.amount();
+                    }
+                    if let Some(sender) = output.features().and_then(|features| features.sender()) {
+                        if !is_own(sender.address()) {
+                            add_counterparty(sender.address());
+                        }
+                    }
+                    if let Some(storage_deposit_return) = storage_deposit_return {
+                        if !is_own(storage_deposit_return.return_address()) {
+                            storage_deposit_received += storage_deposit_return.amount();
+                        }
+                    }
+                }
+                Some(address) => {
+                    sent_to_others = true;
+                    if !transaction.incoming {
+                        add_counterparty(&address);
+                    }
+                    if let Some(storage_deposit_return) = storage_deposit_return {
+                        if is_own(storage_deposit_return.return_address()) {
+                            storage_deposit_sent += storage_deposit_return.amount();
+                        }
+                    }
+                }
+                None => {}
+            }
+        }
+
+        let direction = if transaction.incoming {
+            TransactionDirection::Incoming
+        } else if sent_to_others {
+            TransactionDirection::Outgoing
+        } else {
+            TransactionDirection::SelfTransfer
+        };
+        let (base_coin_received, base_coin_sent) = net_movement(base_coin_received, base_coin_sent);
+
+        Ok(Self {
+            transaction_id: transaction.transaction_id,
+            block_id: transaction.block_id,
+            inclusion_state: transaction.inclusion_state,
+            timestamp: transaction.timestamp,
+            direction,
+            counterparties,
+            counterparty_labels: BTreeMap::new(),
+            base_coin_received,
+            base_coin_sent,
+            native_tokens: native_tokens
+                .into_iter()
+                .filter(|(_, (received, sent))| received != sent)
+                .map(|(token_id, (received, sent))| {
+                    let (received, sent) = net_movement(received, sent);
+                    NativeTokenMovement {
+                        token_id,
+                        received,
+                        sent,
+                    }
+                })
+                .collect(),
+            storage_deposit_sent,
+            storage_deposit_received,
+            note: transaction.note.clone(),
+        })
+    }
+
+    fn write_csv_rows(&self, mut writer: impl Write) -> crate::wallet::Result<()> {
+        let date = OffsetDateTime::from_unix_timestamp_nanos(self.timestamp as i128 * 1_000_000)
+            .map_err(|e| Error::Other(e.into()))?
+            .format(&Rfc3339)
+            .map_err(|e| Error::Other(e.into()))?;
+        let block_id = self.block_id.map(|block_id| block_id.to_string()).unwrap_or_default();
+        let inclusion_state = serde_name(self.inclusion_state)?;
+        let direction = serde_name(self.direction)?;
+        let counterparties = self
+            .counterparties
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(" ");
+        let counterparty_labels = self
+            .counterparties
+            .iter()
+            .filter_map(|address| self.counterparty_labels.get(address).map(String::as_str))
+            .collect::<Vec<_>>()
+            .join("; ");
+        let counterparty_labels = csv_field(&counterparty_labels);
+        let note = csv_field(self.note.as_deref().unwrap_or_default());
+
+        let mut write_row = |token_id: String, received: String, sent: String, storage_deposits: Option<(u64, u64)>| {
+            let (storage_deposit_sent, storage_deposit_received) = storage_deposits
+                .map(|(sent, received)| (sent.to_string(), received.to_string()))
+                .unwrap_or_default();
+            writeln!(
+                writer,
+                "{date},{},{},{block_id},{inclusion_state},{direction},{counterparties},{counterparty_labels},\
+                 {token_id},{received},{sent},\
+                 {storage_deposit_sent},{storage_deposit_received},{note}",
+                self.timestamp, self.transaction_id,
+            )
+        };
+
+        // Storage deposits are only part of the base coin row, so that they're not summed up multiple times
+        write_row(
+            String::new(),
+            self.base_coin_received.to_string(),
+            self.base_coin_sent.to_string(),
+            Some((self.storage_deposit_sent, self.storage_deposit_received)),
+        )?;
+        for native_token in &self.native_tokens {
+            write_row(
+                native_token.token_id.to_string(),
+                native_token.received.to_string(),
+                native_token.sent.to_string(),
+                None,
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TransactionHistoryFormat {
+    /// Writes transaction history entries in this format.
+    pub fn write(&self, entries: &[TransactionHistoryEntry], mut writer: impl Write) -> crate::wallet::Result<()> {
+        match self {
+            Self::Csv => {
+                writeln!(writer, "{CSV_HEADER}")?;
+                for entry in entries {
+                    entry.write_csv_rows(&mut writer)?;
+                }
+            }
+            Self::JsonLines => {
+                for entry in entries {
+                    serde_json::to_writer(&mut writer, entry)?;
+                    writeln!(writer)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<S: 'static + SecretManage> Account<S>
+where
+    Error: From<S::Error>,
+{
+    /// Returns the sent and received transactions of the account ordered by timestamp, conflicting transactions are
+    /// skipped.
+    pub async fn transaction_history(
+        &self,
+        options: impl Into<Option<TransactionHistoryOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<TransactionHistoryEntry>> {
+        log::debug!("[TRANSACTION_HISTORY] transaction_history");
+        let options = options.into().unwrap_or_default();
+        let account_details = self.details().await;
+
+        let account_addresses = account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .map(|address| *address.address.inner())
+            .collect::<HashSet<_>>();
+        let hrp = match account_details.public_addresses.first() {
+            Some(address) => *address.address.hrp(),
+            None => self.client().get_bech32_hrp().await?,
+        };
+
+        let mut transactions = account_details
+            .incoming_transactions
+            .values()
+            .map(|transaction| (transaction.transaction_id, transaction))
+            .collect::<HashMap<_, _>>();
+        // A transaction sent by the account takes precedence over the same transaction received by it
+        transactions.extend(
+            account_details
+                .transactions
+                .values()
+                .map(|transaction| (transaction.transaction_id, transaction)),
+        );
+
+        let mut entries = transactions
+            .into_values()
+            .filter(|transaction| {
+                transaction.inclusion_state != InclusionState::Conflicting
+                    && !matches!(options.lower_bound_timestamp, Some(lower_bound) if transaction.timestamp < lower_bound)
+                    && !matches!(options.upper_bound_timestamp, Some(upper_bound) if transaction.timestamp > upper_bound)
+            })
+            .map(|transaction| TransactionHistoryEntry::from_transaction(transaction, &account_addresses, hrp))
+            .collect::<crate::wallet::Result<Vec<_>>>()?;
+
+        let address_labels = self
+            .wallet
+            .address_labels(
+                entries
+                    .iter()
+                    .flat_map(|entry| entry.counterparties.iter())
+                    .map(Bech32Address::inner),
+            )
+            .await;
+        if !address_labels.is_empty() {
+            for entry in &mut entries {
+                entry.counterparty_labels = entry
+                    .counterparties
+                    .iter()
+                    .filter_map(|address| {
+                        address_labels
+                            .get(address.inner())
+                            .map(|label| (*address, label.clone()))
+                    })
+                    .collect();
+            }
+        }
+        entries.sort_by_key(|entry| (entry.timestamp, entry.transaction_id));
+
+        Ok(entries)
+    }
+
+    /// Writes the transaction history of the account to a file.
+    pub async fn export_transaction_history(
+        &self,
+        destination: impl AsRef<Path> + Send,
+        format: TransactionHistoryFormat,
+        options: impl Into<Option<TransactionHistoryOptions>> + Send,
+    ) -> crate::wallet::Result<()> {
+        let entries = self.transaction_history(options).await?;
+        let mut writer = std::io::BufWriter::new(std::fs::File::create(destination)?);
+        format.write(&entries, &mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+/// Returns the address that owns an output.
+pub(crate) fn owner_address(output: &Output) -> Option<Address> {
+    match output {
+        Output::Alias(alias) => Some(*alias.state_controller_address()),
+        Output::Foundry(foundry) => Some(Address::Alias(*foundry.alias_address())),
+        _ => output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.address())
+            .map(|unlock_condition| *unlock_condition.address()),
+    }
+}
+
+/// Returns the received and sent amount as the net change, only one of them is non zero.
+fn net_movement<T: Default + Ord + std::ops::Sub<Output = T>>(received: T, sent: T) -> (T, T) {
+    if received >= sent {
+        (received - sent, T::default())
+    } else {
+        (T::default(), sent - received)
+    }
+}
+
+fn serde_name(value: impl Serialize) -> crate::wallet::Result<String> {
+    Ok(serde_json::to_value(value)?.as_str().unwrap_or_default().to_owned())
+}
+
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transaction_history_formats() {
+        let mut entry = TransactionHistoryEntry {
+            transaction_id: TransactionId::null(),
+            block_id: None,
+            inclusion_state: InclusionState::Confirmed,
+            timestamp: 1_690_000_000_123,
+            direction: TransactionDirection::Outgoing,
+            counterparties: vec![Bech32Address::try_from_str(
+                "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
+            )
+            .unwrap()],
+            counterparty_labels: BTreeMap::new(),
+            base_coin_received: 0,
+            base_coin_sent: 1_000_000,
+            native_tokens: vec![NativeTokenMovement {
+                token_id: TokenId::null(),
+                received: U256::zero(),
+                sent: U256::from(10),
+            }],
+            storage_deposit_sent: 50_000,
+            storage_deposit_received: 0,
+            note: Some("invoice \"42\", paid".to_owned()),
+        };
+        entry.counterparty_labels = BTreeMap::from([(entry.counterparties[0], "exchange".to_owned())]);
+
+        let mut csv = Vec::new();
+        TransactionHistoryFormat::Csv
+            .write(std::slice::from_ref(&entry), &mut csv)
+            .unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some(CSV_HEADER));
+        assert_eq!(
+            lines.next().unwrap(),
+            format!(
+                "2023-07-22T04:26:40.123Z,1690000000123,{},,Confirmed,outgoing,{},exchange,,0,1000000,50000,0,\"invoice \"\"42\"\", \
+                 paid\"",
+                TransactionId::null(),
+                entry.counterparties[0]
+            )
+        );
+        assert_eq!(
+            lines.next().unwrap(),
+            format!(
+                "2023-07-22T04:26:40.123Z,1690000000123,{},,Confirmed,outgoing,{},exchange,{},0,10,,,\"invoice \"\"42\"\", \
+                 paid\"",
+                TransactionId::null(),
+                entry.counterparties[0],
+                TokenId::null()
+            )
+        );
+        assert_eq!(lines.next(), None);
+
+        let mut json_lines = Vec::new();
+        TransactionHistoryFormat::JsonLines
+            .write(&[entry.clone(), entry.clone()], &mut json_lines)
+            .unwrap();
+        let json_lines = String::from_utf8(json_lines).unwrap();
+        assert_eq!(json_lines.lines().count(), 2);
+        for line in json_lines.lines() {
+            assert_eq!(serde_json::from_str::<TransactionHistoryEntry>(line).unwrap(), entry);
+        }
+    }
+
+    #[test]
+    fn net_movement_is_one_sided() {
+        assert_eq!(net_movement(10u64, 3), (7, 0));
+        assert_eq!(net_movement(3u64, 10), (0, 7));
+        assert_eq!(net_movement(U256::from(5), U256::from(5)), (U256::zero(), U256::zero()));
+    }
+}
@@ -13,6 +13,7 @@ use crate::types::{
 
 /// An account address.
 #[derive(Debug, Getters, Setters, Clone, Serialize, Deserialize, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[getset(get = "pub")]
 pub struct AccountAddress {
@@ -47,6 +48,7 @@ impl ConvertTo<Bech32Address> for AccountAddress {
 
 /// An account address with unspent output_ids for unspent outputs.
 #[derive(Debug, Getters, Setters, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[getset(get = "pub")]
 pub struct AddressWithUnspentOutputs {
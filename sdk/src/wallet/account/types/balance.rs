@@ -12,6 +12,7 @@ use crate::types::block::output::{feature::MetadataFeature, AliasId, FoundryId,
 /// The balance of an account, returned from [`crate::wallet::account::Account::sync()`] and
 /// [`crate::wallet::account::Account::balance()`].
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, Getters)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[getset(get = "pub")]
 pub struct Balance {
@@ -59,17 +60,21 @@ impl std::ops::AddAssign for Balance {
 
 /// Base coin fields for [`Balance`]
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 #[getset(get_copy = "pub")]
 pub struct BaseCoinBalance {
     /// Total amount
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) total: u64,
     /// Balance that can currently be spent
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) available: u64,
     /// Voting power
     #[cfg(feature = "participation")]
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) voting_power: u64,
 }
@@ -86,14 +91,19 @@ impl std::ops::AddAssign for BaseCoinBalance {
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get_copy = "pub")]
 pub struct RequiredStorageDeposit {
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) basic: u64,
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) alias: u64,
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) foundry: u64,
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) nft: u64,
 }
@@ -109,19 +119,23 @@ impl std::ops::AddAssign for RequiredStorageDeposit {
 
 /// Native tokens fields for [`Balance`]
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NativeTokensBalance {
     /// Token id
     #[getset(get = "pub")]
     pub(crate) token_id: TokenId,
     /// Total amount
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[getset(get_copy = "pub")]
     pub(crate) total: U256,
     /// Balance that can currently be spent
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
     #[getset(get_copy = "pub")]
     pub(crate) available: U256,
     /// Token foundry immutable metadata
     #[getset(get = "pub")]
+    #[cfg_attr(feature = "json_schema", schemars(with = "Option<String>"))]
     #[serde(with = "crate::utils::serde::option_string")]
     pub(crate) metadata: Option<MetadataFeature>,
 }
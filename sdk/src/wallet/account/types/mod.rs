@@ -94,6 +94,7 @@ impl OutputData {
 
 /// Dto for an output with metadata
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct OutputDataDto {
     /// The output id
@@ -111,6 +112,10 @@ pub struct OutputDataDto {
     /// Remainder
     pub remainder: bool,
     /// Bip32 path
+    #[cfg_attr(
+        feature = "json_schema",
+        schemars(with = "Option<crate::utils::serde::bip44::Bip44Def>")
+    )]
     #[serde(with = "option_bip44", default)]
     pub chain: Option<Bip44>,
 }
@@ -168,6 +173,8 @@ pub struct Transaction {
     // set if the transaction was created by the wallet or if it was sent by someone else and is incoming
     pub incoming: bool,
     pub note: Option<String>,
+    /// Who approved the transaction, if the spending policy required an approval.
+    pub approver: Option<String>,
     /// Outputs that are used as input in the transaction. May not be all, because some may have already been deleted
     /// from the node.
     // serde(default) is needed so it doesn't break with old dbs
@@ -176,6 +183,7 @@ pub struct Transaction {
 
 /// Dto for a transaction with metadata
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionDto {
     /// The transaction payload
@@ -194,6 +202,9 @@ pub struct TransactionDto {
     pub incoming: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
+    /// Who approved the transaction, if the spending policy required an approval
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approver: Option<String>,
     pub inputs: Vec<OutputWithMetadataResponse>,
 }
 
@@ -208,6 +219,7 @@ impl From<&Transaction> for TransactionDto {
             network_id: value.network_id.to_string(),
             incoming: value.incoming,
             note: value.note.clone(),
+            approver: value.approver.clone(),
             inputs: value.inputs.clone(),
         }
     }
@@ -236,6 +248,7 @@ impl TryFromDto for Transaction {
                 .map_err(|_| BlockError::InvalidField("network id"))?,
             incoming: dto.incoming,
             note: dto.note,
+            approver: dto.approver,
             inputs: dto.inputs,
         })
     }
@@ -243,6 +256,7 @@ impl TryFromDto for Transaction {
 
 /// Possible InclusionStates for transactions
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[non_exhaustive]
 pub enum InclusionState {
     Pending,
@@ -284,6 +298,7 @@ impl FromStr for OutputKind {
 
 /// The account identifier.
 #[derive(Debug, Clone, Serialize, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 #[non_exhaustive]
 pub enum AccountIdentifier {
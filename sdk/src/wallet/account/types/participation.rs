@@ -8,6 +8,7 @@ use crate::{client::node_manager::node::Node, types::api::plugins::participation
 /// Options when registering participation events.
 /// If `events_to_register` is an empty `Vec` or `None`, then every event being tracked by the node will be registered.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct ParticipationEventRegistrationOptions {
     pub node: Node,
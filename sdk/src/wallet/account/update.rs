@@ -150,6 +150,10 @@ where
                     let transaction = account_details
                         .incoming_transactions
                         .get(output_data.output_id.transaction_id());
+                    let sender_label = match output_data.output.features().and_then(|features| features.sender()) {
+                        Some(sender) => self.wallet.address_label(sender.address()).await,
+                        None => None,
+                    };
                     self.emit(
                         account_index,
                         WalletEvent::NewOutput(Box::new(NewOutputEvent {
@@ -162,6 +166,7 @@ where
                                     .map(OutputWithMetadataResponse::from)
                                     .collect()
                             }),
+                            sender_label,
                         })),
                     )
                     .await;
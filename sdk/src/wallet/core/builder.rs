@@ -1,10 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::{
-    atomic::{AtomicU32, AtomicUsize},
-    Arc,
-};
+use std::sync::{atomic::AtomicU32, Arc};
 #[cfg(feature = "storage")]
 use std::{collections::HashSet, sync::atomic::Ordering};
 
@@ -13,6 +10,8 @@ use serde::Serialize;
 use tokio::sync::RwLock;
 
 use super::operations::storage::SaveLoadWallet;
+#[cfg(all(feature = "events", feature = "storage"))]
+use crate::wallet::events::log::EventLog;
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
 #[cfg(all(feature = "storage", not(feature = "rocksdb")))]
@@ -199,15 +198,25 @@ where
 
         #[cfg(feature = "events")]
         let event_emitter = tokio::sync::RwLock::new(EventEmitter::new());
+        #[cfg(all(feature = "events", feature = "storage"))]
+        let event_log = EventLog::new(storage_manager.get_event_log_state().await?);
+        #[cfg(feature = "webhooks")]
+        let webhooks = crate::wallet::events::webhook::Webhooks::load(&storage_manager).await?;
+        #[cfg(feature = "storage")]
+        let labels = storage_manager.get_labels().await?.unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let labels = Default::default();
 
         // It happened that inputs got locked, the transaction failed, but they weren't unlocked again, so we do this
         // here
         #[cfg(feature = "storage")]
-        unlock_unused_inputs(&mut accounts)?;
+        unlock_unused_inputs(&storage_manager, &mut accounts).await?;
         #[cfg(not(feature = "storage"))]
         let accounts = Vec::new();
         let wallet_inner = Arc::new(WalletInner {
-            background_syncing_status: AtomicUsize::new(0),
+            task_manager: Default::default(),
+            sync_schedules: Default::default(),
+            sync_statuses: Default::default(),
             client: self
                 .client_options
                 .clone()
@@ -218,14 +227,22 @@ where
             secret_manager: self
                 .secret_manager
                 .ok_or(crate::wallet::Error::MissingParameter("secret_manager"))?,
+            labels: RwLock::new(labels),
             #[cfg(feature = "events")]
             event_emitter,
+            #[cfg(all(feature = "events", feature = "storage"))]
+            event_log,
+            #[cfg(feature = "webhooks")]
+            webhooks,
             #[cfg(feature = "storage")]
             storage_options,
             #[cfg(feature = "storage")]
             storage_manager: tokio::sync::RwLock::new(storage_manager),
         });
 
+        #[cfg(all(feature = "events", not(target_family = "wasm")))]
+        wallet_inner.forward_node_health_changes().await;
+
         let mut accounts: Vec<Account<S>> = try_join_all(
             accounts
                 .into_iter()
@@ -259,10 +276,13 @@ where
     }
 }
 
-// Check if any of the locked inputs is not used in a transaction and unlock them, so they get available for new
-// transactions
+// Check if any of the locked inputs is not used in a transaction or a pending approval and unlock them, so they get
+// available for new transactions
 #[cfg(feature = "storage")]
-fn unlock_unused_inputs(accounts: &mut [AccountDetails]) -> crate::wallet::Result<()> {
+async fn unlock_unused_inputs(
+    storage_manager: &StorageManager,
+    accounts: &mut [AccountDetails],
+) -> crate::wallet::Result<()> {
     log::debug!("[unlock_unused_inputs]");
     for account in accounts.iter_mut() {
         let mut used_inputs = HashSet::new();
@@ -273,6 +293,16 @@ fn unlock_unused_inputs(accounts: &mut [AccountDetails]) -> crate::wallet::Resul
                 }
             }
         }
+        // The inputs of pending approvals stay locked until they're approved or rejected
+        for pending_approval in storage_manager
+            .get_pending_approvals(*account.index())
+            .await?
+            .unwrap_or_default()
+        {
+            for input in &pending_approval.prepared_transaction_data.inputs_data {
+                used_inputs.insert(*input.output_metadata.output_id());
+            }
+        }
         account.locked_outputs.retain(|input| {
             let used = used_inputs.contains(input);
             if !used {
@@ -3,20 +3,33 @@
 
 pub(crate) mod builder;
 pub(crate) mod operations;
+pub(crate) mod task_manager;
 
-use std::sync::{
-    atomic::{AtomicU32, AtomicUsize},
-    Arc,
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicU32, Arc, Weak},
 };
 
 use crypto::keys::bip39::{Mnemonic, MnemonicRef};
 use tokio::sync::RwLock;
 
-pub use self::builder::WalletBuilder;
+pub use self::{
+    builder::WalletBuilder,
+    operations::{
+        address_book::AddressBookEntry,
+        background_syncing::{AccountSyncSchedule, AccountSyncStatus},
+        export::{AccountExport, EncryptedWalletExport, ExportEncryption, WalletExport, WALLET_EXPORT_VERSION},
+    },
+    task_manager::{
+        CancellationSignal, TaskManager, TaskState, BACKGROUND_SYNCING_TASK, NODE_HEALTH_TASK, WEBHOOKS_TASK,
+    },
+};
+#[cfg(all(feature = "events", feature = "storage"))]
+use crate::wallet::events::log::EventLog;
 #[cfg(feature = "events")]
 use crate::wallet::events::{
     types::{Event, WalletEventType},
-    EventEmitter,
+    EventEmitter, EventFilter, EventStream,
 };
 #[cfg(feature = "storage")]
 use crate::wallet::storage::{StorageManager, StorageOptions};
@@ -46,6 +59,31 @@ impl<S: SecretManage> Clone for Wallet<S> {
     }
 }
 
+/// A reference to a wallet that doesn't keep it alive, held by background tasks so they end with the wallet.
+pub(crate) struct WeakWallet<S: SecretManage> {
+    inner: Weak<WalletInner<S>>,
+    accounts: Weak<RwLock<Vec<Account<S>>>>,
+}
+
+impl<S: SecretManage> WeakWallet<S> {
+    /// Returns the wallet, if it wasn't dropped yet.
+    pub(crate) fn upgrade(&self) -> Option<Wallet<S>> {
+        Some(Wallet {
+            inner: self.inner.upgrade()?,
+            accounts: self.accounts.upgrade()?,
+        })
+    }
+}
+
+impl<S: SecretManage> Wallet<S> {
+    pub(crate) fn downgrade(&self) -> WeakWallet<S> {
+        WeakWallet {
+            inner: Arc::downgrade(&self.inner),
+            accounts: Arc::downgrade(&self.accounts),
+        }
+    }
+}
+
 impl<S: SecretManage> core::ops::Deref for Wallet<S> {
     type Target = WalletInner<S>;
 
@@ -72,13 +110,20 @@ where
 
 #[derive(Debug)]
 pub struct WalletInner<S: SecretManage = SecretManager> {
-    // 0 = not running, 1 = running, 2 = stopping
-    pub(crate) background_syncing_status: AtomicUsize,
+    pub(crate) task_manager: TaskManager,
+    // background syncing overrides and statuses by account index
+    pub(crate) sync_schedules: RwLock<HashMap<u32, AccountSyncSchedule>>,
+    pub(crate) sync_statuses: RwLock<HashMap<u32, AccountSyncStatus>>,
     pub(crate) client: Client,
     pub(crate) coin_type: AtomicU32,
     pub(crate) secret_manager: Arc<RwLock<S>>,
+    pub(crate) labels: RwLock<self::operations::address_book::Labels>,
     #[cfg(feature = "events")]
     pub(crate) event_emitter: tokio::sync::RwLock<EventEmitter>,
+    #[cfg(all(feature = "events", feature = "storage"))]
+    pub(crate) event_log: EventLog,
+    #[cfg(feature = "webhooks")]
+    pub(crate) webhooks: crate::wallet::events::webhook::Webhooks,
     #[cfg(feature = "storage")]
     pub(crate) storage_options: StorageOptions,
     #[cfg(feature = "storage")]
@@ -125,6 +170,8 @@ where
                 if let Some(account) = accounts.get(i) {
                     if *account.details().await.index() == largest_account_index {
                         let _ = accounts.remove(i);
+                        self.sync_schedules.write().await.remove(&largest_account_index);
+                        self.sync_statuses.write().await.remove(&largest_account_index);
 
                         #[cfg(feature = "storage")]
                         self.storage_manager
@@ -172,6 +219,11 @@ impl<S: SecretManage> WalletInner<S> {
         &self.secret_manager
     }
 
+    /// Get the [TaskManager] that runs the background tasks of the wallet
+    pub fn task_manager(&self) -> &TaskManager {
+        &self.task_manager
+    }
+
     /// Listen to wallet events, empty vec will listen to all events
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
@@ -195,6 +247,13 @@ impl<S: SecretManage> WalletInner<S> {
         emitter.clear(events);
     }
 
+    /// Returns a stream of the wallet events matching the filter that are emitted from now on.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn events(&self, filter: EventFilter) -> EventStream {
+        self.event_emitter.read().await.stream(filter)
+    }
+
     /// Generates a new random mnemonic.
     pub fn generate_mnemonic(&self) -> crate::wallet::Result<Mnemonic> {
         Ok(Client::generate_mnemonic()?)
@@ -208,6 +267,10 @@ impl<S: SecretManage> WalletInner<S> {
 
     #[cfg(feature = "events")]
     pub(crate) async fn emit(&self, account_index: u32, event: crate::wallet::events::types::WalletEvent) {
+        #[cfg(feature = "storage")]
+        self.log_event(account_index, &event).await;
+        #[cfg(feature = "webhooks")]
+        self.enqueue_webhook_deliveries(account_index, &event).await;
         self.event_emitter.read().await.emit(account_index, event);
     }
 
@@ -217,6 +280,82 @@ impl<S: SecretManage> WalletInner<S> {
     pub async fn emit_test_event(&self, event: crate::wallet::events::types::WalletEvent) {
         self.emit(0, event).await
     }
+
+    /// Runs a future as an [`Operation`](crate::client::operation::Operation), so it can be cancelled with it, and
+    /// emits its progress as `OperationProgress` events, with the index of the account the progress is related to or
+    /// account index 0.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn run_operation<F: std::future::Future>(
+        &self,
+        operation_id: impl Into<String>,
+        operation: crate::client::operation::Operation,
+        future: F,
+    ) -> F::Output {
+        use crate::wallet::events::types::{OperationProgressEvent, WalletEvent};
+
+        let operation_id = operation_id.into();
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let operation = operation.with_progress_handler(move |progress| {
+            sender.send(progress).ok();
+        });
+        let emit_progress = |progress: crate::client::operation::OperationProgress| {
+            self.emit(
+                progress.account_index().unwrap_or(0),
+                WalletEvent::OperationProgress(OperationProgressEvent {
+                    operation_id: operation_id.clone(),
+                    progress,
+                }),
+            )
+        };
+
+        let run = operation.run(future);
+        tokio::pin!(run);
+        let output = loop {
+            tokio::select! {
+                output = &mut run => break output,
+                Some(progress) = receiver.recv() => emit_progress(progress).await,
+            }
+        };
+        while let Ok(progress) = receiver.try_recv() {
+            emit_progress(progress).await;
+        }
+        output
+    }
+}
+
+#[cfg(all(feature = "events", not(target_family = "wasm")))]
+impl<S: 'static + SecretManage> WalletInner<S> {
+    /// Emits the node health changes detected by the client as wallet wide events, until the wallet is dropped.
+    pub(crate) async fn forward_node_health_changes(self: &Arc<Self>) {
+        use tokio::sync::broadcast::error::RecvError;
+
+        let mut receiver = self.client.node_health_receiver();
+        let wallet = Arc::downgrade(self);
+        self.task_manager
+            .spawn(NODE_HEALTH_TASK, |mut cancellation| async move {
+                loop {
+                    let change = tokio::select! {
+                        change = receiver.recv() => change,
+                        _ = cancellation.cancelled() => break,
+                    };
+                    match change {
+                        Ok(change) => {
+                            let Some(wallet) = wallet.upgrade() else {
+                                break;
+                            };
+                            wallet
+                                .emit(0, crate::wallet::events::types::WalletEvent::NodeHealthChange(change))
+                                .await;
+                        }
+                        Err(RecvError::Lagged(missed)) => log::debug!("[events] missed {missed} node health changes"),
+                        Err(RecvError::Closed) => break,
+                    }
+                }
+                Ok(())
+            })
+            .await;
+    }
 }
 
 impl<S: SecretManage> Drop for Wallet<S> {
@@ -4,7 +4,10 @@
 use instant::Instant;
 
 use crate::{
-    client::secret::SecretManage,
+    client::{
+        operation::{Operation, OperationProgress},
+        secret::SecretManage,
+    },
     wallet::{account::SyncOptions, task, Account, Wallet},
 };
 
@@ -39,13 +42,15 @@ where
 
         // Search for addresses in current accounts
         for account in self.accounts.read().await.iter() {
+            Operation::check_cancelled()?;
+            let account_index = *account.details().await.index();
+            Operation::report_progress(OperationProgress::RecoveringAccount { account_index });
             // If the gap limit is 0, there is no need to search for funds
             if address_gap_limit > 0 {
                 account
                     .search_addresses_with_outputs(address_gap_limit, sync_options.clone())
                     .await?;
             }
-            let account_index = *account.details().await.index();
             match max_account_index_to_keep {
                 Some(max_account_index) => {
                     if account_index > max_account_index {
@@ -118,6 +123,7 @@ where
     ) -> crate::wallet::Result<()> {
         let mut updated_account_gap_limit = account_gap_limit;
         loop {
+            Operation::check_cancelled()?;
             log::debug!("[recover_accounts] generating {updated_account_gap_limit} new accounts");
 
             // Generate account with addresses and get their outputs in parallel
@@ -127,10 +133,11 @@ where
                 async move {
                     task::spawn(async move {
                         let new_account = new_account.finish().await?;
+                        let account_index = *new_account.details().await.index();
+                        Operation::report_progress(OperationProgress::RecoveringAccount { account_index });
                         let account_outputs_count = new_account
                             .search_addresses_with_outputs(address_gap_limit, sync_options_)
                             .await?;
-                        let account_index = *new_account.details().await.index();
                         crate::wallet::Result::Ok((account_index, account_outputs_count))
                     })
                     .await?
@@ -0,0 +1,286 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::SecretManage,
+    types::block::{
+        address::{Address, Bech32Address},
+        output::{NftId, OutputId},
+        ConvertTo,
+    },
+    wallet::{core::WalletInner, Error, Result},
+};
+
+/// A labeled address in the address book of the wallet.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBookEntry {
+    /// The address.
+    pub address: Bech32Address,
+    /// The label, unique in the address book.
+    pub label: String,
+    /// Tags to group addresses.
+    #[serde(default)]
+    pub tags: BTreeSet<String>,
+    /// A note about the address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl AddressBookEntry {
+    /// Creates a new [`AddressBookEntry`].
+    pub fn new(address: impl ConvertTo<Bech32Address>, label: impl Into<String>) -> Result<Self> {
+        Ok(Self {
+            address: address.convert()?,
+            label: label.into(),
+            tags: BTreeSet::new(),
+            note: None,
+        })
+    }
+
+    /// Sets the tags of the entry.
+    pub fn with_tags(mut self, tags: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the note of the entry.
+    pub fn with_note(mut self, note: impl Into<Option<String>>) -> Self {
+        self.note = note.into();
+        self
+    }
+}
+
+/// The address book and the output and NFT labels of a wallet, as they're persisted.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Labels {
+    address_book: Vec<AddressBookEntry>,
+    output_labels: HashMap<OutputId, String>,
+    nft_labels: HashMap<NftId, String>,
+}
+
+impl Labels {
+    /// Returns the address book entry of an address, independent of its human readable part.
+    fn address_book_entry(&self, address: &Address) -> Option<&AddressBookEntry> {
+        self.address_book.iter().find(|entry| entry.address.inner() == address)
+    }
+
+    /// Adds an entry to the address book or replaces the entry of its address.
+    fn set_address_book_entry(&mut self, entry: AddressBookEntry) -> Result<()> {
+        if self
+            .address_book
+            .iter()
+            .any(|existing| existing.label == entry.label && existing.address.inner() != entry.address.inner())
+        {
+            return Err(Error::AddressBookLabelAlreadyExists(entry.label));
+        }
+        match self
+            .address_book
+            .iter_mut()
+            .find(|existing| existing.address.inner() == entry.address.inner())
+        {
+            Some(existing) => *existing = entry,
+            None => self.address_book.push(entry),
+        }
+
+        Ok(())
+    }
+
+    /// Adds the address book entries and the output and NFT labels of a wallet export.
+    pub(crate) fn import(
+        &mut self,
+        address_book: Vec<AddressBookEntry>,
+        output_labels: HashMap<OutputId, String>,
+        nft_labels: HashMap<NftId, String>,
+    ) -> Result<()> {
+        for entry in address_book {
+            self.set_address_book_entry(entry)?;
+        }
+        self.output_labels.extend(output_labels);
+        self.nft_labels.extend(nft_labels);
+
+        Ok(())
+    }
+}
+
+impl<S: 'static + SecretManage> WalletInner<S>
+where
+    Error: From<S::Error>,
+{
+    /// Returns the entries of the address book.
+    pub async fn address_book(&self) -> Vec<AddressBookEntry> {
+        self.labels.read().await.address_book.clone()
+    }
+
+    /// Returns the address book entry of an address.
+    pub async fn get_address_book_entry(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+    ) -> Result<Option<AddressBookEntry>> {
+        let address = address.convert()?;
+        Ok(self.labels.read().await.address_book_entry(address.inner()).cloned())
+    }
+
+    /// Returns the address book entry with a label.
+    pub async fn get_address_book_entry_by_label(&self, label: &str) -> Option<AddressBookEntry> {
+        self.labels
+            .read()
+            .await
+            .address_book
+            .iter()
+            .find(|entry| entry.label == label)
+            .cloned()
+    }
+
+    /// Adds an entry to the address book or replaces the entry of its address. The label must not be used for another
+    /// address.
+    pub async fn set_address_book_entry(&self, entry: AddressBookEntry) -> Result<()> {
+        log::debug!("[set_address_book_entry] {}", entry.address);
+        let mut labels = self.labels.write().await;
+        let mut updated_labels = labels.clone();
+        updated_labels.set_address_book_entry(entry)?;
+
+        self.save_labels(&updated_labels).await?;
+        *labels = updated_labels;
+        Ok(())
+    }
+
+    /// Removes the address book entry of an address and returns it.
+    pub async fn remove_address_book_entry(
+        &self,
+        address: impl ConvertTo<Bech32Address>,
+    ) -> Result<Option<AddressBookEntry>> {
+        let address = address.convert()?;
+        log::debug!("[remove_address_book_entry] {address}");
+        let mut labels = self.labels.write().await;
+
+        let Some(position) = labels
+            .address_book
+            .iter()
+            .position(|entry| entry.address.inner() == address.inner())
+        else {
+            return Ok(None);
+        };
+        let mut updated_labels = labels.clone();
+        let entry = updated_labels.address_book.remove(position);
+        self.save_labels(&updated_labels).await?;
+        *labels = updated_labels;
+
+        Ok(Some(entry))
+    }
+
+    /// Returns the labels of outputs. Labels of spent outputs are kept, so they're still available for the transactions
+    /// which spent them, and are only removed with [`Self::set_output_label()`].
+    pub async fn output_labels(&self) -> HashMap<OutputId, String> {
+        self.labels.read().await.output_labels.clone()
+    }
+
+    /// Sets the label of an output, or removes it if `None` is provided.
+    pub async fn set_output_label(&self, output_id: OutputId, label: Option<String>) -> Result<()> {
+        let mut labels = self.labels.write().await;
+        let mut updated_labels = labels.clone();
+        match label {
+            Some(label) => updated_labels.output_labels.insert(output_id, label),
+            None => updated_labels.output_labels.remove(&output_id),
+        };
+
+        self.save_labels(&updated_labels).await?;
+        *labels = updated_labels;
+        Ok(())
+    }
+
+    /// Returns the labels of NFTs.
+    pub async fn nft_labels(&self) -> HashMap<NftId, String> {
+        self.labels.read().await.nft_labels.clone()
+    }
+
+    /// Sets the label of an NFT, or removes it if `None` is provided.
+    pub async fn set_nft_label(&self, nft_id: NftId, label: Option<String>) -> Result<()> {
+        let mut labels = self.labels.write().await;
+        let mut updated_labels = labels.clone();
+        match label {
+            Some(label) => updated_labels.nft_labels.insert(nft_id, label),
+            None => updated_labels.nft_labels.remove(&nft_id),
+        };
+
+        self.save_labels(&updated_labels).await?;
+        *labels = updated_labels;
+        Ok(())
+    }
+
+    pub(crate) async fn save_labels(&self, labels: &Labels) -> Result<()> {
+        #[cfg(feature = "storage")]
+        self.storage_manager.read().await.set_labels(labels).await?;
+        #[cfg(not(feature = "storage"))]
+        let _ = labels;
+
+        Ok(())
+    }
+}
+
+impl<S: SecretManage> WalletInner<S> {
+    /// Returns the address book label of an address, independent of its human readable part.
+    #[cfg(feature = "events")]
+    pub(crate) async fn address_label(&self, address: &Address) -> Option<String> {
+        self.labels
+            .read()
+            .await
+            .address_book_entry(address)
+            .map(|entry| entry.label.clone())
+    }
+
+    /// Returns the address book labels of addresses, independent of their human readable part.
+    pub async fn address_labels<'a>(
+        &self,
+        addresses: impl IntoIterator<Item = &'a Address> + Send,
+    ) -> HashMap<Address, String> {
+        let labels = self.labels.read().await;
+        addresses
+            .into_iter()
+            .filter_map(|address| {
+                labels
+                    .address_book_entry(address)
+                    .map(|entry| (*address, entry.label.clone()))
+            })
+            .collect()
+    }
+
+    /// Returns the label of an NFT.
+    #[cfg(feature = "events")]
+    pub(crate) async fn nft_label(&self, nft_id: &NftId) -> Option<String> {
+        self.labels.read().await.nft_labels.get(nft_id).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn address_book_entry_lookup_ignores_hrp() {
+        let address =
+            Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy").unwrap();
+        let labels = Labels {
+            address_book: vec![AddressBookEntry::new(address, "exchange")
+                .unwrap()
+                .with_tags(["cex"])
+                .with_note("deposit address".to_owned())],
+            ..Default::default()
+        };
+
+        let smr_address = Bech32Address::new("smr".parse().unwrap(), *address.inner());
+        let entry = labels.address_book_entry(smr_address.inner()).unwrap();
+        assert_eq!(entry.label, "exchange");
+        assert_eq!(entry.tags, BTreeSet::from(["cex".to_owned()]));
+
+        let json = serde_json::to_string(&labels).unwrap();
+        let restored = serde_json::from_str::<Labels>(&json).unwrap();
+        assert_eq!(restored.address_book, labels.address_book);
+    }
+}
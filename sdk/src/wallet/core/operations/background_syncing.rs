@@ -1,98 +1,284 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{sync::atomic::Ordering, time::Duration};
+use std::{collections::HashMap, time::Duration};
 
-use tokio::time::sleep;
+use serde::{Deserialize, Serialize};
+use tokio::{task::JoinSet, time::sleep};
 
 use crate::{
     client::secret::SecretManage,
-    wallet::{account::operations::syncing::SyncOptions, Wallet},
+    wallet::{
+        account::{operations::syncing::SyncOptions, Account},
+        core::{CancellationSignal, WeakWallet, BACKGROUND_SYNCING_TASK},
+        Wallet,
+    },
 };
 
 /// The default interval for background syncing
 pub(crate) const DEFAULT_BACKGROUNDSYNCING_INTERVAL: Duration = Duration::from_secs(7);
+/// How often the background syncing checks for accounts that are due
+const BACKGROUNDSYNCING_TICK: Duration = Duration::from_millis(500);
+
+/// Overrides of the background syncing for a single account.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountSyncSchedule {
+    /// The options to sync the account with, instead of the ones the background syncing was started with.
+    pub options: Option<SyncOptions>,
+    /// The interval to sync the account in, instead of the one the background syncing was started with.
+    pub interval: Option<Duration>,
+}
+
+impl AccountSyncSchedule {
+    /// Creates a schedule without overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the options to sync the account with.
+    pub fn with_options(mut self, options: impl Into<Option<SyncOptions>>) -> Self {
+        self.options = options.into();
+        self
+    }
+
+    /// Sets the interval to sync the account in.
+    pub fn with_interval(mut self, interval: impl Into<Option<Duration>>) -> Self {
+        self.interval = interval.into();
+        self
+    }
+}
+
+/// The background syncing status of an account.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSyncStatus {
+    /// Whether the account is being synced right now.
+    pub syncing: bool,
+    /// Unix timestamp in milliseconds at which the last sync finished.
+    pub last_synced_at: Option<u64>,
+    /// The duration of the last sync in milliseconds.
+    pub last_sync_duration_ms: Option<u64>,
+    /// The error of the last sync, if it failed.
+    pub last_error: Option<String>,
+    /// Unix timestamp in milliseconds from which on the account is synced again.
+    pub next_sync_at: Option<u64>,
+}
 
 impl<S: 'static + SecretManage> Wallet<S>
 where
     crate::wallet::Error: From<S::Error>,
 {
-    /// Start the background syncing process for all accounts, default interval is 7 seconds
+    /// Start the background syncing process for all accounts, default interval is 7 seconds. Accounts are synced
+    /// concurrently, up to `max_parallel_api_requests` at once, accounts with pending transactions first. The options
+    /// and interval can be overridden per account with [`Wallet::set_account_sync_schedule()`].
     pub async fn start_background_syncing(
         &self,
         options: Option<SyncOptions>,
         interval: Option<Duration>,
     ) -> crate::wallet::Result<()> {
         log::debug!("[start_background_syncing]");
-        // stop existing process if running
-        if self.background_syncing_status.load(Ordering::Relaxed) == 1 {
-            self.background_syncing_status.store(2, Ordering::Relaxed);
-        };
-        while self.background_syncing_status.load(Ordering::Relaxed) == 2 {
-            log::debug!("[background_syncing]: waiting for the old process to stop");
-            sleep(Duration::from_secs(1)).await;
+        // Stop an existing process first, so the sync statuses aren't updated by it anymore
+        self.stop_background_syncing().await?;
+
+        // Sync all accounts right away
+        for status in self.sync_statuses.write().await.values_mut() {
+            status.next_sync_at = None;
         }
+        let wallet = self.downgrade();
+        self.task_manager
+            .spawn(BACKGROUND_SYNCING_TASK, |cancellation| async move {
+                Self::run_background_syncing(
+                    wallet,
+                    options,
+                    interval.unwrap_or(DEFAULT_BACKGROUNDSYNCING_INTERVAL),
+                    cancellation,
+                )
+                .await;
+                log::debug!("[background_syncing]: stopped");
+                Ok(())
+            })
+            .await;
+        Ok(())
+    }
+
+    /// Ends when the wallet is dropped, only the running syncs keep it alive until they finish.
+    async fn run_background_syncing(
+        weak_wallet: WeakWallet<S>,
+        options: Option<SyncOptions>,
+        interval: Duration,
+        mut cancellation: CancellationSignal,
+    ) {
+        // Running syncs are aborted when the set is dropped
+        let mut syncs = JoinSet::new();
+        // The account indexes of the running syncs, to reset the status of the accounts whose sync panicked
+        let mut running_syncs = HashMap::new();
 
-        self.background_syncing_status.store(1, Ordering::Relaxed);
-        let wallet = self.clone();
-        let _background_syncing = std::thread::spawn(move || {
+        loop {
+            let Some(wallet) = weak_wallet.upgrade() else {
+                break;
+            };
             #[cfg(not(target_family = "wasm"))]
-            let runtime = tokio::runtime::Builder::new_multi_thread()
-                .enable_all()
-                .build()
-                .unwrap();
+            let max_parallel_syncs = wallet.client.request_pool.size().await.max(1);
             #[cfg(target_family = "wasm")]
-            let runtime = tokio::runtime::Builder::new_current_thread()
-                .enable_all()
-                .build()
-                .unwrap();
-            runtime.block_on(async {
-                'outer: loop {
-                    log::debug!("[background_syncing]: syncing accounts");
-                    for account in wallet.accounts.read().await.iter() {
-                        // Check if the process should stop before syncing each account so it stops faster
-                        if wallet.background_syncing_status.load(Ordering::Relaxed) == 2 {
-                            log::debug!("[background_syncing]: stopping");
-                            break 'outer;
+            let max_parallel_syncs = crate::client::constants::MAX_PARALLEL_API_REQUESTS;
+
+            if syncs.len() < max_parallel_syncs {
+                for account in wallet
+                    .due_accounts()
+                    .await
+                    .into_iter()
+                    .take(max_parallel_syncs - syncs.len())
+                {
+                    let account_index = *account.details().await.index();
+                    let options = wallet
+                        .sync_schedules
+                        .read()
+                        .await
+                        .get(&account_index)
+                        .and_then(|schedule| schedule.options.clone())
+                        .or_else(|| options.clone());
+                    wallet
+                        .sync_statuses
+                        .write()
+                        .await
+                        .entry(account_index)
+                        .or_default()
+                        .syncing = true;
+                    log::debug!("[background_syncing]: syncing account {account_index}");
+                    let sync_start_time = instant::Instant::now();
+                    let abort_handle = syncs.spawn(async move {
+                        let result = account.sync(options).await;
+                        (account_index, sync_start_time.elapsed(), result)
+                    });
+                    running_syncs.insert(account_index, (abort_handle, sync_start_time));
+                }
+            }
+
+            // Don't keep the wallet alive while waiting
+            drop(wallet);
+
+            // Wait for a sync to finish, for other accounts to become due or for the cancellation
+            tokio::select! {
+                Some(joined) = syncs.join_next(), if !syncs.is_empty() => {
+                    let Some(wallet) = weak_wallet.upgrade() else {
+                        break;
+                    };
+                    match joined {
+                        Ok((account_index, duration, result)) => {
+                            running_syncs.remove(&account_index);
+                            let last_error = result.err().map(|err| err.to_string());
+                            wallet.finish_background_sync(account_index, duration, last_error, interval).await;
                         }
-                        match account.sync(options.clone()).await {
-                            Ok(_) => {}
-                            Err(err) => log::debug!("[background_syncing] error: {}", err),
-                        };
-                    }
-                    // split interval syncing to seconds so stopping the process doesn't have to wait long
-                    let seconds = interval.unwrap_or(DEFAULT_BACKGROUNDSYNCING_INTERVAL).as_secs();
-                    for _ in 0..seconds {
-                        if wallet.background_syncing_status.load(Ordering::Relaxed) == 2 {
-                            log::debug!("[background_syncing]: stopping");
-                            break 'outer;
+                        Err(err) => {
+                            // Without `tokio_unstable` the `JoinError` doesn't tell which task failed, but its sync is
+                            // finished. Syncs that finished at the same time, but weren't joined yet, get their
+                            // result later.
+                            log::debug!("[background_syncing] sync task failed: {err}");
+                            let failed_syncs = running_syncs
+                                .iter()
+                                .filter(|(_, (abort_handle, _))| abort_handle.is_finished())
+                                .map(|(account_index, _)| *account_index)
+                                .collect::<Vec<_>>();
+                            for account_index in failed_syncs {
+                                if let Some((_, sync_start_time)) = running_syncs.remove(&account_index) {
+                                    wallet
+                                        .finish_background_sync(
+                                            account_index,
+                                            sync_start_time.elapsed(),
+                                            Some(err.to_string()),
+                                            interval,
+                                        )
+                                        .await;
+                                }
+                            }
                         }
-                        sleep(Duration::from_secs(1)).await;
                     }
                 }
-                wallet.background_syncing_status.store(0, Ordering::Relaxed);
-                log::debug!("[background_syncing]: stopped");
-            });
-        });
-        Ok(())
+                _ = sleep(BACKGROUNDSYNCING_TICK) => {}
+                _ = cancellation.cancelled() => break,
+            }
+        }
+        log::debug!("[background_syncing]: stopping");
+    }
+
+    /// Returns the accounts which should be synced now, the ones with pending transactions first, then the ones that
+    /// wait the longest.
+    async fn due_accounts(&self) -> Vec<Account<S>> {
+        let now = crate::utils::unix_timestamp_now().as_millis() as u64;
+        let sync_statuses = self.sync_statuses.read().await;
+
+        let mut due_accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            let account_details = account.details().await;
+            let status = sync_statuses.get(account_details.index());
+            if status.is_some_and(|status| status.syncing) {
+                continue;
+            }
+            let next_sync_at = status.and_then(|status| status.next_sync_at);
+            if matches!(next_sync_at, Some(next_sync_at) if next_sync_at > now) {
+                continue;
+            }
+            let has_pending_transactions = !account_details.pending_transactions().is_empty();
+            due_accounts.push((!has_pending_transactions, next_sync_at, account.clone()));
+        }
+        due_accounts
+            .sort_by_key(|(no_pending_transactions, next_sync_at, _)| (*no_pending_transactions, *next_sync_at));
+
+        due_accounts.into_iter().map(|(_, _, account)| account).collect()
+    }
+
+    async fn finish_background_sync(
+        &self,
+        account_index: u32,
+        duration: Duration,
+        last_error: Option<String>,
+        interval: Duration,
+    ) {
+        if let Some(err) = &last_error {
+            log::debug!("[background_syncing] error: {}", err);
+        }
+        let interval = self
+            .sync_schedules
+            .read()
+            .await
+            .get(&account_index)
+            .and_then(|schedule| schedule.interval)
+            .unwrap_or(interval);
+        let now = crate::utils::unix_timestamp_now().as_millis() as u64;
+
+        let mut sync_statuses = self.sync_statuses.write().await;
+        let status = sync_statuses.entry(account_index).or_default();
+        status.syncing = false;
+        status.last_synced_at = Some(now);
+        status.last_sync_duration_ms = Some(duration.as_millis() as u64);
+        status.last_error = last_error;
+        status.next_sync_at = Some(now + interval.as_millis() as u64);
     }
 
-    /// Stop the background syncing of the accounts
+    /// Stop the background syncing of the accounts. Running syncs are cancelled.
     pub async fn stop_background_syncing(&self) -> crate::wallet::Result<()> {
         log::debug!("[stop_background_syncing]");
-        // immediately return if not running
-        if self.background_syncing_status.load(Ordering::Relaxed) == 0 {
-            return Ok(());
-        }
-        // send stop request
-        self.background_syncing_status.store(2, Ordering::Relaxed);
-        // wait until it stopped
-        while self.background_syncing_status.load(Ordering::Relaxed) != 0 {
-            #[cfg(target_family = "wasm")]
-            gloo_timers::future::TimeoutFuture::new(10).await;
-            #[cfg(not(target_family = "wasm"))]
-            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        if self.task_manager.cancel(BACKGROUND_SYNCING_TASK).await {
+            for status in self.sync_statuses.write().await.values_mut() {
+                status.syncing = false;
+            }
         }
         Ok(())
     }
+
+    /// Overrides the options and interval of the background syncing for an account. Takes effect from the next sync of
+    /// the account on.
+    pub async fn set_account_sync_schedule(&self, account_index: u32, schedule: AccountSyncSchedule) {
+        self.sync_schedules.write().await.insert(account_index, schedule);
+    }
+
+    /// Removes the background syncing overrides of an account.
+    pub async fn remove_account_sync_schedule(&self, account_index: u32) {
+        self.sync_schedules.write().await.remove(&account_index);
+    }
+
+    /// Returns the background syncing status of the accounts, by account index.
+    pub async fn account_sync_statuses(&self) -> HashMap<u32, AccountSyncStatus> {
+        self.sync_statuses.read().await.clone()
+    }
 }
@@ -40,6 +40,8 @@ where
             mut network_info,
             api_timeout,
             remote_pow_timeout,
+            request_policy,
+            response_cache,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count,
             #[cfg(not(target_family = "wasm"))]
@@ -54,6 +56,8 @@ where
             .await?;
         *self.client.api_timeout.write().await = api_timeout;
         *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
+        *self.client.request_policy.write().await = request_policy;
+        self.client.response_cache.set_options(response_cache);
         #[cfg(not(target_family = "wasm"))]
         self.client.request_pool.resize(max_parallel_api_requests).await;
         #[cfg(not(target_family = "wasm"))]
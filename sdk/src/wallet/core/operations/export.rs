@@ -0,0 +1,435 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, num::NonZeroU32, path::Path, sync::atomic::Ordering};
+
+use crypto::ciphers::chacha;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use zeroize::Zeroizing;
+
+use super::storage::SaveLoadWallet;
+#[cfg(feature = "participation")]
+use crate::wallet::account::operations::participation::ParticipationEventWithNodes;
+use crate::{
+    client::{secret::SecretManage, utils::Password},
+    types::{
+        block::{
+            address::Address,
+            output::{NftId, OutputId},
+        },
+        TryFromDto,
+    },
+    wallet::{
+        account::{AccountDetails, AccountDetailsDto, PendingApproval, SpendingPolicy},
+        core::operations::address_book::{AddressBookEntry, Labels},
+        Account, ClientOptions, Error, Result, Wallet, WalletBuilder,
+    },
+};
+
+/// The current version of the [`WalletExport`] format.
+pub const WALLET_EXPORT_VERSION: u32 = 1;
+
+/// The PBKDF2 rounds used to derive the key of an [`EncryptedWalletExport`].
+const EXPORT_KEY_DERIVATION_ROUNDS: u32 = 100_000;
+const EXPORT_SALT_LENGTH: usize = 32;
+const EXPORT_KDF: &str = "pbkdf2HmacSha512";
+const EXPORT_CIPHER: &str = "xChaCha20Poly1305";
+
+/// A portable snapshot of the wallet data, which doesn't depend on the storage adapter.
+///
+/// The secret manager is not part of the export, a wallet it's imported into has to use the same seed. The sync
+/// cursors of the accounts aren't exported either, so the first sync after an import queries all outputs again.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletExport {
+    /// The version of the format, [`WALLET_EXPORT_VERSION`] for new exports.
+    pub version: u32,
+    /// The coin type of the wallet.
+    pub coin_type: u32,
+    /// The client options of the wallet.
+    pub client_options: ClientOptions,
+    /// The accounts of the wallet.
+    pub accounts: Vec<AccountExport>,
+    /// The address book of the wallet.
+    #[serde(default)]
+    pub address_book: Vec<AddressBookEntry>,
+    /// The labels of outputs.
+    #[serde(default)]
+    pub output_labels: HashMap<OutputId, String>,
+    /// The labels of NFTs.
+    #[serde(default)]
+    pub nft_labels: HashMap<NftId, String>,
+}
+
+/// The data of an account in a [`WalletExport`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountExport {
+    /// The account with its addresses, outputs and transactions.
+    pub account: AccountDetailsDto,
+    /// The spending policy of the account.
+    #[serde(default)]
+    pub spending_policy: SpendingPolicy,
+    /// The transactions of the account which are waiting for an approval.
+    #[serde(default)]
+    pub pending_approvals: Vec<PendingApproval>,
+    /// The participation events registered for the account.
+    #[cfg(feature = "participation")]
+    #[serde(default)]
+    pub participation_events: Vec<ParticipationEventWithNodes>,
+}
+
+/// An account of a [`WalletExport`] which was validated, but not yet added to the wallet.
+struct ImportedAccount<S: SecretManage> {
+    account: Account<S>,
+    #[cfg(feature = "participation")]
+    participation_events: Vec<ParticipationEventWithNodes>,
+}
+
+/// A [`WalletExport`] encrypted with a password.
+///
+/// The key is derived from the password with PBKDF2-HMAC-SHA512 and the export is encrypted with XChaCha20-Poly1305,
+/// the ciphertext is `nonce || tag || encrypted JSON`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedWalletExport {
+    /// The version of the format, [`WALLET_EXPORT_VERSION`] for new exports.
+    pub version: u32,
+    /// How the export is encrypted.
+    pub encryption: ExportEncryption,
+    /// The encrypted export as hex string.
+    pub ciphertext: String,
+}
+
+/// How an [`EncryptedWalletExport`] is encrypted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportEncryption {
+    /// The key derivation function, `pbkdf2HmacSha512`.
+    pub kdf: String,
+    /// The rounds of the key derivation function.
+    pub rounds: u32,
+    /// The salt of the key derivation function as hex string.
+    pub salt: String,
+    /// The cipher, `xChaCha20Poly1305`.
+    pub cipher: String,
+}
+
+impl WalletExport {
+    /// Encrypts the export with a password.
+    pub fn encrypt(&self, password: &Password) -> Result<EncryptedWalletExport> {
+        let mut salt = [0; EXPORT_SALT_LENGTH];
+        crypto::utils::rand::fill(&mut salt)?;
+        let key = derive_export_key(password, &salt, EXPORT_KEY_DERIVATION_ROUNDS)?;
+        let plaintext = Zeroizing::new(serde_json::to_vec(self)?);
+
+        Ok(EncryptedWalletExport {
+            version: WALLET_EXPORT_VERSION,
+            encryption: ExportEncryption {
+                kdf: EXPORT_KDF.to_string(),
+                rounds: EXPORT_KEY_DERIVATION_ROUNDS,
+                salt: prefix_hex::encode(salt),
+                cipher: EXPORT_CIPHER.to_string(),
+            },
+            ciphertext: prefix_hex::encode(chacha::aead_encrypt(key.as_ref(), &plaintext)?),
+        })
+    }
+
+    /// Parses an export from JSON, which has to be decrypted with a password if it's encrypted.
+    pub fn from_json(json: &str, password: Option<&Password>) -> Result<Self> {
+        let value = serde_json::from_str::<Value>(json)?;
+
+        let export = if value.get("ciphertext").is_some() {
+            let password = password.ok_or(Error::Backup("the wallet export is encrypted, a password is required"))?;
+            serde_json::from_value::<EncryptedWalletExport>(value)?.decrypt(password)?
+        } else {
+            serde_json::from_value::<Self>(value)?
+        };
+
+        if export.version != WALLET_EXPORT_VERSION {
+            return Err(Error::Backup("unsupported wallet export version"));
+        }
+
+        Ok(export)
+    }
+}
+
+impl EncryptedWalletExport {
+    /// Decrypts the export with a password.
+    pub fn decrypt(&self, password: &Password) -> Result<WalletExport> {
+        if self.version != WALLET_EXPORT_VERSION {
+            return Err(Error::Backup("unsupported wallet export version"));
+        }
+        if self.encryption.kdf != EXPORT_KDF || self.encryption.cipher != EXPORT_CIPHER {
+            return Err(Error::Backup("unsupported wallet export encryption"));
+        }
+
+        let salt = prefix_hex::decode::<Vec<u8>>(&self.encryption.salt)
+            .map_err(|_| Error::Backup("invalid wallet export salt"))?;
+        let ciphertext = prefix_hex::decode::<Vec<u8>>(&self.ciphertext)
+            .map_err(|_| Error::Backup("invalid wallet export ciphertext"))?;
+        let key = derive_export_key(password, &salt, self.encryption.rounds)?;
+        let plaintext = Zeroizing::new(
+            chacha::aead_decrypt(key.as_ref(), &ciphertext)
+                .map_err(|_| Error::Backup("invalid password or corrupted wallet export"))?,
+        );
+
+        Ok(serde_json::from_slice(&plaintext)?)
+    }
+}
+
+fn derive_export_key(password: &Password, salt: &[u8], rounds: u32) -> Result<Zeroizing<[u8; 32]>> {
+    let rounds = NonZeroU32::new(rounds).ok_or(Error::Backup("invalid wallet export key derivation rounds"))?;
+    let mut key = Zeroizing::new([0; 32]);
+    crypto::keys::pbkdf::PBKDF2_HMAC_SHA512(password.as_bytes(), salt, rounds, key.as_mut());
+    Ok(key)
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    Error: From<S::Error>,
+    WalletBuilder<S>: SaveLoadWallet,
+{
+    /// Exports the coin type, client options, accounts with their addresses, outputs, transactions, spending policies,
+    /// pending approvals and participation events, the address book and the output and NFT labels.
+    pub async fn export_data(&self) -> Result<WalletExport> {
+        log::debug!("[export_data]");
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            accounts.push(AccountExport {
+                account: AccountDetailsDto::from(&*account.details().await),
+                spending_policy: account.spending_policy().await,
+                pending_approvals: account.pending_approvals().await,
+                #[cfg(feature = "participation")]
+                participation_events: account.get_participation_events().await?.into_values().collect(),
+            });
+        }
+
+        Ok(WalletExport {
+            version: WALLET_EXPORT_VERSION,
+            coin_type: self.coin_type.load(Ordering::Relaxed),
+            client_options: self.client_options().await,
+            accounts,
+            address_book: self.address_book().await,
+            output_labels: self.output_labels().await,
+            nft_labels: self.nft_labels().await,
+        })
+    }
+
+    /// Writes a [`WalletExport`] as JSON file, encrypted if a password is provided.
+    pub async fn export_to_file(&self, destination: impl AsRef<Path>, password: Option<Password>) -> Result<()> {
+        let export = self.export_data().await?;
+        let json = Zeroizing::new(match password {
+            Some(password) => serde_json::to_string_pretty(&export.encrypt(&password)?)?,
+            None => serde_json::to_string_pretty(&export)?,
+        });
+        std::fs::write(destination, json.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Imports a [`WalletExport`] into a wallet without accounts, which has to use the same seed as the exported one.
+    /// Replaces the coin type and client options and adds the address book and labels. Everything is validated before
+    /// it's stored and the stored accounts are removed again if storing fails, so the export is either imported
+    /// completely or not at all.
+    pub async fn import_data(&self, mut export: WalletExport) -> Result<()> {
+        log::debug!("[import_data]");
+        if export.version != WALLET_EXPORT_VERSION {
+            return Err(Error::Backup("unsupported wallet export version"));
+        }
+        if export
+            .accounts
+            .iter()
+            .any(|account| account.account.coin_type != export.coin_type)
+        {
+            return Err(Error::Backup("the coin type of an exported account doesn't match"));
+        }
+        // We don't want to overwrite possible existing accounts
+        if !self.accounts.read().await.is_empty() {
+            return Err(Error::Backup("can't import data when there are already accounts"));
+        }
+
+        // Accounts must be ordered by index
+        export.accounts.sort_unstable_by_key(|account| account.account.index);
+        let mut imported_accounts = Vec::with_capacity(export.accounts.len());
+        for account in export.accounts {
+            let details = AccountDetails::try_from_dto(account.account)?;
+            let imported_account = Account::new(details, self.inner.clone()).await?;
+            *imported_account.spending_policy.lock().await = account.spending_policy;
+            *imported_account.pending_approvals.lock().await = account.pending_approvals;
+            imported_accounts.push(ImportedAccount {
+                account: imported_account,
+                #[cfg(feature = "participation")]
+                participation_events: account.participation_events,
+            });
+        }
+        self.verify_export_seed(export.coin_type, &imported_accounts).await?;
+        let mut labels = self.labels.read().await.clone();
+        labels.import(export.address_book, export.output_labels, export.nft_labels)?;
+
+        let previous_client_options = self.client_options().await;
+        let previous_coin_type = self.coin_type.swap(export.coin_type, Ordering::Relaxed);
+        // Also stores the coin type
+        if let Err(err) = self.set_client_options(export.client_options).await {
+            self.coin_type.store(previous_coin_type, Ordering::Relaxed);
+            return Err(err);
+        }
+        if let Err(err) = self.store_imported_data(&imported_accounts, &labels).await {
+            self.revert_imported_data(&imported_accounts, previous_coin_type, previous_client_options)
+                .await;
+            return Err(err);
+        }
+
+        *self.labels.write().await = labels;
+        self.accounts
+            .write()
+            .await
+            .extend(imported_accounts.into_iter().map(|imported| imported.account));
+
+        Ok(())
+    }
+
+    /// Stores the accounts with their spending policies, pending approvals and participation events and the labels of
+    /// an import.
+    async fn store_imported_data(&self, imported_accounts: &[ImportedAccount<S>], labels: &Labels) -> Result<()> {
+        #[cfg(feature = "storage")]
+        for imported in imported_accounts {
+            let details = imported.account.details().await;
+            let account_index = *details.index();
+            let mut storage_manager = self.storage_manager.write().await;
+            storage_manager.save_account(&details).await?;
+            storage_manager
+                .set_spending_policy(account_index, &*imported.account.spending_policy.lock().await)
+                .await?;
+            storage_manager
+                .set_pending_approvals(account_index, &imported.account.pending_approvals.lock().await)
+                .await?;
+            #[cfg(feature = "participation")]
+            for event in &imported.participation_events {
+                storage_manager
+                    .insert_participation_event(account_index, event.clone())
+                    .await?;
+            }
+        }
+        #[cfg(not(feature = "storage"))]
+        let _ = imported_accounts;
+
+        self.save_labels(labels).await
+    }
+
+    /// Removes the stored data of a failed import and restores the previous coin type, client options and labels.
+    async fn revert_imported_data(
+        &self,
+        imported_accounts: &[ImportedAccount<S>],
+        previous_coin_type: u32,
+        previous_client_options: ClientOptions,
+    ) {
+        #[cfg(feature = "storage")]
+        for imported in imported_accounts {
+            let account_index = *imported.account.details().await.index();
+            if let Err(err) = self
+                .storage_manager
+                .write()
+                .await
+                .remove_account_data(account_index)
+                .await
+            {
+                log::warn!("[import_data] couldn't remove imported account {account_index}: {err}");
+            }
+        }
+        #[cfg(not(feature = "storage"))]
+        let _ = imported_accounts;
+
+        if let Err(err) = self.save_labels(&*self.labels.read().await).await {
+            log::warn!("[import_data] couldn't restore the labels: {err}");
+        }
+        self.coin_type.store(previous_coin_type, Ordering::Relaxed);
+        if let Err(err) = self.set_client_options(previous_client_options).await {
+            log::warn!("[import_data] couldn't restore the client options: {err}");
+        }
+    }
+
+    /// Checks that the secret manager uses the seed of an exported wallet, by deriving the first address of the first
+    /// account which has one. Fails if the export has no address to check.
+    async fn verify_export_seed(&self, coin_type: u32, imported_accounts: &[ImportedAccount<S>]) -> Result<()> {
+        for imported in imported_accounts {
+            let details = imported.account.details().await;
+            let Some(first_address) = details.public_addresses().first() else {
+                continue;
+            };
+            let address = self
+                .secret_manager
+                .read()
+                .await
+                .generate_ed25519_addresses(
+                    coin_type,
+                    *details.index(),
+                    first_address.key_index..first_address.key_index + 1,
+                    None,
+                )
+                .await?;
+            if address.first().map(|address| Address::from(*address)) != Some(*first_address.address.inner()) {
+                return Err(Error::Backup(
+                    "the secret manager doesn't use the seed of the exported wallet",
+                ));
+            }
+
+            return Ok(());
+        }
+
+        Err(Error::Backup(
+            "the wallet export has no address to verify the seed of the secret manager",
+        ))
+    }
+
+    /// Imports a [`WalletExport`] JSON file, which has to be decrypted with a password if it's encrypted.
+    pub async fn import_from_file(&self, source: impl AsRef<Path>, password: Option<Password>) -> Result<()> {
+        let json = Zeroizing::new(std::fs::read_to_string(source)?);
+        self.import_data(WalletExport::from_json(&json, password.as_ref())?)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encrypted_export_from_json() {
+        let export = WalletExport {
+            version: WALLET_EXPORT_VERSION,
+            coin_type: 4219,
+            client_options: ClientOptions::new(),
+            accounts: Vec::new(),
+            address_book: Vec::new(),
+            output_labels: HashMap::new(),
+            nft_labels: HashMap::new(),
+        };
+        let password = Password::from("export password".to_owned());
+
+        let plain = serde_json::to_string(&export).unwrap();
+        assert_eq!(WalletExport::from_json(&plain, None).unwrap().coin_type, 4219);
+
+        let encrypted = serde_json::to_string(&export.encrypt(&password).unwrap()).unwrap();
+        assert!(!encrypted.contains("coinType"));
+        assert_eq!(
+            WalletExport::from_json(&encrypted, Some(&password)).unwrap().coin_type,
+            4219
+        );
+        assert!(matches!(
+            WalletExport::from_json(&encrypted, None),
+            Err(Error::Backup("the wallet export is encrypted, a password is required"))
+        ));
+        assert!(matches!(
+            WalletExport::from_json(&encrypted, Some(&Password::from("wrong password".to_owned()))),
+            Err(Error::Backup("invalid password or corrupted wallet export"))
+        ));
+
+        let unsupported = plain.replace("\"version\":1", "\"version\":2");
+        assert!(matches!(
+            WalletExport::from_json(&unsupported, None),
+            Err(Error::Backup("unsupported wallet export version"))
+        ));
+    }
+}
@@ -2,12 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod account_recovery;
+pub(crate) mod address_book;
 pub(crate) mod address_generation;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
+pub(crate) mod export;
 pub(crate) mod get_account;
 #[cfg(feature = "ledger_nano")]
 pub(crate) mod ledger_nano;
+#[cfg(feature = "mqtt")]
+pub(crate) mod push_syncing;
 pub(crate) mod storage;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold;
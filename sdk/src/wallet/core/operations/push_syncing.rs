@@ -0,0 +1,401 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::{sync::mpsc::UnboundedSender, time::sleep};
+
+use crate::{
+    client::{
+        node_api::mqtt::{
+            MqttEvent, TopicHandler, TypedMqttPayload, TypedTopic, TypedTopicEvent, UnlockConditionTopic,
+        },
+        secret::SecretManage,
+    },
+    types::{
+        block::{
+            address::{AliasAddress, NftAddress, ToBech32Ext},
+            output::{Output, OutputWithMetadata},
+        },
+        TryFromDto,
+    },
+    wallet::{
+        account::operations::syncing::SyncOptions,
+        core::{
+            operations::background_syncing::DEFAULT_BACKGROUNDSYNCING_INTERVAL, CancellationSignal, WeakWallet,
+            BACKGROUND_SYNCING_TASK,
+        },
+        Wallet,
+    },
+};
+
+/// What a subscribed topic is relevant for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TopicTarget {
+    /// Outputs of an address of the account, applied incrementally.
+    Address(u32),
+    /// Outputs of an alias or NFT address owned by the account, which require a full sync of the account.
+    ChainAddress(u32),
+    /// Inclusion of a pending transaction of the account.
+    Transaction(u32),
+}
+
+/// A topic subscribed for push syncing.
+struct PushTopic {
+    /// What the topic is relevant for, `None` for milestones.
+    target: Option<TopicTarget>,
+    /// The handler the topic was subscribed with, shared by the topics that were subscribed together.
+    handler: Arc<TopicHandler>,
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Start syncing all accounts with outputs and transactions that are pushed by the node over MQTT, instead of
+    /// polling. All accounts are fully synced at the start, after a reconnection to the broker and when a missed
+    /// milestone is detected, in between only the pushed outputs are applied. The subscribed topics are updated after
+    /// pushed events and milestones. If no MQTT connection can be established, accounts are fully synced every 7
+    /// seconds until it can.
+    /// Replaces a running background syncing and is stopped with [`Wallet::stop_background_syncing()`].
+    pub async fn start_push_syncing(&self, options: Option<SyncOptions>) -> crate::wallet::Result<()> {
+        log::debug!("[start_push_syncing]");
+        self.stop_background_syncing().await?;
+
+        let wallet = self.downgrade();
+        self.task_manager
+            .spawn(BACKGROUND_SYNCING_TASK, |cancellation| async move {
+                Self::run_push_syncing(wallet, options, cancellation).await;
+                log::debug!("[push_syncing]: stopped");
+                Ok(())
+            })
+            .await;
+        Ok(())
+    }
+
+    /// Ends when the wallet is dropped, it's only kept alive while the accounts are synced and pushed events are
+    /// applied.
+    async fn run_push_syncing(
+        weak_wallet: WeakWallet<S>,
+        options: Option<SyncOptions>,
+        mut cancellation: CancellationSignal,
+    ) {
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let mut mqtt_events = match weak_wallet.upgrade() {
+            Some(wallet) => wallet.client.mqtt_event_receiver().await,
+            None => return,
+        };
+        let mut subscribed = HashMap::new();
+        let mut full_sync = true;
+        let mut topics_outdated = true;
+        // The first `Connected` event after subscribing on a new connection is not a reconnection.
+        let mut awaiting_connection = true;
+        let mut last_milestone_index = None;
+
+        while !cancellation.is_cancelled() {
+            let Some(wallet) = weak_wallet.upgrade() else {
+                break;
+            };
+            if full_sync {
+                full_sync = false;
+                topics_outdated = true;
+                last_milestone_index = None;
+                wallet.sync_accounts(options.clone()).await;
+            }
+
+            if topics_outdated {
+                match wallet.update_push_topics(&mut subscribed, &sender).await {
+                    Ok(accounts_with_new_transactions) => {
+                        topics_outdated = false;
+                        // The transactions could have been included before their topics were subscribed
+                        for account_index in accounts_with_new_transactions {
+                            if let Err(err) = wallet.sync_included_transactions(account_index, options.as_ref()).await {
+                                log::debug!("[push_syncing] syncing pending transactions failed: {err}");
+                                full_sync = true;
+                            }
+                        }
+                    }
+                    Err(err) => {
+                        log::debug!("[push_syncing] subscribing failed, falling back to polling: {err}");
+                        wallet.unsubscribe_push_topics(&mut subscribed).await;
+                        awaiting_connection = true;
+                        full_sync = true;
+                        drop(wallet);
+                        tokio::select! {
+                            _ = sleep(DEFAULT_BACKGROUNDSYNCING_INTERVAL) => {}
+                            _ = cancellation.cancelled() => {}
+                        }
+                        continue;
+                    }
+                }
+            }
+            // Don't keep the wallet alive while waiting
+            drop(wallet);
+
+            tokio::select! {
+                Some(event) = receiver.recv() => {
+                    // Pushed outputs can add alias and NFT addresses or resolve transactions, milestones are when
+                    // addresses and transactions created in the meantime are picked up
+                    topics_outdated = true;
+                    let Some(wallet) = weak_wallet.upgrade() else {
+                        break;
+                    };
+                    match wallet
+                        .handle_pushed_event(event, &subscribed, options.as_ref(), &mut last_milestone_index)
+                        .await
+                    {
+                        Ok(gap_detected) => full_sync |= gap_detected,
+                        Err(err) => {
+                            log::debug!("[push_syncing] applying pushed event failed: {err}");
+                            full_sync = true;
+                        }
+                    }
+                }
+                Ok(()) = mqtt_events.changed() => {
+                    let event = mqtt_events.borrow().clone();
+                    match event {
+                        MqttEvent::Connected if awaiting_connection => awaiting_connection = false,
+                        MqttEvent::Connected => {
+                            log::debug!("[push_syncing] reconnected, syncing accounts");
+                            full_sync = true;
+                        }
+                        MqttEvent::Disconnected => {
+                            log::debug!("[push_syncing] disconnected, syncing accounts");
+                            // The handlers were dropped when the connection was given up, subscribing again creates a
+                            // new connection
+                            subscribed.clear();
+                            awaiting_connection = true;
+                            full_sync = true;
+                        }
+                    }
+                }
+                _ = cancellation.cancelled() => {}
+            }
+        }
+        log::debug!("[push_syncing]: stopping");
+
+        // The handlers are dropped with the client otherwise
+        if let Some(wallet) = weak_wallet.upgrade() {
+            wallet.unsubscribe_push_topics(&mut subscribed).await;
+        }
+    }
+
+    /// Removes the handlers of the push syncing topics, without affecting other subscriptions to the same topics.
+    async fn unsubscribe_push_topics(&self, subscribed: &mut HashMap<TypedTopic, PushTopic>) {
+        let topics = subscribed
+            .drain()
+            .map(|(topic, push_topic)| (topic, push_topic.handler));
+        for (handler, topics) in group_by_handler(topics) {
+            if let Err(err) = self.client.unsubscribe_typed_handler(topics, &handler).await {
+                log::debug!("[push_syncing] unsubscribing failed: {err}");
+            }
+        }
+    }
+
+    async fn sync_accounts(&self, options: Option<SyncOptions>) {
+        log::debug!("[push_syncing]: syncing accounts");
+        for account in self.accounts.read().await.iter() {
+            if let Err(err) = account.sync(options.clone()).await {
+                log::debug!("[push_syncing] error: {}", err);
+            }
+        }
+    }
+
+    /// Subscribes to the topics for new addresses, alias and NFT outputs and pending transactions of all accounts
+    /// and unsubscribes from the ones that aren't needed anymore. Returns the indexes of the accounts with newly
+    /// subscribed pending transactions.
+    async fn update_push_topics(
+        &self,
+        subscribed: &mut HashMap<TypedTopic, PushTopic>,
+        sender: &UnboundedSender<TypedTopicEvent>,
+    ) -> crate::wallet::Result<Vec<u32>> {
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let mut topics = HashMap::new();
+        topics.insert(TypedTopic::ConfirmedMilestone, None);
+
+        for account in self.accounts.read().await.iter() {
+            let account_details = account.details().await;
+            let account_index = *account_details.index();
+
+            let chain_addresses = account_details.unspent_outputs().values().filter_map(|output_data| {
+                match &output_data.output {
+                    Output::Alias(alias) => {
+                        Some(AliasAddress::new(alias.alias_id_non_null(&output_data.output_id)).into())
+                    }
+                    Output::Nft(nft) => Some(NftAddress::new(nft.nft_id_non_null(&output_data.output_id)).into()),
+                    _ => None,
+                }
+                .map(|address: crate::types::block::address::Address| address.to_bech32(bech32_hrp))
+            });
+            let addresses = account_details
+                .public_addresses()
+                .iter()
+                .chain(account_details.internal_addresses().iter())
+                .map(|address| (*address.address(), TopicTarget::Address(account_index)))
+                .chain(chain_addresses.map(|address| (address, TopicTarget::ChainAddress(account_index))));
+
+            for (address, target) in addresses {
+                for unlock_condition in [
+                    UnlockConditionTopic::Address,
+                    UnlockConditionTopic::Expiration,
+                    UnlockConditionTopic::StorageReturn,
+                ] {
+                    for spent in [false, true] {
+                        topics.insert(
+                            TypedTopic::OutputsByUnlockCondition {
+                                unlock_condition,
+                                address,
+                                spent,
+                            },
+                            Some(target),
+                        );
+                    }
+                }
+            }
+            for transaction_id in account_details.pending_transactions() {
+                topics.insert(
+                    TypedTopic::TransactionIncludedBlock(*transaction_id),
+                    Some(TopicTarget::Transaction(account_index)),
+                );
+            }
+        }
+
+        let removed = subscribed
+            .iter()
+            .filter(|(topic, _)| !topics.contains_key(*topic))
+            .map(|(topic, push_topic)| (topic.clone(), push_topic.handler.clone()))
+            .collect::<Vec<_>>();
+        for (handler, removed) in group_by_handler(removed) {
+            self.client.unsubscribe_typed_handler(removed.clone(), &handler).await?;
+            for topic in &removed {
+                subscribed.remove(topic);
+            }
+        }
+
+        topics.retain(|topic, _| !subscribed.contains_key(topic));
+        let mut accounts_with_new_transactions = topics
+            .values()
+            .filter_map(|target| match target {
+                Some(TopicTarget::Transaction(account_index)) => Some(*account_index),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        accounts_with_new_transactions.sort_unstable();
+        accounts_with_new_transactions.dedup();
+
+        if !topics.is_empty() {
+            let sender = sender.clone();
+            let handler = self
+                .client
+                .subscribe_typed_handler(topics.keys().cloned(), move |event| {
+                    // The receiver is only dropped once push syncing stopped
+                    sender.send(event.clone()).ok();
+                })
+                .await?;
+            subscribed.extend(topics.into_iter().map(|(topic, target)| {
+                (
+                    topic,
+                    PushTopic {
+                        target,
+                        handler: handler.clone(),
+                    },
+                )
+            }));
+        }
+
+        Ok(accounts_with_new_transactions)
+    }
+
+    /// Applies a pushed event to the account it belongs to. Returns true if a milestone was missed, which requires a
+    /// full sync.
+    async fn handle_pushed_event(
+        &self,
+        event: TypedTopicEvent,
+        subscribed: &HashMap<TypedTopic, PushTopic>,
+        options: Option<&SyncOptions>,
+        last_milestone_index: &mut Option<u32>,
+    ) -> crate::wallet::Result<bool> {
+        if let TypedMqttPayload::MilestoneInfo(milestone) = &event.payload {
+            let gap_detected = last_milestone_index.is_some_and(|last| milestone.index > last + 1);
+            if gap_detected {
+                log::debug!("[push_syncing] missed milestones after {last_milestone_index:?}, syncing accounts");
+            }
+            *last_milestone_index = Some(milestone.index);
+            return Ok(gap_detected);
+        }
+
+        let Some(target) = subscribed.get(&event.topic).and_then(|push_topic| push_topic.target) else {
+            return Ok(false);
+        };
+
+        match target {
+            TopicTarget::Address(account_index) => {
+                let account = self.get_account(account_index).await?;
+                let options = match options {
+                    Some(options) => options.clone(),
+                    None => account.default_sync_options().await,
+                };
+                let (TypedTopic::OutputsByUnlockCondition { address, .. }, TypedMqttPayload::Output(response)) =
+                    (event.topic, event.payload)
+                else {
+                    return Ok(false);
+                };
+                let token_supply = self.client.get_token_supply().await?;
+                let output = OutputWithMetadata::new(
+                    Output::try_from_dto_with_params(response.output, token_supply)?,
+                    response.metadata,
+                );
+                account.apply_pushed_output(&address, output, &options).await?;
+            }
+            TopicTarget::ChainAddress(account_index) => {
+                let account = self.get_account(account_index).await?;
+                let mut options = match options {
+                    Some(options) => options.clone(),
+                    None => account.default_sync_options().await,
+                };
+                options.force_syncing = true;
+                account.sync(Some(options)).await?;
+            }
+            TopicTarget::Transaction(account_index) => {
+                log::debug!("[push_syncing] {} got included", event.topic);
+                self.sync_included_transactions(account_index, options).await?;
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Syncs the pending transactions of an account and the account itself if any of them got included.
+    async fn sync_included_transactions(
+        &self,
+        account_index: u32,
+        options: Option<&SyncOptions>,
+    ) -> crate::wallet::Result<()> {
+        let account = self.get_account(account_index).await?;
+        if account.sync_pending_transactions().await? {
+            // The transactions created outputs that weren't pushed yet
+            let mut options = match options {
+                Some(options) => options.clone(),
+                None => account.default_sync_options().await,
+            };
+            options.force_syncing = true;
+            account.sync(Some(options)).await?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Groups topics by the handler they were subscribed with.
+fn group_by_handler(
+    topics: impl IntoIterator<Item = (TypedTopic, Arc<TopicHandler>)>,
+) -> Vec<(Arc<TopicHandler>, Vec<TypedTopic>)> {
+    let mut groups: Vec<(Arc<TopicHandler>, Vec<TypedTopic>)> = Vec::new();
+    for (topic, handler) in topics {
+        match groups.iter_mut().find(|(h, _)| Arc::ptr_eq(h, &handler)) {
+            Some((_, topics)) => topics.push(topic),
+            None => groups.push((handler, vec![topic])),
+        }
+    }
+    groups
+}
@@ -0,0 +1,213 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::{sync::watch, task::JoinHandle};
+
+/// The task that syncs the accounts, started with
+/// [`Wallet::start_background_syncing()`](crate::wallet::Wallet::start_background_syncing) or
+/// `Wallet::start_push_syncing()`.
+pub const BACKGROUND_SYNCING_TASK: &str = "background-syncing";
+/// The task that emits the node health changes detected by the client as wallet events.
+pub const NODE_HEALTH_TASK: &str = "node-health";
+/// The task that delivers wallet events to the configured webhooks.
+pub const WEBHOOKS_TASK: &str = "webhooks";
+
+/// Lets a task of the [`TaskManager`] know that it should stop.
+#[derive(Debug, Clone)]
+pub struct CancellationSignal(watch::Receiver<bool>);
+
+impl CancellationSignal {
+    /// Returns whether the task was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        *self.0.borrow()
+    }
+
+    /// Completes once the task was cancelled.
+    pub async fn cancelled(&mut self) {
+        // The sender is only dropped after the task finished
+        self.0.wait_for(|cancelled| *cancelled).await.ok();
+    }
+}
+
+/// The state of a task of the [`TaskManager`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "error", rename_all = "camelCase")]
+pub enum TaskState {
+    /// The task is running.
+    Running,
+    /// The task finished by itself.
+    Finished,
+    /// The task was cancelled.
+    Cancelled,
+    /// The task returned an error.
+    Failed(String),
+}
+
+#[derive(Debug)]
+struct ManagedTask {
+    cancel: watch::Sender<bool>,
+    handle: JoinHandle<()>,
+}
+
+/// Runs the background tasks of the wallet on the Tokio runtime of the caller.
+///
+/// Tasks are identified by their name, are aborted when the wallet is dropped and their final state is kept, so
+/// failures can be inspected. The tasks of the wallet only hold weak references to it, so they don't keep it alive.
+#[derive(Debug, Default)]
+pub struct TaskManager {
+    tasks: tokio::sync::Mutex<HashMap<String, ManagedTask>>,
+    states: Arc<Mutex<HashMap<String, TaskState>>>,
+}
+
+impl TaskManager {
+    /// Spawns a task, after cancelling a running task with the same name. The task should return once its
+    /// [`CancellationSignal`] is triggered.
+    pub async fn spawn<F, Fut>(&self, name: impl Into<String>, task: F)
+    where
+        F: FnOnce(CancellationSignal) -> Fut,
+        Fut: Future<Output = crate::wallet::Result<()>> + Send + 'static,
+    {
+        let name = name.into();
+        let mut tasks = self.tasks.lock().await;
+        if let Some(previous_task) = tasks.remove(&name) {
+            self.stop(&name, previous_task).await;
+        }
+
+        let (cancel, receiver) = watch::channel(false);
+        let future = task(CancellationSignal(receiver));
+        let states = self.states.clone();
+        let task_name = name.clone();
+        self.set_state(&name, TaskState::Running);
+        let handle = tokio::spawn(async move {
+            let state = match future.await {
+                Ok(()) => TaskState::Finished,
+                Err(err) => {
+                    log::warn!("[{task_name}] failed: {err}");
+                    TaskState::Failed(err.to_string())
+                }
+            };
+            states.lock().unwrap().insert(task_name, state);
+        });
+        tasks.insert(name, ManagedTask { cancel, handle });
+    }
+
+    /// Signals a task to stop and waits until it did. Returns `false` if there was no such task.
+    pub async fn cancel(&self, name: &str) -> bool {
+        let task = self.tasks.lock().await.remove(name);
+        match task {
+            Some(task) => {
+                self.stop(name, task).await;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Aborts a task at its next await point, without letting it clean up. Returns `false` if there was no such task.
+    pub async fn abort(&self, name: &str) -> bool {
+        let task = self.tasks.lock().await.remove(name);
+        match task {
+            Some(task) => {
+                task.handle.abort();
+                if task.handle.await.is_err() {
+                    self.set_state(name, TaskState::Cancelled);
+                }
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Waits until a task finished by itself and returns its final state, `None` if there was no such task.
+    pub async fn join(&self, name: &str) -> Option<TaskState> {
+        let task = self.tasks.lock().await.remove(name)?;
+        task.handle.await.ok();
+        self.state(name)
+    }
+
+    /// Returns the state of a task.
+    pub fn state(&self, name: &str) -> Option<TaskState> {
+        self.states.lock().unwrap().get(name).cloned()
+    }
+
+    /// Returns the states of all tasks that were spawned, by name.
+    pub fn states(&self) -> HashMap<String, TaskState> {
+        self.states.lock().unwrap().clone()
+    }
+
+    /// Returns whether a task is running.
+    pub fn is_running(&self, name: &str) -> bool {
+        self.state(name) == Some(TaskState::Running)
+    }
+
+    async fn stop(&self, name: &str, task: ManagedTask) {
+        task.cancel.send(true).ok();
+        let finished = task.handle.is_finished();
+        task.handle.await.ok();
+        if !finished {
+            self.set_state(name, TaskState::Cancelled);
+        }
+    }
+
+    fn set_state(&self, name: &str, state: TaskState) {
+        self.states.lock().unwrap().insert(name.to_string(), state);
+    }
+}
+
+impl Drop for TaskManager {
+    fn drop(&mut self) {
+        for task in self.tasks.get_mut().values() {
+            task.handle.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cancel_join_and_fail() {
+        let task_manager = TaskManager::default();
+
+        task_manager
+            .spawn("pending", |mut cancellation| async move {
+                cancellation.cancelled().await;
+                Ok(())
+            })
+            .await;
+        assert!(task_manager.is_running("pending"));
+        assert!(task_manager.cancel("pending").await);
+        assert_eq!(task_manager.state("pending"), Some(TaskState::Cancelled));
+        assert!(!task_manager.cancel("pending").await);
+
+        task_manager
+            .spawn("failing", |_| async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                Err(crate::wallet::Error::Storage("unavailable".to_string()))
+            })
+            .await;
+        assert_eq!(
+            task_manager.join("failing").await,
+            Some(TaskState::Failed("error accessing storage: unavailable".to_string()))
+        );
+
+        task_manager
+            .spawn("aborted", |_| async {
+                std::future::pending::<()>().await;
+                Ok(())
+            })
+            .await;
+        assert!(task_manager.abort("aborted").await);
+        assert_eq!(task_manager.state("aborted"), Some(TaskState::Cancelled));
+    }
+}
@@ -8,7 +8,10 @@ use serde::{
     Serialize,
 };
 
-use crate::types::block::{address::Bech32Address, payload::transaction::TransactionId};
+use crate::{
+    types::block::{address::Bech32Address, payload::transaction::TransactionId},
+    wallet::account::PendingApproval,
+};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -19,9 +22,18 @@ pub enum Error {
     /// Account not found
     #[error("account {0} not found")]
     AccountNotFound(String),
+    /// Address book labels must be unique.
+    #[error("address book label {0} already exists")]
+    AddressBookLabelAlreadyExists(String),
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(Bech32Address),
+    /// The transaction was persisted as pending approval instead of being signed.
+    #[error("transaction requires approval, pending approval {} was created", .0.id)]
+    ApprovalRequired(Box<PendingApproval>),
+    /// The creator of a pending approval can't approve it.
+    #[error("pending approval {id} can't be approved by its creator {creator}")]
+    ApproverIsCreator { id: String, creator: String },
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
@@ -43,6 +55,16 @@ pub enum Error {
     /// Custom input error
     #[error("custom input error {0}")]
     CustomInput(String),
+    /// The event log is disabled
+    #[cfg(all(feature = "events", feature = "storage"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "events", feature = "storage"))))]
+    #[error("the event log is disabled")]
+    EventLogDisabled,
+    /// Invalid webhook options
+    #[cfg(feature = "webhooks")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+    #[error("invalid webhook: {0}")]
+    InvalidWebhook(String),
     /// Failed to get remainder
     #[error("failed to get remainder address")]
     FailedToGetRemainder,
@@ -97,6 +119,15 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[error("participation error {0}")]
     Participation(#[from] crate::types::api::plugins::participation::error::Error),
+    /// Pending approval not found in account.
+    #[error("pending approval {0} not found")]
+    PendingApprovalNotFound(String),
+    /// The transaction violates rules of the spending policy of the account.
+    #[error(
+        "spending policy violated: {}",
+        .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    SpendingPolicyViolated(Vec<crate::wallet::account::PolicyViolation>),
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
@@ -176,6 +207,13 @@ impl From<crate::client::stronghold::Error> for Error {
     }
 }
 
+#[cfg(feature = "mqtt")]
+impl From<crate::client::node_api::mqtt::Error> for Error {
+    fn from(error: crate::client::node_api::mqtt::Error) -> Self {
+        Self::Client(Box::new(crate::client::Error::Mqtt(error)))
+    }
+}
+
 #[cfg(feature = "ledger_nano")]
 impl From<crate::client::secret::ledger_nano::Error> for Error {
     fn from(error: crate::client::secret::ledger_nano::Error) -> Self {
@@ -0,0 +1,245 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::VecDeque;
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{watch, Mutex};
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        core::WalletInner,
+        events::{
+            types::{Event, WalletEvent},
+            EventFilter,
+        },
+        Wallet,
+    },
+};
+
+/// Options of the event log that persists wallet events in the wallet storage.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLogOptions {
+    /// Maximum number of unacknowledged events that are kept, the oldest ones are dropped first.
+    #[serde(default = "default_max_events")]
+    pub max_events: u64,
+    /// Only events matching the filter are persisted.
+    #[serde(default)]
+    pub filter: EventFilter,
+}
+
+fn default_max_events() -> u64 {
+    1000
+}
+
+impl Default for EventLogOptions {
+    fn default() -> Self {
+        Self {
+            max_events: default_max_events(),
+            filter: EventFilter::default(),
+        }
+    }
+}
+
+/// An event from the event log.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoggedEvent {
+    /// The sequence number of the event, increasing by one for every persisted event.
+    pub id: u64,
+    /// The event.
+    #[serde(flatten)]
+    pub event: Event,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EventLogState {
+    /// `None` if the event log is disabled.
+    options: Option<EventLogOptions>,
+    /// Id of the oldest stored event.
+    first_id: u64,
+    /// Id of the latest event, 0 if no event was logged yet.
+    last_id: u64,
+    /// Id of the latest acknowledged event.
+    acknowledged_id: u64,
+}
+
+impl Default for EventLogState {
+    fn default() -> Self {
+        Self {
+            options: None,
+            first_id: 1,
+            last_id: 0,
+            acknowledged_id: 0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct EventLog {
+    state: Mutex<EventLogState>,
+    last_id: watch::Sender<u64>,
+}
+
+impl EventLog {
+    pub(crate) fn new(state: Option<EventLogState>) -> Self {
+        let state = state.unwrap_or_default();
+        Self {
+            last_id: watch::channel(state.last_id).0,
+            state: Mutex::new(state),
+        }
+    }
+}
+
+impl<S: SecretManage> WalletInner<S> {
+    /// Persists an event if the event log is enabled and the event matches its filter. Failures are only logged, so
+    /// they don't interrupt the operation that emitted the event.
+    pub(crate) async fn log_event(&self, account_index: u32, event: &WalletEvent) {
+        if let Err(err) = self.try_log_event(account_index, event).await {
+            log::warn!("[log_event] failed to persist event: {err}");
+        }
+    }
+
+    async fn try_log_event(&self, account_index: u32, event: &WalletEvent) -> crate::wallet::Result<()> {
+        let mut state = self.event_log.state.lock().await;
+        let event = Event {
+            account_index,
+            event: event.clone(),
+        };
+        let Some(max_events) = state
+            .options
+            .as_ref()
+            .filter(|options| options.filter.matches(&event))
+            .map(|options| options.max_events)
+        else {
+            return Ok(());
+        };
+
+        let storage_manager = self.storage_manager.read().await;
+        let logged_event = LoggedEvent {
+            id: state.last_id + 1,
+            event,
+        };
+        storage_manager.insert_logged_event(&logged_event).await?;
+        state.last_id = logged_event.id;
+
+        let first_kept_id = (state.last_id + 1).saturating_sub(max_events.max(1));
+        if first_kept_id > state.first_id {
+            storage_manager
+                .remove_logged_events(state.first_id..first_kept_id)
+                .await?;
+            state.first_id = first_kept_id;
+        }
+        storage_manager.set_event_log_state(&state).await?;
+        self.event_log.last_id.send_replace(state.last_id);
+
+        Ok(())
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Enables the event log, which persists emitted events in the wallet storage until they are acknowledged, or
+    /// updates its options. Stays enabled when the wallet is loaded again.
+    pub async fn enable_event_log(&self, options: EventLogOptions) -> crate::wallet::Result<()> {
+        let mut state = self.event_log.state.lock().await;
+        let storage_manager = self.storage_manager.read().await;
+
+        let first_kept_id = (state.last_id + 1).saturating_sub(options.max_events.max(1));
+        if first_kept_id > state.first_id {
+            storage_manager
+                .remove_logged_events(state.first_id..first_kept_id)
+                .await?;
+            state.first_id = first_kept_id;
+        }
+        state.options = Some(options);
+
+        storage_manager.set_event_log_state(&state).await
+    }
+
+    /// Disables the event log and removes the persisted events. Event ids continue where they stopped if the log is
+    /// enabled again.
+    pub async fn disable_event_log(&self) -> crate::wallet::Result<()> {
+        let mut state = self.event_log.state.lock().await;
+        let storage_manager = self.storage_manager.read().await;
+
+        storage_manager
+            .remove_logged_events(state.first_id..=state.last_id)
+            .await?;
+        state.first_id = state.last_id + 1;
+        state.options = None;
+
+        storage_manager.set_event_log_state(&state).await
+    }
+
+    /// Acknowledges all logged events up to and including `id`, which removes them from the event log. Streams
+    /// created with [`Wallet::logged_events()`] resume after the last acknowledged event.
+    pub async fn acknowledge_event(&self, id: u64) -> crate::wallet::Result<()> {
+        let mut state = self.event_log.state.lock().await;
+        let id = id.min(state.last_id);
+        if id <= state.acknowledged_id {
+            return Ok(());
+        }
+        let storage_manager = self.storage_manager.read().await;
+
+        storage_manager.remove_logged_events(state.first_id..=id).await?;
+        state.first_id = state.first_id.max(id + 1);
+        state.acknowledged_id = id;
+
+        storage_manager.set_event_log_state(&state).await
+    }
+
+    /// Returns a stream of the logged events matching `filter`. It starts with the persisted events after
+    /// `resume_after`, or after the last acknowledged event if `None`, and continues with newly logged events.
+    pub async fn logged_events(
+        &self,
+        filter: EventFilter,
+        resume_after: impl Into<Option<u64>> + Send,
+    ) -> crate::wallet::Result<impl Stream<Item = crate::wallet::Result<LoggedEvent>>> {
+        let cursor = {
+            let state = self.event_log.state.lock().await;
+            if state.options.is_none() {
+                return Err(crate::wallet::Error::EventLogDisabled);
+            }
+            resume_after.into().unwrap_or(state.acknowledged_id)
+        };
+        let receiver = self.event_log.last_id.subscribe();
+
+        Ok(futures::stream::try_unfold(
+            (self.clone(), filter, cursor, receiver, VecDeque::new()),
+            |(wallet, filter, mut cursor, mut receiver, mut pending)| async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        return Ok(Some((event, (wallet, filter, cursor, receiver, pending))));
+                    }
+
+                    let last_id = *receiver.borrow_and_update();
+                    if cursor < last_id {
+                        let first_id = wallet.event_log.state.lock().await.first_id;
+                        let storage_manager = wallet.storage_manager.read().await;
+                        for id in (cursor + 1).max(first_id)..=last_id {
+                            // Events that got dropped or acknowledged in the meantime are skipped
+                            if let Some(event) = storage_manager.get_logged_event(id).await? {
+                                if filter.matches(&event.event) {
+                                    pending.push_back(event);
+                                }
+                            }
+                        }
+                        cursor = last_id;
+                        continue;
+                    }
+
+                    if receiver.changed().await.is_err() {
+                        return Ok(None);
+                    }
+                }
+            },
+        ))
+    }
+}
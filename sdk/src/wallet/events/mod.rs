@@ -1,20 +1,35 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "storage")]
+pub(crate) mod log;
+pub mod stream;
 pub mod types;
+#[cfg(feature = "webhooks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+pub mod webhook;
 
 use alloc::sync::Arc;
 use std::{
     collections::HashMap,
     fmt::{Debug, Formatter, Result},
+    sync::Mutex,
 };
 
-pub use self::types::{Event, WalletEvent, WalletEventType};
+use tokio::sync::mpsc::UnboundedSender;
+
+#[cfg(feature = "storage")]
+pub use self::log::{EventLogOptions, LoggedEvent};
+pub use self::{
+    stream::{EventFilter, EventStream},
+    types::{Event, WalletEvent, WalletEventType},
+};
 
 type Handler<T> = Arc<dyn Fn(&T) + Send + Sync + 'static>;
 
 pub struct EventEmitter {
     handlers: HashMap<WalletEventType, Vec<Handler<Event>>>,
+    streams: Mutex<Vec<(EventFilter, UnboundedSender<Event>)>>,
 }
 
 impl EventEmitter {
@@ -22,9 +37,21 @@ impl EventEmitter {
     pub fn new() -> Self {
         Self {
             handlers: HashMap::new(),
+            streams: Mutex::new(Vec::new()),
         }
     }
 
+    /// Returns a stream of the events matching `filter`. Unlike listeners, streams aren't removed by
+    /// [`EventEmitter::clear()`], but when they are dropped.
+    pub fn stream(&self, filter: EventFilter) -> EventStream {
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+        self.streams
+            .lock()
+            .expect("event streams lock poisoned")
+            .push((filter, sender));
+        EventStream::new(receiver)
+    }
+
     /// Registers function `handler` as a listener for a `WalletEventType`. There may be
     /// multiple listeners for a single event.
     pub fn on<F>(&mut self, events: impl IntoIterator<Item = WalletEventType>, handler: F)
@@ -44,6 +71,18 @@ impl EventEmitter {
                 WalletEventType::ConsolidationRequired,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
+                WalletEventType::BalanceChange,
+                WalletEventType::NativeTokensReceived,
+                WalletEventType::NativeTokensSent,
+                WalletEventType::NftReceived,
+                WalletEventType::NftSent,
+                WalletEventType::ExpirationApproaching,
+                WalletEventType::OutputExpired,
+                WalletEventType::NodeHealthChange,
+                WalletEventType::SyncStarted,
+                WalletEventType::SyncFinished,
+                WalletEventType::StorageError,
+                WalletEventType::OperationProgress,
             ] {
                 self.handlers.entry(event_type).or_default().push(handler.clone());
             }
@@ -69,21 +108,20 @@ impl EventEmitter {
     /// Invokes all listeners of `event`, passing a reference to `payload` as an
     /// argument to each of them.
     pub fn emit(&self, account_index: u32, event: WalletEvent) {
-        let event_type = match &event {
-            WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
-            WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
-            WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
-            WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
-            WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
-            #[cfg(feature = "ledger_nano")]
-            WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
-        };
+        let event_type = WalletEventType::from(&event);
         let event = Event { account_index, event };
         if let Some(handlers) = self.handlers.get(&event_type) {
             for handler in handlers {
                 handler(&event);
             }
         }
+        // Streams that were dropped are removed here
+        self.streams
+            .lock()
+            .expect("event streams lock poisoned")
+            .retain(|(filter, sender)| {
+                !sender.is_closed() && (!filter.matches(&event) || sender.send(event.clone()).is_ok())
+            });
     }
 }
 
@@ -0,0 +1,75 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::HashSet,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use crate::wallet::events::types::{Event, WalletEventType};
+
+/// Filter for the events of a wallet event stream. An empty filter matches all events.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventFilter {
+    /// Only events of these accounts, all accounts if empty. Events that aren't related to an account always pass.
+    #[serde(default)]
+    pub account_indexes: HashSet<u32>,
+    /// Only events of these types, all types if empty.
+    #[serde(default)]
+    pub event_types: HashSet<WalletEventType>,
+}
+
+impl EventFilter {
+    /// Creates a filter matching all events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match events of the given accounts.
+    pub fn with_account_indexes(mut self, account_indexes: impl IntoIterator<Item = u32>) -> Self {
+        self.account_indexes.extend(account_indexes);
+        self
+    }
+
+    /// Only match events of the given types.
+    pub fn with_event_types(mut self, event_types: impl IntoIterator<Item = WalletEventType>) -> Self {
+        self.event_types.extend(event_types);
+        self
+    }
+
+    /// Returns whether the event passes the filter.
+    pub fn matches(&self, event: &Event) -> bool {
+        (self.account_indexes.is_empty()
+            || self.account_indexes.contains(&event.account_index)
+            || event.event.is_wallet_wide())
+            && (self.event_types.is_empty() || self.event_types.contains(&WalletEventType::from(&event.event)))
+    }
+}
+
+/// A stream of wallet events, created with [`Wallet::events()`](crate::wallet::Wallet::events). Events are buffered
+/// until they are consumed and only events emitted after its creation are received.
+#[derive(Debug)]
+#[must_use = "streams do nothing unless polled"]
+pub struct EventStream {
+    receiver: UnboundedReceiver<Event>,
+}
+
+impl EventStream {
+    pub(crate) fn new(receiver: UnboundedReceiver<Event>) -> Self {
+        Self { receiver }
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
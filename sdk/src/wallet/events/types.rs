@@ -2,24 +2,27 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use getset::Getters;
+use primitive_types::U256;
 use serde::{Deserialize, Serialize, Serializer};
 
 use crate::{
-    client::api::PreparedTransactionDataDto,
+    client::{api::PreparedTransactionDataDto, node_manager::node::NodeHealthChange, operation::OperationProgress},
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
             address::Bech32Address,
+            output::{NftId, OutputId, TokenId},
             payload::transaction::{dto::TransactionPayloadDto, TransactionId},
         },
     },
-    wallet::account::types::{InclusionState, OutputDataDto},
+    wallet::account::types::{Balance, InclusionState, OutputDataDto},
 };
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Event {
-    /// Associated account index.
+    /// Associated account index, 0 for events that aren't related to an account.
     pub account_index: u32,
     /// The event
     pub event: WalletEvent,
@@ -36,6 +39,32 @@ pub enum WalletEvent {
     SpentOutput(Box<SpentOutputEvent>),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
+    BalanceChange(Box<BalanceChangeEvent>),
+    NativeTokensReceived(NativeTokensEvent),
+    NativeTokensSent(NativeTokensEvent),
+    NftReceived(NftEvent),
+    NftSent(NftEvent),
+    ExpirationApproaching(ExpirationEvent),
+    OutputExpired(ExpirationEvent),
+    /// Not related to an account, passes every account filter.
+    NodeHealthChange(NodeHealthChange),
+    SyncStarted,
+    SyncFinished(SyncFinishedEvent),
+    StorageError(StorageErrorEvent),
+    /// Emitted with the index of the account the progress is related to, otherwise it's not related to an account
+    /// and passes every account filter.
+    OperationProgress(OperationProgressEvent),
+}
+
+impl WalletEvent {
+    /// Returns whether the event isn't related to an account and is thus emitted with account index 0.
+    pub fn is_wallet_wide(&self) -> bool {
+        match self {
+            Self::NodeHealthChange(_) => true,
+            Self::OperationProgress(event) => event.progress.account_index().is_none(),
+            _ => false,
+        }
+    }
 }
 
 impl Serialize for WalletEvent {
@@ -58,6 +87,15 @@ impl Serialize for WalletEvent {
             T3(&'a SpentOutputEvent),
             T4(&'a TransactionInclusionEvent),
             T5(TransactionProgressEvent_<'a>),
+            T6(&'a BalanceChangeEvent),
+            T7(&'a NativeTokensEvent),
+            T8(&'a NftEvent),
+            T9(&'a ExpirationEvent),
+            T10(&'a NodeHealthChange),
+            T11,
+            T12(&'a SyncFinishedEvent),
+            T13(&'a StorageErrorEvent),
+            T14(&'a OperationProgressEvent),
         }
         #[derive(Serialize)]
         struct TypedWalletEvent_<'a> {
@@ -92,6 +130,54 @@ impl Serialize for WalletEvent {
                 kind: WalletEventType::TransactionProgress as u8,
                 event: WalletEvent_::T5(TransactionProgressEvent_ { progress: e }),
             },
+            Self::BalanceChange(e) => TypedWalletEvent_ {
+                kind: WalletEventType::BalanceChange as u8,
+                event: WalletEvent_::T6(e),
+            },
+            Self::NativeTokensReceived(e) => TypedWalletEvent_ {
+                kind: WalletEventType::NativeTokensReceived as u8,
+                event: WalletEvent_::T7(e),
+            },
+            Self::NativeTokensSent(e) => TypedWalletEvent_ {
+                kind: WalletEventType::NativeTokensSent as u8,
+                event: WalletEvent_::T7(e),
+            },
+            Self::NftReceived(e) => TypedWalletEvent_ {
+                kind: WalletEventType::NftReceived as u8,
+                event: WalletEvent_::T8(e),
+            },
+            Self::NftSent(e) => TypedWalletEvent_ {
+                kind: WalletEventType::NftSent as u8,
+                event: WalletEvent_::T8(e),
+            },
+            Self::ExpirationApproaching(e) => TypedWalletEvent_ {
+                kind: WalletEventType::ExpirationApproaching as u8,
+                event: WalletEvent_::T9(e),
+            },
+            Self::OutputExpired(e) => TypedWalletEvent_ {
+                kind: WalletEventType::OutputExpired as u8,
+                event: WalletEvent_::T9(e),
+            },
+            Self::NodeHealthChange(e) => TypedWalletEvent_ {
+                kind: WalletEventType::NodeHealthChange as u8,
+                event: WalletEvent_::T10(e),
+            },
+            Self::SyncStarted => TypedWalletEvent_ {
+                kind: WalletEventType::SyncStarted as u8,
+                event: WalletEvent_::T11,
+            },
+            Self::SyncFinished(e) => TypedWalletEvent_ {
+                kind: WalletEventType::SyncFinished as u8,
+                event: WalletEvent_::T12(e),
+            },
+            Self::StorageError(e) => TypedWalletEvent_ {
+                kind: WalletEventType::StorageError as u8,
+                event: WalletEvent_::T13(e),
+            },
+            Self::OperationProgress(e) => TypedWalletEvent_ {
+                kind: WalletEventType::OperationProgress as u8,
+                event: WalletEvent_::T14(e),
+            },
         };
         event.serialize(serializer)
     }
@@ -143,12 +229,202 @@ impl<'de> Deserialize<'de> for WalletEvent {
                         })?
                         .progress,
                 ),
+                WalletEventType::BalanceChange => {
+                    Self::BalanceChange(Box::new(BalanceChangeEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize BalanceChange: {e}"))
+                    })?))
+                }
+                WalletEventType::NativeTokensReceived => {
+                    Self::NativeTokensReceived(NativeTokensEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize NativeTokensReceived: {e}"))
+                    })?)
+                }
+                WalletEventType::NativeTokensSent => Self::NativeTokensSent(
+                    NativeTokensEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize NativeTokensSent: {e}")))?,
+                ),
+                WalletEventType::NftReceived => Self::NftReceived(
+                    NftEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize NftReceived: {e}")))?,
+                ),
+                WalletEventType::NftSent => Self::NftSent(
+                    NftEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize NftSent: {e}")))?,
+                ),
+                WalletEventType::ExpirationApproaching => {
+                    Self::ExpirationApproaching(ExpirationEvent::deserialize(value).map_err(|e| {
+                        serde::de::Error::custom(format!("cannot deserialize ExpirationApproaching: {e}"))
+                    })?)
+                }
+                WalletEventType::OutputExpired => Self::OutputExpired(
+                    ExpirationEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize OutputExpired: {e}")))?,
+                ),
+                WalletEventType::NodeHealthChange => Self::NodeHealthChange(
+                    NodeHealthChange::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize NodeHealthChange: {e}")))?,
+                ),
+                WalletEventType::SyncStarted => Self::SyncStarted,
+                WalletEventType::SyncFinished => Self::SyncFinished(
+                    SyncFinishedEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize SyncFinished: {e}")))?,
+                ),
+                WalletEventType::StorageError => Self::StorageError(
+                    StorageErrorEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize StorageError: {e}")))?,
+                ),
+                WalletEventType::OperationProgress => Self::OperationProgress(
+                    OperationProgressEvent::deserialize(value)
+                        .map_err(|e| serde::de::Error::custom(format!("cannot deserialize OperationProgress: {e}")))?,
+                ),
             },
         )
     }
 }
 
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for WalletEvent {
+    fn schema_name() -> String {
+        "WalletEvent".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut events = vec![typed_schema(WalletEventType::ConsolidationRequired as u8, None)];
+        #[cfg(feature = "ledger_nano")]
+        events.push(typed_schema(
+            WalletEventType::LedgerAddressGeneration as u8,
+            Some(gen.subschema_for::<AddressData>()),
+        ));
+        events.extend([
+            typed_schema(
+                WalletEventType::NewOutput as u8,
+                Some(gen.subschema_for::<NewOutputEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::SpentOutput as u8,
+                Some(gen.subschema_for::<SpentOutputEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::TransactionInclusion as u8,
+                Some(gen.subschema_for::<TransactionInclusionEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::TransactionProgress as u8,
+                Some(object_schema(
+                    "progress",
+                    gen.subschema_for::<TransactionProgressEvent>(),
+                )),
+            ),
+            typed_schema(
+                WalletEventType::BalanceChange as u8,
+                Some(gen.subschema_for::<BalanceChangeEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::NativeTokensReceived as u8,
+                Some(gen.subschema_for::<NativeTokensEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::NativeTokensSent as u8,
+                Some(gen.subschema_for::<NativeTokensEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::NftReceived as u8,
+                Some(gen.subschema_for::<NftEvent>()),
+            ),
+            typed_schema(WalletEventType::NftSent as u8, Some(gen.subschema_for::<NftEvent>())),
+            typed_schema(
+                WalletEventType::ExpirationApproaching as u8,
+                Some(gen.subschema_for::<ExpirationEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::OutputExpired as u8,
+                Some(gen.subschema_for::<ExpirationEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::NodeHealthChange as u8,
+                Some(gen.subschema_for::<NodeHealthChange>()),
+            ),
+            typed_schema(WalletEventType::SyncStarted as u8, None),
+            typed_schema(
+                WalletEventType::SyncFinished as u8,
+                Some(gen.subschema_for::<SyncFinishedEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::StorageError as u8,
+                Some(gen.subschema_for::<StorageErrorEvent>()),
+            ),
+            typed_schema(
+                WalletEventType::OperationProgress as u8,
+                Some(gen.subschema_for::<OperationProgressEvent>()),
+            ),
+        ]);
+        one_of_schema(events)
+    }
+}
+
+/// Describes an object with a numeric `type` field and the fields of the payload, the way the events are serialized.
+#[cfg(feature = "json_schema")]
+fn typed_schema(kind: u8, payload: Option<schemars::schema::Schema>) -> schemars::schema::Schema {
+    use schemars::schema::{InstanceType, ObjectValidation, SchemaObject, SubschemaValidation};
+
+    let mut typed = ObjectValidation::default();
+    typed.properties.insert(
+        "type".to_string(),
+        SchemaObject {
+            const_value: Some(kind.into()),
+            ..Default::default()
+        }
+        .into(),
+    );
+    typed.required.insert("type".to_string());
+    let typed = SchemaObject {
+        instance_type: Some(InstanceType::Object.into()),
+        object: Some(Box::new(typed)),
+        ..Default::default()
+    }
+    .into();
+
+    match payload {
+        Some(payload) => SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                all_of: Some(vec![typed, payload]),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into(),
+        None => typed,
+    }
+}
+
+/// Describes an object with a single required field.
+#[cfg(feature = "json_schema")]
+fn object_schema(field: &str, schema: schemars::schema::Schema) -> schemars::schema::Schema {
+    let mut object = schemars::schema::ObjectValidation::default();
+    object.properties.insert(field.to_string(), schema);
+    object.required.insert(field.to_string());
+    schemars::schema::SchemaObject {
+        instance_type: Some(schemars::schema::InstanceType::Object.into()),
+        object: Some(Box::new(object)),
+        ..Default::default()
+    }
+    .into()
+}
+
+#[cfg(feature = "json_schema")]
+fn one_of_schema(schemas: Vec<schemars::schema::Schema>) -> schemars::schema::Schema {
+    schemars::schema::SchemaObject {
+        subschemas: Some(Box::new(schemars::schema::SubschemaValidation {
+            one_of: Some(schemas),
+            ..Default::default()
+        })),
+        ..Default::default()
+    }
+    .into()
+}
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[repr(u8)]
 #[non_exhaustive]
 pub enum WalletEventType {
@@ -160,6 +436,44 @@ pub enum WalletEventType {
     SpentOutput = 3,
     TransactionInclusion = 4,
     TransactionProgress = 5,
+    BalanceChange = 6,
+    NativeTokensReceived = 7,
+    NativeTokensSent = 8,
+    NftReceived = 9,
+    NftSent = 10,
+    ExpirationApproaching = 11,
+    OutputExpired = 12,
+    NodeHealthChange = 13,
+    SyncStarted = 14,
+    SyncFinished = 15,
+    StorageError = 16,
+    OperationProgress = 17,
+}
+
+impl From<&WalletEvent> for WalletEventType {
+    fn from(event: &WalletEvent) -> Self {
+        match event {
+            WalletEvent::NewOutput(_) => Self::NewOutput,
+            WalletEvent::SpentOutput(_) => Self::SpentOutput,
+            WalletEvent::TransactionInclusion(_) => Self::TransactionInclusion,
+            WalletEvent::TransactionProgress(_) => Self::TransactionProgress,
+            WalletEvent::ConsolidationRequired => Self::ConsolidationRequired,
+            #[cfg(feature = "ledger_nano")]
+            WalletEvent::LedgerAddressGeneration(_) => Self::LedgerAddressGeneration,
+            WalletEvent::BalanceChange(_) => Self::BalanceChange,
+            WalletEvent::NativeTokensReceived(_) => Self::NativeTokensReceived,
+            WalletEvent::NativeTokensSent(_) => Self::NativeTokensSent,
+            WalletEvent::NftReceived(_) => Self::NftReceived,
+            WalletEvent::NftSent(_) => Self::NftSent,
+            WalletEvent::ExpirationApproaching(_) => Self::ExpirationApproaching,
+            WalletEvent::OutputExpired(_) => Self::OutputExpired,
+            WalletEvent::NodeHealthChange(_) => Self::NodeHealthChange,
+            WalletEvent::SyncStarted => Self::SyncStarted,
+            WalletEvent::SyncFinished(_) => Self::SyncFinished,
+            WalletEvent::StorageError(_) => Self::StorageError,
+            WalletEvent::OperationProgress(_) => Self::OperationProgress,
+        }
+    }
 }
 
 impl TryFrom<u8> for WalletEventType {
@@ -174,6 +488,18 @@ impl TryFrom<u8> for WalletEventType {
             3 => Self::SpentOutput,
             4 => Self::TransactionInclusion,
             5 => Self::TransactionProgress,
+            6 => Self::BalanceChange,
+            7 => Self::NativeTokensReceived,
+            8 => Self::NativeTokensSent,
+            9 => Self::NftReceived,
+            10 => Self::NftSent,
+            11 => Self::ExpirationApproaching,
+            12 => Self::OutputExpired,
+            13 => Self::NodeHealthChange,
+            14 => Self::SyncStarted,
+            15 => Self::SyncFinished,
+            16 => Self::StorageError,
+            17 => Self::OperationProgress,
             _ => return Err(format!("invalid event type {value}")),
         };
         Ok(event_type)
@@ -181,6 +507,7 @@ impl TryFrom<u8> for WalletEventType {
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct NewOutputEvent {
     /// The new output.
@@ -191,21 +518,103 @@ pub struct NewOutputEvent {
     /// The inputs for the transaction that created the output. Might be pruned and not available.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub transaction_inputs: Option<Vec<OutputWithMetadataResponse>>,
+    /// The address book label of the sender of the output, if it has a sender feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sender_label: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 pub struct SpentOutputEvent {
     /// The spent output.
     pub output: OutputDataDto,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionInclusionEvent {
     pub transaction_id: TransactionId,
     pub inclusion_state: InclusionState,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct BalanceChangeEvent {
+    /// The balance before the change.
+    pub previous: Balance,
+    /// The balance after the change.
+    pub current: Balance,
+}
+
+/// A native token amount that was received or sent.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTokensEvent {
+    pub token_id: TokenId,
+    /// The amount by which the total balance of the native token changed.
+    #[cfg_attr(feature = "json_schema", schemars(with = "String"))]
+    pub amount: U256,
+}
+
+/// An NFT that was received or sent.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NftEvent {
+    pub nft_id: NftId,
+    /// The label of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// A claimable output with an expiration unlock condition whose expiration is approaching or passed. After it
+/// passed, only the return address can unlock the output.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ExpirationEvent {
+    pub output_id: OutputId,
+    /// Unix timestamp in seconds at which the output expires.
+    pub expires_at: u32,
+    /// The address that can unlock the output after it expired.
+    pub return_address: Bech32Address,
+    /// The address book label of the return address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub return_address_label: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct SyncFinishedEvent {
+    /// How long the sync took in milliseconds.
+    pub duration_ms: u64,
+    /// Number of synced addresses.
+    pub synced_addresses: usize,
+    /// Number of synced unspent outputs.
+    pub synced_outputs: usize,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+pub struct StorageErrorEvent {
+    /// The error message.
+    pub error: String,
+}
+
+/// The progress of an operation run with `Wallet::run_operation()`.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct OperationProgressEvent {
+    /// The id of the operation.
+    pub operation_id: String,
+    /// The progress of the operation.
+    pub progress: OperationProgress,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum TransactionProgressEvent {
@@ -325,6 +734,25 @@ impl<'de> Deserialize<'de> for TransactionProgressEvent {
     }
 }
 
+#[cfg(feature = "json_schema")]
+impl schemars::JsonSchema for TransactionProgressEvent {
+    fn schema_name() -> String {
+        "TransactionProgressEvent".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        one_of_schema(vec![
+            typed_schema(0, None),
+            typed_schema(1, Some(gen.subschema_for::<AddressData>())),
+            typed_schema(2, Some(gen.subschema_for::<PreparedTransactionDataDto>())),
+            typed_schema(3, Some(object_schema("hash", gen.subschema_for::<String>()))),
+            typed_schema(4, None),
+            typed_schema(5, None),
+            typed_schema(6, None),
+        ])
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct AddressConsolidationNeeded {
     /// The associated address.
@@ -333,6 +761,7 @@ pub struct AddressConsolidationNeeded {
 
 /// Address event data.
 #[derive(Debug, Clone, Serialize, Deserialize, Getters, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "json_schema", derive(schemars::JsonSchema))]
 #[getset(get = "pub")]
 pub struct AddressData {
     /// The address.
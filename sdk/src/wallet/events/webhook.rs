@@ -0,0 +1,580 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Delivery of wallet events to HTTP endpoints
+
+use std::{
+    collections::VecDeque,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+    time::Duration,
+};
+
+use crypto::macs::hmac::HMAC_SHA256;
+use futures::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{Mutex, Notify};
+
+use crate::{
+    client::secret::SecretManage,
+    wallet::{
+        core::{WalletInner, WEBHOOKS_TASK},
+        events::{
+            types::{Event, WalletEvent},
+            EventFilter,
+        },
+        storage::StorageManager,
+        Wallet,
+    },
+    Url,
+};
+
+/// Header with the id of the delivery, which stays the same for retries, so receivers can ignore duplicates.
+pub const WEBHOOK_ID_HEADER: &str = "X-Webhook-Id";
+/// Header with the unix timestamp in seconds at which the request was signed.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+/// Header with the signature created by [`webhook_signature()`].
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// Returns the signature of a webhook request, `sha256=` followed by the hex encoded HMAC-SHA256 of
+/// `{timestamp}.{body}` with the secret of the endpoint as key.
+pub fn webhook_signature(secret: &str, timestamp: u64, body: &[u8]) -> String {
+    let mut data = format!("{timestamp}.").into_bytes();
+    data.extend_from_slice(body);
+    let mut mac = [0; 32];
+    HMAC_SHA256(&data, secret.as_bytes(), &mut mac);
+    format!("sha256={}", hex::encode(mac))
+}
+
+/// An HTTP endpoint that events are POSTed to as JSON.
+#[derive(Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEndpoint {
+    /// The url of the endpoint, also identifies the endpoint.
+    pub url: Url,
+    /// The secret the requests are signed with, it isn't persisted.
+    pub secret: String,
+    /// Only events matching the filter are sent to the endpoint.
+    #[serde(default)]
+    pub filter: EventFilter,
+}
+
+impl WebhookEndpoint {
+    /// Creates an endpoint that receives all events.
+    pub fn new(url: Url, secret: impl Into<String>) -> Self {
+        Self {
+            url,
+            secret: secret.into(),
+            filter: EventFilter::default(),
+        }
+    }
+
+    /// Only send events matching the filter to the endpoint.
+    pub fn with_filter(mut self, filter: EventFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+}
+
+impl Debug for WebhookEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("WebhookEndpoint")
+            .field("url", &self.url)
+            .field("secret", &"<omitted>")
+            .field("filter", &self.filter)
+            .finish()
+    }
+}
+
+/// Options of the webhook delivery.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookOptions {
+    /// The endpoints the events are sent to.
+    pub endpoints: Vec<WebhookEndpoint>,
+    /// Maximum number of attempts for a delivery before it is dropped.
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every further retry.
+    #[serde(default = "default_initial_retry_delay")]
+    pub initial_retry_delay: Duration,
+    /// Upper bound of the delay between retries.
+    #[serde(default = "default_max_retry_delay")]
+    pub max_retry_delay: Duration,
+    /// Timeout of a single request.
+    #[serde(default = "default_request_timeout")]
+    pub request_timeout: Duration,
+    /// Maximum number of undelivered events in the outbox, the oldest ones are dropped first.
+    #[serde(default = "default_max_outbox_size")]
+    pub max_outbox_size: usize,
+}
+
+fn default_max_attempts() -> u32 {
+    10
+}
+
+fn default_initial_retry_delay() -> Duration {
+    Duration::from_secs(1)
+}
+
+fn default_max_retry_delay() -> Duration {
+    Duration::from_secs(300)
+}
+
+fn default_request_timeout() -> Duration {
+    Duration::from_secs(10)
+}
+
+fn default_max_outbox_size() -> usize {
+    10_000
+}
+
+impl WebhookOptions {
+    /// Creates the default options for the given endpoints.
+    pub fn new(endpoints: impl Into<Vec<WebhookEndpoint>>) -> Self {
+        Self {
+            endpoints: endpoints.into(),
+            max_attempts: default_max_attempts(),
+            initial_retry_delay: default_initial_retry_delay(),
+            max_retry_delay: default_max_retry_delay(),
+            request_timeout: default_request_timeout(),
+            max_outbox_size: default_max_outbox_size(),
+        }
+    }
+
+    /// Sets the maximum number of attempts for a delivery.
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the delay before the first retry and the upper bound of the delay between retries.
+    pub fn with_retry_delay(mut self, initial_retry_delay: Duration, max_retry_delay: Duration) -> Self {
+        self.initial_retry_delay = initial_retry_delay;
+        self.max_retry_delay = max_retry_delay;
+        self
+    }
+
+    /// Sets the timeout of a single request.
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+
+    /// Sets the maximum number of undelivered events.
+    pub fn with_max_outbox_size(mut self, max_outbox_size: usize) -> Self {
+        self.max_outbox_size = max_outbox_size;
+        self
+    }
+
+    /// Returns the delay before the next attempt after `attempts` failed ones.
+    fn retry_delay(&self, attempts: u32) -> Duration {
+        self.initial_retry_delay
+            .checked_mul(2u32.saturating_pow(attempts.saturating_sub(1)))
+            .map_or(self.max_retry_delay, |delay| delay.min(self.max_retry_delay))
+    }
+}
+
+/// An event waiting in the outbox to be delivered to an endpoint.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDelivery {
+    /// The id of the delivery, sent in the [`WEBHOOK_ID_HEADER`].
+    pub id: u64,
+    /// The url of the endpoint.
+    pub endpoint: Url,
+    /// The event, sent as request body.
+    pub event: Event,
+    /// Number of failed attempts.
+    pub attempts: u32,
+    /// Unix timestamp in milliseconds of the next attempt.
+    pub next_attempt_at: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct WebhookState {
+    /// `None` if webhooks are disabled.
+    options: Option<WebhookOptions>,
+    /// Id of the oldest delivery in the outbox.
+    first_id: u64,
+    /// Id of the latest delivery.
+    last_id: u64,
+    /// The deliveries are stored individually.
+    #[serde(skip)]
+    outbox: VecDeque<WebhookDelivery>,
+}
+
+impl Default for WebhookState {
+    fn default() -> Self {
+        Self {
+            options: None,
+            first_id: 1,
+            last_id: 0,
+            outbox: VecDeque::new(),
+        }
+    }
+}
+
+impl WebhookState {
+    /// Returns the state as it's persisted, without the secrets of the endpoints.
+    fn without_secrets(&self) -> Self {
+        let mut options = self.options.clone();
+        for endpoint in options.iter_mut().flat_map(|options| options.endpoints.iter_mut()) {
+            endpoint.secret.clear();
+        }
+        Self {
+            options,
+            first_id: self.first_id,
+            last_id: self.last_id,
+            outbox: VecDeque::new(),
+        }
+    }
+
+    fn update_first_id(&mut self) {
+        self.first_id = self.outbox.front().map_or(self.last_id + 1, |delivery| delivery.id);
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Webhooks {
+    state: Mutex<WebhookState>,
+    notify: Arc<Notify>,
+}
+
+impl Webhooks {
+    /// Loads the webhook options without secrets and the outbox. The delivery only starts once the secrets are set
+    /// again with [`Wallet::set_webhooks()`].
+    pub(crate) async fn load(storage_manager: &StorageManager) -> crate::wallet::Result<Self> {
+        let mut state = storage_manager.get_webhook_state().await?.unwrap_or_default();
+        for id in state.first_id..=state.last_id {
+            if let Some(delivery) = storage_manager.get_webhook_delivery(id).await? {
+                state.outbox.push_back(delivery);
+            }
+        }
+
+        Ok(Self {
+            state: Mutex::new(state),
+            notify: Default::default(),
+        })
+    }
+}
+
+fn now_millis() -> u64 {
+    crate::utils::unix_timestamp_now().as_millis() as u64
+}
+
+impl<S: SecretManage> WalletInner<S> {
+    /// Adds a delivery to the outbox for every endpoint whose filter matches the event. Failures are only logged, so
+    /// they don't interrupt the operation that emitted the event.
+    pub(crate) async fn enqueue_webhook_deliveries(&self, account_index: u32, event: &WalletEvent) {
+        if let Err(err) = self.try_enqueue_webhook_deliveries(account_index, event).await {
+            log::warn!("[webhooks] failed to persist event: {err}");
+        }
+    }
+
+    async fn try_enqueue_webhook_deliveries(
+        &self,
+        account_index: u32,
+        event: &WalletEvent,
+    ) -> crate::wallet::Result<()> {
+        let mut state = self.webhooks.state.lock().await;
+        let Some(options) = &state.options else {
+            return Ok(());
+        };
+        let event = Event {
+            account_index,
+            event: event.clone(),
+        };
+        let max_outbox_size = options.max_outbox_size;
+        let endpoints = options
+            .endpoints
+            .iter()
+            .filter(|endpoint| endpoint.filter.matches(&event))
+            .map(|endpoint| endpoint.url.clone())
+            .collect::<Vec<_>>();
+        if endpoints.is_empty() {
+            return Ok(());
+        }
+
+        let storage_manager = self.storage_manager.read().await;
+        for endpoint in endpoints {
+            state.last_id += 1;
+            let delivery = WebhookDelivery {
+                id: state.last_id,
+                endpoint,
+                event: event.clone(),
+                attempts: 0,
+                next_attempt_at: now_millis(),
+            };
+            storage_manager.set_webhook_delivery(&delivery).await?;
+            state.outbox.push_back(delivery);
+        }
+        while state.outbox.len() > max_outbox_size {
+            if let Some(dropped) = state.outbox.pop_front() {
+                log::warn!("[webhooks] outbox full, dropping delivery {}", dropped.id);
+                storage_manager.delete_webhook_delivery(dropped.id).await?;
+            }
+        }
+        state.update_first_id();
+
+        storage_manager.set_webhook_state(&state.without_secrets()).await?;
+        self.webhooks.notify.notify_one();
+
+        Ok(())
+    }
+}
+
+impl<S: 'static + SecretManage> WalletInner<S> {
+    /// Starts delivering the events in the outbox, replacing a running delivery task.
+    pub(crate) async fn start_webhook_worker(self: &Arc<Self>) {
+        let wallet = Arc::downgrade(self);
+        let notify = self.webhooks.notify.clone();
+        self.task_manager
+            .spawn(WEBHOOKS_TASK, |mut cancellation| async move {
+                let http_client = reqwest::Client::new();
+                while !cancellation.is_cancelled() {
+                    let Some(wallet) = wallet.upgrade() else {
+                        break;
+                    };
+                    let next_attempt_at = match wallet.deliver_due_webhooks(&http_client).await {
+                        Ok(next_attempt_at) => next_attempt_at,
+                        Err(err) => {
+                            log::warn!("[webhooks] failed to update the outbox: {err}");
+                            Some(now_millis() + 1000)
+                        }
+                    };
+                    let notified = notify.notified();
+                    // Don't keep the wallet alive while waiting
+                    drop(wallet);
+                    match next_attempt_at {
+                        Some(next_attempt_at) => {
+                            let delay = Duration::from_millis(next_attempt_at.saturating_sub(now_millis()));
+                            tokio::select! {
+                                _ = notified => {}
+                                _ = tokio::time::sleep(delay) => {}
+                                _ = cancellation.cancelled() => {}
+                            }
+                        }
+                        None => tokio::select! {
+                            _ = notified => {}
+                            _ = cancellation.cancelled() => {}
+                        },
+                    }
+                }
+                Ok(())
+            })
+            .await;
+    }
+
+    /// Attempts the deliveries that are due, up to `max_parallel_api_requests` at once, and returns when the next
+    /// attempt is due, `None` if the outbox is empty.
+    async fn deliver_due_webhooks(&self, http_client: &reqwest::Client) -> crate::wallet::Result<Option<u64>> {
+        let (options, due) = {
+            let state = self.webhooks.state.lock().await;
+            let Some(options) = state.options.clone() else {
+                return Ok(None);
+            };
+            let now = now_millis();
+            let due = state
+                .outbox
+                .iter()
+                .filter(|delivery| delivery.next_attempt_at <= now)
+                .cloned()
+                .collect::<Vec<_>>();
+            (options, due)
+        };
+
+        #[cfg(not(target_family = "wasm"))]
+        let max_parallel_deliveries = self.client.request_pool.size().await.max(1);
+        #[cfg(target_family = "wasm")]
+        let max_parallel_deliveries = crate::client::constants::MAX_PARALLEL_API_REQUESTS;
+
+        futures::stream::iter(due.into_iter().map(Ok))
+            .try_for_each_concurrent(max_parallel_deliveries, |delivery| {
+                self.deliver_webhook(http_client, &options, delivery)
+            })
+            .await?;
+
+        let state = self.webhooks.state.lock().await;
+        Ok(state.outbox.iter().map(|delivery| delivery.next_attempt_at).min())
+    }
+
+    /// Attempts a delivery and removes it from the outbox if it succeeded or ran out of attempts.
+    async fn deliver_webhook(
+        &self,
+        http_client: &reqwest::Client,
+        options: &WebhookOptions,
+        delivery: WebhookDelivery,
+    ) -> crate::wallet::Result<()> {
+        let endpoint = options
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.url == delivery.endpoint);
+        let delivered = match endpoint {
+            Some(endpoint) => match send_webhook(http_client, endpoint, &delivery, options.request_timeout).await {
+                Ok(()) => true,
+                Err(err) => {
+                    log::debug!("[webhooks] delivery {} to {} failed: {err}", delivery.id, endpoint.url);
+                    false
+                }
+            },
+            None => {
+                log::debug!("[webhooks] dropping delivery {} to removed endpoint", delivery.id);
+                true
+            }
+        };
+
+        let mut state = self.webhooks.state.lock().await;
+        let Some(position) = state.outbox.iter().position(|d| d.id == delivery.id) else {
+            // Removed in the meantime
+            return Ok(());
+        };
+        let storage_manager = self.storage_manager.read().await;
+        let attempts = delivery.attempts + 1;
+        if !delivered && attempts < options.max_attempts {
+            state.outbox[position].attempts = attempts;
+            state.outbox[position].next_attempt_at = now_millis() + options.retry_delay(attempts).as_millis() as u64;
+            return storage_manager.set_webhook_delivery(&state.outbox[position]).await;
+        }
+
+        if !delivered {
+            log::warn!(
+                "[webhooks] dropping delivery {} to {} after {attempts} attempts",
+                delivery.id,
+                delivery.endpoint
+            );
+        }
+        state.outbox.remove(position);
+        storage_manager.delete_webhook_delivery(delivery.id).await?;
+        if position == 0 {
+            state.update_first_id();
+            storage_manager.set_webhook_state(&state.without_secrets()).await?;
+        }
+
+        Ok(())
+    }
+}
+
+async fn send_webhook(
+    http_client: &reqwest::Client,
+    endpoint: &WebhookEndpoint,
+    delivery: &WebhookDelivery,
+    timeout: Duration,
+) -> Result<(), String> {
+    let body = serde_json::to_vec(&delivery.event).map_err(|e| e.to_string())?;
+    let timestamp = crate::utils::unix_timestamp_now().as_secs();
+    let response = http_client
+        .post(endpoint.url.clone())
+        .timeout(timeout)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(WEBHOOK_ID_HEADER, delivery.id)
+        .header(WEBHOOK_TIMESTAMP_HEADER, timestamp)
+        .header(
+            WEBHOOK_SIGNATURE_HEADER,
+            webhook_signature(&endpoint.secret, timestamp, &body),
+        )
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(format!("response status {}", response.status()))
+    }
+}
+
+impl<S: 'static + SecretManage> Wallet<S>
+where
+    crate::wallet::Error: From<S::Error>,
+{
+    /// Starts POSTing emitted events to the endpoints, or updates the options. Events are persisted in an outbox
+    /// until they are delivered. The secrets of the endpoints aren't persisted, so when the wallet is loaded again
+    /// events are still added to the outbox, but they're only delivered once this is called again. A delivery is
+    /// retried with exponential backoff until the endpoint responds with a success status or `max_attempts` is reached.
+    pub async fn set_webhooks(&self, options: WebhookOptions) -> crate::wallet::Result<()> {
+        if let Some(endpoint) = options
+            .endpoints
+            .iter()
+            .find(|endpoint| !matches!(endpoint.url.scheme(), "http" | "https"))
+        {
+            return Err(crate::wallet::Error::InvalidWebhook(format!(
+                "unsupported url scheme of {}",
+                endpoint.url
+            )));
+        }
+        {
+            let mut state = self.webhooks.state.lock().await;
+            state.options = Some(options);
+            self.storage_manager
+                .read()
+                .await
+                .set_webhook_state(&state.without_secrets())
+                .await?;
+        }
+        self.inner.start_webhook_worker().await;
+
+        Ok(())
+    }
+
+    /// Stops the webhook delivery and removes the undelivered events.
+    pub async fn disable_webhooks(&self) -> crate::wallet::Result<()> {
+        self.task_manager.abort(WEBHOOKS_TASK).await;
+        let mut state = self.webhooks.state.lock().await;
+        let storage_manager = self.storage_manager.read().await;
+        for delivery in state.outbox.drain(..) {
+            storage_manager.delete_webhook_delivery(delivery.id).await?;
+        }
+        state.options = None;
+        state.update_first_id();
+        storage_manager.set_webhook_state(&state).await
+    }
+
+    /// Returns the events that weren't delivered yet.
+    pub async fn pending_webhook_deliveries(&self) -> Vec<WebhookDelivery> {
+        self.webhooks.state.lock().await.outbox.iter().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delay() {
+        let options = WebhookOptions::new([]).with_retry_delay(Duration::from_secs(1), Duration::from_secs(10));
+
+        assert_eq!(options.retry_delay(1), Duration::from_secs(1));
+        assert_eq!(options.retry_delay(2), Duration::from_secs(2));
+        assert_eq!(options.retry_delay(4), Duration::from_secs(8));
+        assert_eq!(options.retry_delay(5), Duration::from_secs(10));
+        assert_eq!(options.retry_delay(100), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn signature() {
+        // Verified with `printf '1700000000.{}' | openssl dgst -sha256 -hmac secret`
+        assert_eq!(
+            webhook_signature("secret", 1_700_000_000, b"{}"),
+            "sha256=b8569b78799ff9e3cbff0fc2d63a33a2b57f3282abd07c37ae5e8e7d79a5f163"
+        );
+    }
+
+    #[test]
+    fn secret_omitted() {
+        let state = WebhookState {
+            options: Some(WebhookOptions::new([WebhookEndpoint::new(
+                "http://localhost:8080".parse().unwrap(),
+                "webhook-secret",
+            )])),
+            ..Default::default()
+        };
+
+        assert!(!format!("{state:?}").contains("webhook-secret"));
+        assert!(!serde_json::to_string(&state.without_secrets())
+            .unwrap()
+            .contains("webhook-secret"));
+    }
+}
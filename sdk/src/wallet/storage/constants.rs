@@ -23,6 +23,11 @@ pub(crate) const ACCOUNTS_INDEXATION_KEY: &str = "iota-wallet-accounts";
 pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
+pub(crate) const ACCOUNT_SYNC_CURSORS: &str = "sync-cursors";
+pub(crate) const ACCOUNT_SPENDING_POLICY: &str = "spending-policy";
+pub(crate) const ACCOUNT_PENDING_APPROVALS: &str = "pending-approvals";
+
+pub(crate) const LABELS_KEY: &str = "labels";
 
 pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
@@ -31,3 +36,15 @@ pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
 pub(crate) const PARTICIPATION_EVENTS: &str = "participation-events";
 #[cfg(feature = "participation")]
 pub(crate) const PARTICIPATION_CACHED_OUTPUTS: &str = "participation-cached-outputs";
+
+#[cfg(feature = "events")]
+pub(crate) const ACCOUNT_EXPIRATION_EVENTS_TIME: &str = "expiration-events-time";
+#[cfg(feature = "events")]
+pub(crate) const EVENT_LOG_KEY: &str = "event-log";
+#[cfg(feature = "events")]
+pub(crate) const EVENT_LOG_ENTRY_KEY: &str = "event-log-";
+
+#[cfg(feature = "webhooks")]
+pub(crate) const WEBHOOKS_KEY: &str = "webhooks";
+#[cfg(feature = "webhooks")]
+pub(crate) const WEBHOOK_DELIVERY_KEY: &str = "webhook-delivery-";
@@ -0,0 +1,41 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::manager::StorageManager;
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{
+        events::{log::EventLogState, LoggedEvent},
+        storage::constants::{EVENT_LOG_ENTRY_KEY, EVENT_LOG_KEY},
+    },
+};
+
+impl StorageManager {
+    pub(crate) async fn get_event_log_state(&self) -> crate::wallet::Result<Option<EventLogState>> {
+        self.storage.get(EVENT_LOG_KEY).await
+    }
+
+    pub(crate) async fn set_event_log_state(&self, state: &EventLogState) -> crate::wallet::Result<()> {
+        self.storage.set(EVENT_LOG_KEY, state).await
+    }
+
+    pub(crate) async fn insert_logged_event(&self, event: &LoggedEvent) -> crate::wallet::Result<()> {
+        self.storage
+            .set(&format!("{EVENT_LOG_ENTRY_KEY}{}", event.id), event)
+            .await
+    }
+
+    pub(crate) async fn get_logged_event(&self, id: u64) -> crate::wallet::Result<Option<LoggedEvent>> {
+        self.storage.get(&format!("{EVENT_LOG_ENTRY_KEY}{id}")).await
+    }
+
+    pub(crate) async fn remove_logged_events(
+        &self,
+        ids: impl Iterator<Item = u64> + Send,
+    ) -> crate::wallet::Result<()> {
+        for id in ids {
+            self.storage.delete(&format!("{EVENT_LOG_ENTRY_KEY}{id}")).await?;
+        }
+        Ok(())
+    }
+}
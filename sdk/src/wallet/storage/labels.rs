@@ -0,0 +1,18 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::manager::StorageManager;
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{core::operations::address_book::Labels, storage::constants::LABELS_KEY},
+};
+
+impl StorageManager {
+    pub(crate) async fn get_labels(&self) -> crate::wallet::Result<Option<Labels>> {
+        self.storage.get(LABELS_KEY).await
+    }
+
+    pub(crate) async fn set_labels(&self, labels: &Labels) -> crate::wallet::Result<()> {
+        self.storage.set(LABELS_KEY, labels).await
+    }
+}
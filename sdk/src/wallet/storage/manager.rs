@@ -8,7 +8,7 @@ use crate::{
     client::storage::StorageAdapter,
     types::TryFromDto,
     wallet::{
-        account::{AccountDetails, AccountDetailsDto, SyncOptions},
+        account::{AccountDetails, AccountDetailsDto, PendingApproval, SpendingPolicy, SyncCursor, SyncOptions},
         migration::migrate,
         storage::{constants::*, DynStorageAdapter, Storage},
     },
@@ -96,6 +96,26 @@ impl StorageManager {
         self.set(ACCOUNTS_INDEXATION_KEY, &self.account_indexes).await
     }
 
+    /// Removes an account together with its sync options, sync cursors, spending policy, pending approvals and
+    /// participation events.
+    pub(crate) async fn remove_account_data(&mut self, account_index: u32) -> crate::wallet::Result<()> {
+        for suffix in [
+            ACCOUNT_SYNC_OPTIONS,
+            ACCOUNT_SYNC_CURSORS,
+            ACCOUNT_SPENDING_POLICY,
+            ACCOUNT_PENDING_APPROVALS,
+            #[cfg(feature = "events")]
+            ACCOUNT_EXPIRATION_EVENTS_TIME,
+        ] {
+            self.delete(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{suffix}"))
+                .await?;
+        }
+        #[cfg(feature = "participation")]
+        self.delete(&format!("{PARTICIPATION_EVENTS}{account_index}")).await?;
+
+        self.remove_account(account_index).await
+    }
+
     pub(crate) async fn set_default_sync_options(
         &self,
         account_index: u32,
@@ -112,6 +132,66 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.get(&key).await
     }
+
+    pub(crate) async fn set_sync_cursors(
+        &self,
+        account_index: u32,
+        sync_cursors: &[SyncCursor],
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_CURSORS}");
+        self.set(&key, &sync_cursors).await
+    }
+
+    pub(crate) async fn get_sync_cursors(&self, account_index: u32) -> crate::wallet::Result<Option<Vec<SyncCursor>>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_CURSORS}");
+        self.get(&key).await
+    }
+
+    pub(crate) async fn set_spending_policy(
+        &self,
+        account_index: u32,
+        spending_policy: &SpendingPolicy,
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SPENDING_POLICY}");
+        self.set(&key, &spending_policy).await
+    }
+
+    pub(crate) async fn get_spending_policy(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<SpendingPolicy>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SPENDING_POLICY}");
+        self.get(&key).await
+    }
+
+    pub(crate) async fn set_pending_approvals(
+        &self,
+        account_index: u32,
+        pending_approvals: &[PendingApproval],
+    ) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_PENDING_APPROVALS}");
+        self.set(&key, &pending_approvals).await
+    }
+
+    pub(crate) async fn get_pending_approvals(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<Vec<PendingApproval>>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_PENDING_APPROVALS}");
+        self.get(&key).await
+    }
+
+    #[cfg(feature = "events")]
+    pub(crate) async fn set_expiration_events_time(&self, account_index: u32, time: u32) -> crate::wallet::Result<()> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_EXPIRATION_EVENTS_TIME}");
+        self.set(&key, &time).await
+    }
+
+    #[cfg(feature = "events")]
+    pub(crate) async fn get_expiration_events_time(&self, account_index: u32) -> crate::wallet::Result<Option<u32>> {
+        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_EXPIRATION_EVENTS_TIME}");
+        self.get(&key).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -5,8 +5,13 @@
 pub mod adapter;
 /// Storage constants.
 pub mod constants;
+/// Storage functions related to the event log.
+#[cfg(feature = "events")]
+mod event_log;
 /// Storage kind.
 mod kind;
+/// Storage functions related to the address book and labels.
+mod labels;
 /// Storage manager.
 mod manager;
 /// Storage options.
@@ -15,6 +20,9 @@ mod options;
 #[cfg(feature = "participation")]
 #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
 mod participation;
+/// Storage functions related to webhooks.
+#[cfg(feature = "webhooks")]
+mod webhooks;
 
 use async_trait::async_trait;
 use crypto::ciphers::chacha;
@@ -0,0 +1,35 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::manager::StorageManager;
+use crate::{
+    client::storage::StorageAdapter,
+    wallet::{
+        events::webhook::{WebhookDelivery, WebhookState},
+        storage::constants::{WEBHOOKS_KEY, WEBHOOK_DELIVERY_KEY},
+    },
+};
+
+impl StorageManager {
+    pub(crate) async fn get_webhook_state(&self) -> crate::wallet::Result<Option<WebhookState>> {
+        self.storage.get(WEBHOOKS_KEY).await
+    }
+
+    pub(crate) async fn set_webhook_state(&self, state: &WebhookState) -> crate::wallet::Result<()> {
+        self.storage.set(WEBHOOKS_KEY, state).await
+    }
+
+    pub(crate) async fn get_webhook_delivery(&self, id: u64) -> crate::wallet::Result<Option<WebhookDelivery>> {
+        self.storage.get(&format!("{WEBHOOK_DELIVERY_KEY}{id}")).await
+    }
+
+    pub(crate) async fn set_webhook_delivery(&self, delivery: &WebhookDelivery) -> crate::wallet::Result<()> {
+        self.storage
+            .set(&format!("{WEBHOOK_DELIVERY_KEY}{}", delivery.id), delivery)
+            .await
+    }
+
+    pub(crate) async fn delete_webhook_delivery(&self, id: u64) -> crate::wallet::Result<()> {
+        self.storage.delete(&format!("{WEBHOOK_DELIVERY_KEY}{id}")).await
+    }
+}
@@ -1,13 +1,17 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+/// Spawns a task that runs as the operation of the caller, if any, so it can be cancelled with it.
 #[cfg(not(target_family = "wasm"))]
 pub(crate) fn spawn<F>(future: F) -> tokio::task::JoinHandle<F::Output>
 where
     F: futures::Future + Send + 'static,
     F::Output: Send + 'static,
 {
-    tokio::task::spawn(future)
+    match crate::client::operation::Operation::current() {
+        Some(operation) => tokio::task::spawn(async move { operation.run(future).await }),
+        None => tokio::task::spawn(future),
+    }
 }
 
 #[cfg(target_family = "wasm")]
@@ -61,3 +61,24 @@ async fn client_builder() {
 
     let _client_builder = serde_json::from_str::<ClientBuilder>(client_builder_json).unwrap();
 }
+
+#[tokio::test]
+async fn client_builder_request_policy() {
+    use std::time::Duration;
+
+    use iota_sdk::client::node_manager::request_policy::{CircuitBreakerOptions, RequestPolicy};
+
+    let policy = RequestPolicy::new()
+        .with_max_retries(3)
+        .with_route_timeout("api/indexer/", Duration::from_secs(30))
+        .with_circuit_breaker(CircuitBreakerOptions::default());
+    let client_builder = ClientBuilder::new().with_request_policy(policy.clone());
+
+    let json = serde_json::to_string(&client_builder).unwrap();
+    let deserialized = serde_json::from_str::<ClientBuilder>(&json).unwrap();
+    assert_eq!(deserialized.request_policy, policy);
+
+    // The policy is optional in the JSON config
+    let client_builder = serde_json::from_str::<ClientBuilder>("{}").unwrap();
+    assert_eq!(client_builder.request_policy, RequestPolicy::default());
+}
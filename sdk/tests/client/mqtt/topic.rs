@@ -1,7 +1,17 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::mqtt::{Error, Topic};
+use std::str::FromStr;
+
+use iota_sdk::{
+    client::mqtt::{Error, Topic, TypedTopic, UnlockConditionTopic},
+    types::block::{
+        address::Bech32Address,
+        output::{FoundryId, OutputId},
+        payload::transaction::TransactionId,
+        BlockId,
+    },
+};
 
 #[test]
 fn valid_topics() {
@@ -80,3 +90,61 @@ fn invalid_topics() {
         Err(Error::InvalidTopic(_))
     ));
 }
+
+#[test]
+fn typed_topics() {
+    let block_id = BlockId::from_str("0x36845227a59864ac12d3d2389fcb4ea0bdd1a5d1d4ed464bde3154216c3246c4").unwrap();
+    let transaction_id =
+        TransactionId::from_str("0x36845227a59864ac12d3d2389fcb4ea0bdd1a5d1d4ed464bde3154216c3246c4").unwrap();
+    let foundry_id =
+        FoundryId::from_str("0x08e10a5c7bcfdce48ff500156040f7548ca511d79a6e253a22759116c2ae8c818d0100000000").unwrap();
+    let address =
+        Bech32Address::try_from_str("iota1qrwfnskm4f7utdrxqnkfntfqxehtpj8s0kf68zkcwm0yrhuemzjp5sjfw5v").unwrap();
+
+    for (typed_topic, topic) in [
+        (TypedTopic::ConfirmedMilestone, "milestone-info/confirmed".to_string()),
+        (
+            TypedTopic::TaggedDataBlocks {
+                tag: Some(vec![0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef]),
+            },
+            "blocks/tagged-data/0x0123456789abcdef".to_string(),
+        ),
+        (
+            TypedTopic::TransactionTaggedDataBlocks { tag: None },
+            "blocks/transaction/tagged-data".to_string(),
+        ),
+        (
+            TypedTopic::BlockMetadata(block_id),
+            format!("block-metadata/{block_id}"),
+        ),
+        (
+            TypedTopic::TransactionIncludedBlock(transaction_id),
+            format!("transactions/{transaction_id}/included-block"),
+        ),
+        (
+            TypedTopic::Output(OutputId::new(transaction_id, 10).unwrap()),
+            format!("outputs/{transaction_id}0a00"),
+        ),
+        (
+            TypedTopic::FoundryOutput(foundry_id),
+            format!("outputs/foundry/{foundry_id}"),
+        ),
+        (
+            TypedTopic::OutputsByUnlockCondition {
+                unlock_condition: UnlockConditionTopic::StorageReturn,
+                address,
+                spent: true,
+            },
+            format!("outputs/unlock/storage-return/{address}/spent"),
+        ),
+    ] {
+        assert_eq!(typed_topic.to_string(), topic);
+        assert_eq!(typed_topic.to_topic().unwrap().as_str(), topic);
+    }
+
+    // Empty tag.
+    assert!(matches!(
+        TypedTopic::TaggedDataBlocks { tag: Some(Vec::new()) }.to_topic(),
+        Err(Error::InvalidTopic(_))
+    ));
+}
@@ -1,8 +1,13 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use futures::{StreamExt, TryStreamExt};
 use iota_sdk::{
-    client::{api::GetAddressesOptions, Result},
+    client::{
+        api::GetAddressesOptions,
+        node_api::indexer::{query_parameters::QueryParameter, IndexerCursor},
+        Result,
+    },
     types::block::{
         address::AliasAddress,
         output::{
@@ -10,8 +15,8 @@ use iota_sdk::{
                 AddressUnlockCondition, GovernorAddressUnlockCondition, ImmutableAliasAddressUnlockCondition,
                 StateControllerAddressUnlockCondition, UnlockCondition,
             },
-            AliasId, AliasOutputBuilder, FoundryId, FoundryOutputBuilder, NftId, NftOutputBuilder, SimpleTokenScheme,
-            TokenScheme,
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, FoundryId, FoundryOutputBuilder, NftId, NftOutputBuilder,
+            SimpleTokenScheme, TokenScheme,
         },
     },
 };
@@ -149,3 +154,81 @@ async fn get_foundry_output_id_test() -> Result<()> {
 
     Ok(())
 }
+
+#[ignore]
+#[tokio::test]
+async fn basic_output_ids_stream_test() -> Result<()> {
+    let (client, secret_manager) = create_client_and_secret_manager_with_funds(None).await?;
+    let protocol_parameters = client.get_protocol_parameters().await?;
+
+    let address = secret_manager
+        .generate_ed25519_addresses(GetAddressesOptions::from_client(&client).await?.with_range(0..1))
+        .await?[0];
+
+    let outputs = (0..3)
+        .map(|_| {
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(protocol_parameters.token_supply())
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let block = client
+        .build_block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs(outputs)?
+        .finish()
+        .await?;
+
+    client.retry_until_included(&block.id(), None, None).await?;
+
+    let all_output_ids = client.basic_output_ids([QueryParameter::Address(address)]).await?.items;
+    assert!(all_output_ids.len() >= 3);
+
+    let query_parameters = [QueryParameter::Address(address), QueryParameter::PageSize(1)];
+
+    let pages = client
+        .basic_output_ids_stream(query_parameters.clone(), None)?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert!(pages.iter().all(|page| page.items.len() <= 1));
+    let streamed_output_ids = pages.into_iter().flat_map(|page| page.items).collect::<Vec<_>>();
+    assert_eq!(streamed_output_ids, all_output_ids);
+
+    // Stop after the first page and resume from its cursor
+    let first_page = client
+        .basic_output_ids_stream(query_parameters.clone(), None)?
+        .boxed()
+        .next()
+        .await
+        .unwrap()?;
+    let cursor = IndexerCursor::from_response(&first_page).unwrap();
+    let resumed_output_ids = client
+        .basic_output_ids_stream(query_parameters.clone(), cursor)?
+        .map_ok(|page| page.items)
+        .try_concat()
+        .await?;
+    assert_eq!(
+        first_page
+            .items
+            .into_iter()
+            .chain(resumed_output_ids)
+            .collect::<Vec<_>>(),
+        all_output_ids
+    );
+
+    let outputs = client
+        .get_outputs_stream(client.basic_output_ids_stream(query_parameters, None)?)
+        .map_ok(|page| page.items)
+        .try_concat()
+        .await?;
+    assert_eq!(
+        outputs
+            .iter()
+            .map(|output| *output.metadata().output_id())
+            .collect::<Vec<_>>(),
+        all_output_ids
+    );
+
+    Ok(())
+}
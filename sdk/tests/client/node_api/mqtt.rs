@@ -57,3 +57,148 @@ async fn test_mqtt() {
     }
     client.subscriber().disconnect().await.unwrap();
 }
+
+#[ignore]
+#[tokio::test]
+async fn test_mqtt_stream() {
+    use futures::StreamExt;
+    use iota_sdk::client::mqtt::{OverflowPolicy, StreamOptions, Topic};
+
+    let client = setup_client_with_node_health_ignored().await;
+    let options = StreamOptions::new()
+        .buffer_size(5)
+        .overflow_policy(OverflowPolicy::DropOldest);
+
+    let mut milestones = client
+        .subscribe_stream([Topic::new("milestone-info/latest").unwrap()], options)
+        .await
+        .unwrap();
+    let mut blocks = client
+        .subscribe_stream([Topic::new("blocks").unwrap()], options)
+        .await
+        .unwrap();
+
+    let mut received_milestone = false;
+    let mut received_block = false;
+    while !(received_milestone && received_block) {
+        tokio::select! {
+            Some(event) = milestones.next() => {
+                assert_eq!(event.topic, "milestone-info/latest");
+                received_milestone = true;
+            }
+            Some(event) = blocks.next() => {
+                assert_eq!(event.topic, "blocks");
+                received_block = true;
+            }
+        }
+    }
+
+    drop(blocks);
+    // Dropping one stream doesn't end the other one
+    assert!(milestones.next().await.is_some());
+}
+
+#[tokio::test]
+async fn mqtt_stream_ends_after_broker_disconnect() {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use iota_sdk::client::{
+        mqtt::{BrokerOptions, MqttPayload, StreamOptions, Topic},
+        Client,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+
+    // CONNACK, connection accepted
+    const CONNACK: [u8; 4] = [0x20, 0x02, 0x00, 0x00];
+    const CALLBACK_TOPIC: &str = "milestone-info/confirmed";
+    const STREAM_TOPIC: &str = "milestone-info/latest";
+
+    async fn accept(listener: &TcpListener) -> TcpStream {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        // CONNECT
+        assert!(socket.read(&mut [0u8; 1024]).await.unwrap() > 0);
+        socket.write_all(&CONNACK).await.unwrap();
+        socket
+    }
+
+    // Reads until a SUBSCRIBE for the topic was received
+    async fn subscribed(socket: &mut TcpStream, topic: &str) {
+        let mut received = Vec::new();
+        let mut buffer = [0u8; 1024];
+        while !received.windows(topic.len()).any(|window| window == topic.as_bytes()) {
+            let read = socket.read(&mut buffer).await.unwrap();
+            assert!(read > 0);
+            received.extend_from_slice(&buffer[..read]);
+        }
+    }
+
+    // A broker that accepts the connection check and the connection used for the subscriptions, then fails the
+    // reconnection, so the client gives up. The connection of the next subscription gets a PUBLISH for the callback.
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let broker = tokio::spawn(async move {
+        accept(&listener).await;
+        let mut socket = accept(&listener).await;
+        subscribed(&mut socket, STREAM_TOPIC).await;
+        drop(socket);
+        drop(listener.accept().await.unwrap());
+
+        accept(&listener).await;
+        let mut socket = accept(&listener).await;
+        subscribed(&mut socket, CALLBACK_TOPIC).await;
+        let payload = br#"{"index":1}"#;
+        let mut publish = vec![0x30, (2 + CALLBACK_TOPIC.len() + payload.len()) as u8, 0];
+        publish.push(CALLBACK_TOPIC.len() as u8);
+        publish.extend_from_slice(CALLBACK_TOPIC.as_bytes());
+        publish.extend_from_slice(payload);
+        socket.write_all(&publish).await.unwrap();
+        // Keep the connection open until the test is done
+        let _ = socket.read(&mut [0u8; 1024]).await;
+    });
+
+    // The node info requests fail, only the broker port is used for MQTT
+    let client = Client::builder()
+        .with_node("http://127.0.0.1:1")
+        .unwrap()
+        .with_ignore_node_health()
+        .with_mqtt_broker_options(
+            BrokerOptions::new()
+                .use_ws(false)
+                .port(port)
+                .max_reconnection_attempts(2),
+        )
+        .finish()
+        .await
+        .unwrap();
+
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    client
+        .subscribe([Topic::new(CALLBACK_TOPIC).unwrap()], move |event| {
+            sender.send(event.payload.clone()).ok();
+        })
+        .await
+        .unwrap();
+    let mut stream = client
+        .subscribe_stream([Topic::new(STREAM_TOPIC).unwrap()], StreamOptions::new())
+        .await
+        .unwrap();
+
+    let next = tokio::time::timeout(Duration::from_secs(10), stream.next()).await;
+    assert!(matches!(next, Ok(None)));
+
+    // The callback subscription is kept and resubscribed on the new connection
+    let _stream = client
+        .subscribe_stream([Topic::new(STREAM_TOPIC).unwrap()], StreamOptions::new())
+        .await
+        .unwrap();
+    let payload = tokio::time::timeout(Duration::from_secs(10), receiver.recv()).await;
+    assert_eq!(
+        payload.unwrap(),
+        Some(MqttPayload::Json(serde_json::json!({ "index": 1 })))
+    );
+    broker.abort();
+}
@@ -3,7 +3,13 @@
 
 use std::str::FromStr;
 
-use iota_sdk::types::api::plugins::participation::types::{Participation, ParticipationEventId, Participations};
+use iota_sdk::types::api::plugins::participation::{
+    error::Error,
+    types::{
+        Answer, Participation, ParticipationEventDataBuilder, ParticipationEventId, ParticipationEventStatus,
+        Participations, Question, StakingEventPayload, VotingEventPayload,
+    },
+};
 
 #[test]
 fn serialize_deserialize() {
@@ -100,3 +106,136 @@ fn mutate() {
         }
     );
 }
+
+fn ballot() -> VotingEventPayload {
+    VotingEventPayload::new([
+        Question::new(
+            "Which color?",
+            [Answer::new(1, "Red", "").unwrap(), Answer::new(2, "Blue", "").unwrap()],
+            "",
+        )
+        .unwrap(),
+        Question::new(
+            "Which shape?",
+            [
+                Answer::new(1, "Circle", "").unwrap(),
+                Answer::new(2, "Square", "").unwrap(),
+            ],
+            "",
+        )
+        .unwrap(),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn build_event() {
+    let event = ParticipationEventDataBuilder::new("Colors and shapes", ballot())
+        .with_milestone_indexes(10, 20, 30)
+        .finish()
+        .unwrap();
+    assert_eq!(event.milestone_index_start(), &20);
+
+    assert!(matches!(
+        ParticipationEventDataBuilder::new("Colors and shapes", ballot())
+            .with_milestone_indexes(10, 30, 30)
+            .finish(),
+        Err(Error::InvalidMilestoneIndexes { .. })
+    ));
+    assert!(matches!(Answer::new(0, "Skip", ""), Err(Error::ReservedAnswerValue(0))));
+    assert!(matches!(
+        Question::new(
+            "Which color?",
+            [Answer::new(1, "Red", "").unwrap(), Answer::new(1, "Blue", "").unwrap()],
+            "",
+        ),
+        Err(Error::DuplicateAnswerValue(1))
+    ));
+    assert!(matches!(
+        Question::new("Which color?", [Answer::new(1, "Red", "").unwrap()], ""),
+        Err(Error::InvalidAnswerCount(1))
+    ));
+    assert!(matches!(
+        VotingEventPayload::new([]),
+        Err(Error::InvalidQuestionCount(0))
+    ));
+    assert!(matches!(
+        StakingEventPayload::new("Stake", "SMR", 1, 0, 0, ""),
+        Err(Error::InvalidStakingRatio { .. })
+    ));
+    assert!(matches!(
+        StakingEventPayload::new("Stake", "S", 1, 1, 0, ""),
+        Err(Error::InvalidStakingSymbol(1))
+    ));
+}
+
+#[test]
+fn ballot_tally() {
+    let status = serde_json::from_value::<ParticipationEventStatus>(serde_json::json!({
+        "milestoneIndex": 25,
+        "status": "holding",
+        "questions": [
+            { "answers": [
+                { "value": 0, "current": 50, "accumulated": 500 },
+                { "value": 1, "current": 10, "accumulated": 100 },
+                { "value": 2, "current": 30, "accumulated": 300 },
+                { "value": 255, "current": 5, "accumulated": 50 }
+            ] },
+            { "answers": [
+                { "value": 1, "current": 5, "accumulated": 50 },
+                { "value": 2, "current": 5, "accumulated": 50 }
+            ] }
+        ],
+        "checksum": ""
+    }))
+    .unwrap();
+
+    let tally = status.tally(Some(&ballot())).unwrap();
+    assert_eq!(tally.milestone_index, 25);
+
+    let colors = &tally.questions[0];
+    assert_eq!(colors.text.as_deref(), Some("Which color?"));
+    // Skipped and invalid answers don't count
+    assert_eq!(colors.answers.len(), 2);
+    assert_eq!(colors.total_accumulated, 400);
+    let winner = colors.winner().unwrap();
+    assert_eq!(winner.text.as_deref(), Some("Blue"));
+    assert_eq!(winner.accumulated_share_bps(colors), 7_500);
+
+    // A tie has no winner
+    assert!(tally.questions[1].winner().is_none());
+
+    let single_question = VotingEventPayload::new([ballot().questions()[0].clone()]).unwrap();
+    assert!(matches!(
+        status.tally(Some(&single_question)),
+        Err(Error::QuestionCountMismatch { expected: 1, found: 2 })
+    ));
+}
+
+#[test]
+fn staking_reward_projection() {
+    let event = ParticipationEventDataBuilder::new(
+        "Staking",
+        StakingEventPayload::new("Stake", "SMR", 1, 1_000_000, 500, "").unwrap(),
+    )
+    .with_milestone_indexes(0, 100, 200)
+    .finish()
+    .unwrap();
+
+    // Before the start, the whole duration counts
+    let projection = event.project_staking_rewards(2_000_000, 50, 0).unwrap();
+    assert_eq!(projection.milestones, 100);
+    assert_eq!(projection.rewards, 200);
+    assert!(!projection.minimum_reached);
+
+    let projection = event.project_staking_rewards(2_000_000, 150, 400).unwrap();
+    assert_eq!(projection.milestones, 50);
+    assert_eq!(projection.rewards, 500);
+    assert!(projection.minimum_reached);
+
+    let ballot = ParticipationEventDataBuilder::new("Ballot", ballot())
+        .with_milestone_indexes(0, 100, 200)
+        .finish()
+        .unwrap();
+    assert!(ballot.project_staking_rewards(2_000_000, 150, 0).is_none());
+}
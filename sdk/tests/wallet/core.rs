@@ -6,7 +6,8 @@ use crypto::keys::bip39::Mnemonic;
 use iota_sdk::{
     client::constants::SHIMMER_COIN_TYPE,
     client::node_manager::node::{Node, NodeDto},
-    wallet::Error,
+    types::block::output::{NftId, OutputId},
+    wallet::{core::AddressBookEntry, Error},
     Url,
 };
 use iota_sdk::{
@@ -200,3 +201,56 @@ async fn update_node_auth() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn address_book() -> Result<()> {
+    let storage_path = "test-storage/address_book";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let address = Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")?;
+    let other_address = Bech32Address::try_from_str("rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3")?;
+    let entry = AddressBookEntry::new(address, "exchange")?
+        .with_tags(["cex"])
+        .with_note("deposit address".to_owned());
+    wallet.set_address_book_entry(entry.clone()).await?;
+
+    // Labels are unique
+    assert!(matches!(
+        wallet
+            .set_address_book_entry(AddressBookEntry::new(other_address, "exchange")?)
+            .await,
+        Err(Error::AddressBookLabelAlreadyExists(_))
+    ));
+    wallet.set_nft_label(NftId::null(), Some("artwork".to_owned())).await?;
+    wallet
+        .set_output_label(OutputId::null(), Some("salary".to_owned()))
+        .await?;
+
+    // The address book and labels are also stored in the database and available the next time
+    drop(wallet);
+    let wallet = make_wallet(storage_path, None, None).await?;
+    assert_eq!(wallet.address_book().await, std::slice::from_ref(&entry));
+    assert_eq!(
+        wallet.get_address_book_entry_by_label("exchange").await.as_ref(),
+        Some(&entry)
+    );
+    assert_eq!(wallet.nft_labels().await.get(&NftId::null()).unwrap(), "artwork");
+    assert_eq!(wallet.output_labels().await.get(&OutputId::null()).unwrap(), "salary");
+
+    assert_eq!(wallet.remove_address_book_entry(address).await?, Some(entry));
+    assert!(wallet.get_address_book_entry(address).await?.is_none());
+    wallet.set_nft_label(NftId::null(), None).await?;
+    wallet.set_output_label(OutputId::null(), None).await?;
+
+    // Removals are stored as well
+    drop(wallet);
+    let wallet = make_wallet(storage_path, None, None).await?;
+    assert!(wallet.address_book().await.is_empty());
+    assert!(wallet.nft_labels().await.is_empty());
+    assert!(wallet.output_labels().await.is_empty());
+
+    tear_down(storage_path)
+}
@@ -2,24 +2,31 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::{
-    client::api::PreparedTransactionDataDto,
+    client::{api::PreparedTransactionDataDto, node_manager::node::NodeHealthChange, operation::OperationProgress},
     types::block::{
         address::{Address, Bech32Address, Ed25519Address},
         input::{Input, UtxoInput},
-        output::{unlock_condition::AddressUnlockCondition, BasicOutput, Output, OutputId},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutput, NftId, Output, OutputId, TokenId},
         payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
         protocol::protocol_parameters,
         rand::output::{rand_basic_output, rand_inputs_commitment, rand_output_metadata},
     },
     wallet::{
-        account::types::{InclusionState, OutputData, OutputDataDto},
-        events::types::{
-            AddressData, NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, TransactionProgressEvent,
-            WalletEvent,
+        account::types::{Balance, InclusionState, OutputData, OutputDataDto},
+        events::{
+            types::{
+                AddressData, BalanceChangeEvent, Event, ExpirationEvent, NativeTokensEvent, NewOutputEvent, NftEvent,
+                OperationProgressEvent, SpentOutputEvent, StorageErrorEvent, SyncFinishedEvent,
+                TransactionInclusionEvent, TransactionProgressEvent, WalletEvent, WalletEventType,
+            },
+            EventFilter,
         },
+        Result,
     },
 };
 
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
 const ED25519_ADDRESS: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
 const TRANSACTION_ID: &str = "0x52fdfc072182654f163f5f0f9a621d729566c74d10037c4d7bbb0407d1e2c649";
 
@@ -55,6 +62,7 @@ fn wallet_events_serde() {
         output: output_data_dto.clone(),
         transaction: None,
         transaction_inputs: None,
+        sender_label: None,
     })));
 
     assert_serde_eq(WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
@@ -121,4 +129,191 @@ fn wallet_events_serde() {
     ));
 
     assert_serde_eq(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting));
+
+    assert_serde_eq(WalletEvent::BalanceChange(Box::new(BalanceChangeEvent {
+        previous: Balance::default(),
+        current: Balance::default(),
+    })));
+
+    let native_tokens_event = NativeTokensEvent {
+        token_id: TokenId::null(),
+        amount: 100.into(),
+    };
+    assert_serde_eq(WalletEvent::NativeTokensReceived(native_tokens_event));
+    assert_serde_eq(WalletEvent::NativeTokensSent(native_tokens_event));
+
+    let nft_event = NftEvent {
+        nft_id: NftId::null(),
+        label: Some("artwork".to_owned()),
+    };
+    assert_serde_eq(WalletEvent::NftReceived(nft_event.clone()));
+    assert_serde_eq(WalletEvent::NftSent(nft_event));
+
+    let expiration_event = ExpirationEvent {
+        output_id: OutputId::null(),
+        expires_at: 1_700_000_000,
+        return_address: Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")
+            .unwrap(),
+        return_address_label: None,
+    };
+    assert_serde_eq(WalletEvent::ExpirationApproaching(expiration_event.clone()));
+    assert_serde_eq(WalletEvent::OutputExpired(expiration_event));
+
+    assert_serde_eq(WalletEvent::NodeHealthChange(NodeHealthChange {
+        url: "http://localhost:14265".parse().unwrap(),
+        healthy: false,
+    }));
+
+    assert_serde_eq(WalletEvent::SyncStarted);
+
+    assert_serde_eq(WalletEvent::SyncFinished(SyncFinishedEvent {
+        duration_ms: 1200,
+        synced_addresses: 2,
+        synced_outputs: 5,
+    }));
+
+    assert_serde_eq(WalletEvent::StorageError(StorageErrorEvent {
+        error: "storage is locked".to_string(),
+    }));
+
+    assert_serde_eq(WalletEvent::OperationProgress(OperationProgressEvent {
+        operation_id: "sync-1".to_string(),
+        progress: OperationProgress::SyncingAccount { account_index: 1 },
+    }));
+
+    // The event type is part of the serialized event
+    let json = serde_json::to_value(WalletEvent::SyncStarted).unwrap();
+    assert_eq!(json["type"], WalletEventType::SyncStarted as u8);
+    assert_eq!(WalletEventType::try_from(16), Ok(WalletEventType::StorageError));
+}
+
+#[test]
+fn event_filter_passes_wallet_wide_events() {
+    let filter = EventFilter::new().with_account_indexes([1]);
+
+    assert!(!filter.matches(&Event {
+        account_index: 0,
+        event: WalletEvent::ConsolidationRequired,
+    }));
+    assert!(filter.matches(&Event {
+        account_index: 0,
+        event: WalletEvent::NodeHealthChange(NodeHealthChange {
+            url: "http://localhost:14265".parse().unwrap(),
+            healthy: false,
+        }),
+    }));
+    assert!(filter.matches(&Event {
+        account_index: 0,
+        event: WalletEvent::OperationProgress(OperationProgressEvent {
+            operation_id: "pow".to_string(),
+            progress: OperationProgress::PerformingPow { attempt: 1 },
+        }),
+    }));
+    assert!(!filter.matches(&Event {
+        account_index: 0,
+        event: WalletEvent::OperationProgress(OperationProgressEvent {
+            operation_id: "sync".to_string(),
+            progress: OperationProgress::SyncingAccount { account_index: 0 },
+        }),
+    }));
+}
+
+#[tokio::test]
+async fn event_stream() -> Result<()> {
+    use futures::StreamExt;
+
+    let storage_path = "test-storage/event_stream";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let mut events = wallet.events(EventFilter::new()).await;
+    let mut consolidation_events = wallet
+        .events(
+            EventFilter::new()
+                .with_account_indexes([0])
+                .with_event_types([WalletEventType::ConsolidationRequired]),
+        )
+        .await;
+
+    wallet
+        .emit_test_event(WalletEvent::TransactionProgress(
+            TransactionProgressEvent::SelectingInputs,
+        ))
+        .await;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+
+    assert_eq!(
+        events.next().await.unwrap().event,
+        WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs)
+    );
+    assert_eq!(events.next().await.unwrap().event, WalletEvent::ConsolidationRequired);
+    assert_eq!(
+        consolidation_events.next().await.unwrap().event,
+        WalletEvent::ConsolidationRequired
+    );
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn event_log() -> Result<()> {
+    use futures::{StreamExt, TryStreamExt};
+    use iota_sdk::wallet::events::EventLogOptions;
+
+    let storage_path = "test-storage/event_log";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    assert!(matches!(
+        wallet.logged_events(EventFilter::new(), None).await,
+        Err(iota_sdk::wallet::Error::EventLogDisabled)
+    ));
+
+    wallet
+        .enable_event_log(EventLogOptions {
+            max_events: 3,
+            filter: EventFilter::new().with_event_types([
+                WalletEventType::ConsolidationRequired,
+                WalletEventType::TransactionProgress,
+            ]),
+        })
+        .await?;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+    for _ in 0..3 {
+        wallet
+            .emit_test_event(WalletEvent::TransactionProgress(
+                TransactionProgressEvent::SelectingInputs,
+            ))
+            .await;
+    }
+    // Not matching the filter of the log
+    wallet
+        .emit_test_event(WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+            transaction_id: TransactionId::new(prefix_hex::decode(TRANSACTION_ID).unwrap()),
+            inclusion_state: InclusionState::Confirmed,
+        }))
+        .await;
+
+    // The oldest event was dropped because of the maximum
+    let ids = Box::pin(wallet.logged_events(EventFilter::new(), None).await?)
+        .take(3)
+        .map_ok(|event| event.id)
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(ids, [2, 3, 4]);
+
+    // Resumes after the acknowledged event and continues with new events
+    wallet.acknowledge_event(3).await?;
+    let mut events = Box::pin(wallet.logged_events(EventFilter::new(), None).await?);
+    assert_eq!(events.next().await.unwrap()?.id, 4);
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+    let event = events.next().await.unwrap()?;
+    assert_eq!(event.id, 5);
+    assert_eq!(event.event.event, WalletEvent::ConsolidationRequired);
+
+    wallet.disable_event_log().await?;
+    assert!(wallet.logged_events(EventFilter::new(), None).await.is_err());
+
+    tear_down(storage_path)
 }
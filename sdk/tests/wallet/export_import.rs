@@ -0,0 +1,222 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::bip39::Mnemonic;
+use iota_sdk::{
+    client::{
+        constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
+        node_manager::node::{Node, NodeDto},
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        Client,
+    },
+    types::block::output::{NftId, OutputId},
+    wallet::{account::SpendingPolicy, core::AddressBookEntry, ClientOptions, Error, Result, Wallet},
+    Url,
+};
+
+use crate::wallet::common::{setup, tear_down, NODE_LOCAL, NODE_OTHER};
+
+async fn make_wallet(storage_path: &str, node: &str, coin_type: u32) -> Result<Wallet> {
+    let mnemonic = Mnemonic::from("inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak".to_string());
+
+    Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            mnemonic,
+        )?))
+        .with_client_options(ClientOptions::new().with_node(node)?)
+        .with_coin_type(coin_type)
+        .with_storage_path(storage_path)
+        .finish()
+        .await
+}
+
+#[tokio::test]
+async fn export_and_import_encrypted() -> Result<()> {
+    let storage_path = "test-storage/export_and_import_encrypted";
+    setup(storage_path)?;
+    std::fs::create_dir_all(storage_path)?;
+    let export_path = format!("{storage_path}/export.json");
+
+    let wallet = make_wallet(&format!("{storage_path}/1"), NODE_LOCAL, SHIMMER_COIN_TYPE).await?;
+    let account = wallet.create_account().with_alias("Alice").finish().await?;
+    let spending_policy = SpendingPolicy {
+        max_amount_per_transaction: Some(1_000_000),
+        ..Default::default()
+    };
+    account.set_spending_policy(spending_policy.clone()).await?;
+    let alice_address = account.addresses().await?[0].address().clone();
+    wallet
+        .set_address_book_entry(AddressBookEntry::new(alice_address.clone(), "Alice")?.with_tags(["friends"]))
+        .await?;
+    wallet
+        .set_output_label(OutputId::null(), Some("salary".to_owned()))
+        .await?;
+    wallet.set_nft_label(NftId::null(), Some("artwork".to_owned())).await?;
+    wallet
+        .export_to_file(&export_path, Some("export password".to_owned().into()))
+        .await?;
+
+    // Build with a different coin type and node, to check if they get replaced by the ones from the export
+    let import_wallet = make_wallet(&format!("{storage_path}/2"), NODE_OTHER, IOTA_COIN_TYPE).await?;
+
+    // Missing and wrong passwords fail
+    import_wallet.import_from_file(&export_path, None).await.unwrap_err();
+    import_wallet
+        .import_from_file(&export_path, Some("wrong password".to_owned().into()))
+        .await
+        .unwrap_err();
+
+    import_wallet
+        .import_from_file(&export_path, Some("export password".to_owned().into()))
+        .await?;
+
+    // Imported coin type is used
+    let new_account = import_wallet.create_account().finish().await?;
+    assert_eq!(new_account.details().await.coin_type(), &SHIMMER_COIN_TYPE);
+
+    // Imported client options are used
+    let client_options = import_wallet.client_options().await;
+    let node_dto = NodeDto::Node(Node::from(Url::parse(NODE_LOCAL).unwrap()));
+    assert!(client_options.node_manager_builder.nodes.contains(&node_dto));
+
+    let imported_account = import_wallet.get_account("Alice").await?;
+    assert_eq!(account.addresses().await?, imported_account.addresses().await?);
+    assert_eq!(imported_account.spending_policy().await, spending_policy);
+
+    // Imported address book and labels are used
+    assert_eq!(import_wallet.address_book().await, wallet.address_book().await);
+    assert_eq!(
+        import_wallet
+            .get_address_book_entry_by_label("Alice")
+            .await
+            .unwrap()
+            .address,
+        alice_address
+    );
+    assert_eq!(
+        import_wallet.output_labels().await.get(&OutputId::null()).unwrap(),
+        "salary"
+    );
+    assert_eq!(import_wallet.nft_labels().await.get(&NftId::null()).unwrap(), "artwork");
+
+    // Importing into a wallet with accounts fails
+    import_wallet
+        .import_from_file(&export_path, Some("export password".to_owned().into()))
+        .await
+        .unwrap_err();
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn import_with_other_seed() -> Result<()> {
+    let storage_path = "test-storage/import_with_other_seed";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(&format!("{storage_path}/1"), NODE_LOCAL, SHIMMER_COIN_TYPE).await?;
+    wallet.create_account().finish().await?;
+    let export = wallet.export_data().await?;
+
+    let other_wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            Client::generate_mnemonic()?,
+        )?))
+        .with_client_options(ClientOptions::new().with_node(NODE_OTHER)?)
+        .with_coin_type(IOTA_COIN_TYPE)
+        .with_storage_path(&format!("{storage_path}/2"))
+        .finish()
+        .await?;
+    assert!(matches!(
+        other_wallet.import_data(export).await,
+        Err(Error::Backup(
+            "the secret manager doesn't use the seed of the exported wallet"
+        ))
+    ));
+
+    // Nothing was imported
+    assert!(other_wallet.get_accounts().await?.is_empty());
+    let node_dto = NodeDto::Node(Node::from(Url::parse(NODE_OTHER).unwrap()));
+    assert!(other_wallet
+        .client_options()
+        .await
+        .node_manager_builder
+        .nodes
+        .contains(&node_dto));
+    let new_account = other_wallet.create_account().finish().await?;
+    assert_eq!(new_account.details().await.coin_type(), &IOTA_COIN_TYPE);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn import_without_addresses() -> Result<()> {
+    let storage_path = "test-storage/import_without_addresses";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(&format!("{storage_path}/1"), NODE_LOCAL, SHIMMER_COIN_TYPE).await?;
+    let export = wallet.export_data().await?;
+    assert!(export.accounts.is_empty());
+
+    // The seed can't be verified without an address
+    let import_wallet = make_wallet(&format!("{storage_path}/2"), NODE_OTHER, IOTA_COIN_TYPE).await?;
+    assert!(matches!(
+        import_wallet.import_data(export).await,
+        Err(Error::Backup(
+            "the wallet export has no address to verify the seed of the secret manager"
+        ))
+    ));
+    let node_dto = NodeDto::Node(Node::from(Url::parse(NODE_OTHER).unwrap()));
+    assert!(import_wallet
+        .client_options()
+        .await
+        .node_manager_builder
+        .nodes
+        .contains(&node_dto));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "rocksdb")]
+#[tokio::test]
+async fn export_and_import_between_wallets() -> Result<()> {
+    use std::str::FromStr;
+
+    let storage_path = "test-storage/export_and_import_between_wallets";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(&format!("{storage_path}/1"), NODE_LOCAL, SHIMMER_COIN_TYPE).await?;
+    wallet.create_account().with_alias("Alice").finish().await?;
+    let bob = wallet.create_account().with_alias("Bob").finish().await?;
+    let bob_address = bob.addresses().await?[0].address().clone();
+    wallet
+        .set_address_book_entry(iota_sdk::wallet::core::AddressBookEntry::new(
+            bob_address.clone(),
+            "Bob",
+        )?)
+        .await?;
+    let nft_id = iota_sdk::types::block::output::NftId::from_str(
+        "0x0000000000000000000000000000000000000000000000000000000000000001",
+    )?;
+    wallet.set_nft_label(nft_id, Some("Ticket".to_owned())).await?;
+
+    let export = wallet.export_data().await?;
+    assert_eq!(export.accounts.len(), 2);
+
+    let import_wallet = make_wallet(&format!("{storage_path}/2"), NODE_LOCAL, SHIMMER_COIN_TYPE).await?;
+    import_wallet.import_data(export).await?;
+    drop(import_wallet);
+
+    // The imported data is stored
+    let import_wallet = make_wallet(&format!("{storage_path}/2"), NODE_LOCAL, SHIMMER_COIN_TYPE).await?;
+    let accounts = import_wallet.get_accounts().await?;
+    assert_eq!(accounts.len(), 2);
+    assert_eq!(accounts[0].alias().await, "Alice");
+    assert_eq!(accounts[1].alias().await, "Bob");
+    assert_eq!(
+        import_wallet.get_address_book_entry(bob_address).await?.unwrap().label,
+        "Bob"
+    );
+    assert_eq!(import_wallet.nft_labels().await.get(&nft_id).unwrap(), "Ticket");
+
+    tear_down(storage_path)
+}
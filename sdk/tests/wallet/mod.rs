@@ -19,6 +19,8 @@ mod core;
 mod error;
 #[cfg(feature = "events")]
 mod events;
+#[cfg(feature = "storage")]
+mod export_import;
 #[cfg(feature = "stronghold")]
 mod migrate_stronghold_snapshot_v2_to_v3;
 mod native_tokens;
@@ -28,3 +30,5 @@ mod transactions;
 #[cfg(not(target_os = "windows"))]
 #[cfg(feature = "rocksdb")]
 mod wallet_storage;
+#[cfg(feature = "webhooks")]
+mod webhooks;
@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{collections::HashMap, time::Duration};
+
 use iota_sdk::{
     types::block::output::{
         unlock_condition::{
@@ -9,7 +11,7 @@ use iota_sdk::{
         },
         AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, UnlockCondition,
     },
-    wallet::{account::SyncOptions, Result},
+    wallet::{account::SyncOptions, core::AccountSyncSchedule, Result},
 };
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
@@ -233,3 +235,165 @@ async fn background_syncing() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn background_syncing_schedules() -> Result<()> {
+    let storage_path = "test-storage/background_syncing_schedules";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    wallet.create_account().finish().await?;
+    wallet.create_account().finish().await?;
+
+    wallet
+        .set_account_sync_schedule(1, AccountSyncSchedule::new().with_interval(Duration::from_secs(60)))
+        .await;
+    wallet
+        .start_background_syncing(None, Some(Duration::from_secs(1)))
+        .await?;
+
+    let mut statuses = HashMap::new();
+    for _ in 0..30 {
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        statuses = wallet.account_sync_statuses().await;
+        if statuses.len() == 2 && statuses.values().all(|status| status.last_synced_at.is_some()) {
+            break;
+        }
+    }
+    wallet.stop_background_syncing().await?;
+
+    for (account_index, interval) in [(0, 1_000), (1, 60_000)] {
+        let status = &statuses[&account_index];
+        assert_eq!(status.last_error, None);
+        assert_eq!(status.next_sync_at.unwrap() - status.last_synced_at.unwrap(), interval);
+    }
+    assert!(
+        wallet
+            .account_sync_statuses()
+            .await
+            .values()
+            .all(|status| !status.syncing)
+    );
+
+    // The status of a removed account is removed as well
+    wallet.remove_latest_account().await?;
+    assert!(!wallet.account_sync_statuses().await.contains_key(&1));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn incremental_sync() -> Result<()> {
+    let storage_path = "test-storage/incremental_sync";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // The first sync stores the cursors
+    account_1.sync(None).await?;
+    assert!(!account_1.sync_cursors().await.is_empty());
+
+    let account_1_address = *account_1.addresses().await?[0].address().as_ref();
+    let token_supply = account_0.client().get_token_supply().await?;
+    let tx = account_0
+        .send_outputs(
+            [BasicOutputBuilder::new_with_amount(1_000_000)
+                .with_unlock_conditions([AddressUnlockCondition::new(account_1_address)])
+                .finish_output(token_supply)?],
+            None,
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let incremental = SyncOptions {
+        incremental: true,
+        force_syncing: true,
+        ..Default::default()
+    };
+    // New outputs are found
+    let balance = account_1.sync(Some(incremental.clone())).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    // Known unspent outputs are kept
+    let balance = account_1.sync(Some(incremental.clone())).await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+
+    // Spent outputs are removed
+    let tx = account_1
+        .send_outputs(
+            [BasicOutputBuilder::new_with_amount(1_000_000)
+                .with_unlock_conditions([AddressUnlockCondition::new(
+                    *account_0.addresses().await?[0].address().as_ref(),
+                )])
+                .finish_output(token_supply)?],
+            None,
+        )
+        .await?;
+    account_1
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    let balance = account_1.sync(Some(incremental)).await?;
+    assert_eq!(balance.base_coin().total(), 0);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn address_gap_limit() -> Result<()> {
+    let storage_path_0 = "test-storage/address_gap_limit_0";
+    let storage_path_1 = "test-storage/address_gap_limit_1";
+    setup(storage_path_0)?;
+    setup(storage_path_1)?;
+
+    let mnemonic = iota_sdk::client::utils::generate_mnemonic()?;
+    // Create two wallets with the same mnemonic
+    let wallet_0 = make_wallet(storage_path_0, Some(mnemonic.clone()), None).await?;
+    let wallet_0_account = &create_accounts_with_funds(&wallet_0, 1).await?[0];
+    let wallet_1 = make_wallet(storage_path_1, Some(mnemonic), None).await?;
+    let wallet_1_account = wallet_1.create_account().finish().await?;
+
+    // Send to an address the second wallet doesn't know
+    let address = wallet_0_account.generate_ed25519_addresses(3, None).await?[2].clone();
+    assert_eq!(address.key_index(), &3);
+    let token_supply = wallet_0_account.client().get_token_supply().await?;
+    let tx = wallet_0_account
+        .send_outputs(
+            [BasicOutputBuilder::new_with_amount(1_000_000)
+                .with_unlock_conditions([AddressUnlockCondition::new(*address.address().as_ref())])
+                .finish_output(token_supply)?],
+            None,
+        )
+        .await?;
+    wallet_0_account
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    let balance = wallet_0_account.sync(None).await?;
+
+    assert_ne!(balance, wallet_1_account.sync(None).await?);
+    let discovered_balance = wallet_1_account
+        .sync(Some(SyncOptions {
+            address_gap_limit: 5,
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin(), discovered_balance.base_coin());
+    // 5 unused addresses follow the one with the output
+    let public_addresses = wallet_1_account
+        .addresses()
+        .await?
+        .into_iter()
+        .filter(|address| !address.internal())
+        .count();
+    assert_eq!(public_addresses, 4 + 5);
+
+    tear_down(storage_path_0)?;
+    tear_down(storage_path_1)
+}
@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendNftParams, SendParams};
+use iota_sdk::wallet::{
+    account::{SpendingPolicy, SyncOptions, TransactionDirection, TransactionHistoryOptions, TransactionOptions},
+    Error, MintNftParams, Result, SendNftParams, SendParams,
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -304,3 +307,189 @@ async fn prepare_transaction_ledger() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn transaction_history() -> Result<()> {
+    let storage_path = "test-storage/transaction_history";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let account_0_address = *account_0.addresses().await?[0].address();
+    let account_1_address = *account_1.addresses().await?[0].address();
+
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_with_params(
+            [SendParams::new(amount, account_1_address)?],
+            TransactionOptions {
+                note: Some("invoice 42".to_owned()),
+                ..Default::default()
+            },
+        )
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_1
+        .sync(Some(SyncOptions {
+            sync_incoming_transactions: true,
+            ..Default::default()
+        }))
+        .await?;
+
+    let sent = account_0
+        .transaction_history(None)
+        .await?
+        .into_iter()
+        .find(|entry| entry.transaction_id == tx.transaction_id)
+        .unwrap();
+    assert_eq!(sent.direction, TransactionDirection::Outgoing);
+    assert_eq!(sent.counterparties, [account_1_address]);
+    assert_eq!(sent.base_coin_sent, amount);
+    assert_eq!(sent.note.as_deref(), Some("invoice 42"));
+
+    let history = account_1.transaction_history(None).await?;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].direction, TransactionDirection::Incoming);
+    assert_eq!(history[0].counterparties, [account_0_address]);
+    assert_eq!(history[0].base_coin_received, amount);
+
+    let before_tx = account_1
+        .transaction_history(TransactionHistoryOptions {
+            upper_bound_timestamp: Some(history[0].timestamp - 1),
+            ..Default::default()
+        })
+        .await?;
+    assert!(before_tx.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn sign_with_spending_policy() -> Result<()> {
+    let storage_path = "test-storage/sign_with_spending_policy";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let params = [SendParams::new(amount, *account_1.addresses().await?[0].address())?];
+    let prepared = account_0.prepare_send(params, None).await?;
+
+    // A transaction prepared before the policy was set can't be signed
+    account_0
+        .set_spending_policy(SpendingPolicy {
+            max_amount_per_transaction: Some(amount - 1),
+            ..Default::default()
+        })
+        .await?;
+    assert!(matches!(
+        account_0.sign_transaction_essence(&prepared, None).await,
+        Err(Error::SpendingPolicyViolated(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_with_approval() -> Result<()> {
+    let storage_path = "test-storage/send_with_approval";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    account_0
+        .set_spending_policy(SpendingPolicy {
+            require_approval: true,
+            ..Default::default()
+        })
+        .await?;
+
+    let amount = 1_000_000;
+    let params = [SendParams::new(amount, *account_1.addresses().await?[0].address())?];
+
+    // Rejecting unlocks the inputs
+    let Err(Error::ApprovalRequired(pending_approval)) = account_0.send_with_params(params.clone(), None).await else {
+        panic!("expected a pending approval");
+    };
+    assert!(account_0.get_pending_approval(&pending_approval.id).await.is_some());
+    assert_eq!(pending_approval.creator, account_0.alias().await);
+    assert!(!account_0.details().await.locked_outputs().is_empty());
+    account_0.reject_pending_approval(&pending_approval.id).await?;
+    assert!(account_0.pending_approvals().await.is_empty());
+    assert!(account_0.details().await.locked_outputs().is_empty());
+
+    // Approving signs and submits the transaction
+    let prepared = account_0.prepare_send(params, None).await?;
+    let pending_approval = account_0
+        .request_approval(prepared, None, "operator", "payout #7".to_owned())
+        .await?;
+    assert_eq!(account_0.pending_approvals().await.len(), 1);
+    // The creator can't approve it
+    assert!(matches!(
+        account_0
+            .approve_pending_approval(&pending_approval.id, "operator")
+            .await,
+        Err(Error::ApproverIsCreator { .. })
+    ));
+    let tx = account_0
+        .approve_pending_approval(&pending_approval.id, "supervisor")
+        .await?;
+    assert_eq!(tx.approver.as_deref(), Some("supervisor"));
+    assert!(account_0.pending_approvals().await.is_empty());
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), amount);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn pending_approval_inputs_stay_locked_after_restart() -> Result<()> {
+    let storage_path = "test-storage/pending_approval_inputs_stay_locked_after_restart";
+    setup(storage_path)?;
+
+    let mnemonic = iota_sdk::client::Client::generate_mnemonic()?;
+    let wallet = make_wallet(storage_path, Some(mnemonic.clone()), None).await?;
+
+    let accounts = create_accounts_with_funds(&wallet, 1).await?;
+    let account_1 = wallet.create_account().finish().await?;
+
+    let prepared = accounts[0]
+        .prepare_send(
+            [SendParams::new(1_000_000, *account_1.addresses().await?[0].address())?],
+            None,
+        )
+        .await?;
+    let pending_approval = accounts[0].request_approval(prepared, None, "operator", None).await?;
+    let locked_outputs = accounts[0].details().await.locked_outputs().clone();
+    assert!(!locked_outputs.is_empty());
+
+    // The inputs of the pending approval are still locked after rebuilding the wallet from the same storage
+    drop(accounts);
+    drop(account_1);
+    drop(wallet);
+    let wallet = make_wallet(storage_path, Some(mnemonic), None).await?;
+    let account_0 = wallet.get_account(0).await?;
+    assert!(account_0.get_pending_approval(&pending_approval.id).await.is_some());
+    assert_eq!(account_0.details().await.locked_outputs(), &locked_outputs);
+
+    tear_down(storage_path)
+}
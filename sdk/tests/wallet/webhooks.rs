@@ -0,0 +1,138 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, time::Duration};
+
+use iota_sdk::wallet::{
+    events::{
+        types::{Event, TransactionProgressEvent, WalletEvent, WalletEventType},
+        webhook::{
+            webhook_signature, WebhookEndpoint, WebhookOptions, WEBHOOK_ID_HEADER, WEBHOOK_SIGNATURE_HEADER,
+            WEBHOOK_TIMESTAMP_HEADER,
+        },
+        EventFilter,
+    },
+    Result,
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    sync::mpsc::UnboundedSender,
+};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+struct Request {
+    headers: HashMap<String, String>,
+    body: Vec<u8>,
+}
+
+/// Answers one request per status and forwards the requests.
+async fn serve(listener: TcpListener, statuses: Vec<u16>, sender: UnboundedSender<Request>) {
+    for status in statuses {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut data = Vec::new();
+        let mut buffer = [0; 1024];
+        let (header_end, content_length) = loop {
+            let read = stream.read(&mut buffer).await.unwrap();
+            data.extend_from_slice(&buffer[..read]);
+            if let Some(position) = data.windows(4).position(|window| window == b"\r\n\r\n") {
+                let head = String::from_utf8_lossy(&data[..position]).to_lowercase();
+                let content_length = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("content-length:"))
+                    .map_or(0, |length| length.trim().parse::<usize>().unwrap());
+                break (position + 4, content_length);
+            }
+        };
+        while data.len() < header_end + content_length {
+            let read = stream.read(&mut buffer).await.unwrap();
+            data.extend_from_slice(&buffer[..read]);
+        }
+
+        let headers = String::from_utf8_lossy(&data[..header_end])
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.split_once(':'))
+            .map(|(name, value)| (name.trim().to_lowercase(), value.trim().to_string()))
+            .collect();
+        sender
+            .send(Request {
+                headers,
+                body: data[header_end..header_end + content_length].to_vec(),
+            })
+            .unwrap();
+
+        stream
+            .write_all(format!("HTTP/1.1 {status} Status\r\ncontent-length: 0\r\nconnection: close\r\n\r\n").as_bytes())
+            .await
+            .unwrap();
+    }
+}
+
+#[tokio::test]
+async fn webhook_delivery() -> Result<()> {
+    let storage_path = "test-storage/webhook_delivery";
+    setup(storage_path)?;
+
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let url = format!("http://{}/events", listener.local_addr()?).parse().unwrap();
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    // The first attempt fails and is retried
+    tokio::spawn(serve(listener, vec![500, 200], sender));
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    wallet
+        .set_webhooks(
+            WebhookOptions::new([WebhookEndpoint::new(url, "secret")
+                .with_filter(EventFilter::new().with_event_types([WalletEventType::ConsolidationRequired]))])
+            .with_retry_delay(Duration::from_millis(10), Duration::from_millis(10)),
+        )
+        .await?;
+
+    // Filtered out
+    wallet
+        .emit_test_event(WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting))
+        .await;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+
+    let mut delivery_ids = Vec::new();
+    for _ in 0..2 {
+        let request = tokio::time::timeout(Duration::from_secs(10), receiver.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let timestamp = request.headers[&WEBHOOK_TIMESTAMP_HEADER.to_lowercase()]
+            .parse()
+            .unwrap();
+        assert_eq!(
+            request.headers[&WEBHOOK_SIGNATURE_HEADER.to_lowercase()],
+            webhook_signature("secret", timestamp, &request.body)
+        );
+        assert_eq!(
+            serde_json::from_slice::<Event>(&request.body)?,
+            Event {
+                account_index: 0,
+                event: WalletEvent::ConsolidationRequired,
+            }
+        );
+        delivery_ids.push(request.headers[&WEBHOOK_ID_HEADER.to_lowercase()].clone());
+    }
+    // The retry is the same delivery
+    assert_eq!(delivery_ids[0], delivery_ids[1]);
+
+    // Removed from the outbox after the successful delivery
+    tokio::time::timeout(Duration::from_secs(10), async {
+        while !wallet.pending_webhook_deliveries().await.is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .unwrap();
+
+    wallet.disable_webhooks().await?;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+    assert!(wallet.pending_webhook_deliveries().await.is_empty());
+
+    tear_down(storage_path)
+}